@@ -178,6 +178,32 @@ pub enum AuditEvent {
         name: String,
     },
 
+    // Orchestrator download scheduling events
+    /// A running download was paused to free a slot for a higher-priority ticket.
+    DownloadPreempted {
+        /// Ticket whose download was paused
+        ticket_id: String,
+        /// Info hash of the paused torrent
+        info_hash: String,
+        /// Priority of the paused ticket
+        priority: u16,
+        /// Ticket that took the slot
+        preempted_by: String,
+        /// Priority of the ticket that took the slot
+        preempted_by_priority: u16,
+        /// Download progress when paused (0-100)
+        progress_pct: f32,
+    },
+    /// A previously preempted download was resumed.
+    DownloadResumed {
+        /// Ticket whose download was resumed
+        ticket_id: String,
+        /// Info hash of the resumed torrent
+        info_hash: String,
+        /// How long the download was paused, in seconds
+        paused_secs: u64,
+    },
+
     // TextBrain events
     /// Acquisition process started for a ticket.
     AcquisitionStarted {
@@ -263,7 +289,7 @@ pub enum AuditEvent {
     LlmCallStarted {
         /// Associated ticket (if any)
         ticket_id: Option<String>,
-        /// Purpose of the call: "query_building" or "scoring"
+        /// Purpose of the call: "query_building", "scoring" or "file_mapping"
         purpose: String,
         /// LLM provider being used
         provider: String,
@@ -283,6 +309,9 @@ pub enum AuditEvent {
         output_tokens: u32,
         /// Duration in milliseconds
         duration_ms: u64,
+        /// Version of the prompt template used (if rendered from one)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        prompt_version: Option<String>,
     },
 
     /// LLM call failed.
@@ -667,6 +696,8 @@ impl AuditEvent {
             Self::TorrentResumed { .. } => "torrent_resumed",
             Self::TorrentLimitChanged { .. } => "torrent_limit_changed",
             Self::TorrentRechecked { .. } => "torrent_rechecked",
+            Self::DownloadPreempted { .. } => "download_preempted",
+            Self::DownloadResumed { .. } => "download_resumed",
             // Acquisition events
             Self::AcquisitionStarted { .. } => "acquisition_started",
             Self::QueryBuildingStarted { .. } => "query_building_started",
@@ -710,6 +741,8 @@ impl AuditEvent {
             | Self::TicketStateChanged { ticket_id, .. }
             | Self::TicketCancelled { ticket_id, .. }
             | Self::TicketDeleted { ticket_id, .. }
+            | Self::DownloadPreempted { ticket_id, .. }
+            | Self::DownloadResumed { ticket_id, .. }
             // Acquisition events
             | Self::AcquisitionStarted { ticket_id, .. }
             | Self::QueryBuildingStarted { ticket_id, .. }
@@ -769,6 +802,25 @@ impl AuditEvent {
             _ => None,
         }
     }
+
+    /// Outcome of a ticket for training purposes, if this event ends an attempt.
+    ///
+    /// Returns `(ticket_id, success)` for transitions into completed (success)
+    /// or failed, rejected and acquisition_failed (failure).
+    pub fn training_outcome(&self) -> Option<(&str, bool)> {
+        match self {
+            Self::TicketStateChanged {
+                ticket_id,
+                to_state,
+                ..
+            } => match to_state.as_str() {
+                "completed" => Some((ticket_id, true)),
+                "failed" | "rejected" | "acquisition_failed" => Some((ticket_id, false)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
 }
 
 /// A stored audit record with metadata
@@ -1068,6 +1120,32 @@ mod tests {
         assert_eq!(event.user_id(), Some("user-1"));
     }
 
+    #[test]
+    fn test_event_type_download_preempted() {
+        let event = AuditEvent::DownloadPreempted {
+            ticket_id: "t-low".to_string(),
+            info_hash: "abc123".to_string(),
+            priority: 10,
+            preempted_by: "t-high".to_string(),
+            preempted_by_priority: 200,
+            progress_pct: 42.0,
+        };
+        assert_eq!(event.event_type(), "download_preempted");
+        assert_eq!(event.ticket_id(), Some("t-low"));
+        assert_eq!(event.user_id(), None);
+    }
+
+    #[test]
+    fn test_event_type_download_resumed() {
+        let event = AuditEvent::DownloadResumed {
+            ticket_id: "t-low".to_string(),
+            info_hash: "abc123".to_string(),
+            paused_secs: 120,
+        };
+        assert_eq!(event.event_type(), "download_resumed");
+        assert_eq!(event.ticket_id(), Some("t-low"));
+    }
+
     #[test]
     fn test_serialize_deserialize_torrent_added() {
         let event = AuditEvent::TorrentAdded {
@@ -204,6 +204,55 @@ impl AuditStore for SqliteAuditStore {
 
         Ok(count)
     }
+
+    fn backfill_training_outcome(
+        &self,
+        ticket_id: &str,
+        success: bool,
+    ) -> Result<usize, AuditError> {
+        let conn = self.conn.lock().unwrap();
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, data FROM audit_events WHERE ticket_id = ? AND event_type = 'training_query_context'",
+            )
+            .map_err(|e| AuditError::Database(e.to_string()))?;
+
+        let rows = stmt
+            .query_map(params![ticket_id], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(|e| AuditError::Database(e.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AuditError::Database(e.to_string()))?;
+
+        let mut updated = 0;
+        for (id, data_json) in rows {
+            let mut data: AuditEvent = serde_json::from_str(&data_json)
+                .map_err(|e| AuditError::Serialization(e.to_string()))?;
+
+            if let AuditEvent::TrainingQueryContext {
+                success: ref mut outcome @ None,
+                ..
+            } = data
+            {
+                *outcome = Some(success);
+            } else {
+                continue;
+            }
+
+            let data_json = serde_json::to_string(&data)
+                .map_err(|e| AuditError::Serialization(e.to_string()))?;
+            conn.execute(
+                "UPDATE audit_events SET data = ? WHERE id = ?",
+                params![data_json, id],
+            )
+            .map_err(|e| AuditError::Database(e.to_string()))?;
+            updated += 1;
+        }
+
+        Ok(updated)
+    }
 }
 
 #[cfg(test)]
@@ -394,4 +443,65 @@ mod tests {
         let results = store.query(&AuditFilter::new()).unwrap();
         assert_eq!(results.len(), 1);
     }
+
+    fn create_query_training_record(ticket_id: &str, success: Option<bool>) -> AuditRecord {
+        AuditRecord {
+            id: 0,
+            timestamp: Utc::now(),
+            event_type: "training_query_context".to_string(),
+            ticket_id: Some(ticket_id.to_string()),
+            user_id: None,
+            data: AuditEvent::TrainingQueryContext {
+                sample_id: "sample".to_string(),
+                ticket_id: ticket_id.to_string(),
+                input_tags: vec![],
+                input_description: "test".to_string(),
+                input_expected: None,
+                output_queries: vec!["query".to_string()],
+                method: "dumb".to_string(),
+                confidence: 0.5,
+                success,
+            },
+        }
+    }
+
+    #[test]
+    fn test_backfill_training_outcome() {
+        let store = create_test_store();
+        store
+            .insert(&create_query_training_record("ticket-1", None))
+            .unwrap();
+        store
+            .insert(&create_query_training_record("ticket-1", Some(false)))
+            .unwrap();
+        store
+            .insert(&create_query_training_record("ticket-2", None))
+            .unwrap();
+        store
+            .insert(&create_ticket_created_record("ticket-1", "user"))
+            .unwrap();
+
+        let updated = store.backfill_training_outcome("ticket-1", true).unwrap();
+        assert_eq!(updated, 1);
+
+        let outcomes: Vec<(Option<String>, Option<bool>)> = store
+            .query(&AuditFilter::new().with_event_type("training_query_context"))
+            .unwrap()
+            .into_iter()
+            .map(|r| match r.data {
+                AuditEvent::TrainingQueryContext { success, .. } => (r.ticket_id, success),
+                _ => unreachable!(),
+            })
+            .collect();
+
+        // Earlier outcomes are kept, other tickets are untouched
+        let ticket_1: Vec<_> = outcomes
+            .iter()
+            .filter(|(id, _)| id.as_deref() == Some("ticket-1"))
+            .map(|(_, s)| *s)
+            .collect();
+        assert!(ticket_1.contains(&Some(true)));
+        assert!(ticket_1.contains(&Some(false)));
+        assert!(outcomes.contains(&(Some("ticket-2".to_string()), None)));
+    }
 }
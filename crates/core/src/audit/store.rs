@@ -79,4 +79,38 @@ pub trait AuditStore: Send + Sync {
 
     /// Count matching audit records
     fn count(&self, filter: &AuditFilter) -> Result<i64, AuditError>;
+
+    /// Get every record of an event type, oldest first.
+    fn query_all(&self, event_type: &str) -> Result<Vec<AuditRecord>, AuditError> {
+        const PAGE_SIZE: i64 = 500;
+
+        let mut records = Vec::new();
+        let mut offset = 0;
+        loop {
+            let filter = AuditFilter::new()
+                .with_event_type(event_type)
+                .with_limit(PAGE_SIZE)
+                .with_offset(offset);
+            let page = self.query(&filter)?;
+            let page_len = page.len() as i64;
+            records.extend(page);
+            if page_len < PAGE_SIZE {
+                break;
+            }
+            offset += PAGE_SIZE;
+        }
+
+        // Records come back newest first
+        records.sort_by_key(|r| r.id);
+        Ok(records)
+    }
+
+    /// Fill in the outcome of a ticket's query training samples that don't have one yet.
+    ///
+    /// Returns the number of samples updated.
+    fn backfill_training_outcome(
+        &self,
+        ticket_id: &str,
+        success: bool,
+    ) -> Result<usize, AuditError>;
 }
@@ -35,6 +35,13 @@ impl AuditWriter {
             if let Err(e) = self.store.insert(&record) {
                 tracing::error!("Failed to write audit event: {}", e);
             }
+
+            // Once a ticket's attempt ends, record whether its queries paid off
+            if let Some((ticket_id, success)) = record.data.training_outcome() {
+                if let Err(e) = self.store.backfill_training_outcome(ticket_id, success) {
+                    tracing::error!("Failed to back-fill training outcome: {}", e);
+                }
+            }
         }
 
         tracing::info!("Audit writer shutting down");
@@ -113,6 +120,14 @@ mod tests {
         fn count(&self, _filter: &AuditFilter) -> Result<i64, AuditError> {
             Ok(self.records.lock().unwrap().len() as i64)
         }
+
+        fn backfill_training_outcome(
+            &self,
+            _ticket_id: &str,
+            _success: bool,
+        ) -> Result<usize, AuditError> {
+            Ok(0)
+        }
     }
 
     #[tokio::test]
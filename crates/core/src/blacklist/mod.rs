@@ -0,0 +1,33 @@
+//! Candidate blacklist - info hashes, title patterns and release groups that
+//! must never be picked again.
+//!
+//! Entries are either global or scoped to a single ticket. Scoring consults
+//! the compiled [`Blacklist`] for the ticket being acquired.
+
+mod sqlite;
+mod types;
+
+pub use sqlite::SqliteBlacklistStore;
+pub use types::*;
+
+/// Trait for blacklist storage.
+pub trait BlacklistStore: Send + Sync {
+    /// Add an entry. Adding an identical rule again returns the existing entry.
+    fn add(&self, request: CreateBlacklistEntry) -> Result<BlacklistEntry, BlacklistError>;
+
+    /// List entries matching the filter.
+    fn list(&self, filter: &BlacklistFilter) -> Result<Vec<BlacklistEntry>, BlacklistError>;
+
+    /// Get the global entries plus those scoped to the given ticket.
+    fn entries_for_ticket(&self, ticket_id: &str) -> Result<Vec<BlacklistEntry>, BlacklistError>;
+
+    /// Remove an entry by ID, returning it.
+    fn remove(&self, id: &str) -> Result<BlacklistEntry, BlacklistError>;
+
+    /// Compile the rules that apply to a ticket.
+    fn blacklist_for_ticket(&self, ticket_id: &str) -> Result<Blacklist, BlacklistError> {
+        Ok(Blacklist::from_entries(
+            &self.entries_for_ticket(ticket_id)?,
+        ))
+    }
+}
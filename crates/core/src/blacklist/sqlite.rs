@@ -0,0 +1,340 @@
+//! SQLite-backed blacklist store implementation.
+
+use std::path::Path;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection};
+
+use super::{
+    compile_title_regex, BlacklistEntry, BlacklistError, BlacklistFilter, BlacklistKind,
+    BlacklistStore, CreateBlacklistEntry,
+};
+
+/// SQLite-backed blacklist store.
+pub struct SqliteBlacklistStore {
+    conn: Mutex<Connection>,
+}
+
+impl SqliteBlacklistStore {
+    /// Create a new SQLite blacklist store, creating the database file and tables if needed.
+    pub fn new(path: &Path) -> Result<Self, BlacklistError> {
+        let conn = Connection::open(path).map_err(|e| BlacklistError::Database(e.to_string()))?;
+        Self::initialize_schema(&conn)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Create an in-memory SQLite blacklist store (useful for testing).
+    pub fn in_memory() -> Result<Self, BlacklistError> {
+        let conn =
+            Connection::open_in_memory().map_err(|e| BlacklistError::Database(e.to_string()))?;
+        Self::initialize_schema(&conn)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    fn initialize_schema(conn: &Connection) -> Result<(), BlacklistError> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS blacklist (
+                id TEXT PRIMARY KEY,
+                kind TEXT NOT NULL,
+                value TEXT NOT NULL,
+                ticket_id TEXT,
+                reason TEXT,
+                created_by TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_blacklist_ticket_id ON blacklist(ticket_id);
+            "#,
+        )
+        .map_err(|e| BlacklistError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    fn row_to_entry(row: &rusqlite::Row) -> rusqlite::Result<BlacklistEntry> {
+        let kind_str: String = row.get(1)?;
+        let created_at_str: String = row.get(6)?;
+
+        let created_at = DateTime::parse_from_rfc3339(&created_at_str)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+
+        Ok(BlacklistEntry {
+            id: row.get(0)?,
+            kind: BlacklistKind::parse(&kind_str).unwrap_or(BlacklistKind::InfoHash),
+            value: row.get(2)?,
+            ticket_id: row.get(3)?,
+            reason: row.get(4)?,
+            created_by: row.get(5)?,
+            created_at,
+        })
+    }
+
+    /// Normalize and validate an entry value.
+    fn normalize_value(kind: BlacklistKind, value: &str) -> Result<String, BlacklistError> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(BlacklistError::Invalid(
+                "value must not be empty".to_string(),
+            ));
+        }
+
+        match kind {
+            BlacklistKind::InfoHash => Ok(value.to_lowercase()),
+            BlacklistKind::TitleRegex => {
+                compile_title_regex(value)?;
+                Ok(value.to_string())
+            }
+            BlacklistKind::ReleaseGroup => Ok(value.to_string()),
+        }
+    }
+}
+
+impl BlacklistStore for SqliteBlacklistStore {
+    fn add(&self, request: CreateBlacklistEntry) -> Result<BlacklistEntry, BlacklistError> {
+        let value = Self::normalize_value(request.kind, &request.value)?;
+        let conn = self.conn.lock().unwrap();
+
+        // Adding the same rule twice returns the existing entry
+        let existing = conn.query_row(
+            "SELECT id, kind, value, ticket_id, reason, created_by, created_at FROM blacklist WHERE kind = ? AND value = ? AND ticket_id IS ?",
+            params![request.kind.as_str(), value, request.ticket_id],
+            Self::row_to_entry,
+        );
+        match existing {
+            Ok(entry) => return Ok(entry),
+            Err(rusqlite::Error::QueryReturnedNoRows) => {}
+            Err(e) => return Err(BlacklistError::Database(e.to_string())),
+        }
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let now = Utc::now();
+
+        conn.execute(
+            "INSERT INTO blacklist (id, kind, value, ticket_id, reason, created_by, created_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+            params![
+                id,
+                request.kind.as_str(),
+                value,
+                request.ticket_id,
+                request.reason,
+                request.created_by,
+                now.to_rfc3339(),
+            ],
+        )
+        .map_err(|e| BlacklistError::Database(e.to_string()))?;
+
+        Ok(BlacklistEntry {
+            id,
+            kind: request.kind,
+            value,
+            ticket_id: request.ticket_id,
+            reason: request.reason,
+            created_by: request.created_by,
+            created_at: now,
+        })
+    }
+
+    fn list(&self, filter: &BlacklistFilter) -> Result<Vec<BlacklistEntry>, BlacklistError> {
+        let conn = self.conn.lock().unwrap();
+
+        let mut conditions = Vec::new();
+        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+
+        if filter.global_only {
+            conditions.push("ticket_id IS NULL");
+        } else if let Some(ref ticket_id) = filter.ticket_id {
+            conditions.push("ticket_id = ?");
+            params.push(Box::new(ticket_id.clone()));
+        }
+
+        if let Some(kind) = filter.kind {
+            conditions.push("kind = ?");
+            params.push(Box::new(kind.as_str()));
+        }
+
+        let where_clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+
+        let query = format!(
+            "SELECT id, kind, value, ticket_id, reason, created_by, created_at FROM blacklist {} ORDER BY created_at ASC",
+            where_clause
+        );
+
+        let mut stmt = conn
+            .prepare(&query)
+            .map_err(|e| BlacklistError::Database(e.to_string()))?;
+
+        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+
+        let entries = stmt
+            .query_map(params_refs.as_slice(), Self::row_to_entry)
+            .map_err(|e| BlacklistError::Database(e.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| BlacklistError::Database(e.to_string()))?;
+
+        Ok(entries)
+    }
+
+    fn entries_for_ticket(&self, ticket_id: &str) -> Result<Vec<BlacklistEntry>, BlacklistError> {
+        let conn = self.conn.lock().unwrap();
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, kind, value, ticket_id, reason, created_by, created_at FROM blacklist WHERE ticket_id IS NULL OR ticket_id = ? ORDER BY created_at ASC",
+            )
+            .map_err(|e| BlacklistError::Database(e.to_string()))?;
+
+        let entries = stmt
+            .query_map(params![ticket_id], Self::row_to_entry)
+            .map_err(|e| BlacklistError::Database(e.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| BlacklistError::Database(e.to_string()))?;
+
+        Ok(entries)
+    }
+
+    fn remove(&self, id: &str) -> Result<BlacklistEntry, BlacklistError> {
+        let conn = self.conn.lock().unwrap();
+
+        let entry = conn.query_row(
+            "SELECT id, kind, value, ticket_id, reason, created_by, created_at FROM blacklist WHERE id = ?",
+            params![id],
+            Self::row_to_entry,
+        );
+
+        let entry = match entry {
+            Ok(e) => e,
+            Err(rusqlite::Error::QueryReturnedNoRows) => {
+                return Err(BlacklistError::NotFound(id.to_string()));
+            }
+            Err(e) => return Err(BlacklistError::Database(e.to_string())),
+        };
+
+        conn.execute("DELETE FROM blacklist WHERE id = ?", params![id])
+            .map_err(|e| BlacklistError::Database(e.to_string()))?;
+
+        Ok(entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_store() -> SqliteBlacklistStore {
+        SqliteBlacklistStore::in_memory().unwrap()
+    }
+
+    fn request(kind: BlacklistKind, value: &str, ticket_id: Option<&str>) -> CreateBlacklistEntry {
+        CreateBlacklistEntry {
+            kind,
+            value: value.to_string(),
+            ticket_id: ticket_id.map(String::from),
+            reason: None,
+            created_by: "test-user".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_add_and_list() {
+        let store = create_test_store();
+        store
+            .add(request(BlacklistKind::ReleaseGroup, "BadGroup", None))
+            .unwrap();
+        store
+            .add(request(BlacklistKind::InfoHash, "ABC123", Some("ticket-1")))
+            .unwrap();
+
+        let all = store.list(&BlacklistFilter::default()).unwrap();
+        assert_eq!(all.len(), 2);
+
+        let global = store
+            .list(&BlacklistFilter {
+                global_only: true,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(global.len(), 1);
+        assert_eq!(global[0].value, "BadGroup");
+
+        let ticket = store
+            .list(&BlacklistFilter {
+                ticket_id: Some("ticket-1".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(ticket.len(), 1);
+        assert_eq!(ticket[0].value, "abc123");
+    }
+
+    #[test]
+    fn test_entries_for_ticket_includes_global() {
+        let store = create_test_store();
+        store
+            .add(request(BlacklistKind::ReleaseGroup, "BadGroup", None))
+            .unwrap();
+        store
+            .add(request(BlacklistKind::InfoHash, "abc123", Some("ticket-1")))
+            .unwrap();
+        store
+            .add(request(BlacklistKind::InfoHash, "def456", Some("ticket-2")))
+            .unwrap();
+
+        let entries = store.entries_for_ticket("ticket-1").unwrap();
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|e| e.value != "def456"));
+    }
+
+    #[test]
+    fn test_add_duplicate_returns_existing() {
+        let store = create_test_store();
+        let first = store
+            .add(request(BlacklistKind::InfoHash, "abc123", Some("ticket-1")))
+            .unwrap();
+        let second = store
+            .add(request(BlacklistKind::InfoHash, "ABC123", Some("ticket-1")))
+            .unwrap();
+
+        assert_eq!(first.id, second.id);
+        assert_eq!(store.list(&BlacklistFilter::default()).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_add_rejects_invalid_values() {
+        let store = create_test_store();
+        assert!(matches!(
+            store.add(request(BlacklistKind::TitleRegex, "(unclosed", None)),
+            Err(BlacklistError::Invalid(_))
+        ));
+        assert!(matches!(
+            store.add(request(BlacklistKind::ReleaseGroup, "  ", None)),
+            Err(BlacklistError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_remove() {
+        let store = create_test_store();
+        let entry = store
+            .add(request(BlacklistKind::ReleaseGroup, "BadGroup", None))
+            .unwrap();
+
+        let removed = store.remove(&entry.id).unwrap();
+        assert_eq!(removed.id, entry.id);
+        assert!(store.list(&BlacklistFilter::default()).unwrap().is_empty());
+        assert!(matches!(
+            store.remove(&entry.id),
+            Err(BlacklistError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,309 @@
+//! Types for the candidate blacklist.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use regex_lite::Regex;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::searcher::TorrentCandidate;
+
+/// What a blacklist entry matches against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlacklistKind {
+    /// Exact info hash (case-insensitive).
+    InfoHash,
+    /// Regular expression matched against the torrent title (case-insensitive).
+    TitleRegex,
+    /// Release group parsed from the torrent title (case-insensitive).
+    ReleaseGroup,
+}
+
+impl BlacklistKind {
+    /// String representation used for storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BlacklistKind::InfoHash => "info_hash",
+            BlacklistKind::TitleRegex => "title_regex",
+            BlacklistKind::ReleaseGroup => "release_group",
+        }
+    }
+
+    /// Parse the storage representation.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "info_hash" => Some(BlacklistKind::InfoHash),
+            "title_regex" => Some(BlacklistKind::TitleRegex),
+            "release_group" => Some(BlacklistKind::ReleaseGroup),
+            _ => None,
+        }
+    }
+}
+
+/// A stored blacklist entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlacklistEntry {
+    /// Unique identifier (UUID).
+    pub id: String,
+    /// What this entry matches against.
+    pub kind: BlacklistKind,
+    /// Info hash, title regex or release group name.
+    pub value: String,
+    /// Ticket this entry applies to (None = applies to every ticket).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket_id: Option<String>,
+    /// Why the entry was added.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Who added the entry ("system" for automatic entries).
+    pub created_by: String,
+    /// When the entry was added.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Request to add a blacklist entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateBlacklistEntry {
+    pub kind: BlacklistKind,
+    pub value: String,
+    #[serde(default)]
+    pub ticket_id: Option<String>,
+    #[serde(default)]
+    pub reason: Option<String>,
+    pub created_by: String,
+}
+
+/// Filter for listing blacklist entries.
+#[derive(Debug, Clone, Default)]
+pub struct BlacklistFilter {
+    /// Only entries scoped to this ticket.
+    pub ticket_id: Option<String>,
+    /// Only global entries.
+    pub global_only: bool,
+    /// Only entries of this kind.
+    pub kind: Option<BlacklistKind>,
+}
+
+/// Errors for blacklist operations.
+#[derive(Debug, Error)]
+pub enum BlacklistError {
+    #[error("Database error: {0}")]
+    Database(String),
+
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    #[error("Invalid entry: {0}")]
+    Invalid(String),
+}
+
+/// Compiled blacklist rules, ready to check candidates against.
+///
+/// Built from the global entries plus those scoped to a single ticket.
+#[derive(Debug, Clone, Default)]
+pub struct Blacklist {
+    info_hashes: HashSet<String>,
+    title_patterns: Vec<Regex>,
+    release_groups: HashSet<String>,
+}
+
+impl Blacklist {
+    /// Compile a blacklist from stored entries. Invalid regexes are skipped.
+    pub fn from_entries(entries: &[BlacklistEntry]) -> Self {
+        let mut blacklist = Self::default();
+        for entry in entries {
+            match entry.kind {
+                BlacklistKind::InfoHash => {
+                    blacklist.info_hashes.insert(entry.value.to_lowercase());
+                }
+                BlacklistKind::TitleRegex => {
+                    if let Ok(re) = compile_title_regex(&entry.value) {
+                        blacklist.title_patterns.push(re);
+                    }
+                }
+                BlacklistKind::ReleaseGroup => {
+                    blacklist.release_groups.insert(entry.value.to_lowercase());
+                }
+            }
+        }
+        blacklist
+    }
+
+    /// Add info hashes to the blacklist.
+    pub fn with_info_hashes<I, S>(mut self, hashes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.info_hashes
+            .extend(hashes.into_iter().map(|h| h.as_ref().to_lowercase()));
+        self
+    }
+
+    /// Returns true if there are no rules.
+    pub fn is_empty(&self) -> bool {
+        self.info_hashes.is_empty()
+            && self.title_patterns.is_empty()
+            && self.release_groups.is_empty()
+    }
+
+    /// Check a candidate, returning why it is blacklisted (None if allowed).
+    pub fn check(&self, candidate: &TorrentCandidate) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+
+        if self
+            .info_hashes
+            .contains(&candidate.info_hash.to_lowercase())
+        {
+            return Some(format!("info hash {} is blacklisted", candidate.info_hash));
+        }
+
+        if let Some(group) = release_group(&candidate.title) {
+            if self.release_groups.contains(&group.to_lowercase()) {
+                return Some(format!("release group {} is blacklisted", group));
+            }
+        }
+
+        self.title_patterns
+            .iter()
+            .find(|re| re.is_match(&candidate.title))
+            .map(|re| format!("title matches blacklisted pattern {}", re.as_str()))
+    }
+}
+
+/// Compile a title regex the way the blacklist matches it (case-insensitive).
+pub fn compile_title_regex(pattern: &str) -> Result<Regex, BlacklistError> {
+    Regex::new(&format!("(?i){}", pattern))
+        .map_err(|e| BlacklistError::Invalid(format!("invalid title regex: {}", e)))
+}
+
+/// Extract the release group from a torrent title.
+///
+/// Handles scene-style suffixes (`Movie.2020.1080p.WEB-DL.x264-GROUP`) and
+/// anime-style prefixes (`[Group] Show - 01`).
+pub fn release_group(title: &str) -> Option<&str> {
+    let title = title.trim();
+
+    if let Some(rest) = title.strip_prefix('[') {
+        let group = rest.split(']').next()?.trim();
+        return (!group.is_empty()).then_some(group);
+    }
+
+    // Drop trailing tags like "[rartv]" or "(2019)" before looking for "-GROUP"
+    let mut name = title;
+    while let Some(stripped) = strip_trailing_tag(name) {
+        name = stripped;
+    }
+
+    let (before, group) = name.rsplit_once('-')?;
+    if before.ends_with(' ') || group.is_empty() {
+        return None;
+    }
+    group
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        .then_some(group)
+}
+
+/// Strip one trailing bracketed tag, e.g. `"Name [tag]"` -> `"Name"`.
+fn strip_trailing_tag(name: &str) -> Option<&str> {
+    let open = match name.chars().last()? {
+        ']' => '[',
+        ')' => '(',
+        _ => return None,
+    };
+    let idx = name.rfind(open)?;
+    Some(name[..idx].trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(kind: BlacklistKind, value: &str) -> BlacklistEntry {
+        BlacklistEntry {
+            id: "id".to_string(),
+            kind,
+            value: value.to_string(),
+            ticket_id: None,
+            reason: None,
+            created_by: "test".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn candidate(title: &str, info_hash: &str) -> TorrentCandidate {
+        TorrentCandidate {
+            title: title.to_string(),
+            info_hash: info_hash.to_string(),
+            size_bytes: 1024,
+            seeders: 10,
+            leechers: 0,
+            category: None,
+            publish_date: None,
+            files: None,
+            sources: vec![],
+            from_cache: false,
+        }
+    }
+
+    #[test]
+    fn test_release_group() {
+        assert_eq!(
+            release_group("Movie.2020.1080p.BluRay.x264-SPARKS"),
+            Some("SPARKS")
+        );
+        assert_eq!(
+            release_group("Movie.2020.1080p.WEB-DL.x264-NTb [rartv]"),
+            Some("NTb")
+        );
+        assert_eq!(
+            release_group("[SubsPlease] Show - 01 (1080p)"),
+            Some("SubsPlease")
+        );
+        assert_eq!(release_group("Pink Floyd - Dark Side of the Moon"), None);
+        assert_eq!(release_group("Abbey Road (2019 Remaster) [FLAC]"), None);
+    }
+
+    #[test]
+    fn test_check_matches_each_kind() {
+        let blacklist = Blacklist::from_entries(&[
+            entry(BlacklistKind::InfoHash, "ABC123"),
+            entry(BlacklistKind::TitleRegex, r"\bcam\b"),
+            entry(BlacklistKind::ReleaseGroup, "badgroup"),
+        ]);
+
+        assert!(blacklist
+            .check(&candidate("Anything", "abc123"))
+            .unwrap()
+            .contains("info hash"));
+        assert!(blacklist
+            .check(&candidate("Movie.2020.CAM.x264-OK", "h1"))
+            .unwrap()
+            .contains("pattern"));
+        assert!(blacklist
+            .check(&candidate("Movie.2020.1080p.x264-BadGroup", "h2"))
+            .unwrap()
+            .contains("release group BadGroup"));
+        assert!(blacklist
+            .check(&candidate("Movie.2020.1080p.x264-GoodGroup", "h3"))
+            .is_none());
+    }
+
+    #[test]
+    fn test_invalid_regex_is_skipped() {
+        let blacklist = Blacklist::from_entries(&[entry(BlacklistKind::TitleRegex, "(unclosed")]);
+        assert!(blacklist.is_empty());
+        assert!(compile_title_regex("(unclosed").is_err());
+    }
+
+    #[test]
+    fn test_with_info_hashes() {
+        let blacklist = Blacklist::default().with_info_hashes(["DEF456"]);
+        assert!(blacklist.check(&candidate("Anything", "def456")).is_some());
+    }
+}
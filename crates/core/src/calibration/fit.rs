@@ -0,0 +1,553 @@
+//! Fitting scoring weights and the auto-approve threshold to user decisions.
+//!
+//! Weights are fitted by coordinate search: each weight is nudged up and down
+//! in turn (renormalizing so the weights keep their original sum) and a nudge
+//! is kept when it ranks the users' picks better. The step halves whenever a
+//! full pass finds no improvement.
+
+use crate::content::{content_scores, dispatched_scores};
+use crate::textbrain::{
+    DumbMatcher, DumbMatcherConfig, DumbScoringWeights, MusicScoringWeights, ScoringWeights,
+    VideoScoringWeights,
+};
+use crate::ticket::ExpectedContent;
+
+use super::{
+    CalibrationError, CalibrationMetrics, CalibrationPreview, CalibrationSample,
+    CreateScoringProfile,
+};
+
+/// Minimum number of labelled samples needed to calibrate.
+pub const MIN_SAMPLES: usize = 10;
+
+/// Initial step for weight nudges.
+const INITIAL_STEP: f32 = 0.05;
+
+/// Search stops once the step falls below this.
+const MIN_STEP: f32 = 0.005;
+
+/// Upper bound on search passes, whatever the step.
+const MAX_PASSES: usize = 200;
+
+/// Lowest and highest auto-approve thresholds considered, in hundredths.
+const THRESHOLD_RANGE: std::ops::RangeInclusive<u32> = 50..=99;
+
+/// Fit scoring weights and the auto-approve threshold to the samples.
+///
+/// The DumbMatcher weights are fitted on every sample; the music and video
+/// weights only on samples of their content type, and are kept as they are
+/// when there are fewer than [`MIN_SAMPLES`] of them. The threshold is fitted
+/// on the scores of the scorer each sample's content type is matched with.
+pub fn calibrate(
+    samples: &[CalibrationSample],
+    base: &ScoringWeights,
+    base_threshold: f32,
+) -> Result<CreateScoringProfile, CalibrationError> {
+    if samples.len() < MIN_SAMPLES {
+        return Err(CalibrationError::NotEnoughSamples {
+            found: samples.len(),
+            required: MIN_SAMPLES,
+        });
+    }
+
+    let mut weights = base.clone();
+
+    weights.dumb = DumbScoringWeights::from_slice(&fit_weights(base.dumb.to_vec(), |trial| {
+        let dumb = DumbScoringWeights::from_slice(trial);
+        ranking_objective(samples, &dumb_scores(samples, &dumb))
+    }));
+
+    let music_samples = samples_of(samples, |e| {
+        matches!(
+            e,
+            ExpectedContent::Album { .. } | ExpectedContent::Track { .. }
+        )
+    });
+    if music_samples.len() >= MIN_SAMPLES {
+        weights.music =
+            MusicScoringWeights::from_slice(&fit_weights(base.music.to_vec(), |trial| {
+                let trial_weights = ScoringWeights {
+                    music: MusicScoringWeights::from_slice(trial),
+                    ..base.clone()
+                };
+                ranking_objective(
+                    &music_samples,
+                    &content_scores_each(&music_samples, &trial_weights),
+                )
+            }));
+    }
+
+    let video_samples = samples_of(samples, |e| {
+        matches!(
+            e,
+            ExpectedContent::Movie { .. }
+                | ExpectedContent::TvEpisode { .. }
+                | ExpectedContent::TvSeason { .. }
+                | ExpectedContent::TvSeries { .. }
+        )
+    });
+    if video_samples.len() >= MIN_SAMPLES {
+        weights.video =
+            VideoScoringWeights::from_slice(&fit_weights(base.video.to_vec(), |trial| {
+                let trial_weights = ScoringWeights {
+                    video: VideoScoringWeights::from_slice(trial),
+                    ..base.clone()
+                };
+                ranking_objective(
+                    &video_samples,
+                    &content_scores_each(&video_samples, &trial_weights),
+                )
+            }));
+    }
+
+    let scores = dispatched_scores_each(samples, &weights);
+    let auto_approve_threshold = fit_threshold(samples, &scores).unwrap_or(base_threshold);
+
+    Ok(CreateScoringProfile {
+        metrics: evaluate(samples, &weights, auto_approve_threshold),
+        weights,
+        auto_approve_threshold,
+        sample_count: samples.len(),
+    })
+}
+
+/// Measure how well weights and a threshold reproduce the samples' decisions.
+pub fn evaluate(
+    samples: &[CalibrationSample],
+    weights: &ScoringWeights,
+    threshold: f32,
+) -> CalibrationMetrics {
+    let scores = dumb_scores(samples, &weights.dumb);
+    let (pairwise_accuracy, top1_accuracy) = ranking_accuracy(samples, &scores);
+    let (decision_accuracy, auto_approve_rate) = decision_accuracy(
+        samples,
+        &dispatched_scores_each(samples, weights),
+        threshold,
+    );
+
+    let mut content_samples = Vec::new();
+    let mut content_scored = Vec::new();
+    for sample in samples.iter().filter(|s| s.selected.is_some()) {
+        if let Some(scores) = content_scores(&sample.context, &sample.candidates, weights) {
+            content_samples.push(sample.clone());
+            content_scored.push(scores);
+        }
+    }
+    let content_pairwise_accuracy = (!content_samples.is_empty())
+        .then(|| ranking_accuracy(&content_samples, &content_scored).0);
+
+    CalibrationMetrics {
+        pairwise_accuracy,
+        top1_accuracy,
+        decision_accuracy,
+        auto_approve_rate,
+        content_pairwise_accuracy,
+    }
+}
+
+/// Compare proposed weights and threshold against the current ones.
+pub fn preview(
+    samples: &[CalibrationSample],
+    current: &ScoringWeights,
+    current_threshold: f32,
+    proposed: &ScoringWeights,
+    proposed_threshold: f32,
+) -> CalibrationPreview {
+    let current_scores = dispatched_scores_each(samples, current);
+    let proposed_scores = dispatched_scores_each(samples, proposed);
+
+    let mut changed_top_picks = 0;
+    let mut changed_decisions = 0;
+    for (before, after) in current_scores.iter().zip(&proposed_scores) {
+        if top_pick(before) != top_pick(after) {
+            changed_top_picks += 1;
+        }
+        if would_auto_approve(before, current_threshold)
+            != would_auto_approve(after, proposed_threshold)
+        {
+            changed_decisions += 1;
+        }
+    }
+
+    CalibrationPreview {
+        sample_count: samples.len(),
+        baseline: evaluate(samples, current, current_threshold),
+        proposed: evaluate(samples, proposed, proposed_threshold),
+        changed_top_picks,
+        changed_decisions,
+    }
+}
+
+/// Coordinate search over non-negative weights that keep their original sum.
+fn fit_weights(initial: Vec<f32>, objective: impl Fn(&[f32]) -> f32) -> Vec<f32> {
+    let total: f32 = initial.iter().sum();
+    if total <= 0.0 {
+        return initial;
+    }
+
+    let mut best_score = objective(&initial);
+    let mut best = initial;
+    let mut step = INITIAL_STEP;
+
+    for _ in 0..MAX_PASSES {
+        if step < MIN_STEP {
+            break;
+        }
+
+        let mut improved = false;
+        for i in 0..best.len() {
+            for delta in [step, -step] {
+                let mut trial = best.clone();
+                trial[i] = (trial[i] + delta).max(0.0);
+                let sum: f32 = trial.iter().sum();
+                if sum <= 0.0 {
+                    continue;
+                }
+                for w in &mut trial {
+                    *w *= total / sum;
+                }
+
+                let score = objective(&trial);
+                if score > best_score + f32::EPSILON {
+                    best_score = score;
+                    best = trial;
+                    improved = true;
+                }
+            }
+        }
+
+        if !improved {
+            step /= 2.0;
+        }
+    }
+
+    best
+}
+
+/// Highest threshold with the best decision accuracy, if any sample exists.
+fn fit_threshold(samples: &[CalibrationSample], scores: &[Vec<f32>]) -> Option<f32> {
+    if samples.is_empty() {
+        return None;
+    }
+
+    let mut best: Option<(f32, f32)> = None;
+    for hundredths in THRESHOLD_RANGE {
+        let threshold = hundredths as f32 / 100.0;
+        let (accuracy, _) = decision_accuracy(samples, scores, threshold);
+        // Ties go to the higher, more conservative threshold
+        if best.is_none_or(|(_, best_accuracy)| accuracy >= best_accuracy) {
+            best = Some((threshold, accuracy));
+        }
+    }
+    best.map(|(threshold, _)| threshold)
+}
+
+/// DumbMatcher scores per sample, in candidate order.
+fn dumb_scores(samples: &[CalibrationSample], weights: &DumbScoringWeights) -> Vec<Vec<f32>> {
+    let matcher = DumbMatcher::with_config(DumbMatcherConfig::default().with_weights(weights));
+    samples
+        .iter()
+        .map(|s| {
+            matcher
+                .score_each(&s.context, &s.candidates)
+                .into_iter()
+                .map(|sc| sc.score)
+                .collect()
+        })
+        .collect()
+}
+
+/// Content scorer scores per sample, in candidate order.
+fn content_scores_each(samples: &[CalibrationSample], weights: &ScoringWeights) -> Vec<Vec<f32>> {
+    samples
+        .iter()
+        .map(|s| content_scores(&s.context, &s.candidates, weights).unwrap_or_default())
+        .collect()
+}
+
+/// Scores from the scorer each sample's content type is matched with, per
+/// sample, in candidate order.
+fn dispatched_scores_each(
+    samples: &[CalibrationSample],
+    weights: &ScoringWeights,
+) -> Vec<Vec<f32>> {
+    samples
+        .iter()
+        .map(|s| dispatched_scores(&s.context, &s.candidates, weights))
+        .collect()
+}
+
+/// Labelled samples whose expected content matches.
+fn samples_of(
+    samples: &[CalibrationSample],
+    is_type: impl Fn(&ExpectedContent) -> bool,
+) -> Vec<CalibrationSample> {
+    samples
+        .iter()
+        .filter(|s| s.selected.is_some() && s.context.expected.as_ref().is_some_and(&is_type))
+        .cloned()
+        .collect()
+}
+
+/// Mean of pairwise and top-1 accuracy.
+fn ranking_objective(samples: &[CalibrationSample], scores: &[Vec<f32>]) -> f32 {
+    let (pairwise, top1) = ranking_accuracy(samples, scores);
+    (pairwise + top1) / 2.0
+}
+
+/// Pairwise and top-1 accuracy over samples with an approved candidate.
+fn ranking_accuracy(samples: &[CalibrationSample], scores: &[Vec<f32>]) -> (f32, f32) {
+    let mut pairs = 0usize;
+    let mut pairs_correct = 0.0f32;
+    let mut approved = 0usize;
+    let mut top1 = 0usize;
+
+    for (sample, scores) in samples.iter().zip(scores) {
+        let Some(selected) = sample.selected else {
+            continue;
+        };
+        let Some(&chosen) = scores.get(selected) else {
+            continue;
+        };
+
+        approved += 1;
+        let mut beats_all = true;
+        for (i, &other) in scores.iter().enumerate() {
+            if i == selected {
+                continue;
+            }
+            pairs += 1;
+            if chosen > other {
+                pairs_correct += 1.0;
+            } else {
+                beats_all = false;
+                if chosen == other {
+                    pairs_correct += 0.5;
+                }
+            }
+        }
+        if beats_all {
+            top1 += 1;
+        }
+    }
+
+    (
+        fraction(pairs_correct, pairs),
+        fraction(top1 as f32, approved),
+    )
+}
+
+/// Decision accuracy and auto-approve rate at a threshold.
+///
+/// Auto-approving is right when the user approved the top pick as is, and
+/// holding for approval is right when they picked another candidate or
+/// rejected them all.
+fn decision_accuracy(
+    samples: &[CalibrationSample],
+    scores: &[Vec<f32>],
+    threshold: f32,
+) -> (f32, f32) {
+    let mut correct = 0usize;
+    let mut approved = 0usize;
+
+    for (sample, scores) in samples.iter().zip(scores) {
+        let auto_approve = would_auto_approve(scores, threshold);
+        let top_pick_accepted = sample.selected.is_some() && sample.selected == top_pick(scores);
+        if auto_approve == top_pick_accepted {
+            correct += 1;
+        }
+        if auto_approve {
+            approved += 1;
+        }
+    }
+
+    (
+        fraction(correct as f32, samples.len()),
+        fraction(approved as f32, samples.len()),
+    )
+}
+
+/// Index of the highest-scoring candidate (first on ties).
+fn top_pick(scores: &[f32]) -> Option<usize> {
+    scores
+        .iter()
+        .enumerate()
+        .fold(None, |best: Option<(usize, f32)>, (i, &score)| match best {
+            Some((_, best_score)) if best_score >= score => best,
+            _ => Some((i, score)),
+        })
+        .map(|(i, _)| i)
+}
+
+fn would_auto_approve(scores: &[f32], threshold: f32) -> bool {
+    scores.iter().any(|&score| score >= threshold)
+}
+
+fn fraction(numerator: f32, denominator: usize) -> f32 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator / denominator as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searcher::TorrentCandidate;
+    use crate::ticket::QueryContext;
+
+    fn candidate(title: &str, seeders: u32) -> TorrentCandidate {
+        TorrentCandidate {
+            title: title.to_string(),
+            info_hash: format!("hash-{}", title.len()),
+            size_bytes: 500_000_000,
+            seeders,
+            leechers: 0,
+            category: None,
+            publish_date: None,
+            files: None,
+            sources: vec![],
+            from_cache: false,
+        }
+    }
+
+    fn sample(description: &str, selected: Option<usize>) -> CalibrationSample {
+        CalibrationSample {
+            ticket_id: "t".to_string(),
+            context: QueryContext::new(vec![], description),
+            candidates: vec![
+                candidate("Unrelated Compilation 2003", 900),
+                candidate(&format!("{} FLAC", description), 3),
+                candidate(&format!("{} 1080p", description), 40),
+            ],
+            selected,
+        }
+    }
+
+    fn samples() -> Vec<CalibrationSample> {
+        let mut samples: Vec<_> = (0..12)
+            .map(|i| sample(&format!("Some Artist Album {}", i), Some(1)))
+            .collect();
+        samples.push(sample("Another Artist Record", None));
+        samples
+    }
+
+    #[test]
+    fn test_calibrate_requires_enough_samples() {
+        let result = calibrate(&samples()[..3], &ScoringWeights::default(), 0.85);
+        assert!(matches!(
+            result,
+            Err(CalibrationError::NotEnoughSamples {
+                found: 3,
+                required: MIN_SAMPLES
+            })
+        ));
+    }
+
+    #[test]
+    fn test_calibrate_does_not_rank_worse_than_base() {
+        let samples = samples();
+        let base = ScoringWeights::default();
+        let baseline = evaluate(&samples, &base, 0.85);
+
+        let profile = calibrate(&samples, &base, 0.85).unwrap();
+
+        assert_eq!(profile.sample_count, samples.len());
+        assert!(
+            profile.metrics.pairwise_accuracy + profile.metrics.top1_accuracy
+                >= baseline.pairwise_accuracy + baseline.top1_accuracy
+        );
+        assert!((0.5..=0.99).contains(&profile.auto_approve_threshold));
+
+        let weights = profile.weights.dumb.to_vec();
+        assert!(weights.iter().all(|&w| w >= 0.0));
+        let sum: f32 = weights.iter().sum();
+        let base_sum: f32 = base.dumb.to_vec().iter().sum();
+        assert!((sum - base_sum).abs() < 1e-3);
+    }
+
+    fn album_sample(title: &str, selected: Option<usize>) -> CalibrationSample {
+        let mut context = QueryContext::new(vec![], title);
+        context.expected = Some(ExpectedContent::Album {
+            artist: Some("Some Artist".to_string()),
+            title: title.to_string(),
+            tracks: vec![],
+        });
+        CalibrationSample {
+            ticket_id: "t".to_string(),
+            context,
+            candidates: vec![
+                candidate("Unrelated Compilation 2003", 900),
+                candidate(&format!("Some Artist - {} FLAC", title), 3),
+                candidate(&format!("Some Artist - {} MP3 320", title), 40),
+            ],
+            selected,
+        }
+    }
+
+    #[test]
+    fn test_calibrate_fits_threshold_on_dispatched_scorer() {
+        let samples: Vec<_> = (0..12)
+            .map(|i| album_sample(&format!("Album {}", i), Some(1)))
+            .collect();
+
+        let profile = calibrate(&samples, &ScoringWeights::default(), 0.85).unwrap();
+
+        let music_scores = dispatched_scores_each(&samples, &profile.weights);
+        let dumb = dumb_scores(&samples, &profile.weights.dumb);
+        assert_ne!(music_scores, dumb);
+        assert_eq!(
+            Some(profile.auto_approve_threshold),
+            fit_threshold(&samples, &music_scores)
+        );
+        assert_eq!(
+            profile.metrics.decision_accuracy,
+            decision_accuracy(&samples, &music_scores, profile.auto_approve_threshold).0
+        );
+    }
+
+    #[test]
+    fn test_decision_accuracy() {
+        let samples = vec![
+            sample("A", Some(0)),
+            sample("B", Some(1)),
+            sample("C", None),
+        ];
+        let scores = vec![
+            vec![0.9, 0.1, 0.2],
+            vec![0.95, 0.3, 0.2],
+            vec![0.4, 0.3, 0.2],
+        ];
+
+        // Only the first sample should be auto-approved
+        let (accuracy, rate) = decision_accuracy(&samples, &scores, 0.5);
+        assert!((accuracy - 2.0 / 3.0).abs() < 1e-6);
+        assert!((rate - 2.0 / 3.0).abs() < 1e-6);
+
+        let (accuracy, rate) = decision_accuracy(&samples, &scores, 0.99);
+        assert!((accuracy - 2.0 / 3.0).abs() < 1e-6);
+        assert_eq!(rate, 0.0);
+
+        assert_eq!(fit_threshold(&samples, &scores), Some(0.99));
+    }
+
+    #[test]
+    fn test_ranking_accuracy() {
+        let samples = vec![
+            sample("A", Some(1)),
+            sample("B", Some(0)),
+            sample("C", None),
+        ];
+        let scores = vec![
+            vec![0.2, 0.8, 0.5],
+            vec![0.5, 0.5, 0.1],
+            vec![0.1, 0.2, 0.3],
+        ];
+
+        let (pairwise, top1) = ranking_accuracy(&samples, &scores);
+        // Pairs: (0.8>0.2, 0.8>0.5, 0.5=0.5, 0.5>0.1) = 3.5 of 4
+        assert!((pairwise - 0.875).abs() < 1e-6);
+        assert!((top1 - 0.5).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,40 @@
+//! Scoring calibration - fitting scorer weights and the auto-approve
+//! threshold to past user approvals, rejections and corrections, and
+//! replaying that history to compare scoring configurations.
+//!
+//! Fitted weights are saved as versioned [`ScoringProfile`]s. A profile has
+//! no effect until it is activated; at most one profile is active at a time,
+//! and with none active the configured weights are used.
+
+mod fit;
+mod replay;
+mod samples;
+mod sqlite;
+mod types;
+
+pub use fit::{calibrate, evaluate, preview, MIN_SAMPLES};
+pub use replay::{collect_replay_samples, replay, ReplayChange, ReplayMetrics, ReplayReport};
+pub use samples::collect_samples;
+pub use sqlite::SqliteScoringProfileStore;
+pub use types::*;
+
+/// Trait for scoring profile storage.
+pub trait ScoringProfileStore: Send + Sync {
+    /// Save a new (inactive) profile with the next version number.
+    fn create(&self, request: CreateScoringProfile) -> Result<ScoringProfile, CalibrationError>;
+
+    /// Get a profile by ID.
+    fn get(&self, id: &str) -> Result<Option<ScoringProfile>, CalibrationError>;
+
+    /// List all profiles, newest version first.
+    fn list(&self) -> Result<Vec<ScoringProfile>, CalibrationError>;
+
+    /// Get the active profile, if any.
+    fn active(&self) -> Result<Option<ScoringProfile>, CalibrationError>;
+
+    /// Make a profile the active one, deactivating any other.
+    fn activate(&self, id: &str) -> Result<ScoringProfile, CalibrationError>;
+
+    /// Deactivate all profiles, reverting to the configured weights.
+    fn deactivate_all(&self) -> Result<(), CalibrationError>;
+}
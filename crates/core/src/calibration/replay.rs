@@ -0,0 +1,332 @@
+//! Offline replay of past decisions under two scoring configurations.
+//!
+//! Replays re-score the candidates users decided on, using the torrent catalog
+//! for their details, and report how often each configuration agrees with
+//! the human choice. Nothing is fetched over the network.
+
+use serde::{Deserialize, Serialize};
+
+use crate::audit::AuditStore;
+use crate::catalog::TorrentCatalog;
+use crate::content::score_candidates;
+use crate::textbrain::TextBrainConfig;
+use crate::ticket::TicketStore;
+
+use super::{collect_samples, CalibrationError, CalibrationSample};
+
+/// How well one configuration reproduces the human decisions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReplayMetrics {
+    /// Fraction of approved samples where the approved candidate is ranked first.
+    pub top1_accuracy: f32,
+    /// Fraction of auto-approvals that picked the candidate the user approved
+    /// (None when nothing would be auto-approved).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_approve_precision: Option<f32>,
+    /// Fraction of samples that would be auto-approved.
+    pub auto_approve_rate: f32,
+}
+
+/// A sample on which the two configurations disagree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayChange {
+    pub ticket_id: String,
+    /// Info hash the user approved (None = all rejected).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approved_hash: Option<String>,
+    /// Top-ranked info hash under the baseline configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline_pick: Option<String>,
+    /// Top-ranked info hash under the candidate configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub candidate_pick: Option<String>,
+    pub baseline_auto_approve: bool,
+    pub candidate_auto_approve: bool,
+}
+
+/// Result of replaying the history under a baseline and a candidate configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayReport {
+    /// Number of samples replayed.
+    pub sample_count: usize,
+    pub baseline: ReplayMetrics,
+    pub candidate: ReplayMetrics,
+    /// Samples whose top-ranked candidate differs.
+    pub changed_top_picks: usize,
+    /// Samples whose auto-approve decision differs.
+    pub changed_decisions: usize,
+    /// The samples that differ, in history order.
+    pub changes: Vec<ReplayChange>,
+}
+
+/// Top pick and auto-approve decision for one sample.
+struct Outcome {
+    pick: Option<String>,
+    auto_approve: bool,
+}
+
+/// Collect replay samples, taking candidate details from the torrent catalog.
+///
+/// Candidates missing from the catalog keep the details recorded in the
+/// audit log.
+pub fn collect_replay_samples(
+    audit_store: &dyn AuditStore,
+    ticket_store: &dyn TicketStore,
+    catalog: &dyn TorrentCatalog,
+) -> Result<Vec<CalibrationSample>, CalibrationError> {
+    let mut samples = collect_samples(audit_store, ticket_store)?;
+    for sample in &mut samples {
+        for candidate in &mut sample.candidates {
+            if let Ok(cached) = catalog.get(&candidate.info_hash) {
+                *candidate = cached.into();
+            }
+        }
+    }
+    Ok(samples)
+}
+
+/// Replay samples under a baseline and a candidate configuration.
+pub async fn replay(
+    samples: &[CalibrationSample],
+    baseline: &TextBrainConfig,
+    candidate: &TextBrainConfig,
+) -> Result<ReplayReport, CalibrationError> {
+    let mut baseline_outcomes = Vec::with_capacity(samples.len());
+    let mut candidate_outcomes = Vec::with_capacity(samples.len());
+    for sample in samples {
+        baseline_outcomes.push(replay_one(sample, baseline).await?);
+        candidate_outcomes.push(replay_one(sample, candidate).await?);
+    }
+
+    let mut changes = Vec::new();
+    let mut changed_top_picks = 0;
+    let mut changed_decisions = 0;
+    for ((sample, before), after) in samples
+        .iter()
+        .zip(&baseline_outcomes)
+        .zip(&candidate_outcomes)
+    {
+        let pick_changed = before.pick != after.pick;
+        let decision_changed = before.auto_approve != after.auto_approve;
+        if pick_changed {
+            changed_top_picks += 1;
+        }
+        if decision_changed {
+            changed_decisions += 1;
+        }
+        if pick_changed || decision_changed {
+            changes.push(ReplayChange {
+                ticket_id: sample.ticket_id.clone(),
+                approved_hash: approved_hash(sample),
+                baseline_pick: before.pick.clone(),
+                candidate_pick: after.pick.clone(),
+                baseline_auto_approve: before.auto_approve,
+                candidate_auto_approve: after.auto_approve,
+            });
+        }
+    }
+
+    Ok(ReplayReport {
+        sample_count: samples.len(),
+        baseline: metrics(samples, &baseline_outcomes),
+        candidate: metrics(samples, &candidate_outcomes),
+        changed_top_picks,
+        changed_decisions,
+        changes,
+    })
+}
+
+async fn replay_one(
+    sample: &CalibrationSample,
+    config: &TextBrainConfig,
+) -> Result<Outcome, CalibrationError> {
+    let result = score_candidates(&sample.context, &sample.candidates, config)
+        .await
+        .map_err(|e| CalibrationError::History(e.to_string()))?;
+
+    let top = result.candidates.first();
+    Ok(Outcome {
+        pick: top.map(|sc| sc.candidate.info_hash.clone()),
+        auto_approve: top.is_some_and(|sc| sc.score >= config.auto_approve_threshold),
+    })
+}
+
+fn approved_hash(sample: &CalibrationSample) -> Option<String> {
+    sample
+        .selected
+        .and_then(|idx| sample.candidates.get(idx))
+        .map(|c| c.info_hash.clone())
+}
+
+fn metrics(samples: &[CalibrationSample], outcomes: &[Outcome]) -> ReplayMetrics {
+    let mut approved = 0usize;
+    let mut top1 = 0usize;
+    let mut auto_approved = 0usize;
+    let mut auto_approved_correct = 0usize;
+
+    for (sample, outcome) in samples.iter().zip(outcomes) {
+        let approved_hash = approved_hash(sample);
+        let correct = approved_hash.is_some() && approved_hash == outcome.pick;
+        if approved_hash.is_some() {
+            approved += 1;
+            if correct {
+                top1 += 1;
+            }
+        }
+        if outcome.auto_approve {
+            auto_approved += 1;
+            if correct {
+                auto_approved_correct += 1;
+            }
+        }
+    }
+
+    let fraction = |n: usize, d: usize| if d == 0 { 0.0 } else { n as f32 / d as f32 };
+    ReplayMetrics {
+        top1_accuracy: fraction(top1, approved),
+        auto_approve_precision: (auto_approved > 0)
+            .then(|| fraction(auto_approved_correct, auto_approved)),
+        auto_approve_rate: fraction(auto_approved, samples.len()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    use crate::audit::{AuditEvent, AuditRecord, SqliteAuditStore, TrainingCandidate};
+    use crate::catalog::SqliteCatalog;
+    use crate::searcher::{TorrentCandidate, TorrentSource};
+    use crate::ticket::{QueryContext, SqliteTicketStore};
+
+    fn candidate(title: &str, hash: &str, seeders: u32) -> TorrentCandidate {
+        TorrentCandidate {
+            title: title.to_string(),
+            info_hash: hash.to_string(),
+            size_bytes: 400_000_000,
+            seeders,
+            leechers: 0,
+            category: None,
+            publish_date: None,
+            files: None,
+            sources: vec![],
+            from_cache: false,
+        }
+    }
+
+    fn sample(ticket_id: &str, selected: Option<usize>) -> CalibrationSample {
+        CalibrationSample {
+            ticket_id: ticket_id.to_string(),
+            context: QueryContext::new(vec![], "Daft Punk Discovery"),
+            candidates: vec![
+                candidate("Daft Punk - Discovery (2001) FLAC", "aaa", 50),
+                candidate("Unrelated Podcast Episode 12", "bbb", 900),
+            ],
+            selected,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_reports_changed_decisions() {
+        let samples = vec![sample("t1", Some(0)), sample("t2", None)];
+        let baseline = TextBrainConfig {
+            auto_approve_threshold: 0.99,
+            ..Default::default()
+        };
+        let lenient = TextBrainConfig {
+            auto_approve_threshold: 0.01,
+            ..Default::default()
+        };
+
+        let report = replay(&samples, &baseline, &lenient).await.unwrap();
+
+        assert_eq!(report.sample_count, 2);
+        assert_eq!(report.baseline.top1_accuracy, 1.0);
+        assert_eq!(report.candidate.top1_accuracy, 1.0);
+        assert_eq!(report.changed_top_picks, 0);
+
+        // The lenient threshold auto-approves both, including the rejected one
+        assert_eq!(report.baseline.auto_approve_precision, None);
+        assert_eq!(report.candidate.auto_approve_rate, 1.0);
+        assert_eq!(report.candidate.auto_approve_precision, Some(0.5));
+        assert_eq!(report.changed_decisions, 2);
+        assert_eq!(report.changes.len(), 2);
+        assert_eq!(report.changes[0].approved_hash.as_deref(), Some("aaa"));
+        assert_eq!(report.changes[1].approved_hash, None);
+    }
+
+    fn insert(store: &SqliteAuditStore, event: AuditEvent) {
+        store
+            .insert(&AuditRecord {
+                id: 0,
+                timestamp: Utc::now(),
+                event_type: event.event_type().to_string(),
+                ticket_id: event.ticket_id().map(String::from),
+                user_id: event.user_id().map(String::from),
+                data: event,
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_collect_replay_samples_uses_catalog_details() {
+        let audit = SqliteAuditStore::in_memory().unwrap();
+        let tickets = SqliteTicketStore::in_memory().unwrap();
+        let catalog = SqliteCatalog::in_memory().unwrap();
+
+        let recorded = |title: &str, hash: &str| TrainingCandidate {
+            title: title.to_string(),
+            hash: hash.to_string(),
+            size_bytes: 400_000_000,
+            seeders: 1,
+            category: None,
+        };
+        insert(
+            &audit,
+            AuditEvent::TrainingScoringContext {
+                sample_id: "s1".to_string(),
+                ticket_id: "t1".to_string(),
+                input_description: "Daft Punk Discovery".to_string(),
+                input_expected: None,
+                input_candidates: vec![
+                    recorded("Daft Punk - Discovery (2001) FLAC", "aaa"),
+                    recorded("Unrelated Podcast Episode 12", "bbb"),
+                ],
+                output_recommended_idx: 0,
+                output_scores: vec![0.7, 0.1],
+                method: "dumb".to_string(),
+            },
+        );
+        insert(
+            &audit,
+            AuditEvent::TicketStateChanged {
+                ticket_id: "t1".to_string(),
+                from_state: "needs_approval".to_string(),
+                to_state: "approved".to_string(),
+                reason: None,
+            },
+        );
+        let mut cached = candidate("Daft Punk - Discovery (2001) FLAC", "aaa", 75);
+        cached.sources = vec![TorrentSource {
+            indexer: "test".to_string(),
+            magnet_uri: Some("magnet:?xt=urn:btih:aaa".to_string()),
+            torrent_url: None,
+            seeders: 75,
+            leechers: 3,
+            details_url: None,
+        }];
+        catalog.store(&[cached]).unwrap();
+
+        let samples = collect_replay_samples(&audit, &tickets, &catalog).unwrap();
+
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].selected, Some(0));
+        assert_eq!(samples[0].context.description, "Daft Punk Discovery");
+        let candidates = &samples[0].candidates;
+        assert!(candidates[0].from_cache);
+        assert_eq!(candidates[0].seeders, 75);
+        assert!(!candidates[1].from_cache);
+        assert_eq!(candidates[1].seeders, 1);
+    }
+}
@@ -0,0 +1,148 @@
+//! Labelled samples from the approval history.
+
+use std::collections::HashMap;
+
+use crate::audit::{AuditEvent, AuditStore, TrainingCandidate};
+use crate::searcher::TorrentCandidate;
+use crate::ticket::{ExpectedContent, QueryContext, TicketStore};
+
+use super::{CalibrationError, CalibrationSample};
+
+/// Number of candidates shown for manual approval.
+const CANDIDATES_SHOWN: usize = 5;
+
+/// Collect labelled samples from approvals, rejections and user corrections.
+///
+/// An approval without a correction labels the recommended (top) candidate;
+/// a correction labels the candidate the user picked instead; a rejection
+/// labels every candidate shown as wrong.
+pub fn collect_samples(
+    audit_store: &dyn AuditStore,
+    ticket_store: &dyn TicketStore,
+) -> Result<Vec<CalibrationSample>, CalibrationError> {
+    let history_err = |e: crate::audit::AuditError| CalibrationError::History(e.to_string());
+
+    let mut records = audit_store
+        .query_all("training_scoring_context")
+        .map_err(history_err)?;
+    records.extend(
+        audit_store
+            .query_all("user_correction")
+            .map_err(history_err)?,
+    );
+    records.extend(
+        audit_store
+            .query_all("ticket_state_changed")
+            .map_err(history_err)?,
+    );
+    records.sort_by_key(|r| r.id);
+
+    // Latest scored candidates per ticket, and corrections awaiting their approval
+    let mut shown: HashMap<String, (String, Option<String>, Vec<TrainingCandidate>)> =
+        HashMap::new();
+    let mut corrections: HashMap<String, (usize, Vec<TrainingCandidate>)> = HashMap::new();
+    let mut samples = Vec::new();
+
+    for record in records {
+        match record.data {
+            AuditEvent::TrainingScoringContext {
+                ticket_id,
+                input_description,
+                input_expected,
+                input_candidates,
+                ..
+            } => {
+                shown.insert(
+                    ticket_id,
+                    (input_description, input_expected, input_candidates),
+                );
+            }
+            AuditEvent::UserCorrection {
+                ticket_id,
+                selected_idx,
+                candidates,
+                ..
+            } => {
+                corrections.insert(ticket_id, (selected_idx, candidates));
+            }
+            AuditEvent::TicketStateChanged {
+                ticket_id,
+                from_state,
+                to_state,
+                ..
+            } if from_state == "needs_approval" => {
+                let Some((description, expected, scored)) = shown.get(&ticket_id) else {
+                    continue;
+                };
+                let correction = corrections.remove(&ticket_id);
+
+                let (selected, candidates) = match (to_state.as_str(), correction) {
+                    ("approved", Some((selected_idx, candidates))) => {
+                        (Some(selected_idx), candidates)
+                    }
+                    ("approved", None) => (Some(0), shown_candidates(scored)),
+                    ("rejected", _) => (None, shown_candidates(scored)),
+                    _ => continue,
+                };
+                if candidates.is_empty() || selected.is_some_and(|idx| idx >= candidates.len()) {
+                    continue;
+                }
+
+                let context = ticket_context(ticket_store, &ticket_id, description, expected)?;
+                samples.push(CalibrationSample {
+                    ticket_id,
+                    context,
+                    candidates: candidates.iter().map(to_torrent_candidate).collect(),
+                    selected,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    Ok(samples)
+}
+
+/// The candidates that were up for approval.
+fn shown_candidates(scored: &[TrainingCandidate]) -> Vec<TrainingCandidate> {
+    scored.iter().take(CANDIDATES_SHOWN).cloned().collect()
+}
+
+/// The ticket's query context, rebuilt from the audit data if the ticket is gone.
+fn ticket_context(
+    ticket_store: &dyn TicketStore,
+    ticket_id: &str,
+    description: &str,
+    expected: &Option<String>,
+) -> Result<QueryContext, CalibrationError> {
+    let ticket = ticket_store
+        .get(ticket_id)
+        .map_err(|e| CalibrationError::History(e.to_string()))?;
+    if let Some(ticket) = ticket {
+        return Ok(ticket.query_context);
+    }
+
+    let mut context = QueryContext::new(vec![], description);
+    if let Some(expected) = expected
+        .as_deref()
+        .and_then(|e| serde_json::from_str::<ExpectedContent>(e).ok())
+    {
+        context = context.with_expected(expected);
+    }
+    Ok(context)
+}
+
+fn to_torrent_candidate(candidate: &TrainingCandidate) -> TorrentCandidate {
+    TorrentCandidate {
+        title: candidate.title.clone(),
+        info_hash: candidate.hash.clone(),
+        size_bytes: candidate.size_bytes,
+        seeders: candidate.seeders,
+        leechers: 0,
+        category: candidate.category.clone(),
+        publish_date: None,
+        files: None,
+        sources: vec![],
+        from_cache: false,
+    }
+}
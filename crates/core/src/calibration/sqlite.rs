@@ -0,0 +1,287 @@
+//! SQLite-backed scoring profile store implementation.
+
+use std::path::Path;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use super::{CalibrationError, CreateScoringProfile, ScoringProfile, ScoringProfileStore};
+
+const SELECT_COLUMNS: &str = "SELECT id, version, weights, auto_approve_threshold, sample_count, metrics, active, created_at, activated_at FROM scoring_profiles";
+
+/// SQLite-backed scoring profile store.
+pub struct SqliteScoringProfileStore {
+    conn: Mutex<Connection>,
+}
+
+impl SqliteScoringProfileStore {
+    /// Create a new SQLite scoring profile store, creating the database file and tables if needed.
+    pub fn new(path: &Path) -> Result<Self, CalibrationError> {
+        let conn = Connection::open(path).map_err(|e| CalibrationError::Database(e.to_string()))?;
+        Self::initialize_schema(&conn)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Create an in-memory SQLite scoring profile store (useful for testing).
+    pub fn in_memory() -> Result<Self, CalibrationError> {
+        let conn =
+            Connection::open_in_memory().map_err(|e| CalibrationError::Database(e.to_string()))?;
+        Self::initialize_schema(&conn)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    fn initialize_schema(conn: &Connection) -> Result<(), CalibrationError> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS scoring_profiles (
+                id TEXT PRIMARY KEY,
+                version INTEGER NOT NULL UNIQUE,
+                weights TEXT NOT NULL,
+                auto_approve_threshold REAL NOT NULL,
+                sample_count INTEGER NOT NULL,
+                metrics TEXT NOT NULL,
+                active INTEGER NOT NULL DEFAULT 0,
+                created_at TEXT NOT NULL,
+                activated_at TEXT
+            );
+            "#,
+        )
+        .map_err(|e| CalibrationError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    fn row_to_profile(row: &rusqlite::Row) -> rusqlite::Result<ScoringProfile> {
+        let weights_json: String = row.get(2)?;
+        let metrics_json: String = row.get(5)?;
+        let created_at_str: String = row.get(7)?;
+        let activated_at_str: Option<String> = row.get(8)?;
+
+        let parse_time = |s: &str| {
+            DateTime::parse_from_rfc3339(s)
+                .map(|dt| dt.with_timezone(&Utc))
+                .ok()
+        };
+
+        Ok(ScoringProfile {
+            id: row.get(0)?,
+            version: row.get(1)?,
+            weights: serde_json::from_str(&weights_json).unwrap_or_default(),
+            auto_approve_threshold: row.get::<_, f64>(3)? as f32,
+            sample_count: row.get::<_, i64>(4)? as usize,
+            metrics: serde_json::from_str(&metrics_json).unwrap_or_default(),
+            active: row.get(6)?,
+            created_at: parse_time(&created_at_str).unwrap_or_else(Utc::now),
+            activated_at: activated_at_str.as_deref().and_then(parse_time),
+        })
+    }
+
+    fn get_locked(conn: &Connection, id: &str) -> Result<Option<ScoringProfile>, CalibrationError> {
+        conn.query_row(
+            &format!("{} WHERE id = ?", SELECT_COLUMNS),
+            params![id],
+            Self::row_to_profile,
+        )
+        .optional()
+        .map_err(|e| CalibrationError::Database(e.to_string()))
+    }
+}
+
+impl ScoringProfileStore for SqliteScoringProfileStore {
+    fn create(&self, request: CreateScoringProfile) -> Result<ScoringProfile, CalibrationError> {
+        let conn = self.conn.lock().unwrap();
+
+        let version: u32 = conn
+            .query_row(
+                "SELECT COALESCE(MAX(version), 0) + 1 FROM scoring_profiles",
+                [],
+                |row| row.get(0),
+            )
+            .map_err(|e| CalibrationError::Database(e.to_string()))?;
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let now = Utc::now();
+        let weights_json = serde_json::to_string(&request.weights)
+            .map_err(|e| CalibrationError::Database(e.to_string()))?;
+        let metrics_json = serde_json::to_string(&request.metrics)
+            .map_err(|e| CalibrationError::Database(e.to_string()))?;
+
+        conn.execute(
+            "INSERT INTO scoring_profiles (id, version, weights, auto_approve_threshold, sample_count, metrics, active, created_at) VALUES (?, ?, ?, ?, ?, ?, 0, ?)",
+            params![
+                id,
+                version,
+                weights_json,
+                request.auto_approve_threshold as f64,
+                request.sample_count as i64,
+                metrics_json,
+                now.to_rfc3339(),
+            ],
+        )
+        .map_err(|e| CalibrationError::Database(e.to_string()))?;
+
+        Ok(ScoringProfile {
+            id,
+            version,
+            weights: request.weights,
+            auto_approve_threshold: request.auto_approve_threshold,
+            sample_count: request.sample_count,
+            metrics: request.metrics,
+            active: false,
+            created_at: now,
+            activated_at: None,
+        })
+    }
+
+    fn get(&self, id: &str) -> Result<Option<ScoringProfile>, CalibrationError> {
+        let conn = self.conn.lock().unwrap();
+        Self::get_locked(&conn, id)
+    }
+
+    fn list(&self) -> Result<Vec<ScoringProfile>, CalibrationError> {
+        let conn = self.conn.lock().unwrap();
+
+        let mut stmt = conn
+            .prepare(&format!("{} ORDER BY version DESC", SELECT_COLUMNS))
+            .map_err(|e| CalibrationError::Database(e.to_string()))?;
+
+        let profiles = stmt
+            .query_map([], Self::row_to_profile)
+            .map_err(|e| CalibrationError::Database(e.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| CalibrationError::Database(e.to_string()))?;
+
+        Ok(profiles)
+    }
+
+    fn active(&self) -> Result<Option<ScoringProfile>, CalibrationError> {
+        let conn = self.conn.lock().unwrap();
+        conn.query_row(
+            &format!("{} WHERE active = 1", SELECT_COLUMNS),
+            [],
+            Self::row_to_profile,
+        )
+        .optional()
+        .map_err(|e| CalibrationError::Database(e.to_string()))
+    }
+
+    fn activate(&self, id: &str) -> Result<ScoringProfile, CalibrationError> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn
+            .transaction()
+            .map_err(|e| CalibrationError::Database(e.to_string()))?;
+
+        if Self::get_locked(&tx, id)?.is_none() {
+            return Err(CalibrationError::NotFound(format!(
+                "scoring profile {}",
+                id
+            )));
+        }
+
+        tx.execute(
+            "UPDATE scoring_profiles SET active = 0 WHERE active = 1",
+            [],
+        )
+        .map_err(|e| CalibrationError::Database(e.to_string()))?;
+        tx.execute(
+            "UPDATE scoring_profiles SET active = 1, activated_at = ? WHERE id = ?",
+            params![Utc::now().to_rfc3339(), id],
+        )
+        .map_err(|e| CalibrationError::Database(e.to_string()))?;
+
+        let profile = Self::get_locked(&tx, id)?
+            .ok_or_else(|| CalibrationError::NotFound(format!("scoring profile {}", id)))?;
+        tx.commit()
+            .map_err(|e| CalibrationError::Database(e.to_string()))?;
+
+        Ok(profile)
+    }
+
+    fn deactivate_all(&self) -> Result<(), CalibrationError> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "UPDATE scoring_profiles SET active = 0 WHERE active = 1",
+            [],
+        )
+        .map_err(|e| CalibrationError::Database(e.to_string()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calibration::CalibrationMetrics;
+    use crate::textbrain::ScoringWeights;
+
+    fn request(threshold: f32) -> CreateScoringProfile {
+        CreateScoringProfile {
+            weights: ScoringWeights::default(),
+            auto_approve_threshold: threshold,
+            sample_count: 12,
+            metrics: CalibrationMetrics {
+                pairwise_accuracy: 0.9,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_create_assigns_versions() {
+        let store = SqliteScoringProfileStore::in_memory().unwrap();
+
+        let first = store.create(request(0.8)).unwrap();
+        let second = store.create(request(0.9)).unwrap();
+
+        assert_eq!(first.version, 1);
+        assert_eq!(second.version, 2);
+        assert!(!first.active);
+
+        let listed = store.list().unwrap();
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed[0].id, second.id);
+
+        let fetched = store.get(&first.id).unwrap().unwrap();
+        assert_eq!(fetched.auto_approve_threshold, 0.8);
+        assert_eq!(fetched.metrics.pairwise_accuracy, 0.9);
+        assert_eq!(fetched.sample_count, 12);
+    }
+
+    #[test]
+    fn test_activate_switches_active_profile() {
+        let store = SqliteScoringProfileStore::in_memory().unwrap();
+        let first = store.create(request(0.8)).unwrap();
+        let second = store.create(request(0.9)).unwrap();
+
+        assert!(store.active().unwrap().is_none());
+
+        let activated = store.activate(&first.id).unwrap();
+        assert!(activated.active);
+        assert!(activated.activated_at.is_some());
+
+        store.activate(&second.id).unwrap();
+        let active = store.active().unwrap().unwrap();
+        assert_eq!(active.id, second.id);
+        assert!(!store.get(&first.id).unwrap().unwrap().active);
+
+        store.deactivate_all().unwrap();
+        assert!(store.active().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_activate_unknown_profile() {
+        let store = SqliteScoringProfileStore::in_memory().unwrap();
+        let first = store.create(request(0.8)).unwrap();
+        store.activate(&first.id).unwrap();
+
+        let result = store.activate("missing");
+        assert!(matches!(result, Err(CalibrationError::NotFound(_))));
+        // The active profile is untouched
+        assert_eq!(store.active().unwrap().unwrap().id, first.id);
+    }
+}
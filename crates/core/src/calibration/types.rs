@@ -0,0 +1,113 @@
+//! Types for scoring calibration.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::searcher::TorrentCandidate;
+use crate::textbrain::{ScoringWeights, TextBrainConfig};
+use crate::ticket::QueryContext;
+
+/// A versioned set of fitted scoring weights and auto-approve threshold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoringProfile {
+    /// Unique identifier (UUID).
+    pub id: String,
+    /// Version number, increasing with each saved profile.
+    pub version: u32,
+    /// Scorer weights.
+    pub weights: ScoringWeights,
+    /// Auto-approve threshold (0.0-1.0).
+    pub auto_approve_threshold: f32,
+    /// Number of labelled samples the profile was fitted on.
+    pub sample_count: usize,
+    /// How the profile scored on those samples.
+    pub metrics: CalibrationMetrics,
+    /// Whether this is the profile in use.
+    pub active: bool,
+    /// When the profile was created.
+    pub created_at: DateTime<Utc>,
+    /// When the profile was last activated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activated_at: Option<DateTime<Utc>>,
+}
+
+impl ScoringProfile {
+    /// Apply the profile's weights and threshold to a TextBrain configuration.
+    pub fn apply(&self, config: &TextBrainConfig) -> TextBrainConfig {
+        TextBrainConfig {
+            scoring: self.weights.clone(),
+            auto_approve_threshold: self.auto_approve_threshold,
+            ..config.clone()
+        }
+    }
+}
+
+/// Request to save a scoring profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateScoringProfile {
+    pub weights: ScoringWeights,
+    pub auto_approve_threshold: f32,
+    pub sample_count: usize,
+    pub metrics: CalibrationMetrics,
+}
+
+/// How well a set of weights and threshold reproduce the users' decisions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CalibrationMetrics {
+    /// Fraction of (approved, other) candidate pairs ranked the right way round.
+    pub pairwise_accuracy: f32,
+    /// Fraction of approved samples where the approved candidate ranks first.
+    pub top1_accuracy: f32,
+    /// Fraction of samples where auto-approval would match the user's decision.
+    pub decision_accuracy: f32,
+    /// Fraction of samples that would be auto-approved.
+    pub auto_approve_rate: f32,
+    /// Pairwise accuracy of the music/video scorers on their content types.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_pairwise_accuracy: Option<f32>,
+}
+
+/// A human decision on a set of candidates.
+#[derive(Debug, Clone)]
+pub struct CalibrationSample {
+    /// Ticket the decision was made on.
+    pub ticket_id: String,
+    /// What was being looked for.
+    pub context: QueryContext,
+    /// Candidates the user was shown.
+    pub candidates: Vec<TorrentCandidate>,
+    /// Index of the approved candidate (None = all rejected).
+    pub selected: Option<usize>,
+}
+
+/// Impact of switching from one profile (or the configured weights) to another.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalibrationPreview {
+    /// Number of samples evaluated.
+    pub sample_count: usize,
+    /// Metrics of the weights currently in use.
+    pub baseline: CalibrationMetrics,
+    /// Metrics of the proposed profile.
+    pub proposed: CalibrationMetrics,
+    /// Samples whose top-ranked candidate would change.
+    pub changed_top_picks: usize,
+    /// Samples whose auto-approve decision would change.
+    pub changed_decisions: usize,
+}
+
+/// Errors for calibration and scoring profile operations.
+#[derive(Debug, Error)]
+pub enum CalibrationError {
+    #[error("Database error: {0}")]
+    Database(String),
+
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    #[error("Not enough labelled samples: found {found}, need at least {required}")]
+    NotEnoughSamples { found: usize, required: usize },
+
+    #[error("Failed to read decision history: {0}")]
+    History(String),
+}
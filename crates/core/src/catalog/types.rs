@@ -4,6 +4,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::searcher::{TorrentCandidate, TorrentFile, TorrentSource};
+
 /// A cached torrent entry from previous searches.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CachedTorrent {
@@ -29,6 +31,42 @@ pub struct CachedTorrent {
     pub files: Option<Vec<CachedTorrentFile>>,
 }
 
+impl From<CachedTorrent> for TorrentCandidate {
+    fn from(cached: CachedTorrent) -> Self {
+        TorrentCandidate {
+            title: cached.title,
+            info_hash: cached.info_hash,
+            size_bytes: cached.size_bytes,
+            seeders: cached.sources.iter().map(|s| s.seeders).sum(),
+            leechers: cached.sources.iter().map(|s| s.leechers).sum(),
+            category: cached.category,
+            publish_date: None,
+            files: cached.files.map(|files| {
+                files
+                    .into_iter()
+                    .map(|f| TorrentFile {
+                        path: f.path,
+                        size_bytes: f.size_bytes,
+                    })
+                    .collect()
+            }),
+            sources: cached
+                .sources
+                .into_iter()
+                .map(|s| TorrentSource {
+                    indexer: s.indexer,
+                    magnet_uri: s.magnet_uri,
+                    torrent_url: s.torrent_url,
+                    seeders: s.seeders,
+                    leechers: s.leechers,
+                    details_url: s.details_url,
+                })
+                .collect(),
+            from_cache: true,
+        }
+    }
+}
+
 /// A source/indexer for a cached torrent.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CachedTorrentSource {
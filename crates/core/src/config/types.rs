@@ -3,7 +3,7 @@ use std::net::IpAddr;
 use std::path::PathBuf;
 
 use crate::external_catalog::{MusicBrainzConfig, TmdbConfig};
-use crate::orchestrator::OrchestratorConfig;
+use crate::orchestrator::{FailoverStrategyKind, OrchestratorConfig};
 use crate::textbrain::TextBrainConfig;
 
 /// Root configuration
@@ -242,6 +242,9 @@ pub struct SanitizedOrchestratorConfig {
     pub download_poll_interval_ms: u64,
     pub auto_approve_threshold: f32,
     pub max_concurrent_downloads: usize,
+    pub preemption_enabled: bool,
+    pub failover_strategy: FailoverStrategyKind,
+    pub reacquire_on_exhaustion: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -376,6 +379,9 @@ impl From<&Config> for SanitizedConfig {
                 download_poll_interval_ms: config.orchestrator.download_poll_interval_ms,
                 auto_approve_threshold: config.orchestrator.auto_approve_threshold,
                 max_concurrent_downloads: config.orchestrator.max_concurrent_downloads,
+                preemption_enabled: config.orchestrator.preemption_enabled,
+                failover_strategy: config.orchestrator.failover.strategy,
+                reacquire_on_exhaustion: config.orchestrator.failover.reacquire_on_exhaustion,
             },
             external_catalogs: config.external_catalogs.as_ref().map(|ec| {
                 SanitizedExternalCatalogsConfig {
@@ -4,6 +4,7 @@ use super::{types::Config, ConfigError};
 /// Currently validates:
 /// - Auth section exists (enforced by serde)
 /// - Server port is not 0
+/// - TextBrain settings (thresholds, LLM providers and budget pricing)
 pub fn validate_config(config: &Config) -> Result<(), ConfigError> {
     // Server validation
     if config.server.port == 0 {
@@ -12,6 +13,11 @@ pub fn validate_config(config: &Config) -> Result<(), ConfigError> {
         ));
     }
 
+    config
+        .textbrain
+        .validate()
+        .map_err(|e| ConfigError::ValidationError(format!("textbrain: {}", e)))?;
+
     Ok(())
 }
 
@@ -0,0 +1,764 @@
+//! Audiobook content handling.
+//!
+//! Provides audiobook-specific implementations for:
+//! - Query building: "{author} {title}", "{title} audiobook", narrator and series variants
+//! - Scoring: format (M4B > MP3), abridged vs unabridged, narrator match, ebook red flags
+//! - File mapping: chapter files in natural order
+//! - Pipeline input: resolving chapter files of a download for the M4B merge
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::processor::PipelineMetadata;
+use crate::searcher::{TorrentCandidate, TorrentFile};
+use crate::textbrain::{
+    calculate_mapping_quality, DumbFileMapper, DumbFileMapperConfig, FileMapping, MatchResult,
+    QueryBuildResult, ScoredCandidate, TextBrainConfig, TextBrainError,
+};
+use crate::ticket::{ExpectedContent, QueryContext, Ticket};
+
+use super::generic;
+use super::types::{ContentError, PostProcessResult};
+
+// =============================================================================
+// Query Building
+// =============================================================================
+
+/// Build queries for audiobook content.
+///
+/// Generates patterns like "{author} {title}", "{title} audiobook",
+/// "{title} {narrator}" and "{series} {title}", plus M4B/unabridged variants.
+pub async fn build_queries(
+    context: &QueryContext,
+    config: &TextBrainConfig,
+) -> Result<QueryBuildResult, TextBrainError> {
+    let queries = match &context.expected {
+        Some(ExpectedContent::Audiobook {
+            author,
+            title,
+            narrator,
+            series,
+            ..
+        }) => build_audiobook_queries(
+            author.as_deref(),
+            title,
+            narrator.as_deref(),
+            series.as_deref(),
+        ),
+        _ => return generic::build_queries(context, config).await,
+    };
+
+    if queries.is_empty() {
+        return Err(TextBrainError::NoQueriesGenerated);
+    }
+
+    Ok(QueryBuildResult {
+        queries,
+        method: "audiobook".to_string(),
+        confidence: estimate_query_confidence(context),
+        llm_usage: None,
+    })
+}
+
+fn build_audiobook_queries(
+    author: Option<&str>,
+    title: &str,
+    narrator: Option<&str>,
+    series: Option<&str>,
+) -> Vec<String> {
+    let mut queries = Vec::new();
+    let mut seen = HashSet::new();
+
+    let title_clean = normalize_text(title);
+
+    if let Some(author) = author {
+        let author_clean = normalize_text(author);
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} {}", author_clean, title_clean),
+        );
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} {} m4b", author_clean, title_clean),
+        );
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} {} unabridged", author_clean, title_clean),
+        );
+    }
+
+    add_query(
+        &mut queries,
+        &mut seen,
+        format!("{} audiobook", title_clean),
+    );
+
+    if let Some(narrator) = narrator {
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} {}", title_clean, normalize_text(narrator)),
+        );
+    }
+
+    if let Some(series) = series {
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} {}", normalize_text(series), title_clean),
+        );
+    }
+
+    add_query(&mut queries, &mut seen, title_clean);
+
+    queries
+}
+
+/// Add query if not already seen.
+fn add_query(queries: &mut Vec<String>, seen: &mut HashSet<String>, query: String) {
+    let normalized = query.to_lowercase();
+    if !normalized.is_empty() && seen.insert(normalized) {
+        queries.push(query);
+    }
+}
+
+/// Normalize text for search queries.
+fn normalize_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Estimate confidence in generated queries.
+fn estimate_query_confidence(context: &QueryContext) -> f32 {
+    let mut confidence: f32 = 0.5;
+
+    if let Some(ExpectedContent::Audiobook {
+        author,
+        title,
+        narrator,
+        ..
+    }) = &context.expected
+    {
+        if author.is_some() {
+            confidence += 0.2;
+        }
+        if title.len() > 10 {
+            confidence += 0.1;
+        }
+        if narrator.is_some() {
+            confidence += 0.05;
+        }
+    }
+
+    confidence.min(0.9)
+}
+
+// =============================================================================
+// Candidate Scoring
+// =============================================================================
+
+/// Score candidates for audiobook content.
+///
+/// Uses audiobook-specific heuristics:
+/// - Author/title match, with a bonus for the expected narrator
+/// - Format (single M4B > MP3 chapters)
+/// - Abridged releases penalized unless marked unabridged
+/// - Red flags (ebook formats, summaries)
+/// - Chapter file mapping quality
+pub async fn score_candidates(
+    context: &QueryContext,
+    candidates: &[TorrentCandidate],
+    _config: &TextBrainConfig,
+) -> Result<MatchResult, TextBrainError> {
+    let mut scored = score_each(context, candidates);
+
+    scored.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    Ok(MatchResult {
+        candidates: scored,
+        method: "audiobook".to_string(),
+        llm_usage: None,
+    })
+}
+
+/// Score each candidate, in input order.
+pub(crate) fn score_each(
+    context: &QueryContext,
+    candidates: &[TorrentCandidate],
+) -> Vec<ScoredCandidate> {
+    let scorer = AudiobookScorer::new(context);
+    candidates
+        .iter()
+        .map(|c| scorer.score_candidate(c))
+        .collect()
+}
+
+/// Audiobook-specific candidate scorer.
+struct AudiobookScorer<'a> {
+    context: &'a QueryContext,
+    file_mapper: DumbFileMapper,
+    expected_author: Option<&'a str>,
+    expected_title: Option<&'a str>,
+    expected_narrator: Option<&'a str>,
+}
+
+impl<'a> AudiobookScorer<'a> {
+    fn new(context: &'a QueryContext) -> Self {
+        let (expected_author, expected_title, expected_narrator) = match &context.expected {
+            Some(ExpectedContent::Audiobook {
+                author,
+                title,
+                narrator,
+                ..
+            }) => (author.as_deref(), Some(title.as_str()), narrator.as_deref()),
+            _ => (None, None, None),
+        };
+
+        Self {
+            context,
+            file_mapper: DumbFileMapper::new(),
+            expected_author,
+            expected_title,
+            expected_narrator,
+        }
+    }
+
+    fn score_candidate(&self, candidate: &TorrentCandidate) -> ScoredCandidate {
+        let title_lower = candidate.title.to_lowercase();
+
+        let title_score = self.title_match_score(&title_lower);
+        let format_score = self.format_score(candidate, &title_lower);
+        let health_score = self.health_score(candidate);
+        let narrator_bonus = self.narrator_bonus(&title_lower);
+        let edition_penalty = self.edition_penalty(&title_lower);
+        let red_flag_penalty = self.red_flag_penalty(&title_lower);
+
+        let (file_mappings, mapping_score) = self.file_mapping_score(candidate);
+
+        let base_score =
+            (title_score * 0.55) + (format_score * 0.25) + (health_score * 0.20) + narrator_bonus
+                - edition_penalty
+                - red_flag_penalty;
+
+        let final_score = if mapping_score > 0.0 {
+            (base_score * 0.6) + (mapping_score * 0.4)
+        } else {
+            base_score
+        };
+
+        let reasoning = self.generate_reasoning(
+            title_score,
+            format_score,
+            narrator_bonus,
+            edition_penalty,
+            red_flag_penalty,
+            mapping_score,
+            candidate,
+        );
+
+        ScoredCandidate {
+            candidate: candidate.clone(),
+            score: final_score.clamp(0.0, 1.0),
+            reasoning,
+            file_mappings,
+        }
+    }
+
+    /// Score author and title match.
+    fn title_match_score(&self, title: &str) -> f32 {
+        let mut score = 0.0;
+        let mut max_possible = 0.0;
+
+        if let Some(author) = self.expected_author {
+            max_possible += 1.0;
+            score += word_match(title, author);
+        }
+
+        if let Some(expected_title) = self.expected_title {
+            max_possible += 1.0;
+            score += word_match(title, expected_title);
+        }
+
+        if max_possible > 0.0 {
+            score / max_possible
+        } else {
+            0.5
+        }
+    }
+
+    /// Score release format. A single M4B keeps chapters and cover together.
+    fn format_score(&self, candidate: &TorrentCandidate, title: &str) -> f32 {
+        let has_file_ext = |ext: &str| {
+            candidate
+                .files
+                .as_ref()
+                .is_some_and(|files| files.iter().any(|f| f.path.to_lowercase().ends_with(ext)))
+        };
+
+        if title.contains("m4b") || has_file_ext(".m4b") {
+            1.0
+        } else if title.contains("mp3") || has_file_ext(".mp3") {
+            0.6
+        } else if title.contains("flac") || title.contains("aac") || has_file_ext(".m4a") {
+            0.7
+        } else {
+            0.5
+        }
+    }
+
+    /// Score torrent health.
+    fn health_score(&self, candidate: &TorrentCandidate) -> f32 {
+        match candidate.seeders {
+            0 => 0.0,
+            1..=2 => 0.3,
+            3..=10 => 0.6,
+            11..=50 => 0.9,
+            _ => 1.0,
+        }
+    }
+
+    /// Bonus when the release names the expected narrator.
+    fn narrator_bonus(&self, title: &str) -> f32 {
+        match self.expected_narrator {
+            Some(narrator) if word_match(title, narrator) >= 1.0 => 0.10,
+            _ => 0.0,
+        }
+    }
+
+    /// Penalty for abridged releases. "Unabridged" contains "abridged", so
+    /// check for it first.
+    fn edition_penalty(&self, title: &str) -> f32 {
+        if title.contains("unabridged") {
+            0.0
+        } else if title.contains("abridged") {
+            0.3
+        } else {
+            0.0
+        }
+    }
+
+    /// Calculate penalty for red flags.
+    fn red_flag_penalty(&self, title: &str) -> f32 {
+        let mut penalty: f32 = 0.0;
+
+        // Ebook rather than audio
+        if ["epub", "pdf", "mobi", "azw3", "ebook"]
+            .iter()
+            .any(|flag| title.contains(flag))
+        {
+            penalty += 0.5;
+        }
+
+        // Summaries and dramatizations are not the book
+        if title.contains("summary") || title.contains("dramatized") || title.contains("dramatised")
+        {
+            penalty += 0.3;
+        }
+
+        penalty.min(1.0)
+    }
+
+    /// Calculate chapter file mapping score.
+    fn file_mapping_score(&self, candidate: &TorrentCandidate) -> (Vec<FileMapping>, f32) {
+        let expected = match &self.context.expected {
+            Some(e) => e,
+            None => return (Vec::new(), 0.0),
+        };
+
+        let files = match &candidate.files {
+            Some(f) if !f.is_empty() => f,
+            _ => return (Vec::new(), 0.0),
+        };
+
+        let mappings = self.file_mapper.map_files(files, expected);
+        if mappings.is_empty() {
+            return (Vec::new(), 0.0);
+        }
+
+        let score = calculate_mapping_quality(&mappings, expected);
+        (mappings, score)
+    }
+
+    /// Generate human-readable reasoning.
+    #[allow(clippy::too_many_arguments)]
+    fn generate_reasoning(
+        &self,
+        title_score: f32,
+        format_score: f32,
+        narrator_bonus: f32,
+        edition_penalty: f32,
+        red_flag_penalty: f32,
+        mapping_score: f32,
+        candidate: &TorrentCandidate,
+    ) -> String {
+        let mut parts = Vec::new();
+
+        if title_score >= 0.9 {
+            parts.push("excellent match".to_string());
+        } else if title_score >= 0.7 {
+            parts.push("good match".to_string());
+        } else if title_score >= 0.5 {
+            parts.push("partial match".to_string());
+        } else {
+            parts.push("weak match".to_string());
+        }
+
+        if format_score >= 0.9 {
+            parts.push("M4B".to_string());
+        } else if format_score >= 0.6 {
+            parts.push("audio chapters".to_string());
+        }
+
+        if narrator_bonus > 0.0 {
+            parts.push("narrator match".to_string());
+        }
+        if edition_penalty > 0.0 {
+            parts.push("abridged".to_string());
+        }
+        if red_flag_penalty >= 0.5 {
+            parts.push("ebook".to_string());
+        } else if red_flag_penalty > 0.0 {
+            parts.push("not the full book".to_string());
+        }
+
+        if candidate.seeders == 0 {
+            parts.push("dead (0 seeders)".to_string());
+        } else {
+            parts.push(format!("{} seeders", candidate.seeders));
+        }
+
+        if mapping_score > 0.0 {
+            parts.push(format!("{:.0}% chapters mapped", mapping_score * 100.0));
+        }
+
+        parts.join(", ")
+    }
+}
+
+/// Fraction of the expected words found in a title (1.0 for a full phrase match).
+fn word_match(title: &str, expected: &str) -> f32 {
+    let expected_lower = expected.to_lowercase();
+    if title.contains(&expected_lower) {
+        return 1.0;
+    }
+
+    let words: Vec<&str> = expected_lower
+        .split_whitespace()
+        .filter(|w| w.len() > 2)
+        .collect();
+    if words.is_empty() {
+        return 0.0;
+    }
+
+    let matches = words.iter().filter(|w| title.contains(*w)).count();
+    (matches as f32 / words.len() as f32) * 0.8
+}
+
+// =============================================================================
+// File Mapping
+// =============================================================================
+
+/// Map files for audiobook content.
+///
+/// The standard file mapper assigns audio files to chapters in natural order.
+pub fn map_files(context: &QueryContext, files: &[TorrentFile]) -> Vec<FileMapping> {
+    generic::map_files(context, files)
+}
+
+// =============================================================================
+// Pipeline Input
+// =============================================================================
+
+/// Resolve the audio files of a completed download in playback order.
+///
+/// Uses the chapter mappings from acquisition when present (mapping paths are
+/// relative to the torrent's save directory, or to the download itself).
+/// Otherwise scans the download for audio files and orders them naturally.
+pub fn chapter_files(download_path: &Path, file_mappings: &[FileMapping]) -> Vec<PathBuf> {
+    let mut mapped: Vec<(u32, &str)> = file_mappings
+        .iter()
+        .filter_map(|m| {
+            let number = if m.ticket_item_id == "book" {
+                0
+            } else {
+                m.ticket_item_id.strip_prefix("chapter-")?.parse().ok()?
+            };
+            Some((number, m.torrent_file_path.as_str()))
+        })
+        .collect();
+    mapped.sort_by_key(|(number, _)| *number);
+
+    let save_dir = download_path.parent().unwrap_or(download_path);
+    let resolved: Vec<PathBuf> = mapped
+        .into_iter()
+        .map(|(_, rel)| {
+            let in_save_dir = save_dir.join(rel);
+            if in_save_dir.exists() {
+                in_save_dir
+            } else {
+                download_path.join(rel)
+            }
+        })
+        .collect();
+
+    if !resolved.is_empty() {
+        return resolved;
+    }
+
+    if download_path.is_file() {
+        return vec![download_path.to_path_buf()];
+    }
+
+    let extensions = DumbFileMapperConfig::default().audio_extensions;
+    let mut found = Vec::new();
+    collect_audio_files(download_path, &extensions, &mut found);
+    found.sort_by(|a, b| crate::textbrain::natural_cmp(&a.to_string_lossy(), &b.to_string_lossy()));
+    found
+}
+
+fn collect_audio_files(dir: &Path, extensions: &[String], found: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_audio_files(&path, extensions, found);
+        } else if path
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .is_some_and(|ext| extensions.contains(&ext))
+        {
+            found.push(path);
+        }
+    }
+}
+
+/// Metadata to embed in the merged M4B.
+pub fn pipeline_metadata(expected: &ExpectedContent) -> Option<PipelineMetadata> {
+    let ExpectedContent::Audiobook {
+        author,
+        title,
+        narrator,
+        ..
+    } = expected
+    else {
+        return None;
+    };
+
+    Some(PipelineMetadata {
+        title: Some(title.clone()),
+        artist: author.clone(),
+        album: Some(title.clone()),
+        album_artist: author.clone(),
+        year: None,
+        track_number: None,
+        track_total: None,
+        disc_number: None,
+        disc_total: None,
+        genre: Some("Audiobook".to_string()),
+        comment: narrator.as_ref().map(|n| format!("Narrated by {}", n)),
+        cover_art: None,
+    })
+}
+
+// =============================================================================
+// Post-Processing
+// =============================================================================
+
+/// Cover art file patterns to check.
+const COVER_ART_PATTERNS: &[&str] = &[
+    "cover.jpg",
+    "cover.jpeg",
+    "cover.png",
+    "folder.jpg",
+    "folder.png",
+    "front.jpg",
+    "front.png",
+];
+
+/// Post-process audiobook content.
+///
+/// Picks up existing cover art for embedding in the M4B.
+pub async fn post_process(
+    ticket: &Ticket,
+    download_path: &Path,
+) -> Result<PostProcessResult, ContentError> {
+    let _ = ticket;
+    for pattern in COVER_ART_PATTERNS {
+        let path = download_path.join(pattern);
+        if tokio::fs::metadata(&path).await.is_ok() {
+            return Ok(PostProcessResult::with_cover_art(path));
+        }
+    }
+    Ok(PostProcessResult::empty())
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searcher::TorrentSource;
+    use crate::textbrain::TextBrainMode;
+    use crate::ticket::ExpectedChapter;
+
+    fn make_config() -> TextBrainConfig {
+        TextBrainConfig {
+            mode: TextBrainMode::DumbOnly,
+            ..Default::default()
+        }
+    }
+
+    fn make_context(narrator: Option<&str>, chapters: Vec<ExpectedChapter>) -> QueryContext {
+        QueryContext {
+            tags: vec![],
+            description: "Dune audiobook".to_string(),
+            expected: Some(ExpectedContent::Audiobook {
+                author: Some("Frank Herbert".to_string()),
+                title: "Dune".to_string(),
+                narrator: narrator.map(String::from),
+                series: Some("Dune Chronicles".to_string()),
+                chapters,
+            }),
+            catalog_reference: None,
+            search_constraints: None,
+        }
+    }
+
+    fn make_candidate(title: &str, files: Option<Vec<TorrentFile>>) -> TorrentCandidate {
+        TorrentCandidate {
+            title: title.to_string(),
+            info_hash: title.to_string(),
+            size_bytes: 900_000_000,
+            seeders: 25,
+            leechers: 1,
+            category: None,
+            publish_date: None,
+            files,
+            sources: vec![TorrentSource {
+                indexer: "test".to_string(),
+                magnet_uri: None,
+                torrent_url: None,
+                seeders: 25,
+                leechers: 1,
+                details_url: None,
+            }],
+            from_cache: false,
+        }
+    }
+
+    fn file(path: &str) -> TorrentFile {
+        TorrentFile {
+            path: path.to_string(),
+            size_bytes: 50_000_000,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_build_queries() {
+        let context = make_context(Some("Scott Brick"), vec![]);
+        let result = build_queries(&context, &make_config()).await.unwrap();
+
+        assert_eq!(result.method, "audiobook");
+        assert_eq!(result.queries[0], "Frank Herbert Dune");
+        assert!(result.queries.contains(&"Dune audiobook".to_string()));
+        assert!(result.queries.contains(&"Dune Scott Brick".to_string()));
+        assert!(result.queries.contains(&"Dune Chronicles Dune".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_scoring_prefers_unabridged_m4b_with_narrator() {
+        let context = make_context(Some("Scott Brick"), vec![]);
+        let candidates = vec![
+            make_candidate("Frank Herbert - Dune (Abridged) MP3", None),
+            make_candidate("Frank Herbert - Dune [Unabridged] Scott Brick M4B", None),
+            make_candidate("Frank Herbert - Dune EPUB", None),
+        ];
+
+        let result = score_candidates(&context, &candidates, &make_config())
+            .await
+            .unwrap();
+
+        assert!(result.candidates[0].candidate.title.contains("M4B"));
+        assert!(result.candidates[0].reasoning.contains("narrator match"));
+        assert!(result.candidates[2].candidate.title.contains("EPUB"));
+    }
+
+    #[tokio::test]
+    async fn test_scoring_maps_chapter_files() {
+        let context = make_context(
+            None,
+            vec![
+                ExpectedChapter::new(1, "One"),
+                ExpectedChapter::new(2, "Two"),
+            ],
+        );
+        let candidates = vec![make_candidate(
+            "Frank Herbert - Dune MP3",
+            Some(vec![file("Dune/02.mp3"), file("Dune/01.mp3")]),
+        )];
+
+        let result = score_candidates(&context, &candidates, &make_config())
+            .await
+            .unwrap();
+
+        let mappings = &result.candidates[0].file_mappings;
+        assert_eq!(mappings.len(), 2);
+        assert_eq!(mappings[0].ticket_item_id, "chapter-1");
+        assert_eq!(mappings[0].torrent_file_path, "Dune/01.mp3");
+    }
+
+    #[test]
+    fn test_chapter_files_orders_mappings() {
+        let dir = tempfile::tempdir().unwrap();
+        let download = dir.path().join("Dune");
+        std::fs::create_dir(&download).unwrap();
+        std::fs::write(download.join("01.mp3"), b"a").unwrap();
+        std::fs::write(download.join("02.mp3"), b"b").unwrap();
+
+        let mappings = vec![
+            FileMapping {
+                torrent_file_path: "Dune/02.mp3".to_string(),
+                ticket_item_id: "chapter-2".to_string(),
+                confidence: 0.9,
+            },
+            FileMapping {
+                torrent_file_path: "Dune/01.mp3".to_string(),
+                ticket_item_id: "chapter-1".to_string(),
+                confidence: 0.9,
+            },
+        ];
+
+        let files = chapter_files(&download, &mappings);
+        assert_eq!(
+            files,
+            vec![download.join("01.mp3"), download.join("02.mp3")]
+        );
+    }
+
+    #[test]
+    fn test_chapter_files_scans_without_mappings() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Part 10.mp3"), b"a").unwrap();
+        std::fs::write(dir.path().join("Part 2.mp3"), b"b").unwrap();
+        std::fs::write(dir.path().join("cover.jpg"), b"c").unwrap();
+
+        let files = chapter_files(dir.path(), &[]);
+        assert_eq!(
+            files,
+            vec![
+                dir.path().join("Part 2.mp3"),
+                dir.path().join("Part 10.mp3")
+            ]
+        );
+    }
+}
@@ -0,0 +1,711 @@
+//! Ebook and comic content handling (Book, Comic).
+//!
+//! Provides book-specific implementations for:
+//! - Query building: "{author} {title}", "{title} epub", "{series} 012", "{series} v03"
+//! - Scoring: requested formats (epub/azw3/pdf/cbz), collection dumps, audiobook red flags
+//! - File mapping: the single target file out of a pack
+//!
+//! Books are placed as-is; no conversion is involved.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::searcher::{SearchCategory, TorrentCandidate, TorrentFile};
+use crate::textbrain::{
+    DumbFileMapper, FileMapping, MatchResult, QueryBuildResult, ScoredCandidate, TextBrainConfig,
+    TextBrainError,
+};
+use crate::ticket::{BookFormat, ExpectedContent, QueryContext, Ticket};
+
+use super::generic;
+use super::types::{ContentError, PostProcessResult};
+
+/// Indexer categories to search for books and comics.
+pub fn search_categories() -> Vec<SearchCategory> {
+    vec![SearchCategory::Books]
+}
+
+// =============================================================================
+// Query Building
+// =============================================================================
+
+/// Build queries for ebook and comic content.
+///
+/// - Books: "{author} {title}", "{author} {title} {format}", "{isbn}", "{title}"
+/// - Comics: "{series} 012", "{series} #12", "{series} v03", "{series}"
+pub async fn build_queries(
+    context: &QueryContext,
+    config: &TextBrainConfig,
+) -> Result<QueryBuildResult, TextBrainError> {
+    let queries = match &context.expected {
+        Some(ExpectedContent::Book {
+            author,
+            title,
+            isbn,
+            formats,
+        }) => build_book_queries(author.as_deref(), title, isbn.as_deref(), formats),
+        Some(ExpectedContent::Comic {
+            series,
+            issue,
+            volume,
+        }) => build_comic_queries(series, *issue, *volume),
+        _ => return generic::build_queries(context, config).await,
+    };
+
+    if queries.is_empty() {
+        return Err(TextBrainError::NoQueriesGenerated);
+    }
+
+    Ok(QueryBuildResult {
+        queries,
+        method: "book".to_string(),
+        confidence: estimate_query_confidence(context),
+        llm_usage: None,
+    })
+}
+
+fn build_book_queries(
+    author: Option<&str>,
+    title: &str,
+    isbn: Option<&str>,
+    formats: &[BookFormat],
+) -> Vec<String> {
+    let mut queries = Vec::new();
+    let mut seen = HashSet::new();
+
+    let title_clean = normalize_text(title);
+    let preferred = formats.first().copied().unwrap_or(BookFormat::Epub);
+
+    if let Some(author) = author {
+        let author_clean = normalize_text(author);
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} {}", author_clean, title_clean),
+        );
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} {} {}", author_clean, title_clean, preferred.extension()),
+        );
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} {}", title_clean, author_clean),
+        );
+    }
+
+    if let Some(isbn) = isbn {
+        add_query(&mut queries, &mut seen, isbn.replace('-', ""));
+    }
+
+    add_query(
+        &mut queries,
+        &mut seen,
+        format!("{} {}", title_clean, preferred.extension()),
+    );
+    add_query(&mut queries, &mut seen, title_clean);
+
+    queries
+}
+
+fn build_comic_queries(series: &str, issue: Option<u32>, volume: Option<u32>) -> Vec<String> {
+    let mut queries = Vec::new();
+    let mut seen = HashSet::new();
+
+    let series_clean = normalize_text(series);
+
+    if let Some(issue) = issue {
+        // Scene releases zero-pad issue numbers
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} {:03}", series_clean, issue),
+        );
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} #{}", series_clean, issue),
+        );
+    }
+
+    if let Some(volume) = volume {
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} v{:02}", series_clean, volume),
+        );
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} vol {}", series_clean, volume),
+        );
+    }
+
+    add_query(&mut queries, &mut seen, series_clean);
+
+    queries
+}
+
+/// Add query if not already seen.
+fn add_query(queries: &mut Vec<String>, seen: &mut HashSet<String>, query: String) {
+    let normalized = query.to_lowercase();
+    if !normalized.is_empty() && seen.insert(normalized) {
+        queries.push(query);
+    }
+}
+
+/// Normalize text for search queries.
+fn normalize_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Estimate confidence in generated queries.
+fn estimate_query_confidence(context: &QueryContext) -> f32 {
+    let mut confidence: f32 = 0.5;
+
+    match &context.expected {
+        Some(ExpectedContent::Book {
+            author,
+            title,
+            isbn,
+            ..
+        }) => {
+            if author.is_some() {
+                confidence += 0.2;
+            }
+            if isbn.is_some() {
+                confidence += 0.1;
+            }
+            if title.len() > 10 {
+                confidence += 0.05;
+            }
+        }
+        Some(ExpectedContent::Comic { issue, volume, .. })
+            if issue.is_some() || volume.is_some() =>
+        {
+            confidence += 0.2;
+        }
+        _ => {}
+    }
+
+    confidence.min(0.9)
+}
+
+// =============================================================================
+// Candidate Scoring
+// =============================================================================
+
+/// Keywords that mark a pack or dump rather than a single book.
+const COLLECTION_KEYWORDS: &[&str] = &[
+    "collection",
+    "complete works",
+    "bundle",
+    "library",
+    "pack",
+    "anthology",
+    "0-day",
+    "weekly",
+];
+
+/// Score candidates for ebook and comic content.
+///
+/// Uses book-specific heuristics:
+/// - Title/author (or series and issue) match
+/// - Requested formats, in order of preference
+/// - Collection dumps penalized, unless the target file can be mapped out
+/// - Audiobook releases penalized
+pub async fn score_candidates(
+    context: &QueryContext,
+    candidates: &[TorrentCandidate],
+    _config: &TextBrainConfig,
+) -> Result<MatchResult, TextBrainError> {
+    let mut scored = score_each(context, candidates);
+
+    scored.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    Ok(MatchResult {
+        candidates: scored,
+        method: "book".to_string(),
+        llm_usage: None,
+    })
+}
+
+/// Score each candidate, in input order.
+pub(crate) fn score_each(
+    context: &QueryContext,
+    candidates: &[TorrentCandidate],
+) -> Vec<ScoredCandidate> {
+    let scorer = BookScorer::new(context);
+    candidates
+        .iter()
+        .map(|c| scorer.score_candidate(c))
+        .collect()
+}
+
+/// Ebook/comic candidate scorer.
+struct BookScorer<'a> {
+    context: &'a QueryContext,
+    file_mapper: DumbFileMapper,
+    /// Name parts that should appear in the title (author, title or series).
+    expected_names: Vec<&'a str>,
+    /// Issue or volume number expected in the title.
+    expected_number: Option<u32>,
+    formats: Vec<BookFormat>,
+    is_comic: bool,
+}
+
+impl<'a> BookScorer<'a> {
+    fn new(context: &'a QueryContext) -> Self {
+        let (expected_names, expected_number, formats, is_comic) = match &context.expected {
+            Some(ExpectedContent::Book {
+                author,
+                title,
+                formats,
+                ..
+            }) => {
+                let mut names = vec![title.as_str()];
+                names.extend(author.as_deref());
+                (names, None, formats.clone(), false)
+            }
+            Some(ExpectedContent::Comic {
+                series,
+                issue,
+                volume,
+            }) => (
+                vec![series.as_str()],
+                issue.or(*volume),
+                vec![BookFormat::Cbz, BookFormat::Cbr, BookFormat::Pdf],
+                true,
+            ),
+            _ => (vec![], None, vec![], false),
+        };
+
+        Self {
+            context,
+            file_mapper: DumbFileMapper::new(),
+            expected_names,
+            expected_number,
+            formats,
+            is_comic,
+        }
+    }
+
+    fn score_candidate(&self, candidate: &TorrentCandidate) -> ScoredCandidate {
+        let title_lower = candidate.title.to_lowercase();
+
+        let title_score = self.title_match_score(&title_lower);
+        let format_score = self.format_score(candidate, &title_lower);
+        let health_score = self.health_score(candidate);
+        let red_flag_penalty = self.red_flag_penalty(&title_lower);
+
+        let file_mappings = self.map_target_file(candidate);
+        let collection_penalty = self.collection_penalty(candidate, &title_lower, &file_mappings);
+
+        let mut score = (title_score * 0.55) + (format_score * 0.25) + (health_score * 0.20)
+            - collection_penalty
+            - red_flag_penalty;
+
+        // A confidently mapped target file is strong evidence
+        if let Some(mapping) = file_mappings.first() {
+            score = score * 0.6 + mapping.confidence * 0.4;
+        }
+
+        let reasoning = self.generate_reasoning(
+            title_score,
+            format_score,
+            collection_penalty,
+            red_flag_penalty,
+            &file_mappings,
+            candidate,
+        );
+
+        ScoredCandidate {
+            candidate: candidate.clone(),
+            score: score.clamp(0.0, 1.0),
+            reasoning,
+            file_mappings,
+        }
+    }
+
+    /// Score name and issue/volume match.
+    fn title_match_score(&self, title: &str) -> f32 {
+        if self.expected_names.is_empty() {
+            return 0.5;
+        }
+
+        let name_score = self
+            .expected_names
+            .iter()
+            .map(|name| word_match(title, name))
+            .sum::<f32>()
+            / self.expected_names.len() as f32;
+
+        match self.expected_number {
+            Some(number) => {
+                let has_number = title
+                    .split(|c: char| !c.is_ascii_digit())
+                    .filter_map(|run| run.parse::<u32>().ok())
+                    .any(|n| n == number);
+                name_score * 0.6 + if has_number { 0.4 } else { 0.0 }
+            }
+            None => name_score,
+        }
+    }
+
+    /// Score format against the requested formats.
+    fn format_score(&self, candidate: &TorrentCandidate, title: &str) -> f32 {
+        let found: Vec<BookFormat> = match &candidate.files {
+            Some(files) if !files.is_empty() => files
+                .iter()
+                .filter_map(|f| {
+                    let ext = f.path.rsplit('.').next()?;
+                    BookFormat::from_extension(ext)
+                })
+                .collect(),
+            _ => BookFormat::ALL
+                .into_iter()
+                .filter(|f| title.contains(f.extension()))
+                .collect(),
+        };
+
+        if found.is_empty() {
+            // Listed files but no book files at all
+            return if candidate.files.as_ref().is_some_and(|f| !f.is_empty()) {
+                0.0
+            } else {
+                0.5
+            };
+        }
+
+        if self.formats.is_empty() {
+            return 0.8;
+        }
+
+        self.formats
+            .iter()
+            .position(|f| found.contains(f))
+            .map(|idx| (1.0 - idx as f32 * 0.15).max(0.5))
+            .unwrap_or(0.2)
+    }
+
+    /// Score torrent health.
+    fn health_score(&self, candidate: &TorrentCandidate) -> f32 {
+        match candidate.seeders {
+            0 => 0.0,
+            1..=2 => 0.3,
+            3..=10 => 0.6,
+            11..=50 => 0.9,
+            _ => 1.0,
+        }
+    }
+
+    /// Penalty for collection dumps. Softened when the target file could be
+    /// mapped out of the pack, since it is then still usable.
+    fn collection_penalty(
+        &self,
+        candidate: &TorrentCandidate,
+        title: &str,
+        file_mappings: &[FileMapping],
+    ) -> f32 {
+        let file_count = candidate.files.as_ref().map(|f| f.len()).unwrap_or(0);
+        let is_collection = file_count > 20
+            || COLLECTION_KEYWORDS.iter().any(|kw| title.contains(kw))
+            // An issue range like "001-050" when a single issue was asked for
+            || (self.is_comic && has_number_range(title));
+
+        if !is_collection {
+            return 0.0;
+        }
+
+        if file_mappings.is_empty() {
+            0.35
+        } else {
+            0.15
+        }
+    }
+
+    /// Calculate penalty for red flags.
+    fn red_flag_penalty(&self, title: &str) -> f32 {
+        // Audiobooks share titles with their ebooks
+        if title.contains("audiobook")
+            || title.contains("m4b")
+            || title.contains("mp3")
+            || title.contains("unabridged")
+        {
+            return 0.5;
+        }
+        0.0
+    }
+
+    /// Map the single target file (if the file listing is known).
+    fn map_target_file(&self, candidate: &TorrentCandidate) -> Vec<FileMapping> {
+        match (&self.context.expected, &candidate.files) {
+            (Some(expected), Some(files)) if !files.is_empty() => {
+                self.file_mapper.map_files(files, expected)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Generate human-readable reasoning.
+    fn generate_reasoning(
+        &self,
+        title_score: f32,
+        format_score: f32,
+        collection_penalty: f32,
+        red_flag_penalty: f32,
+        file_mappings: &[FileMapping],
+        candidate: &TorrentCandidate,
+    ) -> String {
+        let mut parts = Vec::new();
+
+        if title_score >= 0.9 {
+            parts.push("excellent match".to_string());
+        } else if title_score >= 0.7 {
+            parts.push("good match".to_string());
+        } else if title_score >= 0.5 {
+            parts.push("partial match".to_string());
+        } else {
+            parts.push("weak match".to_string());
+        }
+
+        if format_score >= 0.9 {
+            parts.push("preferred format".to_string());
+        } else if format_score < 0.3 {
+            parts.push("unwanted format".to_string());
+        }
+
+        if collection_penalty > 0.0 {
+            parts.push("collection".to_string());
+        }
+        if red_flag_penalty > 0.0 {
+            parts.push("audiobook".to_string());
+        }
+
+        if candidate.seeders == 0 {
+            parts.push("dead (0 seeders)".to_string());
+        } else {
+            parts.push(format!("{} seeders", candidate.seeders));
+        }
+
+        if let Some(mapping) = file_mappings.first() {
+            parts.push(format!("target file: {}", mapping.torrent_file_path));
+        }
+
+        parts.join(", ")
+    }
+}
+
+/// Fraction of the expected words found in a title (1.0 for a full phrase match).
+fn word_match(title: &str, expected: &str) -> f32 {
+    let expected_lower = expected.to_lowercase();
+    if title.contains(&expected_lower) {
+        return 1.0;
+    }
+
+    let words: Vec<&str> = expected_lower
+        .split_whitespace()
+        .filter(|w| w.len() > 2)
+        .collect();
+    if words.is_empty() {
+        return 0.0;
+    }
+
+    let matches = words.iter().filter(|w| title.contains(*w)).count();
+    (matches as f32 / words.len() as f32) * 0.8
+}
+
+/// Whether a title contains an issue range like "001-050".
+fn has_number_range(title: &str) -> bool {
+    let chars: Vec<char> = title.chars().collect();
+    chars
+        .windows(3)
+        .any(|w| w[0].is_ascii_digit() && (w[1] == '-' || w[1] == '–') && w[2].is_ascii_digit())
+}
+
+// =============================================================================
+// File Mapping
+// =============================================================================
+
+/// Map files for ebook and comic content.
+///
+/// The standard file mapper picks the target file by title and format.
+pub fn map_files(context: &QueryContext, files: &[TorrentFile]) -> Vec<FileMapping> {
+    generic::map_files(context, files)
+}
+
+// =============================================================================
+// Post-Processing
+// =============================================================================
+
+/// Post-process ebook and comic content.
+///
+/// Books are placed as downloaded; nothing to fetch.
+pub async fn post_process(
+    ticket: &Ticket,
+    download_path: &Path,
+) -> Result<PostProcessResult, ContentError> {
+    generic::post_process(ticket, download_path).await
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searcher::TorrentSource;
+    use crate::textbrain::TextBrainMode;
+
+    fn make_config() -> TextBrainConfig {
+        TextBrainConfig {
+            mode: TextBrainMode::DumbOnly,
+            ..Default::default()
+        }
+    }
+
+    fn make_context(expected: ExpectedContent) -> QueryContext {
+        QueryContext {
+            tags: vec![],
+            description: "book".to_string(),
+            expected: Some(expected),
+            catalog_reference: None,
+            search_constraints: None,
+        }
+    }
+
+    fn make_candidate(title: &str, files: Option<Vec<&str>>) -> TorrentCandidate {
+        TorrentCandidate {
+            title: title.to_string(),
+            info_hash: title.to_string(),
+            size_bytes: 5_000_000,
+            seeders: 20,
+            leechers: 1,
+            category: None,
+            publish_date: None,
+            files: files.map(|paths| {
+                paths
+                    .into_iter()
+                    .map(|p| TorrentFile {
+                        path: p.to_string(),
+                        size_bytes: 1_000_000,
+                    })
+                    .collect()
+            }),
+            sources: vec![TorrentSource {
+                indexer: "test".to_string(),
+                magnet_uri: None,
+                torrent_url: None,
+                seeders: 20,
+                leechers: 1,
+                details_url: None,
+            }],
+            from_cache: false,
+        }
+    }
+
+    fn dune() -> ExpectedContent {
+        ExpectedContent::Book {
+            author: Some("Frank Herbert".to_string()),
+            title: "Dune".to_string(),
+            isbn: Some("978-0-441-17271-9".to_string()),
+            formats: vec![BookFormat::Epub, BookFormat::Azw3],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_build_book_queries() {
+        let result = build_queries(&make_context(dune()), &make_config())
+            .await
+            .unwrap();
+
+        assert_eq!(result.method, "book");
+        assert_eq!(result.queries[0], "Frank Herbert Dune");
+        assert!(result
+            .queries
+            .contains(&"Frank Herbert Dune epub".to_string()));
+        assert!(result.queries.contains(&"9780441172719".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_build_comic_queries() {
+        let context = make_context(ExpectedContent::comic_issue("Saga", 12));
+        let result = build_queries(&context, &make_config()).await.unwrap();
+
+        assert_eq!(result.queries[0], "Saga 012");
+        assert!(result.queries.contains(&"Saga #12".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_scoring_prefers_requested_format_over_collection() {
+        let context = make_context(dune());
+        let candidates = vec![
+            make_candidate("Frank Herbert - Dune (PDF)", None),
+            make_candidate("Frank Herbert - Complete Works Collection EPUB", None),
+            make_candidate("Frank Herbert - Dune (EPUB)", None),
+            make_candidate("Frank Herbert - Dune Unabridged Audiobook", None),
+        ];
+
+        let result = score_candidates(&context, &candidates, &make_config())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.candidates[0].candidate.title,
+            "Frank Herbert - Dune (EPUB)"
+        );
+        let collection = result
+            .candidates
+            .iter()
+            .find(|c| c.candidate.title.contains("Collection"))
+            .unwrap();
+        assert!(collection.reasoning.contains("collection"));
+        assert!(collection.score < result.candidates[0].score);
+    }
+
+    #[tokio::test]
+    async fn test_scoring_maps_target_out_of_pack() {
+        let context = make_context(dune());
+        let candidates = vec![make_candidate(
+            "Frank Herbert Collection",
+            Some(vec![
+                "Herbert/Dune.epub",
+                "Herbert/Dune Messiah.epub",
+                "Herbert/Children of Dune.epub",
+            ]),
+        )];
+
+        let result = score_candidates(&context, &candidates, &make_config())
+            .await
+            .unwrap();
+
+        let mappings = &result.candidates[0].file_mappings;
+        assert_eq!(mappings.len(), 1);
+        assert_eq!(mappings[0].torrent_file_path, "Herbert/Dune.epub");
+    }
+
+    #[tokio::test]
+    async fn test_scoring_comic_issue() {
+        let context = make_context(ExpectedContent::comic_issue("Saga", 12));
+        let candidates = vec![
+            make_candidate("Saga 001-054 (2012-2018) Complete", None),
+            make_candidate("Saga 012 (2013) (Digital) CBZ", None),
+            make_candidate("Saga 013 (2013) (Digital) CBZ", None),
+        ];
+
+        let result = score_candidates(&context, &candidates, &make_config())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.candidates[0].candidate.title,
+            "Saga 012 (2013) (Digital) CBZ"
+        );
+    }
+}
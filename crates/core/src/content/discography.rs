@@ -0,0 +1,630 @@
+//! Artist discography handling.
+//!
+//! Provides discography-specific implementations for:
+//! - Query building: "{artist} discography", "{artist} complete collection", year ranges
+//! - Scoring: artist match, coverage of the requested albums, audio format
+//! - File mapping: audio files split by album folder
+//! - Pipeline input: per-album destination folders ("{year} - {album}/")
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use regex_lite::Regex;
+
+use crate::processor::SourceFile;
+use crate::searcher::{TorrentCandidate, TorrentFile};
+use crate::textbrain::{
+    FileMapping, MatchResult, QueryBuildResult, ScoredCandidate, TextBrainConfig, TextBrainError,
+};
+use crate::ticket::{ExpectedAlbum, ExpectedContent, QueryContext, Ticket};
+
+use super::generic;
+use super::music;
+use super::types::{ContentError, PostProcessResult};
+
+// =============================================================================
+// Query Building
+// =============================================================================
+
+/// Build queries for a discography.
+///
+/// Reuses the music discography patterns ("{artist} discography FLAC",
+/// "{artist} complete collection", ...) and adds "{artist} {first}-{last}"
+/// when the album years are known.
+pub async fn build_queries(
+    context: &QueryContext,
+    config: &TextBrainConfig,
+) -> Result<QueryBuildResult, TextBrainError> {
+    let Some(ExpectedContent::Discography { artist, albums }) = &context.expected else {
+        return generic::build_queries(context, config).await;
+    };
+
+    let audio_constraints = context
+        .search_constraints
+        .as_ref()
+        .and_then(|sc| sc.audio.as_ref());
+
+    let mut queries = Vec::new();
+    let mut seen = HashSet::new();
+    let artist_clean = normalize_text(artist);
+
+    if let Some((first, last)) = year_span(albums) {
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} {}-{}", artist_clean, first, last),
+        );
+    }
+    for query in music::build_discography_queries(Some(&artist_clean), audio_constraints) {
+        add_query(&mut queries, &mut seen, query);
+    }
+
+    if queries.is_empty() {
+        return Err(TextBrainError::NoQueriesGenerated);
+    }
+
+    Ok(QueryBuildResult {
+        queries,
+        method: "discography".to_string(),
+        confidence: if albums.is_empty() { 0.6 } else { 0.75 },
+        llm_usage: None,
+    })
+}
+
+/// Add query if not already seen.
+fn add_query(queries: &mut Vec<String>, seen: &mut HashSet<String>, query: String) {
+    let normalized = query.to_lowercase();
+    if !normalized.is_empty() && seen.insert(normalized) {
+        queries.push(query);
+    }
+}
+
+/// Normalize text for search queries.
+fn normalize_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// First and last release year of the requested albums.
+fn year_span(albums: &[ExpectedAlbum]) -> Option<(u32, u32)> {
+    let years = albums.iter().filter_map(|a| a.year);
+    Some((years.clone().min()?, years.max()?))
+}
+
+// =============================================================================
+// Candidate Scoring
+// =============================================================================
+
+/// Score candidates for a discography.
+///
+/// Uses discography-specific heuristics:
+/// - Artist match
+/// - Coverage: fraction of the requested albums found in the file listing,
+///   or estimated from discography keywords and year ranges in the title
+/// - Audio format (lossless preferred)
+/// - Torrent health
+pub async fn score_candidates(
+    context: &QueryContext,
+    candidates: &[TorrentCandidate],
+    _config: &TextBrainConfig,
+) -> Result<MatchResult, TextBrainError> {
+    let mut scored = score_each(context, candidates);
+
+    scored.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    Ok(MatchResult {
+        candidates: scored,
+        method: "discography".to_string(),
+        llm_usage: None,
+    })
+}
+
+/// Score each candidate, in input order.
+pub(crate) fn score_each(
+    context: &QueryContext,
+    candidates: &[TorrentCandidate],
+) -> Vec<ScoredCandidate> {
+    let scorer = DiscographyCoverageScorer::new(context);
+    candidates
+        .iter()
+        .map(|c| scorer.score_candidate(c))
+        .collect()
+}
+
+/// Discography candidate scorer, based on album coverage.
+struct DiscographyCoverageScorer<'a> {
+    context: &'a QueryContext,
+    artist: &'a str,
+    albums: &'a [ExpectedAlbum],
+}
+
+impl<'a> DiscographyCoverageScorer<'a> {
+    fn new(context: &'a QueryContext) -> Self {
+        let (artist, albums) = match &context.expected {
+            Some(ExpectedContent::Discography { artist, albums }) => {
+                (artist.as_str(), albums.as_slice())
+            }
+            _ => ("", [].as_slice()),
+        };
+
+        Self {
+            context,
+            artist,
+            albums,
+        }
+    }
+
+    fn score_candidate(&self, candidate: &TorrentCandidate) -> ScoredCandidate {
+        let title_lower = candidate.title.to_lowercase();
+
+        let artist_score = self.artist_score(&title_lower);
+        let file_mappings = self.file_mappings(candidate);
+        let (coverage, covered) = self.coverage(candidate, &title_lower, &file_mappings);
+        let format_score = self.format_score(candidate, &title_lower);
+        let health_score = self.health_score(candidate);
+
+        let score = (artist_score * 0.25)
+            + (coverage * 0.45)
+            + (format_score * 0.10)
+            + (health_score * 0.20);
+
+        let reasoning =
+            self.generate_reasoning(artist_score, coverage, covered, format_score, candidate);
+
+        ScoredCandidate {
+            candidate: candidate.clone(),
+            score: score.clamp(0.0, 1.0),
+            reasoning,
+            file_mappings,
+        }
+    }
+
+    /// Score artist name match in the title.
+    fn artist_score(&self, title: &str) -> f32 {
+        let artist = self.artist.to_lowercase();
+        if artist.is_empty() {
+            return 0.5;
+        }
+        if title.contains(&artist) {
+            return 1.0;
+        }
+
+        let words: Vec<&str> = artist.split_whitespace().filter(|w| w.len() > 2).collect();
+        if words.is_empty() {
+            return 0.0;
+        }
+        let matches = words.iter().filter(|w| title.contains(*w)).count();
+        (matches as f32 / words.len() as f32) * 0.8
+    }
+
+    /// Map the candidate's files to the requested albums.
+    fn file_mappings(&self, candidate: &TorrentCandidate) -> Vec<FileMapping> {
+        match &candidate.files {
+            Some(files) if !files.is_empty() && !self.albums.is_empty() => {
+                map_files(self.context, files)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Fraction of the requested albums present in the candidate.
+    ///
+    /// Returns the coverage and, when it was counted from the file listing,
+    /// the number of albums found.
+    fn coverage(
+        &self,
+        candidate: &TorrentCandidate,
+        title: &str,
+        mappings: &[FileMapping],
+    ) -> (f32, Option<usize>) {
+        let has_files = candidate.files.as_ref().is_some_and(|f| !f.is_empty());
+        if has_files && !self.albums.is_empty() {
+            let found = mapped_albums(mappings).len();
+            return (found as f32 / self.albums.len() as f32, Some(found));
+        }
+
+        let is_collection = music::is_discography_candidate(Some(self.artist), candidate);
+        if !is_collection {
+            // Most likely a single album
+            return (0.1, None);
+        }
+
+        // Estimate from a year range in the title ("1967-2014")
+        let span = Regex::new(r"\b((?:19|20)\d{2})\s*[-–]\s*((?:19|20)\d{2})\b")
+            .ok()
+            .and_then(|re| re.captures(title))
+            .and_then(|caps| Some((caps[1].parse::<u32>().ok()?, caps[2].parse::<u32>().ok()?)));
+        let dated: Vec<u32> = self.albums.iter().filter_map(|a| a.year).collect();
+
+        match span {
+            Some((from, to)) if !dated.is_empty() => {
+                let inside = dated.iter().filter(|y| **y >= from && **y <= to).count();
+                (inside as f32 / dated.len() as f32, None)
+            }
+            _ => (0.7, None),
+        }
+    }
+
+    /// Score audio format. Lossless is preferred unless the constraints say
+    /// otherwise.
+    fn format_score(&self, candidate: &TorrentCandidate, title: &str) -> f32 {
+        let preferred = self
+            .context
+            .search_constraints
+            .as_ref()
+            .and_then(|sc| sc.audio.as_ref())
+            .map(|a| a.preferred_formats.as_slice())
+            .unwrap_or_default();
+
+        let has_file_ext = |ext: &str| {
+            candidate
+                .files
+                .as_ref()
+                .is_some_and(|files| files.iter().any(|f| f.path.to_lowercase().ends_with(ext)))
+        };
+
+        if let Some(format) = preferred.first() {
+            let ext = format.extension();
+            if title.contains(ext) || has_file_ext(&format!(".{}", ext)) {
+                return 1.0;
+            }
+        }
+
+        if title.contains("flac") || title.contains("lossless") || has_file_ext(".flac") {
+            if preferred.is_empty() {
+                1.0
+            } else {
+                0.8
+            }
+        } else if title.contains("320") {
+            0.7
+        } else if title.contains("mp3") || has_file_ext(".mp3") {
+            0.5
+        } else {
+            0.4
+        }
+    }
+
+    /// Score torrent health.
+    fn health_score(&self, candidate: &TorrentCandidate) -> f32 {
+        match candidate.seeders {
+            0 => 0.0,
+            1..=2 => 0.3,
+            3..=10 => 0.6,
+            11..=50 => 0.9,
+            _ => 1.0,
+        }
+    }
+
+    /// Generate human-readable reasoning.
+    fn generate_reasoning(
+        &self,
+        artist_score: f32,
+        coverage: f32,
+        covered: Option<usize>,
+        format_score: f32,
+        candidate: &TorrentCandidate,
+    ) -> String {
+        let mut parts = Vec::new();
+
+        if artist_score >= 0.9 {
+            parts.push("artist match".to_string());
+        } else if artist_score >= 0.5 {
+            parts.push("partial artist match".to_string());
+        } else {
+            parts.push("weak artist match".to_string());
+        }
+
+        match covered {
+            Some(found) => parts.push(format!("{}/{} albums", found, self.albums.len())),
+            None if coverage <= 0.1 => parts.push("single release".to_string()),
+            None => parts.push(format!("~{:.0}% coverage", coverage * 100.0)),
+        }
+
+        if format_score >= 0.8 {
+            parts.push("lossless".to_string());
+        }
+
+        if candidate.seeders == 0 {
+            parts.push("dead (0 seeders)".to_string());
+        } else {
+            parts.push(format!("{} seeders", candidate.seeders));
+        }
+
+        parts.join(", ")
+    }
+}
+
+/// Album indices (1-based) that have at least one mapped file.
+fn mapped_albums(mappings: &[FileMapping]) -> HashSet<usize> {
+    mappings
+        .iter()
+        .filter_map(|m| album_index(&m.ticket_item_id))
+        .collect()
+}
+
+/// Album index from an item ID like "album-3-track-7".
+fn album_index(item_id: &str) -> Option<usize> {
+    item_id
+        .strip_prefix("album-")?
+        .split('-')
+        .next()?
+        .parse()
+        .ok()
+}
+
+// =============================================================================
+// File Mapping
+// =============================================================================
+
+/// Map files for a discography.
+///
+/// The standard file mapper groups audio files by album folder and assigns
+/// each folder to the best-matching requested album.
+pub fn map_files(context: &QueryContext, files: &[TorrentFile]) -> Vec<FileMapping> {
+    generic::map_files(context, files)
+}
+
+// =============================================================================
+// Pipeline Input
+// =============================================================================
+
+/// Build pipeline source files that place each album in its own folder.
+///
+/// Mapped files go to "{year} - {album}/{file name}" under the ticket's
+/// destination (mapping paths are relative to the torrent's save directory,
+/// or to the download itself). When `output_extension` is given the file
+/// extension is replaced, since converted files are placed under the same
+/// name. Unmapped files are not placed.
+pub fn album_files(
+    download_path: &Path,
+    albums: &[ExpectedAlbum],
+    file_mappings: &[FileMapping],
+    output_extension: Option<&str>,
+) -> Vec<SourceFile> {
+    let save_dir = download_path.parent().unwrap_or(download_path);
+
+    file_mappings
+        .iter()
+        .filter_map(|m| {
+            let album = albums.get(album_index(&m.ticket_item_id)?.checked_sub(1)?)?;
+            let in_save_dir = save_dir.join(&m.torrent_file_path);
+            let path = if in_save_dir.exists() {
+                in_save_dir
+            } else {
+                download_path.join(&m.torrent_file_path)
+            };
+
+            let file_name = Path::new(&m.torrent_file_path).file_name()?;
+            let mut file_name = PathBuf::from(file_name);
+            if let Some(ext) = output_extension {
+                file_name.set_extension(ext);
+            }
+
+            Some(SourceFile {
+                path,
+                item_id: m.ticket_item_id.clone(),
+                dest_filename: format!(
+                    "{}/{}",
+                    album_folder_name(album),
+                    sanitize(&file_name.to_string_lossy())
+                ),
+                segment: None,
+                metadata: None,
+            })
+        })
+        .collect()
+}
+
+/// Destination folder for an album, e.g. "1973 - The Dark Side of the Moon".
+fn album_folder_name(album: &ExpectedAlbum) -> String {
+    match album.year {
+        Some(year) => sanitize(&format!("{} - {}", year, album.title)),
+        None => sanitize(&album.title),
+    }
+}
+
+/// Replace characters that are not allowed in file names.
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c => c,
+        })
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+// =============================================================================
+// Post-Processing
+// =============================================================================
+
+/// Post-process a discography.
+///
+/// Cover art differs per album, so nothing is fetched.
+pub async fn post_process(
+    ticket: &Ticket,
+    download_path: &Path,
+) -> Result<PostProcessResult, ContentError> {
+    generic::post_process(ticket, download_path).await
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searcher::TorrentSource;
+    use crate::textbrain::TextBrainMode;
+
+    fn make_config() -> TextBrainConfig {
+        TextBrainConfig {
+            mode: TextBrainMode::DumbOnly,
+            ..Default::default()
+        }
+    }
+
+    fn album(title: &str, year: u32) -> ExpectedAlbum {
+        ExpectedAlbum {
+            title: title.to_string(),
+            year: Some(year),
+            mbid: None,
+            tracks: vec![],
+        }
+    }
+
+    fn make_context() -> QueryContext {
+        QueryContext {
+            tags: vec![],
+            description: "Portishead discography".to_string(),
+            expected: Some(ExpectedContent::Discography {
+                artist: "Portishead".to_string(),
+                albums: vec![
+                    album("Dummy", 1994),
+                    album("Portishead", 1997),
+                    album("Third", 2008),
+                ],
+            }),
+            catalog_reference: None,
+            search_constraints: None,
+        }
+    }
+
+    fn make_candidate(title: &str, files: Option<Vec<&str>>) -> TorrentCandidate {
+        TorrentCandidate {
+            title: title.to_string(),
+            info_hash: title.to_string(),
+            size_bytes: 2_000_000_000,
+            seeders: 30,
+            leechers: 1,
+            category: None,
+            publish_date: None,
+            files: files.map(|paths| {
+                paths
+                    .into_iter()
+                    .map(|p| TorrentFile {
+                        path: p.to_string(),
+                        size_bytes: 30_000_000,
+                    })
+                    .collect()
+            }),
+            sources: vec![TorrentSource {
+                indexer: "test".to_string(),
+                magnet_uri: Some("magnet:?xt=urn:btih:abc".to_string()),
+                torrent_url: None,
+                seeders: 30,
+                leechers: 1,
+                details_url: None,
+            }],
+            from_cache: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_queries_include_year_span() {
+        let result = build_queries(&make_context(), &make_config())
+            .await
+            .unwrap();
+
+        assert_eq!(result.method, "discography");
+        assert_eq!(result.queries[0], "Portishead 1994-2008");
+        assert!(result.queries.iter().any(|q| q == "Portishead discography"));
+    }
+
+    #[tokio::test]
+    async fn test_full_coverage_beats_partial() {
+        let full = make_candidate(
+            "Portishead - Discography FLAC",
+            Some(vec![
+                "Portishead/1994 - Dummy/01 - Mysterons.flac",
+                "Portishead/1994 - Dummy/02 - Sour Times.flac",
+                "Portishead/1997 - Portishead/01 - Cowboys.flac",
+                "Portishead/2008 - Third/01 - Silence.flac",
+            ]),
+        );
+        let partial = make_candidate(
+            "Portishead - Collection FLAC",
+            Some(vec![
+                "Portishead/1994 - Dummy/01 - Mysterons.flac",
+                "Portishead/1994 - Dummy/02 - Sour Times.flac",
+            ]),
+        );
+        let single = make_candidate("Portishead - Dummy (1994) FLAC", None);
+
+        let result = score_candidates(&make_context(), &[single, partial, full], &make_config())
+            .await
+            .unwrap();
+
+        let titles: Vec<&str> = result
+            .candidates
+            .iter()
+            .map(|c| c.candidate.title.as_str())
+            .collect();
+        assert_eq!(
+            titles,
+            [
+                "Portishead - Discography FLAC",
+                "Portishead - Collection FLAC",
+                "Portishead - Dummy (1994) FLAC",
+            ]
+        );
+        assert!(result.candidates[0].reasoning.contains("3/3 albums"));
+        assert!(result.candidates[1].reasoning.contains("1/3 albums"));
+    }
+
+    #[tokio::test]
+    async fn test_year_range_estimates_coverage() {
+        let context = make_context();
+        let scorer = DiscographyCoverageScorer::new(&context);
+
+        let candidate = make_candidate("Portishead (1994-1997) MP3", None);
+        let (coverage, covered) = scorer.coverage(&candidate, &candidate.title.to_lowercase(), &[]);
+
+        assert!((coverage - 2.0 / 3.0).abs() < 0.01);
+        assert_eq!(covered, None);
+    }
+
+    #[test]
+    fn test_album_files_per_album_folders() {
+        let albums = vec![album("Dummy", 1994), album("Third", 2008)];
+        let mappings = vec![
+            FileMapping {
+                torrent_file_path: "Dummy/01 Mysterons.flac".to_string(),
+                ticket_item_id: "album-1-track-1".to_string(),
+                confidence: 0.9,
+            },
+            FileMapping {
+                torrent_file_path: "Third/01 Silence.flac".to_string(),
+                ticket_item_id: "album-2-track-1".to_string(),
+                confidence: 0.9,
+            },
+        ];
+
+        let files = album_files(
+            Path::new("/downloads/Disco"),
+            &albums,
+            &mappings,
+            Some("mp3"),
+        );
+
+        let dests: Vec<&str> = files.iter().map(|f| f.dest_filename.as_str()).collect();
+        assert_eq!(
+            dests,
+            [
+                "1994 - Dummy/01 Mysterons.mp3",
+                "2008 - Third/01 Silence.mp3"
+            ]
+        );
+        assert_eq!(files[1].item_id, "album-2-track-1");
+        assert_eq!(
+            files[0].path,
+            PathBuf::from("/downloads/Disco/Dummy/01 Mysterons.flac")
+        );
+    }
+}
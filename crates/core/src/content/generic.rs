@@ -6,8 +6,8 @@ use std::path::Path;
 
 use crate::searcher::{TorrentCandidate, TorrentFile};
 use crate::textbrain::{
-    CandidateMatcher, DumbFileMapper, DumbMatcher, DumbQueryBuilder, FileMapping, MatchResult,
-    QueryBuildResult, QueryBuilder, TextBrainConfig, TextBrainError,
+    CandidateMatcher, DumbFileMapper, DumbMatcher, DumbMatcherConfig, DumbQueryBuilder,
+    FileMapping, MatchResult, QueryBuildResult, QueryBuilder, TextBrainConfig, TextBrainError,
 };
 use crate::ticket::{QueryContext, Ticket};
 
@@ -26,9 +26,10 @@ pub async fn build_queries(
 pub async fn score_candidates(
     context: &QueryContext,
     candidates: &[TorrentCandidate],
-    _config: &TextBrainConfig,
+    config: &TextBrainConfig,
 ) -> Result<MatchResult, TextBrainError> {
-    let matcher = DumbMatcher::new();
+    let matcher =
+        DumbMatcher::with_config(DumbMatcherConfig::default().with_weights(&config.scoring.dumb));
     matcher.score_candidates(context, candidates).await
 }
 
@@ -95,7 +96,9 @@ mod tests {
             updated_at: now,
             created_by: "test".to_string(),
             output_constraints: None,
+            textbrain_overrides: None,
             retry_count: 0,
+            reacquire_count: 0,
         };
 
         let result = post_process(&ticket, Path::new("/tmp")).await.unwrap();
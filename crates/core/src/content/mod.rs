@@ -8,7 +8,10 @@
 //! ```text
 //! match ticket.query_context.expected {
 //!     Album/Track    => content::music::*
-//!     Movie/TvEpisode => content::video::*
+//!     Movie/TvEpisode/TvSeason/TvSeries => content::video::*
+//!     Discography    => content::discography::*
+//!     Audiobook      => content::audiobook::*
+//!     Book/Comic     => content::book::*
 //!     _              => content::generic::*
 //! }
 //! ```
@@ -17,18 +20,26 @@
 //!
 //! - **Music** (`music.rs`): Albums and tracks - Phase 5c
 //! - **Video** (`video.rs`): Movies and TV episodes - Phase 5d
+//! - **Discography** (`discography.rs`): Artist discographies split into album folders
+//! - **Audiobook** (`audiobook.rs`): Chaptered audiobooks merged to M4B
+//! - **Book** (`book.rs`): Ebooks and comics, placed without conversion
 //! - **Generic** (`generic.rs`): Fallback for unknown content
 
+pub mod audiobook;
+mod book;
+pub mod discography;
 mod generic;
-mod music;
+pub mod music;
+mod size;
 mod types;
 mod video;
 
 use std::path::Path;
 
-use crate::searcher::{TorrentCandidate, TorrentFile};
+use crate::searcher::{SearchCategory, TorrentCandidate, TorrentFile};
 use crate::textbrain::{
-    FileMapping, MatchResult, QueryBuildResult, TextBrainConfig, TextBrainError,
+    DumbMatcher, DumbMatcherConfig, FileMapping, MatchResult, QueryBuildResult, ScoringWeights,
+    TextBrainConfig, TextBrainError,
 };
 use crate::ticket::{ExpectedContent, QueryContext, Ticket};
 
@@ -101,16 +112,38 @@ pub async fn build_queries(
         Some(ExpectedContent::Album { .. }) | Some(ExpectedContent::Track { .. }) => {
             music::build_queries(context, config).await
         }
-        Some(ExpectedContent::Movie { .. }) | Some(ExpectedContent::TvEpisode { .. }) => {
-            video::build_queries(context, config).await
+        Some(ExpectedContent::Movie { .. })
+        | Some(ExpectedContent::TvEpisode { .. })
+        | Some(ExpectedContent::TvSeason { .. })
+        | Some(ExpectedContent::TvSeries { .. }) => video::build_queries(context, config).await,
+        Some(ExpectedContent::Discography { .. }) => {
+            discography::build_queries(context, config).await
+        }
+        Some(ExpectedContent::Audiobook { .. }) => audiobook::build_queries(context, config).await,
+        Some(ExpectedContent::Book { .. }) | Some(ExpectedContent::Comic { .. }) => {
+            book::build_queries(context, config).await
         }
         _ => generic::build_queries(context, config).await,
     }
 }
 
+/// Indexer categories to restrict searches to, by content type.
+///
+/// None searches all categories.
+pub fn search_categories(context: &QueryContext) -> Option<Vec<SearchCategory>> {
+    match &context.expected {
+        Some(ExpectedContent::Book { .. }) | Some(ExpectedContent::Comic { .. }) => {
+            Some(book::search_categories())
+        }
+        _ => None,
+    }
+}
+
 /// Score candidates based on content type.
 ///
 /// Dispatches to content-specific scorers based on `ExpectedContent`.
+/// Scorers never see blacklisted candidates: `TextBrain` drops them before
+/// dispatching here, so the blacklist is enforced in one place.
 pub async fn score_candidates(
     context: &QueryContext,
     candidates: &[TorrentCandidate],
@@ -120,13 +153,70 @@ pub async fn score_candidates(
         Some(ExpectedContent::Album { .. }) | Some(ExpectedContent::Track { .. }) => {
             music::score_candidates(context, candidates, config).await
         }
-        Some(ExpectedContent::Movie { .. }) | Some(ExpectedContent::TvEpisode { .. }) => {
+        Some(ExpectedContent::Movie { .. })
+        | Some(ExpectedContent::TvEpisode { .. })
+        | Some(ExpectedContent::TvSeason { .. })
+        | Some(ExpectedContent::TvSeries { .. }) => {
             video::score_candidates(context, candidates, config).await
         }
+        Some(ExpectedContent::Discography { .. }) => {
+            discography::score_candidates(context, candidates, config).await
+        }
+        Some(ExpectedContent::Audiobook { .. }) => {
+            audiobook::score_candidates(context, candidates, config).await
+        }
+        Some(ExpectedContent::Book { .. }) | Some(ExpectedContent::Comic { .. }) => {
+            book::score_candidates(context, candidates, config).await
+        }
         _ => generic::score_candidates(context, candidates, config).await,
     }
 }
 
+/// Scores from the content-specific scorer (music or video) with the given
+/// weights, in candidate order. None for content without a dedicated scorer.
+pub(crate) fn content_scores(
+    context: &QueryContext,
+    candidates: &[TorrentCandidate],
+    weights: &ScoringWeights,
+) -> Option<Vec<f32>> {
+    let scored = match &context.expected {
+        Some(ExpectedContent::Album { .. }) | Some(ExpectedContent::Track { .. }) => {
+            music::score_each(context, candidates, &weights.music)
+        }
+        Some(ExpectedContent::Movie { .. })
+        | Some(ExpectedContent::TvEpisode { .. })
+        | Some(ExpectedContent::TvSeason { .. })
+        | Some(ExpectedContent::TvSeries { .. }) => {
+            video::score_each(context, candidates, &weights.video)
+        }
+        _ => return None,
+    };
+    Some(scored.into_iter().map(|sc| sc.score).collect())
+}
+
+/// Scores from the scorer [`score_candidates`] dispatches to, with the given
+/// weights, in candidate order.
+pub(crate) fn dispatched_scores(
+    context: &QueryContext,
+    candidates: &[TorrentCandidate],
+    weights: &ScoringWeights,
+) -> Vec<f32> {
+    if let Some(scores) = content_scores(context, candidates, weights) {
+        return scores;
+    }
+
+    let scored = match &context.expected {
+        Some(ExpectedContent::Discography { .. }) => discography::score_each(context, candidates),
+        Some(ExpectedContent::Audiobook { .. }) => audiobook::score_each(context, candidates),
+        Some(ExpectedContent::Book { .. }) | Some(ExpectedContent::Comic { .. }) => {
+            book::score_each(context, candidates)
+        }
+        _ => DumbMatcher::with_config(DumbMatcherConfig::default().with_weights(&weights.dumb))
+            .score_each(context, candidates),
+    };
+    scored.into_iter().map(|sc| sc.score).collect()
+}
+
 /// Map torrent files to expected content items.
 ///
 /// Dispatches to content-specific file mappers based on `ExpectedContent`.
@@ -135,8 +225,14 @@ pub fn map_files(context: &QueryContext, files: &[TorrentFile]) -> Vec<FileMappi
         Some(ExpectedContent::Album { .. }) | Some(ExpectedContent::Track { .. }) => {
             music::map_files(context, files)
         }
-        Some(ExpectedContent::Movie { .. }) | Some(ExpectedContent::TvEpisode { .. }) => {
-            video::map_files(context, files)
+        Some(ExpectedContent::Movie { .. })
+        | Some(ExpectedContent::TvEpisode { .. })
+        | Some(ExpectedContent::TvSeason { .. })
+        | Some(ExpectedContent::TvSeries { .. }) => video::map_files(context, files),
+        Some(ExpectedContent::Discography { .. }) => discography::map_files(context, files),
+        Some(ExpectedContent::Audiobook { .. }) => audiobook::map_files(context, files),
+        Some(ExpectedContent::Book { .. }) | Some(ExpectedContent::Comic { .. }) => {
+            book::map_files(context, files)
         }
         _ => generic::map_files(context, files),
     }
@@ -154,9 +250,21 @@ pub async fn post_process(
         Some(ExpectedContent::Album { .. }) | Some(ExpectedContent::Track { .. }) => {
             music::post_process(ticket, download_path).await
         }
-        Some(ExpectedContent::Movie { .. }) | Some(ExpectedContent::TvEpisode { .. }) => {
+        Some(ExpectedContent::Movie { .. })
+        | Some(ExpectedContent::TvEpisode { .. })
+        | Some(ExpectedContent::TvSeason { .. })
+        | Some(ExpectedContent::TvSeries { .. }) => {
             video::post_process(ticket, download_path).await
         }
+        Some(ExpectedContent::Discography { .. }) => {
+            discography::post_process(ticket, download_path).await
+        }
+        Some(ExpectedContent::Audiobook { .. }) => {
+            audiobook::post_process(ticket, download_path).await
+        }
+        Some(ExpectedContent::Book { .. }) | Some(ExpectedContent::Comic { .. }) => {
+            book::post_process(ticket, download_path).await
+        }
         _ => generic::post_process(ticket, download_path).await,
     }
 }
@@ -225,7 +333,9 @@ mod tests {
             updated_at: now,
             created_by: "test".to_string(),
             output_constraints: None,
+            textbrain_overrides: None,
             retry_count: 0,
+            reacquire_count: 0,
         }
     }
 
@@ -3,25 +3,28 @@
 //! Provides music-specific implementations for:
 //! - Query building: "{artist} {album}", "{artist} FLAC", etc.
 //! - Scoring: track count validation, audio format, red flags
-//! - File mapping: track number extraction, disc handling
+//! - File mapping: track number extraction, disc handling, CUE + single-file images
+//! - Pipeline input: splitting CUE images into per-track files
 //! - Post-processing: cover art detection and fetching
 
 use std::collections::HashSet;
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 
 use regex_lite::Regex;
 
-use crate::converter::AudioFormat;
+use crate::converter::{AudioFormat, TimeSegment};
+use crate::processor::{PipelineMetadata, SourceFile};
 use crate::searcher::{TorrentCandidate, TorrentFile};
 use crate::textbrain::{
-    DumbFileMapper, FileMapping, MatchResult, QueryBuildResult, ScoredCandidate, TextBrainConfig,
-    TextBrainError,
+    DumbFileMapper, FileMapping, MatchResult, MusicScoringWeights, QueryBuildResult,
+    ScoredCandidate, TextBrainConfig, TextBrainError,
 };
 use crate::ticket::{
-    AudioSearchConstraints, CatalogReference, ExpectedContent, QueryContext, Ticket,
+    AudioSearchConstraints, CatalogReference, ExpectedContent, ExpectedTrack, QueryContext, Ticket,
 };
 
 use super::generic;
+use super::size::{self, SizeCheck};
 use super::types::{ContentError, PostProcessResult};
 
 // =============================================================================
@@ -254,26 +257,34 @@ fn build_album_queries_inner(
 }
 
 /// Get format keywords from audio constraints, with sensible defaults.
+///
+/// Hi-res constraints (24-bit, or above 48kHz) put "24bit" first.
 fn get_format_keywords(constraints: Option<&AudioSearchConstraints>) -> Vec<&'static str> {
-    if let Some(c) = constraints {
-        if !c.preferred_formats.is_empty() {
-            return c
-                .preferred_formats
-                .iter()
-                .filter_map(|f| match f {
-                    AudioFormat::Flac => Some("FLAC"),
-                    AudioFormat::Alac => Some("ALAC"),
-                    AudioFormat::Aac => Some("AAC"),
-                    AudioFormat::Mp3 => Some("MP3"),
-                    AudioFormat::Opus => Some("OPUS"),
-                    AudioFormat::OggVorbis => Some("OGG"),
-                    AudioFormat::Wav => None,
-                })
-                .collect();
-        }
+    let mut keywords = match constraints {
+        Some(c) if !c.preferred_formats.is_empty() => c
+            .preferred_formats
+            .iter()
+            .filter_map(|f| match f {
+                AudioFormat::Flac => Some("FLAC"),
+                AudioFormat::Alac => Some("ALAC"),
+                AudioFormat::Aac => Some("AAC"),
+                AudioFormat::Mp3 => Some("MP3"),
+                AudioFormat::Opus => Some("OPUS"),
+                AudioFormat::OggVorbis => Some("OGG"),
+                AudioFormat::Wav => None,
+            })
+            .collect(),
+        // Default: prefer FLAC
+        _ => vec!["FLAC"],
+    };
+
+    let hi_res = constraints.is_some_and(|c| {
+        c.min_bit_depth.is_some_and(|d| d > 16) || c.min_sample_rate_hz.is_some_and(|r| r > 48_000)
+    });
+    if hi_res {
+        keywords.insert(0, "24bit");
     }
-    // Default: prefer FLAC
-    vec!["FLAC"]
+    keywords
 }
 
 /// Build queries for a single track.
@@ -447,14 +458,9 @@ fn estimate_query_confidence(context: &QueryContext) -> f32 {
 pub async fn score_candidates(
     context: &QueryContext,
     candidates: &[TorrentCandidate],
-    _config: &TextBrainConfig,
+    config: &TextBrainConfig,
 ) -> Result<MatchResult, TextBrainError> {
-    let scorer = MusicScorer::new(context);
-
-    let mut scored: Vec<ScoredCandidate> = candidates
-        .iter()
-        .map(|c| scorer.score_candidate(c))
-        .collect();
+    let mut scored = score_each(context, candidates, &config.scoring.music);
 
     // Sort by score descending
     scored.sort_by(|a, b| {
@@ -470,9 +476,23 @@ pub async fn score_candidates(
     })
 }
 
+/// Score each candidate with the given weights, in input order.
+pub(crate) fn score_each(
+    context: &QueryContext,
+    candidates: &[TorrentCandidate],
+    weights: &MusicScoringWeights,
+) -> Vec<ScoredCandidate> {
+    let scorer = MusicScorer::new(context, weights);
+    candidates
+        .iter()
+        .map(|c| scorer.score_candidate(c))
+        .collect()
+}
+
 /// Music-specific candidate scorer.
 struct MusicScorer<'a> {
     context: &'a QueryContext,
+    weights: &'a MusicScoringWeights,
     file_mapper: DumbFileMapper,
     expected_artist: Option<&'a str>,
     expected_title: Option<&'a str>,
@@ -481,10 +501,13 @@ struct MusicScorer<'a> {
     audio_constraints: Option<&'a AudioSearchConstraints>,
     /// Catalog reference for validation (track count, duration).
     catalog_ref: Option<&'a CatalogReference>,
+    /// Expected album duration in seconds, for size sanity checks, and
+    /// whether it was estimated from the track count.
+    expected_duration: Option<(u64, bool)>,
 }
 
 impl<'a> MusicScorer<'a> {
-    fn new(context: &'a QueryContext) -> Self {
+    fn new(context: &'a QueryContext, weights: &'a MusicScoringWeights) -> Self {
         let (expected_artist, expected_title, expected_track_count) = match &context.expected {
             Some(ExpectedContent::Album {
                 artist,
@@ -504,14 +527,21 @@ impl<'a> MusicScorer<'a> {
 
         let catalog_ref = context.catalog_reference.as_ref();
 
+        let expected_duration = match &context.expected {
+            Some(ExpectedContent::Album { tracks, .. }) => album_duration(tracks, catalog_ref),
+            _ => None,
+        };
+
         Self {
             context,
+            weights,
             file_mapper: DumbFileMapper::new(),
             expected_artist,
             expected_title,
             expected_track_count,
             audio_constraints,
             catalog_ref,
+            expected_duration,
         }
     }
 
@@ -522,7 +552,7 @@ impl<'a> MusicScorer<'a> {
         let title_score = self.title_match_score(&title_lower);
         let format_score = self.format_score(&title_lower);
         let health_score = self.health_score(candidate);
-        let red_flag_penalty = self.red_flag_penalty(&title_lower);
+        let red_flag_penalty = self.red_flag_penalty(&title_lower, candidate.size_bytes);
 
         // Constraint-based scoring adjustments
         let constraint_bonus = self.constraint_bonus(&title_lower);
@@ -534,9 +564,9 @@ impl<'a> MusicScorer<'a> {
         let catalog_bonus = self.catalog_validation_bonus(&file_mappings);
 
         // Weighted combination
-        let base_score = (title_score * 0.40)
-            + (format_score * 0.15)
-            + (health_score * 0.10)
+        let base_score = (title_score * self.weights.title)
+            + (format_score * self.weights.format)
+            + (health_score * self.weights.health)
             + constraint_bonus
             + catalog_bonus
             - red_flag_penalty;
@@ -604,6 +634,25 @@ impl<'a> MusicScorer<'a> {
             }
         }
 
+        // Check lossless bit depth and sample rate (only when tagged)
+        let release = parse_lossless_release(title);
+        if let (Some(min_depth), Some(depth)) = (constraints.min_bit_depth, release.bit_depth) {
+            if depth >= min_depth {
+                bonus += 0.05;
+            } else {
+                bonus -= 0.10;
+            }
+        }
+        if let (Some(min_rate), Some(rate)) =
+            (constraints.min_sample_rate_hz, release.sample_rate_hz)
+        {
+            if rate >= min_rate {
+                bonus += 0.05;
+            } else {
+                bonus -= 0.10;
+            }
+        }
+
         bonus
     }
 
@@ -750,7 +799,7 @@ impl<'a> MusicScorer<'a> {
     /// Calculate penalty for red flags.
     ///
     /// Uses audio constraints for avoid_compilations and avoid_live if present.
-    fn red_flag_penalty(&self, title: &str) -> f32 {
+    fn red_flag_penalty(&self, title: &str, size_bytes: u64) -> f32 {
         let mut penalty: f32 = 0.0;
 
         // Check if user explicitly wants to avoid compilations via constraints
@@ -814,9 +863,20 @@ impl<'a> MusicScorer<'a> {
             penalty += 0.2;
         }
 
+        // Implausible size for the claimed format
+        penalty += self.size_check(title, size_bytes).penalty();
+
         penalty.min(0.8) // Don't completely eliminate
     }
 
+    /// Check the release size against the expected album duration.
+    fn size_check(&self, title: &str, size_bytes: u64) -> SizeCheck {
+        match self.expected_duration {
+            Some((secs, estimated)) => size::check_music(title, size_bytes, secs, estimated),
+            None => SizeCheck::Plausible,
+        }
+    }
+
     /// Calculate file mapping score.
     fn file_mapping_score(&self, candidate: &TorrentCandidate) -> (Vec<FileMapping>, f32) {
         let expected = match &self.context.expected {
@@ -829,7 +889,7 @@ impl<'a> MusicScorer<'a> {
             _ => return (Vec::new(), 0.0),
         };
 
-        let mappings = self.file_mapper.map_files(files, expected);
+        let mappings = map_music_files(&self.file_mapper, files, expected);
 
         // Score based on coverage and confidence
         if mappings.is_empty() {
@@ -885,6 +945,20 @@ impl<'a> MusicScorer<'a> {
             parts.push("lossy format".to_string());
         }
 
+        // Lossless resolution and source
+        let release = parse_lossless_release(&candidate.title.to_lowercase());
+        match (release.bit_depth, release.sample_rate_hz) {
+            (Some(depth), Some(rate)) => {
+                parts.push(format!("{}/{}", depth, rate as f32 / 1000.0));
+            }
+            (Some(depth), None) => parts.push(format!("{}-bit", depth)),
+            (None, Some(rate)) => parts.push(format!("{}kHz", rate as f32 / 1000.0)),
+            (None, None) => {}
+        }
+        if release.vinyl {
+            parts.push("vinyl rip".to_string());
+        }
+
         // Health
         if health_score >= 0.8 {
             parts.push(format!("{} seeders", candidate.seeders));
@@ -923,6 +997,12 @@ impl<'a> MusicScorer<'a> {
                 parts.push("tribute album".to_string());
             }
         }
+        if let Some(size_note) = self
+            .size_check(&candidate.title.to_lowercase(), candidate.size_bytes)
+            .describe()
+        {
+            parts.push(size_note);
+        }
 
         // File mapping
         if mapping_score > 0.0 {
@@ -933,6 +1013,41 @@ impl<'a> MusicScorer<'a> {
     }
 }
 
+/// Expected album duration in seconds, and whether it is an estimate.
+///
+/// Prefers the MusicBrainz total, then summed track durations, then falls
+/// back to the track count.
+fn album_duration(
+    tracks: &[ExpectedTrack],
+    catalog_ref: Option<&CatalogReference>,
+) -> Option<(u64, bool)> {
+    if let Some(CatalogReference::MusicBrainz {
+        total_duration_ms: Some(ms),
+        ..
+    }) = catalog_ref
+    {
+        return Some((ms / 1000, false));
+    }
+
+    let durations: Option<Vec<u64>> = tracks
+        .iter()
+        .map(|t| {
+            t.duration_ms
+                .map(|ms| ms / 1000)
+                .or(t.duration_secs.map(u64::from))
+        })
+        .collect();
+    if let Some(durations) = durations.filter(|d| !d.is_empty()) {
+        return Some((durations.iter().sum(), false));
+    }
+
+    let track_count = match catalog_ref {
+        Some(CatalogReference::MusicBrainz { track_count, .. }) => *track_count as usize,
+        _ => tracks.len(),
+    };
+    (track_count > 0).then(|| (track_count as u64 * size::ESTIMATED_TRACK_SECS, true))
+}
+
 /// Extract bitrate from title string (e.g., "320", "256", "192").
 fn extract_bitrate(title: &str) -> Option<u32> {
     // Common bitrate patterns
@@ -953,6 +1068,63 @@ fn extract_bitrate(title: &str) -> Option<u32> {
     None
 }
 
+/// Lossless resolution and source parsed from a lowercase release title.
+#[derive(Debug, Default, PartialEq)]
+struct LosslessRelease {
+    /// Bit depth ("24bit", "24-96", "Hi-Res").
+    bit_depth: Option<u8>,
+    /// Sample rate in Hz ("96kHz", "24-96").
+    sample_rate_hz: Option<u32>,
+    /// Vinyl rip ("Vinyl", "LP rip", "needle drop").
+    vinyl: bool,
+}
+
+/// Parse bit depth, sample rate and vinyl tags from a lowercase title.
+fn parse_lossless_release(title: &str) -> LosslessRelease {
+    let captures = |pattern: &str| Regex::new(pattern).ok()?.captures(title);
+    let rate_hz = |khz: &str| match khz {
+        "44" | "44.1" => Some(44_100),
+        "88" | "88.2" => Some(88_200),
+        "176" | "176.4" => Some(176_400),
+        "352.8" => Some(352_800),
+        _ => khz.parse::<u32>().ok().map(|k| k * 1000),
+    };
+    let rates = r"(44\.1|44|48|88\.2|88|96|176\.4|176|192|352\.8|384)";
+
+    let mut release = LosslessRelease::default();
+
+    // "24-96", "24/192", "16-44.1"
+    if let Some(caps) = captures(&format!(r"\b(16|24|32)\s*[-/]\s*{}\b", rates)) {
+        release.bit_depth = caps[1].parse().ok();
+        release.sample_rate_hz = rate_hz(&caps[2]);
+    }
+    // "24bit", "24-bit", "24 bit"
+    if release.bit_depth.is_none() {
+        if let Some(caps) = captures(r"\b(16|24|32)\s*-?\s*bits?\b") {
+            release.bit_depth = caps[1].parse().ok();
+        }
+    }
+    // "96kHz", "96 khz"
+    if release.sample_rate_hz.is_none() {
+        if let Some(caps) = captures(&format!(r"\b{}\s*-?\s*khz\b", rates)) {
+            release.sample_rate_hz = rate_hz(&caps[1]);
+        }
+    }
+    if release.bit_depth.is_none()
+        && ["hi-res", "hires", "hi res", "hd audio"]
+            .iter()
+            .any(|tag| title.contains(tag))
+    {
+        release.bit_depth = Some(24);
+    }
+
+    release.vinyl = ["vinyl", "lp rip", "lprip", "needle drop", "needledrop"]
+        .iter()
+        .any(|tag| title.contains(tag));
+
+    release
+}
+
 /// Check if a word is a stop word.
 fn is_stop_word(word: &str) -> bool {
     matches!(
@@ -1425,10 +1597,430 @@ impl<'a> DiscographyScorer<'a> {
 
 /// Map files for music content.
 ///
-/// Uses the standard file mapper with music-aware extensions.
+/// Uses the standard file mapper with music-aware extensions. Albums released
+/// as one audio image plus a CUE sheet map every track to the image.
 pub fn map_files(context: &QueryContext, files: &[TorrentFile]) -> Vec<FileMapping> {
-    // Use the generic mapper which already handles music well
-    generic::map_files(context, files)
+    match &context.expected {
+        Some(expected) => map_music_files(&DumbFileMapper::new(), files, expected),
+        None => vec![],
+    }
+}
+
+/// Map files, handling CUE + single-file images before the standard mapper.
+fn map_music_files(
+    mapper: &DumbFileMapper,
+    files: &[TorrentFile],
+    expected: &ExpectedContent,
+) -> Vec<FileMapping> {
+    if let ExpectedContent::Album { tracks, .. } = expected {
+        if let Some(mappings) = map_cue_image(files, tracks) {
+            return mappings;
+        }
+    }
+    mapper.map_files(files, expected)
+}
+
+/// Map every expected track to the audio image of a CUE release.
+///
+/// Applies when the listing has a CUE sheet and a single audio file for a
+/// multi-track album. Only the listing is known at this point, so the CUE
+/// itself is parsed after download (see [`cue_track_files`]).
+fn map_cue_image(files: &[TorrentFile], tracks: &[ExpectedTrack]) -> Option<Vec<FileMapping>> {
+    if tracks.len() < 2 {
+        return None;
+    }
+
+    let has_cue = files
+        .iter()
+        .any(|f| f.path.to_lowercase().ends_with(".cue"));
+    let audio: Vec<&TorrentFile> = files
+        .iter()
+        .filter(|f| {
+            let lower = f.path.to_lowercase();
+            CUE_IMAGE_EXTENSIONS
+                .iter()
+                .chain(["mp3", "m4a", "ogg", "opus"].iter())
+                .any(|ext| lower.ends_with(&format!(".{}", ext)))
+        })
+        .collect();
+    let [image] = audio.as_slice() else {
+        return None;
+    };
+    if !has_cue {
+        return None;
+    }
+
+    Some(
+        tracks
+            .iter()
+            .map(|track| FileMapping {
+                torrent_file_path: image.path.clone(),
+                ticket_item_id: format!("track-{}", track.number),
+                confidence: 0.7,
+            })
+            .collect(),
+    )
+}
+
+// =============================================================================
+// CUE Sheets
+// =============================================================================
+
+/// Audio image formats that are split by their CUE sheet.
+const CUE_IMAGE_EXTENSIONS: &[&str] = &["flac", "ape", "wv", "wav"];
+
+/// A parsed CUE sheet.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CueSheet {
+    /// Album performer.
+    pub performer: Option<String>,
+    /// Album title.
+    pub title: Option<String>,
+    /// Release year (`REM DATE`).
+    pub year: Option<u16>,
+    /// Genre (`REM GENRE`).
+    pub genre: Option<String>,
+    /// Tracks in sheet order.
+    pub tracks: Vec<CueTrack>,
+}
+
+/// One track of a [`CueSheet`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueTrack {
+    /// Track number.
+    pub number: u32,
+    /// Track title.
+    pub title: Option<String>,
+    /// Track performer, when different from the album performer.
+    pub performer: Option<String>,
+    /// Audio file the track is in.
+    pub file: String,
+    /// Start offset (`INDEX 01`) in seconds.
+    pub start_secs: f64,
+}
+
+impl CueSheet {
+    /// Whether all tracks are in one audio file, which then needs splitting.
+    pub fn is_single_file(&self) -> bool {
+        self.tracks.len() > 1 && self.tracks.iter().all(|t| t.file == self.tracks[0].file)
+    }
+
+    /// Time range of each track within its file. A track ends where the next
+    /// one in the same file starts; the last one runs to the end.
+    pub fn segments(&self) -> Vec<TimeSegment> {
+        self.tracks
+            .iter()
+            .enumerate()
+            .map(|(idx, track)| TimeSegment {
+                start_secs: track.start_secs,
+                end_secs: self
+                    .tracks
+                    .get(idx + 1)
+                    .filter(|next| next.file == track.file)
+                    .map(|next| next.start_secs),
+            })
+            .collect()
+    }
+}
+
+/// Parse a CUE sheet.
+///
+/// Tracks without an `INDEX 01` are skipped. Unknown commands are ignored.
+pub fn parse_cue_sheet(text: &str) -> CueSheet {
+    let mut sheet = CueSheet::default();
+    let mut file = String::new();
+    let mut current: Option<PendingCueTrack> = None;
+
+    for line in text.trim_start_matches('\u{feff}').lines() {
+        let line = line.trim();
+        let (command, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let rest = rest.trim();
+
+        match command.to_uppercase().as_str() {
+            "FILE" => {
+                sheet
+                    .tracks
+                    .extend(current.take().and_then(|t| t.finish(&file)));
+                file = cue_file_name(rest);
+            }
+            "TRACK" => {
+                sheet
+                    .tracks
+                    .extend(current.take().and_then(|t| t.finish(&file)));
+                current = rest
+                    .split_whitespace()
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .map(PendingCueTrack::new);
+            }
+            "TITLE" => match current.as_mut() {
+                Some(track) => track.title = Some(unquote(rest)),
+                None => sheet.title = Some(unquote(rest)),
+            },
+            "PERFORMER" => match current.as_mut() {
+                Some(track) => track.performer = Some(unquote(rest)),
+                None => sheet.performer = Some(unquote(rest)),
+            },
+            "INDEX" => {
+                let mut parts = rest.split_whitespace();
+                if let (Some("01"), Some(time), Some(track)) =
+                    (parts.next(), parts.next(), current.as_mut())
+                {
+                    track.start_secs = parse_cue_time(time);
+                }
+            }
+            "REM" => {
+                let (key, value) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                match key.to_uppercase().as_str() {
+                    "DATE" => sheet.year = value.trim().get(..4).and_then(|y| y.parse().ok()),
+                    "GENRE" => sheet.genre = Some(unquote(value)),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    sheet.tracks.extend(current.and_then(|t| t.finish(&file)));
+
+    sheet
+}
+
+/// A track whose `INDEX 01` may not have been seen yet.
+struct PendingCueTrack {
+    number: u32,
+    title: Option<String>,
+    performer: Option<String>,
+    start_secs: Option<f64>,
+}
+
+impl PendingCueTrack {
+    fn new(number: u32) -> Self {
+        Self {
+            number,
+            title: None,
+            performer: None,
+            start_secs: None,
+        }
+    }
+
+    fn finish(self, file: &str) -> Option<CueTrack> {
+        Some(CueTrack {
+            number: self.number,
+            title: self.title,
+            performer: self.performer,
+            file: file.to_string(),
+            start_secs: self.start_secs?,
+        })
+    }
+}
+
+/// Remove surrounding quotes.
+fn unquote(value: &str) -> String {
+    value.trim().trim_matches('"').to_string()
+}
+
+/// File name from a FILE command argument (`"Album.flac" WAVE`).
+fn cue_file_name(rest: &str) -> String {
+    match rest.strip_prefix('"') {
+        Some(quoted) => quoted.split('"').next().unwrap_or_default().to_string(),
+        None => rest
+            .rsplit_once(char::is_whitespace)
+            .map(|(name, _)| name)
+            .unwrap_or(rest)
+            .to_string(),
+    }
+}
+
+/// Parse a CUE timestamp (`mm:ss:ff`, 75 frames per second) into seconds.
+fn parse_cue_time(time: &str) -> Option<f64> {
+    let mut parts = time.split(':').map(|p| p.parse::<u32>().ok());
+    let (minutes, seconds, frames) = (parts.next()??, parts.next()??, parts.next()??);
+    Some(minutes as f64 * 60.0 + seconds as f64 + frames as f64 / 75.0)
+}
+
+/// The expected track a CUE track corresponds to: same title, else same number.
+fn match_cue_track<'a>(
+    cue_track: &CueTrack,
+    tracks: &'a [ExpectedTrack],
+) -> Option<&'a ExpectedTrack> {
+    let by_title = cue_track.title.as_ref().and_then(|title| {
+        let title = normalize_text(&title.to_lowercase());
+        tracks
+            .iter()
+            .find(|t| normalize_text(&t.title.to_lowercase()) == title)
+    });
+    by_title.or_else(|| tracks.iter().find(|t| t.number == cue_track.number))
+}
+
+// =============================================================================
+// Pipeline Input
+// =============================================================================
+
+/// Build per-track pipeline source files from a CUE + single-file image.
+///
+/// Looks for a CUE sheet in the download whose tracks are all in one audio
+/// file, and returns one source per track with its time segment and metadata
+/// (track from the CUE, matched to the ticket's expected tracks; album and
+/// artist from the ticket, falling back to the CUE). Destination names are
+/// "{nn} - {title}.{output_extension}". Returns None when there is no such
+/// CUE, in which case the download is placed as usual.
+pub fn cue_track_files(
+    download_path: &Path,
+    expected: &ExpectedContent,
+    output_extension: &str,
+) -> Option<Vec<SourceFile>> {
+    let ExpectedContent::Album {
+        artist,
+        title: album_title,
+        tracks,
+    } = expected
+    else {
+        return None;
+    };
+
+    let (sheet, image) = find_cue_image(download_path)?;
+    let segments = sheet.segments();
+    let track_total = sheet.tracks.len() as u16;
+
+    // Tracks sharing a number would be split to the same file; number them
+    // as the CUE does when the matches don't keep them apart
+    let mut matches: Vec<Option<&ExpectedTrack>> = sheet
+        .tracks
+        .iter()
+        .map(|cue_track| match_cue_track(cue_track, tracks))
+        .collect();
+    let mut numbers = HashSet::new();
+    let distinct = sheet
+        .tracks
+        .iter()
+        .zip(&matches)
+        .all(|(cue_track, matched)| numbers.insert(matched.map_or(cue_track.number, |t| t.number)));
+    if !distinct {
+        matches.fill(None);
+    }
+
+    let sources = sheet
+        .tracks
+        .iter()
+        .zip(matches)
+        .zip(segments)
+        .map(|((cue_track, matched), segment)| {
+            let number = matched.map(|t| t.number).unwrap_or(cue_track.number);
+            let title = matched
+                .map(|t| t.title.clone())
+                .or_else(|| cue_track.title.clone())
+                .unwrap_or_else(|| format!("Track {}", number));
+            let album_artist = artist.clone().or_else(|| sheet.performer.clone());
+
+            SourceFile {
+                path: image.clone(),
+                item_id: format!("track-{}", number),
+                dest_filename: format!(
+                    "{:02} - {}.{}",
+                    number,
+                    sanitize_file_name(&title),
+                    output_extension
+                ),
+                segment: Some(segment),
+                metadata: Some(PipelineMetadata {
+                    title: Some(title),
+                    artist: cue_track.performer.clone().or_else(|| album_artist.clone()),
+                    album: Some(album_title.clone()),
+                    album_artist,
+                    year: sheet.year,
+                    track_number: Some(number as u16),
+                    track_total: Some(track_total),
+                    disc_number: None,
+                    disc_total: None,
+                    genre: sheet.genre.clone(),
+                    comment: None,
+                    cover_art: None,
+                }),
+            }
+        })
+        .collect();
+
+    Some(sources)
+}
+
+/// Find a single-file CUE sheet in a download and the image it refers to.
+///
+/// The image is the file named in the sheet, or a file with the same stem
+/// and a supported extension (sheets often name the original WAV). Sheets
+/// naming a file outside the download are ignored.
+fn find_cue_image(download_path: &Path) -> Option<(CueSheet, PathBuf)> {
+    let mut cues = Vec::new();
+    collect_cue_files(download_path, &mut cues);
+    cues.sort();
+
+    cues.into_iter().find_map(|cue_path| {
+        let bytes = std::fs::read(&cue_path).ok()?;
+        let sheet = parse_cue_sheet(&String::from_utf8_lossy(&bytes));
+        if !sheet.is_single_file() {
+            return None;
+        }
+
+        // The sheet comes with the download, so its FILE may not point
+        // anywhere else: no absolute paths or parent directories
+        let name = Path::new(&sheet.tracks[0].file);
+        if !name
+            .components()
+            .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+        {
+            return None;
+        }
+
+        let dir = cue_path.parent()?;
+        let named = dir.join(name);
+        let image = if named.is_file() {
+            named
+        } else {
+            let stem = named.file_stem()?.to_owned();
+            CUE_IMAGE_EXTENSIONS
+                .iter()
+                .map(|ext| dir.join(&stem).with_extension(ext))
+                .find(|p| p.is_file())?
+        };
+        is_within(download_path, &image).then_some((sheet, image))
+    })
+}
+
+/// Whether `path` resolves (following symlinks) to somewhere inside `root`.
+fn is_within(root: &Path, path: &Path) -> bool {
+    match (root.canonicalize(), path.canonicalize()) {
+        (Ok(root), Ok(path)) => path.starts_with(root),
+        _ => false,
+    }
+}
+
+fn collect_cue_files(path: &Path, found: &mut Vec<PathBuf>) {
+    if path.is_file() {
+        if path
+            .extension()
+            .is_some_and(|e| e.eq_ignore_ascii_case("cue"))
+        {
+            found.push(path.to_path_buf());
+        }
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        collect_cue_files(&entry.path(), found);
+    }
+}
+
+/// Replace characters that are not allowed in file names.
+fn sanitize_file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c => c,
+        })
+        .collect::<String>()
+        .trim()
+        .to_string()
 }
 
 // =============================================================================
@@ -1522,7 +2114,7 @@ mod tests {
     use super::*;
     use crate::searcher::TorrentSource;
     use crate::textbrain::TextBrainMode;
-    use crate::ticket::ExpectedTrack;
+    use crate::ticket::{ExpectedTrack, SearchConstraints};
 
     fn make_config() -> TextBrainConfig {
         TextBrainConfig {
@@ -1607,6 +2199,25 @@ mod tests {
         assert!(result.queries.iter().any(|q| q.contains("FLAC")));
     }
 
+    #[tokio::test]
+    async fn test_build_album_queries_hi_res() {
+        let mut context = make_album_context(Some("Pink Floyd"), "Dark Side of the Moon", vec![]);
+        context.search_constraints = Some(SearchConstraints {
+            audio: Some(AudioSearchConstraints {
+                min_bit_depth: Some(24),
+                ..Default::default()
+            }),
+            video: None,
+        });
+
+        let result = build_queries(&context, &make_config()).await.unwrap();
+
+        assert_eq!(result.queries[0], "Pink Floyd Dark Side of the Moon 24bit");
+        assert!(result
+            .queries
+            .contains(&"Pink Floyd Dark Side of the Moon FLAC".to_string()));
+    }
+
     #[tokio::test]
     async fn test_build_track_queries() {
         let context = make_track_context(Some("Beatles"), "Yesterday");
@@ -1733,6 +2344,83 @@ mod tests {
         assert!(!result.candidates[0].candidate.title.contains("[Live]"));
     }
 
+    #[tokio::test]
+    async fn test_score_candidates_penalizes_implausible_size() {
+        let mut context = make_album_context(Some("Radiohead"), "OK Computer", vec![]);
+        context.catalog_reference = Some(CatalogReference::MusicBrainz {
+            release_id: "mbid".to_string(),
+            track_count: 12,
+            total_duration_ms: Some(53 * 60 * 1000),
+        });
+
+        let mut undersized = make_candidate("Radiohead - OK Computer [FLAC]", 100);
+        undersized.size_bytes = 80_000_000;
+        let candidates = vec![
+            undersized,
+            make_candidate("Radiohead - OK Computer [FLAC]", 50),
+        ];
+
+        let result = score_candidates(&context, &candidates, &make_config())
+            .await
+            .unwrap();
+
+        // 80MB is MP3-sized for 53 minutes of FLAC
+        assert_eq!(result.candidates[0].candidate.seeders, 50);
+        assert!(result.candidates[1]
+            .reasoning
+            .contains("suspiciously small for lossless"));
+    }
+
+    #[tokio::test]
+    async fn test_score_candidates_bit_depth_constraint() {
+        let mut context = make_album_context(Some("Miles Davis"), "Kind of Blue", vec![]);
+        context.search_constraints = Some(SearchConstraints {
+            audio: Some(AudioSearchConstraints {
+                min_bit_depth: Some(24),
+                min_sample_rate_hz: Some(96_000),
+                ..Default::default()
+            }),
+            video: None,
+        });
+
+        let candidates = vec![
+            make_candidate("Miles Davis - Kind of Blue (1959) [FLAC 16-44.1]", 100),
+            make_candidate("Miles Davis - Kind of Blue (1959) [FLAC 24-96] Vinyl", 50),
+        ];
+
+        let result = score_candidates(&context, &candidates, &make_config())
+            .await
+            .unwrap();
+
+        assert!(result.candidates[0].candidate.title.contains("24-96"));
+        assert!(result.candidates[0].reasoning.contains("24/96"));
+        assert!(result.candidates[0].reasoning.contains("vinyl rip"));
+        assert!(result.candidates[1].reasoning.contains("16/44.1"));
+    }
+
+    #[test]
+    fn test_parse_lossless_release() {
+        let release = parse_lossless_release("artist - album (2019) [flac 24-192]");
+        assert_eq!(release.bit_depth, Some(24));
+        assert_eq!(release.sample_rate_hz, Some(192_000));
+        assert!(!release.vinyl);
+
+        let release = parse_lossless_release("artist - album [24bit 88.2khz] vinyl rip");
+        assert_eq!(release.bit_depth, Some(24));
+        assert_eq!(release.sample_rate_hz, Some(88_200));
+        assert!(release.vinyl);
+
+        let release = parse_lossless_release("artist - album [hi-res flac]");
+        assert_eq!(release.bit_depth, Some(24));
+        assert_eq!(release.sample_rate_hz, None);
+
+        // Years and track counts are not resolutions
+        assert_eq!(
+            parse_lossless_release("artist - album (2016) 24 tracks [flac]"),
+            LosslessRelease::default()
+        );
+    }
+
     // =========================================================================
     // File Mapping Tests
     // =========================================================================
@@ -1783,7 +2471,9 @@ mod tests {
             updated_at: now,
             created_by: "test".to_string(),
             output_constraints: None,
+            textbrain_overrides: None,
             retry_count: 0,
+            reacquire_count: 0,
         };
 
         // Use a temp dir that definitely doesn't have cover art
@@ -1824,6 +2514,8 @@ mod tests {
         let constraints = AudioSearchConstraints {
             preferred_formats: vec![AudioFormat::Flac],
             min_bitrate_kbps: None,
+            min_bit_depth: None,
+            min_sample_rate_hz: None,
             avoid_compilations: false,
             avoid_live: false,
         };
@@ -2058,4 +2750,214 @@ mod tests {
             .iter()
             .any(|m| m.ticket_item_id == "track-1"));
     }
+
+    // =========================================================================
+    // CUE Sheet Tests
+    // =========================================================================
+
+    const CUE: &str = "\u{feff}REM GENRE \"Trip Hop\"
+REM DATE 1994
+PERFORMER \"Portishead\"
+TITLE \"Dummy\"
+FILE \"Portishead - Dummy.wav\" WAVE
+  TRACK 01 AUDIO
+    TITLE \"Mysterons\"
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    TITLE \"Sour Times\"
+    INDEX 00 05:01:50
+    INDEX 01 05:02:00
+  TRACK 03 AUDIO
+    TITLE \"Strangers\"
+    PERFORMER \"Portishead feat. Guest\"
+    INDEX 01 09:16:37
+";
+
+    #[test]
+    fn test_parse_cue_sheet() {
+        let sheet = parse_cue_sheet(CUE);
+
+        assert_eq!(sheet.performer.as_deref(), Some("Portishead"));
+        assert_eq!(sheet.title.as_deref(), Some("Dummy"));
+        assert_eq!(sheet.year, Some(1994));
+        assert_eq!(sheet.genre.as_deref(), Some("Trip Hop"));
+        assert_eq!(sheet.tracks.len(), 3);
+        assert!(sheet.is_single_file());
+        assert_eq!(sheet.tracks[0].file, "Portishead - Dummy.wav");
+        assert_eq!(sheet.tracks[1].start_secs, 302.0);
+        assert_eq!(
+            sheet.tracks[2].performer.as_deref(),
+            Some("Portishead feat. Guest")
+        );
+
+        let segments = sheet.segments();
+        assert_eq!(segments[0].end_secs, Some(302.0));
+        assert!((segments[2].start_secs - (556.0 + 37.0 / 75.0)).abs() < 1e-9);
+        assert_eq!(segments[2].end_secs, None);
+    }
+
+    #[test]
+    fn test_map_files_cue_image() {
+        let context = make_album_context(
+            Some("Portishead"),
+            "Dummy",
+            vec![
+                ExpectedTrack::new(1, "Mysterons"),
+                ExpectedTrack::new(2, "Sour Times"),
+                ExpectedTrack::new(3, "Strangers"),
+            ],
+        );
+        let files = vec![
+            TorrentFile {
+                path: "Portishead - Dummy/Portishead - Dummy.flac".to_string(),
+                size_bytes: 300_000_000,
+            },
+            TorrentFile {
+                path: "Portishead - Dummy/Portishead - Dummy.cue".to_string(),
+                size_bytes: 1_000,
+            },
+            TorrentFile {
+                path: "Portishead - Dummy/Portishead - Dummy.log".to_string(),
+                size_bytes: 5_000,
+            },
+        ];
+
+        let mappings = map_files(&context, &files);
+
+        let ids: Vec<&str> = mappings.iter().map(|m| m.ticket_item_id.as_str()).collect();
+        assert_eq!(ids, ["track-1", "track-2", "track-3"]);
+        assert!(mappings
+            .iter()
+            .all(|m| m.torrent_file_path.ends_with("Dummy.flac")));
+    }
+
+    #[test]
+    fn test_cue_track_files_split_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let album = dir.path().join("Dummy");
+        std::fs::create_dir(&album).unwrap();
+        // The sheet names the original WAV; the release ships FLAC
+        std::fs::write(album.join("Portishead - Dummy.cue"), CUE).unwrap();
+        std::fs::write(album.join("Portishead - Dummy.flac"), b"").unwrap();
+
+        let expected = ExpectedContent::Album {
+            artist: Some("Portishead".to_string()),
+            title: "Dummy".to_string(),
+            tracks: vec![
+                ExpectedTrack::new(1, "Mysterons"),
+                ExpectedTrack::new(2, "Sour Times"),
+                ExpectedTrack::new(3, "Strangers"),
+            ],
+        };
+
+        let sources = cue_track_files(&album, &expected, "mp3").unwrap();
+
+        assert_eq!(sources.len(), 3);
+        assert!(sources
+            .iter()
+            .all(|s| s.path == album.join("Portishead - Dummy.flac")));
+        assert_eq!(sources[1].item_id, "track-2");
+        assert_eq!(sources[1].dest_filename, "02 - Sour Times.mp3");
+        assert_eq!(
+            sources[1].segment,
+            Some(TimeSegment {
+                start_secs: 302.0,
+                end_secs: Some(556.0 + 37.0 / 75.0),
+            })
+        );
+
+        let metadata = sources[2].metadata.as_ref().unwrap();
+        assert_eq!(metadata.title.as_deref(), Some("Strangers"));
+        assert_eq!(metadata.artist.as_deref(), Some("Portishead feat. Guest"));
+        assert_eq!(metadata.album_artist.as_deref(), Some("Portishead"));
+        assert_eq!(metadata.album.as_deref(), Some("Dummy"));
+        assert_eq!(metadata.track_number, Some(3));
+        assert_eq!(metadata.track_total, Some(3));
+        assert_eq!(metadata.year, Some(1994));
+    }
+
+    fn two_track_cue(file: &str) -> String {
+        format!(
+            "FILE \"{}\" WAVE\n  TRACK 01 AUDIO\n    INDEX 01 00:00:00\n  TRACK 02 AUDIO\n    INDEX 01 03:00:00\n",
+            file
+        )
+    }
+
+    #[test]
+    fn test_cue_track_files_rejects_files_outside_download() {
+        let dir = tempfile::tempdir().unwrap();
+        let album = dir.path().join("Dummy");
+        std::fs::create_dir(&album).unwrap();
+        let outside = dir.path().join("secret.flac");
+        std::fs::write(&outside, b"").unwrap();
+        let expected = ExpectedContent::Album {
+            artist: None,
+            title: "Dummy".to_string(),
+            tracks: vec![],
+        };
+
+        for file in ["../secret.flac", outside.to_str().unwrap()] {
+            std::fs::write(album.join("Dummy.cue"), two_track_cue(file)).unwrap();
+            assert!(cue_track_files(&album, &expected, "flac").is_none());
+        }
+
+        // Nor through a symlink inside the download
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(&outside, album.join("Dummy.flac")).unwrap();
+            std::fs::write(album.join("Dummy.cue"), two_track_cue("Dummy.flac")).unwrap();
+            assert!(cue_track_files(&album, &expected, "flac").is_none());
+        }
+
+        std::fs::write(album.join("Image.flac"), b"").unwrap();
+        std::fs::write(album.join("Dummy.cue"), two_track_cue("./Image.flac")).unwrap();
+        assert!(cue_track_files(&album, &expected, "flac").is_some());
+    }
+
+    #[test]
+    fn test_cue_track_files_keeps_tracks_apart() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Dummy.flac"), b"").unwrap();
+        // Track 1's title matches expected track 2, track 2 matches it by number
+        std::fs::write(
+            dir.path().join("Dummy.cue"),
+            "FILE \"Dummy.flac\" WAVE
+  TRACK 01 AUDIO
+    TITLE \"Sour Times\"
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    TITLE \"Untitled\"
+    INDEX 01 03:00:00
+",
+        )
+        .unwrap();
+        let expected = ExpectedContent::Album {
+            artist: None,
+            title: "Dummy".to_string(),
+            tracks: vec![
+                ExpectedTrack::new(1, "Mysterons"),
+                ExpectedTrack::new(2, "Sour Times"),
+            ],
+        };
+
+        let sources = cue_track_files(dir.path(), &expected, "flac").unwrap();
+
+        let names: Vec<&str> = sources.iter().map(|s| s.dest_filename.as_str()).collect();
+        assert_eq!(names, ["01 - Sour Times.flac", "02 - Untitled.flac"]);
+        let ids: Vec<&str> = sources.iter().map(|s| s.item_id.as_str()).collect();
+        assert_eq!(ids, ["track-1", "track-2"]);
+    }
+
+    #[test]
+    fn test_cue_track_files_without_cue() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("01 - Mysterons.flac"), b"").unwrap();
+        let expected = ExpectedContent::Album {
+            artist: None,
+            title: "Dummy".to_string(),
+            tracks: vec![],
+        };
+
+        assert!(cue_track_files(dir.path(), &expected, "flac").is_none());
+    }
 }
@@ -0,0 +1,404 @@
+//! Content-aware size sanity checks.
+//!
+//! A release's size divided by its expected playing time is its average
+//! bitrate, which should fall inside a plausible band for the quality the
+//! title claims:
+//! - Video: per resolution and source (remux, BluRay encode, WEB)
+//! - Music: per format (lossless or lossy)
+//!
+//! Playing time comes from catalog references (TMDB movie or episode runtime,
+//! MusicBrainz duration) and expected track durations. Without one, nothing
+//! is flagged.
+
+use crate::ticket::Resolution;
+
+const BYTES_PER_MB: f64 = 1_000_000.0;
+
+/// Seconds assumed per track when only a track count is known.
+pub(crate) const ESTIMATED_TRACK_SECS: u64 = 240;
+
+/// Penalty for a release too small for its claimed quality.
+const UNDERSIZED_PENALTY: f32 = 0.35;
+
+/// Penalty for a release much larger than its claimed quality needs.
+const OVERSIZED_PENALTY: f32 = 0.15;
+
+/// Plausible sizes, in MB per minute of playing time.
+#[derive(Debug, Clone, Copy)]
+struct Band {
+    min: f64,
+    max: f64,
+}
+
+impl Band {
+    const fn new(min: f64, max: f64) -> Self {
+        Self { min, max }
+    }
+
+    /// Widen the band when the playing time is only an estimate.
+    fn widen(self) -> Self {
+        Self::new(self.min / 2.0, self.max * 2.0)
+    }
+}
+
+/// How a release's size compares to the band for its claimed quality.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SizeCheck {
+    /// Inside the band, or nothing to compare against.
+    Plausible,
+    /// Too small for the claimed quality (fake, re-encoded or incomplete).
+    Undersized {
+        quality: &'static str,
+        mb_per_min: f64,
+    },
+    /// Much larger than the claimed quality needs (padding, extras).
+    Oversized {
+        quality: &'static str,
+        mb_per_min: f64,
+    },
+}
+
+impl SizeCheck {
+    /// Red-flag penalty for this result.
+    pub(crate) fn penalty(&self) -> f32 {
+        match self {
+            SizeCheck::Plausible => 0.0,
+            SizeCheck::Undersized { .. } => UNDERSIZED_PENALTY,
+            SizeCheck::Oversized { .. } => OVERSIZED_PENALTY,
+        }
+    }
+
+    /// Reasoning fragment for a flagged size.
+    pub(crate) fn describe(&self) -> Option<String> {
+        match self {
+            SizeCheck::Plausible => None,
+            SizeCheck::Undersized {
+                quality,
+                mb_per_min,
+            } => Some(format!(
+                "suspiciously small for {} ({:.1} MB/min)",
+                quality, mb_per_min
+            )),
+            SizeCheck::Oversized {
+                quality,
+                mb_per_min,
+            } => Some(format!(
+                "bloated for {} ({:.0} MB/min)",
+                quality, mb_per_min
+            )),
+        }
+    }
+}
+
+/// Where a video release was taken from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum VideoSource {
+    /// Untouched disc streams.
+    Remux,
+    /// Re-encoded from a BluRay.
+    BluRay,
+    /// Streaming service download or capture.
+    Web,
+    /// Not stated in the title.
+    Unknown,
+}
+
+impl VideoSource {
+    fn detect(title: &str) -> Self {
+        if title.contains("remux") {
+            VideoSource::Remux
+        } else if ["bluray", "blu-ray", "bdrip", "brrip"]
+            .iter()
+            .any(|s| title.contains(s))
+        {
+            VideoSource::BluRay
+        } else if ["web-dl", "webdl", "webrip", "web-rip"]
+            .iter()
+            .any(|s| title.contains(s))
+            || title
+                .split(|c: char| !c.is_alphanumeric())
+                .any(|w| w == "web")
+        {
+            VideoSource::Web
+        } else {
+            VideoSource::Unknown
+        }
+    }
+}
+
+/// Whether a title without an HD resolution is standard definition.
+fn is_sd(title: &str) -> bool {
+    ["480p", "480i", "576p", "576i", "dvd", "sdtv", "pdtv"]
+        .iter()
+        .any(|s| title.contains(s))
+}
+
+/// Check a video release against the band for its resolution and source.
+///
+/// `title` is the lowercase release title; a title without an HD
+/// `resolution` is checked as standard definition when it says so.
+pub(crate) fn check_video(
+    title: &str,
+    resolution: Option<Resolution>,
+    size_bytes: u64,
+    runtime_secs: u64,
+) -> SizeCheck {
+    use VideoSource::*;
+
+    let source = VideoSource::detect(title);
+    let (quality, band) = match (resolution, source) {
+        (Some(Resolution::R2160p), Remux) => ("2160p remux", Band::new(150.0, 1000.0)),
+        (Some(Resolution::R2160p), BluRay) => ("2160p BluRay", Band::new(35.0, 600.0)),
+        (Some(Resolution::R2160p), Web) => ("2160p WEB", Band::new(20.0, 300.0)),
+        (Some(Resolution::R2160p), Unknown) => ("2160p", Band::new(20.0, 1000.0)),
+        (Some(Resolution::R1080p), Remux) => ("1080p remux", Band::new(80.0, 400.0)),
+        (Some(Resolution::R1080p), BluRay) => ("1080p BluRay", Band::new(10.0, 250.0)),
+        (Some(Resolution::R1080p), Web) => ("1080p WEB", Band::new(6.0, 120.0)),
+        (Some(Resolution::R1080p), Unknown) => ("1080p", Band::new(6.0, 350.0)),
+        (Some(Resolution::R720p), Remux | BluRay) => ("720p BluRay", Band::new(5.0, 100.0)),
+        (Some(Resolution::R720p), Web | Unknown) => ("720p", Band::new(3.0, 100.0)),
+        (None, Remux) if is_sd(title) => ("SD remux", Band::new(25.0, 120.0)),
+        (None, _) if is_sd(title) => ("SD", Band::new(2.0, 40.0)),
+        (None, _) => ("its runtime", Band::new(2.0, 1000.0)),
+    };
+    check(quality, band, size_bytes, runtime_secs)
+}
+
+/// Check a music release against the band for its format.
+///
+/// `title` is the lowercase release title. `estimated` marks a playing time
+/// derived from the track count rather than real durations.
+pub(crate) fn check_music(
+    title: &str,
+    size_bytes: u64,
+    duration_secs: u64,
+    estimated: bool,
+) -> SizeCheck {
+    let lossless = ["flac", "alac", "24bit", "24-bit", "hi-res", "lossless"]
+        .iter()
+        .any(|f| title.contains(f));
+    let lossy = ["mp3", "aac", "ogg", "opus", "320", "v0"]
+        .iter()
+        .any(|f| title.contains(f));
+
+    let (quality, band) = match (lossless, lossy) {
+        // 24-bit/192kHz FLAC reaches ~5000 kbps
+        (true, false) => ("lossless", Band::new(2.0, 50.0)),
+        // ~100-530 kbps, leaving room for artwork
+        (false, true) => ("lossy", Band::new(0.75, 4.0)),
+        // Untagged or mixed-format releases
+        _ => ("its duration", Band::new(0.75, 50.0)),
+    };
+    let band = if estimated { band.widen() } else { band };
+    check(quality, band, size_bytes, duration_secs)
+}
+
+fn check(quality: &'static str, band: Band, size_bytes: u64, playing_secs: u64) -> SizeCheck {
+    // Unknown size or playing time
+    if size_bytes == 0 || playing_secs == 0 {
+        return SizeCheck::Plausible;
+    }
+
+    let mb_per_min = size_bytes as f64 / BYTES_PER_MB / (playing_secs as f64 / 60.0);
+    if mb_per_min < band.min {
+        SizeCheck::Undersized {
+            quality,
+            mb_per_min,
+        }
+    } else if mb_per_min > band.max {
+        SizeCheck::Oversized {
+            quality,
+            mb_per_min,
+        }
+    } else {
+        SizeCheck::Plausible
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GB: u64 = 1_000_000_000;
+    const MB: u64 = 1_000_000;
+
+    #[test]
+    fn test_video_bands() {
+        let two_hours = 120 * 60;
+
+        // Typical 1080p encode
+        assert_eq!(
+            check_video(
+                "movie 1080p bluray x264",
+                Some(Resolution::R1080p),
+                10 * GB,
+                two_hours
+            ),
+            SizeCheck::Plausible
+        );
+
+        // 700MB "1080p" is a fake or a heavy re-encode
+        let check = check_video("movie 1080p", Some(Resolution::R1080p), 700 * MB, two_hours);
+        assert!(matches!(
+            check,
+            SizeCheck::Undersized {
+                quality: "1080p",
+                ..
+            }
+        ));
+        assert_eq!(
+            check.describe().unwrap(),
+            "suspiciously small for 1080p (5.8 MB/min)"
+        );
+
+        // Remuxes need far more room than encodes
+        let check = check_video(
+            "movie 1080p bluray remux",
+            Some(Resolution::R1080p),
+            5 * GB,
+            two_hours,
+        );
+        assert!(matches!(
+            check,
+            SizeCheck::Undersized {
+                quality: "1080p remux",
+                ..
+            }
+        ));
+
+        // 40GB 720p is padded
+        let check = check_video("movie 720p", Some(Resolution::R720p), 40 * GB, two_hours);
+        assert!(matches!(check, SizeCheck::Oversized { .. }));
+        assert_eq!(check.describe().unwrap(), "bloated for 720p (333 MB/min)");
+    }
+
+    #[test]
+    fn test_video_bands_by_source() {
+        let two_hours = 120 * 60;
+
+        // A 1080p BluRay encode needs more room than a WEB release
+        assert!(matches!(
+            check_video(
+                "movie 1080p bluray x264",
+                Some(Resolution::R1080p),
+                1000 * MB,
+                two_hours
+            ),
+            SizeCheck::Undersized {
+                quality: "1080p BluRay",
+                ..
+            }
+        ));
+        assert_eq!(
+            check_video(
+                "movie 1080p web-dl",
+                Some(Resolution::R1080p),
+                1000 * MB,
+                two_hours
+            ),
+            SizeCheck::Plausible
+        );
+
+        // 20GB is a remux's size, not a WEB release's
+        assert!(matches!(
+            check_video(
+                "movie 1080p web-dl",
+                Some(Resolution::R1080p),
+                20 * GB,
+                two_hours
+            ),
+            SizeCheck::Oversized {
+                quality: "1080p WEB",
+                ..
+            }
+        ));
+        assert_eq!(
+            check_video(
+                "movie 1080p bluray remux",
+                Some(Resolution::R1080p),
+                20 * GB,
+                two_hours
+            ),
+            SizeCheck::Plausible
+        );
+    }
+
+    #[test]
+    fn test_sd_band() {
+        let two_hours = 120 * 60;
+
+        assert_eq!(
+            check_video("movie 480p dvdrip xvid", None, 1400 * MB, two_hours),
+            SizeCheck::Plausible
+        );
+        assert!(matches!(
+            check_video("movie dvdrip xvid", None, 10 * GB, two_hours),
+            SizeCheck::Oversized { quality: "SD", .. }
+        ));
+        assert!(matches!(
+            check_video("movie dvd remux", None, 1400 * MB, two_hours),
+            SizeCheck::Undersized {
+                quality: "SD remux",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_music_bands() {
+        let album = 45 * 60;
+
+        assert_eq!(
+            check_music("artist - album [flac]", 300 * MB, album, false),
+            SizeCheck::Plausible
+        );
+        assert_eq!(
+            check_music("artist - album [mp3 320]", 100 * MB, album, false),
+            SizeCheck::Plausible
+        );
+
+        // "FLAC" at MP3 size is a transcode or incomplete
+        assert!(matches!(
+            check_music("artist - album [flac]", 60 * MB, album, false),
+            SizeCheck::Undersized {
+                quality: "lossless",
+                ..
+            }
+        ));
+
+        // MP3 album at lossless size
+        assert!(matches!(
+            check_music("artist - album [mp3 320]", 400 * MB, album, false),
+            SizeCheck::Oversized {
+                quality: "lossy",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_estimated_duration_widens_band() {
+        let album = 12 * ESTIMATED_TRACK_SECS;
+
+        assert!(matches!(
+            check_music("artist - album [mp3]", 250 * MB, album, false),
+            SizeCheck::Oversized { .. }
+        ));
+        assert_eq!(
+            check_music("artist - album [mp3]", 250 * MB, album, true),
+            SizeCheck::Plausible
+        );
+    }
+
+    #[test]
+    fn test_unknown_size_or_runtime_is_plausible() {
+        assert_eq!(
+            check_video("movie 1080p", Some(Resolution::R1080p), 0, 7200),
+            SizeCheck::Plausible
+        );
+        assert_eq!(
+            check_music("album flac", 300 * MB, 0, false),
+            SizeCheck::Plausible
+        );
+    }
+}
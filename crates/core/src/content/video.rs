@@ -1,25 +1,31 @@
-//! Video content handling (Movie, TvEpisode).
+//! Video content handling (Movie, TvEpisode, TvSeason, TvSeries).
 //!
 //! Provides video-specific implementations for:
 //! - Query building: "{title} {year}", "S01E01", resolution/source tags
-//! - Scoring: resolution, source quality, codec, red flags
-//! - File mapping: delegates to DumbFileMapper (handles video well)
+//! - Scoring: resolution, source quality, codec, HDR/Dolby Vision, REPACK/PROPER,
+//!   editions, red flags, season pack coverage
+//! - Anime: absolute episode numbers, batches, fansub groups, dual audio
+//! - File mapping: delegates to DumbFileMapper (handles video well), with
+//!   absolute-number mapping for anime
 //! - Post-processing: subtitle detection
 
 use std::collections::HashSet;
 use std::path::Path;
 
+use regex_lite::Regex;
+
 use crate::searcher::{TorrentCandidate, TorrentFile};
 use crate::textbrain::{
-    DumbFileMapper, FileMapping, MatchResult, QueryBuildResult, ScoredCandidate, TextBrainConfig,
-    TextBrainError,
+    DumbFileMapper, DumbFileMapperConfig, FileMapping, MatchResult, QueryBuildResult,
+    ScoredCandidate, TextBrainConfig, TextBrainError, VideoScoringWeights,
 };
 use crate::ticket::{
-    CatalogReference, ExpectedContent, LanguagePriority, QueryContext, Resolution, Ticket,
-    VideoCodec, VideoSearchConstraints, VideoSource,
+    CatalogReference, Edition, ExpectedContent, HdrPreference, LanguagePriority, QueryContext,
+    Resolution, Ticket, VideoCodec, VideoSearchConstraints, VideoSource,
 };
 
 use super::generic;
+use super::size::{self, SizeCheck};
 use super::types::{ContentError, PostProcessResult};
 
 // =============================================================================
@@ -52,12 +58,39 @@ pub async fn build_queries(
             season,
             episodes,
         }) => build_tv_queries(series, *season, episodes, video_constraints),
+        Some(ExpectedContent::TvSeason { series, season, .. }) => {
+            build_season_queries(series, *season, video_constraints)
+        }
+        Some(ExpectedContent::TvSeries {
+            series,
+            seasons,
+            resolved_seasons,
+        }) => {
+            let wanted: Vec<u32> = match seasons {
+                Some(seasons) => seasons.clone(),
+                None => resolved_seasons.iter().map(|s| s.season).collect(),
+            };
+            build_series_queries(series, &wanted, seasons.is_none(), video_constraints)
+        }
         _ => {
             // Fall back to generic for unexpected content types
             return generic::build_queries(context, config).await;
         }
     };
 
+    // Anime releases are named by absolute episode number, so those
+    // queries go first
+    let queries = if context.is_anime() {
+        let mut anime_queries = build_anime_queries(context);
+        let mut seen: HashSet<String> = anime_queries.iter().map(|q| q.to_lowercase()).collect();
+        for query in queries {
+            add_query(&mut anime_queries, &mut seen, query);
+        }
+        anime_queries
+    } else {
+        queries
+    };
+
     if queries.is_empty() {
         return Err(TextBrainError::NoQueriesGenerated);
     }
@@ -87,9 +120,19 @@ fn build_movie_queries(
 
     // Get preferred resolution/source from constraints or use defaults
     let (resolution_kw, source_kw) = get_quality_keywords(constraints);
+    let (edition_kw, hdr_kw) = get_release_keywords(constraints);
 
     // With year (most specific)
     if let Some(y) = year {
+        // Required edition first, since untagged releases won't do
+        if let Some(edition) = edition_kw {
+            add_query(
+                &mut queries,
+                &mut seen,
+                format!("{} {} {}", title_clean, y, edition),
+            );
+        }
+
         // Preferred quality first
         if let Some(res) = resolution_kw {
             if let Some(src) = source_kw {
@@ -106,6 +149,15 @@ fn build_movie_queries(
             );
         }
 
+        // HDR releases are almost always 2160p
+        if let Some(hdr) = hdr_kw {
+            add_query(
+                &mut queries,
+                &mut seen,
+                format!("{} {} 2160p {}", title_clean, y, hdr),
+            );
+        }
+
         // Fallback quality-specific queries
         add_query(
             &mut queries,
@@ -131,6 +183,13 @@ fn build_movie_queries(
     }
 
     // Without year (fallback)
+    if let Some(edition) = edition_kw {
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} {}", title_clean, edition),
+        );
+    }
     add_query(
         &mut queries,
         &mut seen,
@@ -157,6 +216,25 @@ fn get_quality_keywords(
     (resolution, source)
 }
 
+/// Get edition and HDR keywords from video constraints.
+fn get_release_keywords(
+    constraints: Option<&VideoSearchConstraints>,
+) -> (Option<&'static str>, Option<&'static str>) {
+    let Some(c) = constraints else {
+        return (None, None);
+    };
+
+    // Untagged releases are theatrical cuts, so searching for the tag narrows too much
+    let edition = c
+        .required_edition
+        .filter(|e| *e != Edition::Theatrical)
+        .map(|e| e.as_keyword());
+
+    let hdr = c.hdr.and_then(|h| h.as_keyword());
+
+    (edition, hdr)
+}
+
 /// Build queries for TV episodes.
 fn build_tv_queries(
     series: &str,
@@ -169,6 +247,7 @@ fn build_tv_queries(
 
     let series_clean = clean_series_title(series);
     let (resolution_kw, _) = get_quality_keywords(constraints);
+    let (_, hdr_kw) = get_release_keywords(constraints);
 
     // Specific episode queries
     if episodes.len() == 1 {
@@ -188,6 +267,13 @@ fn build_tv_queries(
                 format!("{} S{:02}E{:02} {}", series_clean, season, ep, res),
             );
         }
+        if let Some(hdr) = hdr_kw {
+            add_query(
+                &mut queries,
+                &mut seen,
+                format!("{} S{:02}E{:02} {}", series_clean, season, ep, hdr),
+            );
+        }
         add_query(
             &mut queries,
             &mut seen,
@@ -245,6 +331,193 @@ fn build_tv_queries(
     queries
 }
 
+/// Build queries for a whole season, favouring season packs.
+fn build_season_queries(
+    series: &str,
+    season: u32,
+    constraints: Option<&VideoSearchConstraints>,
+) -> Vec<String> {
+    let mut queries = Vec::new();
+    let mut seen = HashSet::new();
+
+    let series_clean = clean_series_title(series);
+    let (resolution_kw, _) = get_quality_keywords(constraints);
+    let (_, hdr_kw) = get_release_keywords(constraints);
+
+    add_query(
+        &mut queries,
+        &mut seen,
+        format!("{} S{:02} complete", series_clean, season),
+    );
+    if let Some(res) = resolution_kw {
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} S{:02} {}", series_clean, season, res),
+        );
+    }
+    if let Some(hdr) = hdr_kw {
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} S{:02} {}", series_clean, season, hdr),
+        );
+    }
+    add_query(
+        &mut queries,
+        &mut seen,
+        format!("{} season {}", series_clean, season),
+    );
+    add_query(
+        &mut queries,
+        &mut seen,
+        format!("{} S{:02}", series_clean, season),
+    );
+
+    // A complete-series pack also contains the season
+    add_query(
+        &mut queries,
+        &mut seen,
+        format!("{} complete series", series_clean),
+    );
+
+    queries
+}
+
+/// Build queries for several seasons or a complete series.
+///
+/// `all_seasons` is set when every season is wanted; `seasons` then holds
+/// whatever season numbers have been resolved from the catalog.
+fn build_series_queries(
+    series: &str,
+    seasons: &[u32],
+    all_seasons: bool,
+    constraints: Option<&VideoSearchConstraints>,
+) -> Vec<String> {
+    let mut queries = Vec::new();
+    let mut seen = HashSet::new();
+
+    let series_clean = clean_series_title(series);
+    let (resolution_kw, _) = get_quality_keywords(constraints);
+
+    if all_seasons {
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} complete series", series_clean),
+        );
+        if let Some(res) = resolution_kw {
+            add_query(
+                &mut queries,
+                &mut seen,
+                format!("{} complete series {}", series_clean, res),
+            );
+        }
+    }
+
+    // Season range, e.g. "S01-S05"
+    if let (Some(first), Some(last)) = (seasons.iter().min(), seasons.iter().max()) {
+        if first != last {
+            add_query(
+                &mut queries,
+                &mut seen,
+                format!("{} S{:02}-S{:02}", series_clean, first, last),
+            );
+        }
+    }
+
+    if all_seasons {
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} complete", series_clean),
+        );
+    }
+
+    // Individual season packs
+    for season in seasons.iter().take(3) {
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} S{:02} complete", series_clean, season),
+        );
+    }
+
+    if !all_seasons {
+        add_query(
+            &mut queries,
+            &mut seen,
+            format!("{} complete series", series_clean),
+        );
+    }
+    add_query(&mut queries, &mut seen, series_clean);
+
+    queries
+}
+
+/// Build anime queries: "{series} {absolute episode}" for episodes, batches
+/// for seasons, and the same with the first preferred release group.
+fn build_anime_queries(context: &QueryContext) -> Vec<String> {
+    let mut queries = Vec::new();
+    let mut seen = HashSet::new();
+
+    let offset = context.absolute_offset().unwrap_or(0);
+    let group = context
+        .anime_constraints()
+        .and_then(|a| a.preferred_groups.first());
+
+    match &context.expected {
+        Some(ExpectedContent::TvEpisode {
+            series, episodes, ..
+        }) => {
+            let series_clean = clean_series_title(series);
+            for ep in episodes.iter().take(3) {
+                let absolute = offset + ep;
+                if let Some(group) = group {
+                    add_query(
+                        &mut queries,
+                        &mut seen,
+                        format!("{} {} {:02}", group, series_clean, absolute),
+                    );
+                }
+                add_query(
+                    &mut queries,
+                    &mut seen,
+                    format!("{} {:02}", series_clean, absolute),
+                );
+            }
+        }
+        Some(ExpectedContent::TvSeason {
+            series, episodes, ..
+        }) => {
+            let series_clean = clean_series_title(series);
+            if let Some(group) = group {
+                add_query(
+                    &mut queries,
+                    &mut seen,
+                    format!("{} {} batch", group, series_clean),
+                );
+            }
+            add_query(&mut queries, &mut seen, format!("{} batch", series_clean));
+            if let (Some(first), Some(last)) = (episodes.iter().min(), episodes.iter().max()) {
+                add_query(
+                    &mut queries,
+                    &mut seen,
+                    format!(
+                        "{} {:02}-{:02}",
+                        series_clean,
+                        offset + first,
+                        offset + last
+                    ),
+                );
+            }
+        }
+        _ => {}
+    }
+
+    queries
+}
+
 /// Add query if not already seen.
 fn add_query(queries: &mut Vec<String>, seen: &mut HashSet<String>, query: String) {
     let normalized = query.to_lowercase();
@@ -324,6 +597,18 @@ fn estimate_query_confidence(context: &QueryContext) -> f32 {
                 confidence += 0.2;
             }
         }
+        Some(ExpectedContent::TvSeason { series, .. }) => {
+            if series.len() > 5 {
+                confidence += 0.1;
+            }
+            confidence += 0.2;
+        }
+        Some(ExpectedContent::TvSeries { series, .. }) => {
+            if series.len() > 5 {
+                confidence += 0.1;
+            }
+            confidence += 0.1;
+        }
         _ => {}
     }
 
@@ -340,18 +625,14 @@ fn estimate_query_confidence(context: &QueryContext) -> f32 {
 /// - Resolution: 2160p > 1080p > 720p > SD
 /// - Source: Remux > BluRay > WEB-DL > HDTV
 /// - Codec: x265/HEVC > x264
+/// - Release: HDR/Dolby Vision, REPACK/PROPER and editions, per constraints
 /// - Red flags: CAM, TS, wrong year/season
 pub async fn score_candidates(
     context: &QueryContext,
     candidates: &[TorrentCandidate],
-    _config: &TextBrainConfig,
+    config: &TextBrainConfig,
 ) -> Result<MatchResult, TextBrainError> {
-    let scorer = VideoScorer::new(context);
-
-    let mut scored: Vec<ScoredCandidate> = candidates
-        .iter()
-        .map(|c| scorer.score_candidate(c))
-        .collect();
+    let mut scored = score_each(context, candidates, &config.scoring.video);
 
     // Sort by score descending
     scored.sort_by(|a, b| {
@@ -367,23 +648,45 @@ pub async fn score_candidates(
     })
 }
 
+/// Score each candidate with the given weights, in input order.
+pub(crate) fn score_each(
+    context: &QueryContext,
+    candidates: &[TorrentCandidate],
+    weights: &VideoScoringWeights,
+) -> Vec<ScoredCandidate> {
+    let scorer = VideoScorer::new(context, weights);
+    candidates
+        .iter()
+        .map(|c| scorer.score_candidate(c))
+        .collect()
+}
+
 /// Video-specific candidate scorer.
 struct VideoScorer<'a> {
     context: &'a QueryContext,
+    weights: &'a VideoScoringWeights,
     file_mapper: DumbFileMapper,
     expected_title: Option<&'a str>,
     expected_year: Option<u32>,
     expected_series: Option<&'a str>,
     expected_season: Option<u32>,
     expected_episodes: Vec<u32>,
+    /// Seasons wanted as packs (TvSeason/TvSeries); empty means every season.
+    pack_seasons: Option<Vec<u32>>,
+    /// Whether this is an anime request.
+    anime: bool,
+    /// Episodes aired before the expected season (anime absolute numbering).
+    absolute_offset: u32,
     /// Video search constraints (resolution, source, codec preferences).
     video_constraints: Option<&'a VideoSearchConstraints>,
     /// Catalog reference for validation (runtime, episode count).
     catalog_ref: Option<&'a CatalogReference>,
+    /// Expected movie runtime in seconds, for size sanity checks.
+    runtime_secs: Option<u64>,
 }
 
 impl<'a> VideoScorer<'a> {
-    fn new(context: &'a QueryContext) -> Self {
+    fn new(context: &'a QueryContext, weights: &'a VideoScoringWeights) -> Self {
         let (expected_title, expected_year, expected_series, expected_season, expected_episodes) =
             match &context.expected {
                 Some(ExpectedContent::Movie { title, year }) => {
@@ -400,9 +703,31 @@ impl<'a> VideoScorer<'a> {
                     Some(*season),
                     episodes.clone(),
                 ),
+                Some(ExpectedContent::TvSeason {
+                    series,
+                    season,
+                    episodes,
+                }) => (
+                    None,
+                    None,
+                    Some(series.as_str()),
+                    Some(*season),
+                    episodes.clone(),
+                ),
+                Some(ExpectedContent::TvSeries { series, .. }) => {
+                    (None, None, Some(series.as_str()), None, vec![])
+                }
                 _ => (None, None, None, None, vec![]),
             };
 
+        let pack_seasons = match &context.expected {
+            Some(ExpectedContent::TvSeason { season, .. }) => Some(vec![*season]),
+            Some(ExpectedContent::TvSeries { seasons, .. }) => {
+                Some(seasons.clone().unwrap_or_default())
+            }
+            _ => None,
+        };
+
         let video_constraints = context
             .search_constraints
             .as_ref()
@@ -410,16 +735,47 @@ impl<'a> VideoScorer<'a> {
 
         let catalog_ref = context.catalog_reference.as_ref();
 
+        // Movie runtime, or episode runtime times the episodes a pack holds
+        let runtime_secs = match (&context.expected, catalog_ref) {
+            (
+                Some(expected),
+                Some(CatalogReference::Tmdb {
+                    runtime_minutes: Some(runtime),
+                    episode_count,
+                    ..
+                }),
+            ) => {
+                let items = match expected {
+                    ExpectedContent::Movie { .. } => 1,
+                    ExpectedContent::TvEpisode { .. } | ExpectedContent::TvSeries { .. } => {
+                        expected.expected_file_count() as u64
+                    }
+                    ExpectedContent::TvSeason { episodes, .. } if episodes.is_empty() => {
+                        episode_count.unwrap_or(0) as u64
+                    }
+                    ExpectedContent::TvSeason { episodes, .. } => episodes.len() as u64,
+                    _ => 0,
+                };
+                (items > 0).then(|| *runtime as u64 * 60 * items)
+            }
+            _ => None,
+        };
+
         Self {
             context,
+            weights,
             file_mapper: DumbFileMapper::new(),
             expected_title,
             expected_year,
             expected_series,
             expected_season,
             expected_episodes,
+            pack_seasons,
+            anime: context.is_anime(),
+            absolute_offset: context.absolute_offset().unwrap_or(0),
             video_constraints,
             catalog_ref,
+            runtime_secs,
         }
     }
 
@@ -432,7 +788,7 @@ impl<'a> VideoScorer<'a> {
         let source_score = self.source_score(&title_lower);
         let codec_score = self.codec_score(&title_lower);
         let health_score = self.health_score(candidate);
-        let red_flag_penalty = self.red_flag_penalty(&title_lower);
+        let red_flag_penalty = self.red_flag_penalty(&title_lower, candidate.size_bytes);
 
         // Constraint-based scoring adjustments
         let constraint_result = self.constraint_check(&title_lower);
@@ -443,23 +799,23 @@ impl<'a> VideoScorer<'a> {
         // Catalog validation
         let catalog_bonus = self.catalog_validation_bonus(&file_mappings);
 
-        // Handle constraint rejection (below min resolution)
+        // Handle constraint rejection (below min resolution, wrong edition)
         if constraint_result.rejected {
             // Heavily penalize but don't completely reject
             return ScoredCandidate {
                 candidate: candidate.clone(),
                 score: 0.05,
-                reasoning: format!("below minimum resolution ({})", constraint_result.reason),
+                reasoning: constraint_result.reason,
                 file_mappings: vec![],
             };
         }
 
         // Weighted combination
-        let base_score = (title_score * 0.30)
-            + (resolution_score * 0.15)
-            + (source_score * 0.12)
-            + (codec_score * 0.05)
-            + (health_score * 0.08)
+        let base_score = (title_score * self.weights.title)
+            + (resolution_score * self.weights.resolution)
+            + (source_score * self.weights.source)
+            + (codec_score * self.weights.codec)
+            + (health_score * self.weights.health)
             + constraint_result.bonus
             + catalog_bonus
             - red_flag_penalty;
@@ -509,7 +865,7 @@ impl<'a> VideoScorer<'a> {
                         rejected: true,
                         bonus: 0.0,
                         reason: format!(
-                            "detected {} < min {}",
+                            "below minimum resolution (detected {} < min {})",
                             detected.as_keyword(),
                             min_res.as_keyword()
                         ),
@@ -518,6 +874,41 @@ impl<'a> VideoScorer<'a> {
             }
         }
 
+        let release = parse_video_release(title);
+
+        // Check required edition (hard filter)
+        if let Some(required) = constraints.required_edition {
+            match release.edition {
+                Some(found) if found == required => bonus += 0.10,
+                None if required == Edition::Theatrical => {}
+                found => {
+                    return ConstraintCheckResult {
+                        rejected: true,
+                        bonus: 0.0,
+                        reason: format!(
+                            "not the required edition ({} instead of {})",
+                            found.map_or("untagged", |e| e.as_keyword()),
+                            required.as_keyword()
+                        ),
+                    };
+                }
+            }
+        }
+
+        // Check HDR preference
+        match constraints.hdr {
+            Some(HdrPreference::Prefer) if release.is_hdr() => bonus += 0.08,
+            Some(HdrPreference::PreferDolbyVision) if release.dolby_vision => bonus += 0.10,
+            Some(HdrPreference::PreferDolbyVision) if release.is_hdr() => bonus += 0.04,
+            Some(HdrPreference::Exclude) if release.is_hdr() => bonus -= 0.20,
+            _ => {}
+        }
+
+        // Check REPACK/PROPER preference
+        if constraints.prefer_proper && release.proper {
+            bonus += 0.05;
+        }
+
         // Check preferred resolution
         if let Some(pref_res) = &constraints.preferred_resolution {
             if let Some(detected) = detected_resolution {
@@ -603,6 +994,23 @@ impl<'a> VideoScorer<'a> {
             }
         }
 
+        // Check anime release preferences
+        if let Some(anime) = &constraints.anime {
+            let release = parse_anime_release(title);
+            let preferred_group = release.group.as_ref().is_some_and(|group| {
+                anime
+                    .preferred_groups
+                    .iter()
+                    .any(|g| g.eq_ignore_ascii_case(group))
+            });
+            if preferred_group {
+                bonus += 0.10;
+            }
+            if anime.prefer_dual_audio && release.dual_audio {
+                bonus += 0.08;
+            }
+        }
+
         ConstraintCheckResult {
             rejected: false,
             bonus,
@@ -714,6 +1122,29 @@ impl<'a> VideoScorer<'a> {
                 }
             }
 
+            // Season packs: reward coverage of the wanted seasons
+            if let Some(wanted) = &self.pack_seasons {
+                let release = parse_anime_release(title);
+                score += if self.anime && release.batch.is_some() {
+                    self.anime_batch_score(&release)
+                } else if self.anime && release.episode.is_some() {
+                    0.0 // A single absolute-numbered episode
+                } else {
+                    pack_coverage_score(detect_season_coverage(title), wanted)
+                };
+                return score.min(1.0);
+            }
+
+            // Absolute episode numbers and batches
+            if self.anime {
+                let release = parse_anime_release(title);
+                if release.episode.is_some_and(|ep| self.is_wanted_episode(ep)) {
+                    score += 0.35;
+                } else if release.batch.is_some() {
+                    score += self.anime_batch_score(&release) * 0.3;
+                }
+            }
+
             // Season/episode match
             if let Some(season) = self.expected_season {
                 let season_pattern = format!("s{:02}", season);
@@ -737,6 +1168,35 @@ impl<'a> VideoScorer<'a> {
         0.5 // No expected content
     }
 
+    /// Whether an episode number from an anime title is one we want, either
+    /// as an absolute number or as a per-season number.
+    fn is_wanted_episode(&self, episode: u32) -> bool {
+        self.expected_episodes
+            .iter()
+            .any(|ep| *ep == episode || self.absolute_offset + ep == episode)
+    }
+
+    /// Score how much of the wanted episodes an anime batch covers (0.0-0.5).
+    fn anime_batch_score(&self, release: &AnimeRelease) -> f32 {
+        let Some((first, last)) = release.batch else {
+            return 0.0;
+        };
+
+        if self.expected_episodes.is_empty() {
+            // Episode list unknown; any batch is a plausible season pack
+            return 0.4;
+        }
+
+        let covered = |offset: u32| {
+            self.expected_episodes
+                .iter()
+                .filter(|ep| (first..=last).contains(&(offset + *ep)))
+                .count()
+        };
+        let covered = covered(self.absolute_offset).max(covered(0));
+        0.5 * covered as f32 / self.expected_episodes.len() as f32
+    }
+
     /// Score video resolution.
     fn resolution_score(&self, title: &str) -> f32 {
         // Check for resolution indicators (higher is better)
@@ -834,7 +1294,7 @@ impl<'a> VideoScorer<'a> {
     }
 
     /// Calculate penalty for red flags.
-    fn red_flag_penalty(&self, title: &str) -> f32 {
+    fn red_flag_penalty(&self, title: &str, size_bytes: u64) -> f32 {
         let mut penalty: f32 = 0.0;
 
         // CAM/TS releases (terrible quality)
@@ -878,6 +1338,24 @@ impl<'a> VideoScorer<'a> {
             }
         }
 
+        if self.anime {
+            let release = parse_anime_release(title);
+
+            // Wrong absolute episode
+            if self.pack_seasons.is_none()
+                && release
+                    .episode
+                    .is_some_and(|ep| !self.is_wanted_episode(ep))
+            {
+                penalty += 0.4;
+            }
+
+            // Untranslated raws
+            if release.raw {
+                penalty += 0.3;
+            }
+        }
+
         // Hardcoded subtitles (often low quality)
         if title.contains("hc ") || title.contains("[hc]") || title.contains("hardcoded") {
             penalty += 0.15;
@@ -888,9 +1366,22 @@ impl<'a> VideoScorer<'a> {
             penalty += 0.6;
         }
 
+        // Implausible size for the claimed resolution
+        penalty += self.size_check(title, size_bytes).penalty();
+
         penalty.min(0.9) // Don't completely eliminate
     }
 
+    /// Check the release size against the expected runtime.
+    fn size_check(&self, title: &str, size_bytes: u64) -> SizeCheck {
+        match self.runtime_secs {
+            Some(runtime) => {
+                size::check_video(title, detect_resolution(title), size_bytes, runtime)
+            }
+            None => SizeCheck::Plausible,
+        }
+    }
+
     /// Calculate file mapping score.
     fn file_mapping_score(&self, candidate: &TorrentCandidate) -> (Vec<FileMapping>, f32) {
         let expected = match &self.context.expected {
@@ -903,7 +1394,10 @@ impl<'a> VideoScorer<'a> {
             _ => return (Vec::new(), 0.0),
         };
 
-        let mappings = self.file_mapper.map_files(files, expected);
+        let mut mappings = self.file_mapper.map_files(files, expected);
+        if self.anime {
+            mappings = prefer_anime_mappings(self.context, files, mappings);
+        }
 
         if mappings.is_empty() {
             return (Vec::new(), 0.0);
@@ -951,6 +1445,36 @@ impl<'a> VideoScorer<'a> {
             parts.push("weak match".to_string());
         }
 
+        // Anime release details
+        if self.anime {
+            let release = parse_anime_release(title);
+            if let Some(group) = &release.group {
+                parts.push(format!("[{}]", group));
+            }
+            if let Some((first, last)) = release.batch {
+                parts.push(format!("batch {:02}-{:02}", first, last));
+            }
+            if release.dual_audio {
+                parts.push("dual audio".to_string());
+            }
+            if release.raw {
+                parts.push("raw".to_string());
+            }
+        }
+
+        // Pack coverage
+        if self.pack_seasons.is_some() {
+            match detect_season_coverage(title) {
+                SeasonCoverage::Complete => parts.push("complete series".to_string()),
+                SeasonCoverage::Seasons(seasons) if seasons.len() > 1 => {
+                    parts.push(format!("{} seasons", seasons.len()))
+                }
+                SeasonCoverage::Seasons(_) => parts.push("season pack".to_string()),
+                SeasonCoverage::SingleEpisode => parts.push("single episode".to_string()),
+                SeasonCoverage::Unknown => {}
+            }
+        }
+
         // Resolution
         if title.contains("2160p") || title.contains("4k") {
             parts.push("4K".to_string());
@@ -980,6 +1504,23 @@ impl<'a> VideoScorer<'a> {
             parts.push("HEVC".to_string());
         }
 
+        // HDR, edition and fixes
+        let release = parse_video_release(title);
+        if release.dolby_vision {
+            parts.push("Dolby Vision".to_string());
+        }
+        if release.hdr10_plus {
+            parts.push("HDR10+".to_string());
+        } else if release.hdr10 {
+            parts.push("HDR".to_string());
+        }
+        if let Some(edition) = release.edition {
+            parts.push(edition.as_keyword().to_string());
+        }
+        if release.proper {
+            parts.push("REPACK/PROPER".to_string());
+        }
+
         // Health
         if health_score >= 0.8 {
             parts.push(format!("{} seeders", candidate.seeders));
@@ -1017,6 +1558,9 @@ impl<'a> VideoScorer<'a> {
                 parts.push("sample".to_string());
             }
         }
+        if let Some(size_note) = self.size_check(title, candidate.size_bytes).describe() {
+            parts.push(size_note);
+        }
 
         parts.join(", ")
     }
@@ -1046,6 +1590,287 @@ fn detect_resolution(title: &str) -> Option<Resolution> {
     }
 }
 
+/// HDR, edition and repack details parsed from a lowercase release title.
+#[derive(Debug, Default, PartialEq)]
+struct VideoRelease {
+    /// HDR10, or an untyped "HDR" tag.
+    hdr10: bool,
+    /// HDR10+ dynamic metadata.
+    hdr10_plus: bool,
+    /// Dolby Vision ("DV", "DoVi").
+    dolby_vision: bool,
+    /// REPACK/PROPER/RERIP fixing an earlier release.
+    proper: bool,
+    /// Edition tag, if any.
+    edition: Option<Edition>,
+}
+
+impl VideoRelease {
+    /// Whether the release is in any HDR format.
+    fn is_hdr(&self) -> bool {
+        self.hdr10 || self.hdr10_plus || self.dolby_vision
+    }
+}
+
+/// Parse HDR formats, REPACK/PROPER and editions from a lowercase title.
+fn parse_video_release(title: &str) -> VideoRelease {
+    // Separators become spaces so "directors.cut" and "director's cut" read alike
+    let words: String = title
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '+' {
+                c
+            } else {
+                ' '
+            }
+        })
+        .collect();
+    let words = format!(
+        " {} ",
+        words.split_whitespace().collect::<Vec<_>>().join(" ")
+    );
+    let has = |phrase: &str| words.contains(&format!(" {} ", phrase));
+
+    let edition = if has("directors cut") || has("director s cut") || has("directors edition") {
+        Some(Edition::DirectorsCut)
+    } else if has("extended") {
+        Some(Edition::Extended)
+    } else if has("unrated") {
+        Some(Edition::Unrated)
+    } else if has("imax") {
+        Some(Edition::Imax)
+    } else if has("theatrical") {
+        Some(Edition::Theatrical)
+    } else {
+        None
+    };
+
+    VideoRelease {
+        hdr10: has("hdr") || has("hdr10"),
+        hdr10_plus: has("hdr10+") || has("hdr10plus"),
+        dolby_vision: has("dv") || has("dovi") || has("dolby vision") || has("dolbyvision"),
+        proper: has("proper") || has("repack") || has("rerip"),
+        edition,
+    }
+}
+
+/// Which seasons a release title claims to contain.
+#[derive(Debug, PartialEq)]
+enum SeasonCoverage {
+    /// A single episode (or episode range) such as "S02E05".
+    SingleEpisode,
+    /// A complete-series pack.
+    Complete,
+    /// One or more whole seasons.
+    Seasons(Vec<u32>),
+    /// No season information in the title.
+    Unknown,
+}
+
+/// Detect season coverage from a lowercase release title.
+fn detect_season_coverage(title: &str) -> SeasonCoverage {
+    let captures = |pattern: &str| Regex::new(pattern).ok()?.captures(title);
+    let number = |caps: &regex_lite::Captures, idx: usize| -> Option<u32> {
+        caps.get(idx)?.as_str().parse().ok()
+    };
+
+    if captures(r"\bs\d{1,2}[ ._-]?e\d{1,3}").is_some()
+        || captures(r"\b\d{1,2}x\d{2,3}\b").is_some()
+    {
+        return SeasonCoverage::SingleEpisode;
+    }
+
+    if ["complete series", "complete collection", "all seasons"]
+        .iter()
+        .any(|kw| title.contains(kw))
+    {
+        return SeasonCoverage::Complete;
+    }
+
+    let range = captures(r"\bs(\d{1,2})\s*-\s*s?(\d{1,2})\b")
+        .or_else(|| captures(r"\bseasons?\s*(\d{1,2})\s*-\s*(\d{1,2})\b"));
+    if let Some(caps) = range {
+        if let (Some(first), Some(last)) = (number(&caps, 1), number(&caps, 2)) {
+            if first <= last {
+                return SeasonCoverage::Seasons((first..=last).collect());
+            }
+        }
+    }
+
+    let single = captures(r"\bs(\d{1,2})\b").or_else(|| captures(r"\bseason\s*(\d{1,2})\b"));
+    if let Some(season) = single.as_ref().and_then(|caps| number(caps, 1)) {
+        return SeasonCoverage::Seasons(vec![season]);
+    }
+
+    if title.contains("complete") {
+        return SeasonCoverage::Complete;
+    }
+
+    SeasonCoverage::Unknown
+}
+
+/// Title score contribution for how well a release covers the wanted seasons.
+///
+/// `wanted` is empty when every season is wanted. Single episodes score
+/// nothing so that season packs always win.
+fn pack_coverage_score(coverage: SeasonCoverage, wanted: &[u32]) -> f32 {
+    match coverage {
+        SeasonCoverage::SingleEpisode => 0.0,
+        SeasonCoverage::Complete if wanted.is_empty() => 0.5,
+        // Contains what we want, plus seasons we don't
+        SeasonCoverage::Complete => 0.4,
+        SeasonCoverage::Seasons(found) if wanted.is_empty() => {
+            if found.len() > 1 {
+                0.35
+            } else {
+                0.2
+            }
+        }
+        SeasonCoverage::Seasons(found) => {
+            let covered = wanted.iter().filter(|s| found.contains(s)).count();
+            0.5 * covered as f32 / wanted.len() as f32
+        }
+        SeasonCoverage::Unknown => 0.1,
+    }
+}
+
+/// Fansub-style release details parsed from a lowercase title.
+#[derive(Debug, Default, PartialEq)]
+struct AnimeRelease {
+    /// Release group from a leading "[Group]" tag.
+    group: Option<String>,
+    /// Absolute episode number, as in "show - 137".
+    episode: Option<u32>,
+    /// Batch episode range, as in "show (01-26)".
+    batch: Option<(u32, u32)>,
+    /// Original and dubbed audio.
+    dual_audio: bool,
+    /// Raw release without subtitles.
+    raw: bool,
+}
+
+/// Parse anime release conventions from a lowercase title or filename.
+fn parse_anime_release(title: &str) -> AnimeRelease {
+    let captures = |pattern: &str, text: &str| {
+        Regex::new(pattern).ok()?.captures(text).map(|caps| {
+            caps.iter()
+                .map(|m| m.map(|m| m.as_str().to_string()))
+                .collect::<Vec<_>>()
+        })
+    };
+
+    let group = captures(r"^\s*\[([^\]]+)\]", title).and_then(|caps| caps[1].clone());
+    // Group names like "Erai-raws" must not count as raw markers
+    let rest = match &group {
+        Some(group) => title.replacen(&format!("[{}]", group), "", 1),
+        None => title.to_string(),
+    };
+
+    let batch = captures(
+        r"(?:^|[\s(\[_-])(\d{1,4})\s*[-~]\s*(\d{1,4})(?:$|[\s)\]_])",
+        &rest,
+    )
+    .and_then(|caps| {
+        let first: u32 = caps[1].as_ref()?.parse().ok()?;
+        let last: u32 = caps[2].as_ref()?.parse().ok()?;
+        // Year ranges such as "2019-2020" are not batches
+        (first < last && last < 1900).then_some((first, last))
+    });
+
+    let episode = if batch.is_some() {
+        None
+    } else {
+        captures(r"\s-\s(\d{1,4})(?:v\d)?(?:$|[\s\[(.])", &rest)
+            .or_else(|| captures(r"(?:^|\s)(?:ep|episode)\s*(\d{1,4})\b", &rest))
+            .and_then(|caps| caps[1].as_ref()?.parse().ok())
+    };
+
+    let dual_audio = ["dual audio", "dual-audio", "dual.audio", "dualaudio"]
+        .iter()
+        .any(|kw| rest.contains(kw));
+    let raw = captures(r"\braw\b", &rest).is_some();
+
+    AnimeRelease {
+        group,
+        episode,
+        batch,
+        dual_audio,
+        raw,
+    }
+}
+
+/// Use absolute-number mappings for anime when they cover more episodes
+/// than the standard SxxEyy mapping.
+fn prefer_anime_mappings(
+    context: &QueryContext,
+    files: &[TorrentFile],
+    mappings: Vec<FileMapping>,
+) -> Vec<FileMapping> {
+    let anime = map_anime_files(context, files);
+    if anime.len() > mappings.len() {
+        anime
+    } else {
+        mappings
+    }
+}
+
+/// Map anime episode files by absolute episode number.
+///
+/// Numbers above the season's absolute offset are converted back to
+/// per-season numbers; smaller ones are taken as already per-season.
+fn map_anime_files(context: &QueryContext, files: &[TorrentFile]) -> Vec<FileMapping> {
+    let (season, episodes) = match &context.expected {
+        Some(ExpectedContent::TvEpisode {
+            season, episodes, ..
+        })
+        | Some(ExpectedContent::TvSeason {
+            season, episodes, ..
+        }) => (*season, episodes),
+        _ => return Vec::new(),
+    };
+    let offset = context.absolute_offset().unwrap_or(0);
+    let video_extensions = DumbFileMapperConfig::default().video_extensions;
+
+    let mut mappings: Vec<FileMapping> = Vec::new();
+    for file in files {
+        let path = Path::new(&file.path);
+        let is_video = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|ext| video_extensions.iter().any(|v| v.eq_ignore_ascii_case(ext)));
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if !is_video {
+            continue;
+        }
+
+        let Some(number) = parse_anime_release(&name.to_lowercase()).episode else {
+            continue;
+        };
+        let episode = if offset > 0 && number > offset {
+            number - offset
+        } else {
+            number
+        };
+        if !episodes.is_empty() && !episodes.contains(&episode) {
+            continue;
+        }
+
+        let item_id = format!("s{:02}e{:02}", season, episode);
+        if mappings.iter().all(|m| m.ticket_item_id != item_id) {
+            mappings.push(FileMapping {
+                torrent_file_path: file.path.clone(),
+                ticket_item_id: item_id,
+                confidence: 0.8,
+            });
+        }
+    }
+
+    mappings.sort_by(|a, b| a.ticket_item_id.cmp(&b.ticket_item_id));
+    mappings
+}
+
 /// Check if a word is a stop word.
 fn is_stop_word(word: &str) -> bool {
     matches!(
@@ -1156,7 +1981,12 @@ fn title_contains_language(title: &str, lang_code: &str, for_subs: bool) -> bool
 ///
 /// Uses the standard file mapper which handles video well.
 pub fn map_files(context: &QueryContext, files: &[TorrentFile]) -> Vec<FileMapping> {
-    generic::map_files(context, files)
+    let mappings = generic::map_files(context, files);
+    if context.is_anime() {
+        prefer_anime_mappings(context, files, mappings)
+    } else {
+        mappings
+    }
 }
 
 // =============================================================================
@@ -1315,6 +2145,7 @@ mod tests {
     use super::*;
     use crate::searcher::TorrentSource;
     use crate::textbrain::TextBrainMode;
+    use crate::ticket::{AnimeConstraints, SearchConstraints, TmdbMediaType};
 
     fn make_config() -> TextBrainConfig {
         TextBrainConfig {
@@ -1516,6 +2347,459 @@ mod tests {
         assert!(result.candidates[0].candidate.title.contains("S01E01"));
     }
 
+    fn make_expected_context(expected: ExpectedContent) -> QueryContext {
+        QueryContext {
+            tags: vec!["tv".to_string()],
+            description: String::new(),
+            expected: Some(expected),
+            catalog_reference: None,
+            search_constraints: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_build_season_and_series_queries() {
+        let season = make_expected_context(ExpectedContent::tv_season("Breaking Bad", 3));
+        let result = build_queries(&season, &make_config()).await.unwrap();
+        assert_eq!(result.queries[0], "Breaking Bad S03 complete");
+        assert!(result.queries.iter().all(|q| !q.contains("E0")));
+
+        let series = make_expected_context(ExpectedContent::TvSeries {
+            series: "Firefly".to_string(),
+            seasons: Some(vec![1, 2]),
+            resolved_seasons: vec![],
+        });
+        let result = build_queries(&series, &make_config()).await.unwrap();
+        assert!(result.queries.contains(&"Firefly S01-S02".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_score_candidates_season_prefers_pack_over_single() {
+        let context = make_expected_context(ExpectedContent::tv_season("Breaking Bad", 3));
+
+        let candidates = vec![
+            make_candidate("Breaking Bad S03E01 1080p BluRay", 200),
+            make_candidate("Breaking Bad S02 1080p BluRay", 100),
+            make_candidate("Breaking Bad S03 1080p BluRay", 50),
+        ];
+
+        let result = score_candidates(&context, &candidates, &make_config())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.candidates[0].candidate.title,
+            "Breaking Bad S03 1080p BluRay"
+        );
+        assert!(result.candidates[0].reasoning.contains("season pack"));
+    }
+
+    #[tokio::test]
+    async fn test_score_candidates_series_prefers_complete_pack() {
+        let context = make_expected_context(ExpectedContent::tv_series("Firefly"));
+
+        let candidates = vec![
+            make_candidate("Firefly S01E01 1080p BluRay", 200),
+            make_candidate("Firefly S01 1080p BluRay", 100),
+            make_candidate("Firefly Complete Series 1080p BluRay", 50),
+        ];
+
+        let result = score_candidates(&context, &candidates, &make_config())
+            .await
+            .unwrap();
+
+        assert!(result.candidates[0].candidate.title.contains("Complete"));
+        assert!(result.candidates[2].candidate.title.contains("S01E01"));
+    }
+
+    #[test]
+    fn test_detect_season_coverage() {
+        assert_eq!(
+            detect_season_coverage("show.s02e05.1080p"),
+            SeasonCoverage::SingleEpisode
+        );
+        assert_eq!(
+            detect_season_coverage("show s01-s03 1080p"),
+            SeasonCoverage::Seasons(vec![1, 2, 3])
+        );
+        assert_eq!(
+            detect_season_coverage("show season 4 complete"),
+            SeasonCoverage::Seasons(vec![4])
+        );
+        assert_eq!(
+            detect_season_coverage("show complete series 720p"),
+            SeasonCoverage::Complete
+        );
+        assert_eq!(
+            detect_season_coverage("show 1080p x265"),
+            SeasonCoverage::Unknown
+        );
+    }
+
+    /// Attack on Titan: season 4 starts at absolute episode 76.
+    fn make_anime_context(expected: ExpectedContent, groups: &[&str]) -> QueryContext {
+        QueryContext {
+            tags: vec!["anime".to_string()],
+            description: String::new(),
+            expected: Some(expected),
+            catalog_reference: Some(CatalogReference::Tmdb {
+                id: 1429,
+                media_type: TmdbMediaType::Tv,
+                runtime_minutes: None,
+                episode_count: None,
+                absolute_offset: Some(75),
+            }),
+            search_constraints: Some(SearchConstraints {
+                audio: None,
+                video: Some(VideoSearchConstraints {
+                    anime: Some(AnimeConstraints {
+                        preferred_groups: groups.iter().map(|g| g.to_string()).collect(),
+                        prefer_dual_audio: false,
+                    }),
+                    ..Default::default()
+                }),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_parse_anime_release() {
+        let release =
+            parse_anime_release("[subsplease] shingeki no kyojin - 87 (1080p) [a1b2].mkv");
+        assert_eq!(release.group.as_deref(), Some("subsplease"));
+        assert_eq!(release.episode, Some(87));
+        assert_eq!(release.batch, None);
+
+        let release = parse_anime_release("[judas] one punch man (01-12) [bd 1080p] [dual audio]");
+        assert_eq!(release.batch, Some((1, 12)));
+        assert_eq!(release.episode, None);
+        assert!(release.dual_audio);
+
+        assert!(!parse_anime_release("[erai-raws] show - 05 [1080p]").raw);
+        assert!(parse_anime_release("show - 05 raw 720p").raw);
+        assert_eq!(parse_anime_release("show 2019-2020 complete").batch, None);
+    }
+
+    #[test]
+    fn test_parse_video_release() {
+        let release = parse_video_release(
+            "blade runner 2049 2017 2160p uhd bluray remux dv hdr10 hevc-group",
+        );
+        assert!(release.dolby_vision);
+        assert!(release.hdr10);
+        assert!(!release.hdr10_plus);
+        assert!(!release.proper);
+
+        let release = parse_video_release("show s01e01 2160p amzn web-dl hdr10+ repack");
+        assert!(release.hdr10_plus);
+        assert!(!release.dolby_vision);
+        assert!(release.proper);
+
+        assert_eq!(
+            parse_video_release("apocalypse.now.1979.directors.cut.1080p").edition,
+            Some(Edition::DirectorsCut)
+        );
+        assert_eq!(
+            parse_video_release("the lord of the rings (2001) extended edition 1080p").edition,
+            Some(Edition::Extended)
+        );
+        assert_eq!(
+            parse_video_release("dune 2021 imax 2160p").edition,
+            Some(Edition::Imax)
+        );
+
+        // Lookalike tags
+        let release = parse_video_release("movie 2010 720p hdrip dvdrip xvid");
+        assert!(!release.is_hdr());
+        assert!(!release.proper);
+        assert_eq!(release.edition, None);
+    }
+
+    #[tokio::test]
+    async fn test_build_movie_queries_with_edition_and_hdr() {
+        let mut context = make_movie_context("Blade Runner", Some(1982));
+        context.search_constraints = Some(SearchConstraints {
+            audio: None,
+            video: Some(VideoSearchConstraints {
+                required_edition: Some(Edition::DirectorsCut),
+                hdr: Some(HdrPreference::PreferDolbyVision),
+                ..Default::default()
+            }),
+        });
+
+        let result = build_queries(&context, &make_config()).await.unwrap();
+
+        assert_eq!(result.queries[0], "Blade Runner 1982 Directors Cut");
+        assert!(result
+            .queries
+            .contains(&"Blade Runner 1982 2160p DV".to_string()));
+        assert!(result
+            .queries
+            .contains(&"Blade Runner Directors Cut".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_score_candidates_penalizes_implausible_size() {
+        let mut context = make_movie_context("Inception", Some(2010));
+        context.catalog_reference = Some(CatalogReference::Tmdb {
+            id: 27205,
+            media_type: TmdbMediaType::Movie,
+            runtime_minutes: Some(148),
+            episode_count: None,
+            absolute_offset: None,
+        });
+
+        let mut undersized = make_candidate("Inception 2010 1080p WEB-DL x264", 200);
+        undersized.size_bytes = 700_000_000;
+        let candidates = vec![
+            undersized,
+            make_candidate("Inception 2010 1080p WEB-DL x264", 50),
+        ];
+
+        let result = score_candidates(&context, &candidates, &make_config())
+            .await
+            .unwrap();
+
+        // 700MB over 148 minutes is not a real 1080p release
+        assert_eq!(result.candidates[0].candidate.seeders, 50);
+        assert!(result.candidates[1]
+            .reasoning
+            .contains("suspiciously small for 1080p WEB (4.7 MB/min)"));
+    }
+
+    #[tokio::test]
+    async fn test_season_pack_size_uses_episode_runtime() {
+        let mut context = make_expected_context(ExpectedContent::TvSeason {
+            series: "Firefly".to_string(),
+            season: 1,
+            episodes: (1..=14).collect(),
+        });
+        context.description = "Firefly season 1".to_string();
+        context.catalog_reference = Some(CatalogReference::Tmdb {
+            id: 1437,
+            media_type: TmdbMediaType::Tv,
+            runtime_minutes: Some(45),
+            episode_count: None,
+            absolute_offset: None,
+        });
+
+        // 5GB is a plausible single episode, but not 14 of them in 1080p BluRay
+        let mut undersized = make_candidate("Firefly S01 1080p BluRay x264", 200);
+        undersized.size_bytes = 5_000_000_000;
+        let mut full = make_candidate("Firefly S01 1080p BluRay x264", 50);
+        full.size_bytes = 30_000_000_000;
+
+        let result = score_candidates(&context, &[undersized, full], &make_config())
+            .await
+            .unwrap();
+
+        // 5GB over 14 x 45 minutes
+        assert_eq!(result.candidates[0].candidate.seeders, 50);
+        assert!(result.candidates[1]
+            .reasoning
+            .contains("suspiciously small for 1080p BluRay (7.9 MB/min)"));
+    }
+
+    #[tokio::test]
+    async fn test_score_candidates_hdr_preference() {
+        let candidates = vec![
+            make_candidate("Dune 2021 2160p WEB-DL HDR10 x265", 50),
+            make_candidate("Dune 2021 2160p WEB-DL x265", 50),
+            make_candidate("Dune 2021 2160p WEB-DL DV x265", 50),
+        ];
+        let with_hdr = |hdr: HdrPreference| {
+            let mut context = make_movie_context("Dune", Some(2021));
+            context.search_constraints = Some(SearchConstraints {
+                audio: None,
+                video: Some(VideoSearchConstraints {
+                    hdr: Some(hdr),
+                    ..Default::default()
+                }),
+            });
+            context
+        };
+
+        let result = score_candidates(
+            &with_hdr(HdrPreference::PreferDolbyVision),
+            &candidates,
+            &make_config(),
+        )
+        .await
+        .unwrap();
+        assert!(result.candidates[0].candidate.title.contains("DV"));
+        assert!(result.candidates[0].reasoning.contains("Dolby Vision"));
+        assert!(result.candidates[1].candidate.title.contains("HDR10"));
+
+        let result = score_candidates(
+            &with_hdr(HdrPreference::Exclude),
+            &candidates,
+            &make_config(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            result.candidates[0].candidate.title,
+            "Dune 2021 2160p WEB-DL x265"
+        );
+        assert!(result.candidates[2].reasoning.contains("below preferences"));
+    }
+
+    #[tokio::test]
+    async fn test_score_candidates_required_edition() {
+        let mut context = make_movie_context("The Lord of the Rings", Some(2001));
+        context.search_constraints = Some(SearchConstraints {
+            audio: None,
+            video: Some(VideoSearchConstraints {
+                required_edition: Some(Edition::Extended),
+                ..Default::default()
+            }),
+        });
+
+        let candidates = vec![
+            make_candidate("The Lord of the Rings 2001 1080p BluRay x264", 200),
+            make_candidate("The Lord of the Rings 2001 Extended 1080p BluRay x264", 20),
+        ];
+
+        let result = score_candidates(&context, &candidates, &make_config())
+            .await
+            .unwrap();
+
+        assert!(result.candidates[0].candidate.title.contains("Extended"));
+        assert!(result.candidates[0].reasoning.contains("Extended"));
+        assert!((result.candidates[1].score - 0.05).abs() < 0.001);
+        assert_eq!(
+            result.candidates[1].reasoning,
+            "not the required edition (untagged instead of Extended)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_score_candidates_prefers_proper() {
+        let mut context = make_tv_context("Breaking Bad", 1, vec![1]);
+        context.search_constraints = Some(SearchConstraints {
+            audio: None,
+            video: Some(VideoSearchConstraints {
+                prefer_proper: true,
+                ..Default::default()
+            }),
+        });
+
+        let candidates = vec![
+            make_candidate("Breaking.Bad.S01E01.1080p.WEB-DL.x264", 50),
+            make_candidate("Breaking.Bad.S01E01.REPACK.1080p.WEB-DL.x264", 50),
+        ];
+
+        let result = score_candidates(&context, &candidates, &make_config())
+            .await
+            .unwrap();
+
+        assert!(result.candidates[0].candidate.title.contains("REPACK"));
+        assert!(result.candidates[0].reasoning.contains("REPACK/PROPER"));
+    }
+
+    #[tokio::test]
+    async fn test_build_anime_queries_use_absolute_numbers() {
+        let context = make_anime_context(
+            ExpectedContent::TvEpisode {
+                series: "Attack on Titan".to_string(),
+                season: 4,
+                episodes: vec![12],
+            },
+            &["SubsPlease"],
+        );
+
+        let result = build_queries(&context, &make_config()).await.unwrap();
+
+        assert_eq!(result.queries[0], "SubsPlease Attack on Titan 87");
+        assert_eq!(result.queries[1], "Attack on Titan 87");
+        assert!(result.queries.iter().any(|q| q.contains("S04E12")));
+    }
+
+    #[tokio::test]
+    async fn test_score_anime_absolute_episode_and_group() {
+        let context = make_anime_context(
+            ExpectedContent::TvEpisode {
+                series: "Attack on Titan".to_string(),
+                season: 4,
+                episodes: vec![12],
+            },
+            &["SubsPlease"],
+        );
+
+        let candidates = vec![
+            make_candidate("[SubsPlease] Attack on Titan - 86 (1080p)", 200),
+            make_candidate("[OtherSubs] Attack on Titan - 87 (1080p)", 50),
+            make_candidate("[SubsPlease] Attack on Titan - 87 (1080p)", 50),
+        ];
+
+        let result = score_candidates(&context, &candidates, &make_config())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.candidates[0].candidate.title,
+            "[SubsPlease] Attack on Titan - 87 (1080p)"
+        );
+        assert!(result.candidates[2].candidate.title.contains("- 86"));
+        assert!(result.candidates[0].reasoning.contains("[subsplease]"));
+    }
+
+    #[tokio::test]
+    async fn test_score_anime_season_prefers_batch() {
+        let context = make_anime_context(
+            ExpectedContent::TvSeason {
+                series: "Attack on Titan".to_string(),
+                season: 4,
+                episodes: (1..=16).collect(),
+            },
+            &[],
+        );
+
+        let candidates = vec![
+            make_candidate("[SubsPlease] Attack on Titan - 76 (1080p)", 200),
+            make_candidate("[Judas] Attack on Titan (76-91) [1080p]", 50),
+        ];
+
+        let result = score_candidates(&context, &candidates, &make_config())
+            .await
+            .unwrap();
+
+        assert!(result.candidates[0].candidate.title.contains("76-91"));
+        assert!(result.candidates[0].reasoning.contains("batch 76-91"));
+    }
+
+    #[test]
+    fn test_map_anime_files_by_absolute_number() {
+        let context = make_anime_context(
+            ExpectedContent::TvSeason {
+                series: "Attack on Titan".to_string(),
+                season: 4,
+                episodes: vec![1, 2],
+            },
+            &[],
+        );
+        let files = vec![
+            TorrentFile {
+                path: "[Judas] AoT/[Judas] Attack on Titan - 77.mkv".to_string(),
+                size_bytes: 500_000_000,
+            },
+            TorrentFile {
+                path: "[Judas] AoT/[Judas] Attack on Titan - 76.mkv".to_string(),
+                size_bytes: 500_000_000,
+            },
+            TorrentFile {
+                path: "[Judas] AoT/[Judas] Attack on Titan - 76.ass".to_string(),
+                size_bytes: 50_000,
+            },
+        ];
+
+        let mappings = map_files(&context, &files);
+
+        let ids: Vec<_> = mappings.iter().map(|m| m.ticket_item_id.as_str()).collect();
+        assert_eq!(ids, ["s04e01", "s04e02"]);
+        assert!(mappings[0].torrent_file_path.ends_with("76.mkv"));
+    }
+
     #[tokio::test]
     async fn test_score_candidates_penalizes_wrong_year() {
         let context = make_movie_context("Spider-Man", Some(2002));
@@ -1614,7 +2898,9 @@ mod tests {
             updated_at: now,
             created_by: "test".to_string(),
             output_constraints: None,
+            textbrain_overrides: None,
             retry_count: 0,
+            reacquire_count: 0,
         };
 
         let result = post_process(&ticket, Path::new("/tmp/nonexistent_video_dir"))
@@ -15,8 +15,9 @@ use super::config::ConverterConfig;
 use super::error::ConverterError;
 use super::traits::Converter;
 use super::types::{
-    AudioConstraints, AudioFormat, ConversionConstraints, ConversionJob, ConversionProgress,
-    ConversionResult, MediaInfo, VideoConstraints,
+    AudioConstraints, AudioFormat, AudiobookConstraints, ChapterMergeJob, ConversionConstraints,
+    ConversionJob, ConversionProgress, ConversionResult, MediaInfo, PcmAudio, TimeSegment,
+    VideoConstraints,
 };
 
 /// FFmpeg-based converter implementation.
@@ -201,6 +202,86 @@ impl FfmpegConverter {
         args
     }
 
+    /// Builds ffmpeg arguments for audiobook conversion to M4B.
+    ///
+    /// `input_args` select the audio input (a single file or a concat list).
+    /// Chapter markers and global metadata are read from the FFMETADATA file
+    /// at `chapters_path`, if given.
+    fn build_audiobook_args(
+        &self,
+        input_args: Vec<String>,
+        output_path: &Path,
+        constraints: &AudiobookConstraints,
+        metadata_args: &[String],
+        chapters_path: Option<&Path>,
+        cover_art_path: Option<&Path>,
+    ) -> Vec<String> {
+        let mut args = vec!["-y".to_string()];
+        args.extend(input_args);
+
+        // Input 0 is the audio, then the chapters and the cover art if given
+        let mut input_count = 1;
+        if let Some(path) = chapters_path {
+            args.extend(["-i".to_string(), path.to_string_lossy().to_string()]);
+            input_count += 1;
+        }
+        let cover_input = cover_art_path.map(|path| {
+            args.extend(["-i".to_string(), path.to_string_lossy().to_string()]);
+            input_count
+        });
+
+        args.extend(["-map".to_string(), "0:a".to_string()]);
+        if chapters_path.is_some() {
+            args.extend([
+                "-map_metadata".to_string(),
+                "1".to_string(),
+                "-map_chapters".to_string(),
+                "1".to_string(),
+            ]);
+        }
+        if let Some(input) = cover_input {
+            args.extend([
+                "-map".to_string(),
+                format!("{}:v", input),
+                "-c:v".to_string(),
+                "copy".to_string(),
+                "-disposition:v:0".to_string(),
+                "attached_pic".to_string(),
+            ]);
+        }
+
+        args.extend(["-c:a".to_string(), "aac".to_string()]);
+        if let Some(bitrate) = constraints.bitrate_kbps {
+            args.extend(["-b:a".to_string(), format!("{}k", bitrate)]);
+        }
+        if let Some(channels) = constraints.channels {
+            args.extend(["-ac".to_string(), channels.to_string()]);
+        }
+
+        // Metadata
+        args.extend(metadata_args.iter().cloned());
+
+        // Log level and progress
+        args.extend([
+            "-loglevel".to_string(),
+            self.config.ffmpeg_log_level.clone(),
+            "-progress".to_string(),
+            "pipe:2".to_string(),
+        ]);
+
+        // Extra args
+        args.extend(self.config.extra_ffmpeg_args.iter().cloned());
+
+        // Output (.m4b is not recognized as a muxer by extension)
+        args.extend([
+            "-f".to_string(),
+            "mp4".to_string(),
+            output_path.to_string_lossy().to_string(),
+        ]);
+
+        args
+    }
+
     /// Parses ffprobe JSON output into MediaInfo.
     fn parse_probe_output(path: &Path, output: &str) -> Result<MediaInfo, ConverterError> {
         #[derive(Deserialize)]
@@ -225,6 +306,8 @@ impl FfmpegConverter {
             bit_rate: Option<String>,
             sample_rate: Option<String>,
             channels: Option<u8>,
+            bits_per_raw_sample: Option<String>,
+            bits_per_sample: Option<u8>,
             width: Option<u32>,
             height: Option<u32>,
             r_frame_rate: Option<String>,
@@ -276,6 +359,16 @@ impl FfmpegConverter {
                 .and_then(|s| s.sample_rate.as_ref())
                 .and_then(|r| r.parse::<u32>().ok()),
             audio_channels: audio_stream.and_then(|s| s.channels),
+            // FLAC reports bits_per_raw_sample, PCM reports bits_per_sample;
+            // lossy codecs report neither (or zero)
+            audio_bit_depth: audio_stream
+                .and_then(|s| {
+                    s.bits_per_raw_sample
+                        .as_ref()
+                        .and_then(|b| b.parse::<u8>().ok())
+                        .or(s.bits_per_sample)
+                })
+                .filter(|&bits| bits > 0),
             video_codec: video_stream.and_then(|s| s.codec_name.clone()),
             video_width: video_stream.and_then(|s| s.width),
             video_height: video_stream.and_then(|s| s.height),
@@ -299,15 +392,18 @@ impl FfmpegConverter {
         })
     }
 
-    /// Runs the conversion with optional progress reporting.
-    async fn run_conversion(
+    /// Merges chapter files into one M4B with a chapter marker per input.
+    async fn run_chapter_merge(
         &self,
-        job: &ConversionJob,
+        job: &ChapterMergeJob,
         progress_tx: Option<mpsc::Sender<ConversionProgress>>,
     ) -> Result<ConversionResult, ConverterError> {
         let start = Instant::now();
 
-        // Ensure output directory exists
+        if job.chapters.is_empty() {
+            return Err(ConverterError::invalid_constraints("no chapters to merge"));
+        }
+
         if let Some(parent) = job.output_path.parent() {
             tokio::fs::create_dir_all(parent).await.map_err(|_| {
                 ConverterError::OutputDirectoryFailed {
@@ -316,33 +412,79 @@ impl FfmpegConverter {
             })?;
         }
 
-        // Get input duration for progress calculation
-        let input_info = self.probe(&job.input_path).await.ok();
-        let duration_secs = input_info.as_ref().map(|i| i.duration_secs);
+        // Chapter boundaries follow the duration of each input
+        let mut chapters = Vec::with_capacity(job.chapters.len());
+        for chapter in &job.chapters {
+            let info = self.probe(&chapter.path).await?;
+            chapters.push((chapter.title.clone(), info.duration_secs));
+        }
+        let total_secs: f64 = chapters.iter().map(|(_, secs)| secs).sum();
+
+        let list_path = job.output_path.with_extension("concat.txt");
+        let chapters_path = job.output_path.with_extension("chapters.txt");
+        let paths: Vec<&Path> = job.chapters.iter().map(|c| c.path.as_path()).collect();
+        tokio::fs::write(&list_path, build_concat_list(&paths)).await?;
+        tokio::fs::write(&chapters_path, build_chapter_metadata(&chapters)).await?;
 
-        // Build arguments
         let metadata_args = job
             .metadata
             .as_ref()
             .map(|m| m.to_ffmpeg_args())
             .unwrap_or_default();
+        let args = self.build_audiobook_args(
+            vec![
+                "-f".to_string(),
+                "concat".to_string(),
+                "-safe".to_string(),
+                "0".to_string(),
+                "-i".to_string(),
+                list_path.to_string_lossy().to_string(),
+            ],
+            &job.output_path,
+            &job.constraints,
+            &metadata_args,
+            Some(&chapters_path),
+            job.cover_art_path.as_deref(),
+        );
 
-        let args = match &job.constraints {
-            ConversionConstraints::Audio(audio) => self.build_audio_args(
-                &job.input_path,
-                &job.output_path,
-                audio,
-                &metadata_args,
-                job.cover_art_path.as_deref(),
-            ),
-            ConversionConstraints::Video(video) => {
-                self.build_video_args(&job.input_path, &job.output_path, video, &metadata_args)
-            }
-        };
+        let result = self
+            .run_ffmpeg(&job.job_id, &args, Some(total_secs), progress_tx)
+            .await;
+        let _ = tokio::fs::remove_file(&list_path).await;
+        let _ = tokio::fs::remove_file(&chapters_path).await;
+        result?;
+
+        let output_meta = tokio::fs::metadata(&job.output_path)
+            .await
+            .map_err(|_| ConverterError::conversion_failed("Output file not created", None))?;
 
-        // Run ffmpeg
+        let input_format = job.chapters[0]
+            .path
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        Ok(ConversionResult {
+            job_id: job.job_id.clone(),
+            output_path: job.output_path.clone(),
+            output_size_bytes: output_meta.len(),
+            duration_ms: start.elapsed().as_millis() as u64,
+            input_format,
+            output_format: "m4b".to_string(),
+        })
+    }
+
+    /// Runs ffmpeg with the given arguments, reporting progress against
+    /// `duration_secs` if known.
+    async fn run_ffmpeg(
+        &self,
+        job_id: &str,
+        args: &[String],
+        duration_secs: Option<f64>,
+        progress_tx: Option<mpsc::Sender<ConversionProgress>>,
+    ) -> Result<(), ConverterError> {
         let mut child = Command::new(&self.config.ffmpeg_path)
-            .args(&args)
+            .args(args)
             .stdin(Stdio::null())
             .stdout(Stdio::null())
             .stderr(Stdio::piped())
@@ -413,7 +555,7 @@ impl FfmpegConverter {
                         };
 
                         let progress = ConversionProgress {
-                            job_id: job.job_id.clone(),
+                            job_id: job_id.to_string(),
                             percent,
                             time_secs: current_time,
                             duration_secs,
@@ -436,38 +578,102 @@ impl FfmpegConverter {
         match result {
             Ok(Ok((status, error_output))) => {
                 if !status.success() {
-                    return Err(ConverterError::conversion_failed(
+                    Err(ConverterError::conversion_failed(
                         format!("FFmpeg exited with code: {:?}", status.code()),
                         if error_output.is_empty() {
                             None
                         } else {
                             Some(error_output)
                         },
-                    ));
+                    ))
+                } else {
+                    Ok(())
                 }
             }
-            Ok(Err(e)) => return Err(ConverterError::Io(e)),
+            Ok(Err(e)) => Err(ConverterError::Io(e)),
             Err(_) => {
                 // Kill the process on timeout
                 let _ = child.kill().await;
-                return Err(ConverterError::Timeout {
+                Err(ConverterError::Timeout {
                     timeout_secs: self.config.timeout_secs,
-                });
+                })
             }
         }
+    }
+
+    /// Runs the conversion with optional progress reporting.
+    async fn run_conversion(
+        &self,
+        job: &ConversionJob,
+        progress_tx: Option<mpsc::Sender<ConversionProgress>>,
+    ) -> Result<ConversionResult, ConverterError> {
+        let start = Instant::now();
+
+        // Ensure output directory exists
+        if let Some(parent) = job.output_path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|_| {
+                ConverterError::OutputDirectoryFailed {
+                    path: parent.to_path_buf(),
+                }
+            })?;
+        }
+
+        // Get input duration for progress calculation
+        let input_info = self.probe(&job.input_path).await.ok();
+        let duration_secs = match &job.segment {
+            Some(segment) => segment.duration_secs().or_else(|| {
+                input_info
+                    .as_ref()
+                    .map(|i| (i.duration_secs - segment.start_secs).max(0.0))
+            }),
+            None => input_info.as_ref().map(|i| i.duration_secs),
+        };
+
+        // Build arguments
+        let metadata_args = job
+            .metadata
+            .as_ref()
+            .map(|m| m.to_ffmpeg_args())
+            .unwrap_or_default();
+
+        let mut args = match &job.constraints {
+            ConversionConstraints::Audio(audio) => self.build_audio_args(
+                &job.input_path,
+                &job.output_path,
+                audio,
+                &metadata_args,
+                job.cover_art_path.as_deref(),
+            ),
+            ConversionConstraints::Video(video) => {
+                self.build_video_args(&job.input_path, &job.output_path, video, &metadata_args)
+            }
+            ConversionConstraints::Audiobook(audiobook) => self.build_audiobook_args(
+                vec![
+                    "-i".to_string(),
+                    job.input_path.to_string_lossy().to_string(),
+                ],
+                &job.output_path,
+                audiobook,
+                &metadata_args,
+                None,
+                job.cover_art_path.as_deref(),
+            ),
+        };
+
+        // Tracks of a single-file image are cut out of the input
+        if let Some(segment) = &job.segment {
+            add_segment_args(&mut args, segment);
+        }
+
+        self.run_ffmpeg(&job.job_id, &args, duration_secs, progress_tx)
+            .await?;
 
         // Verify output exists and get size
         let output_meta = tokio::fs::metadata(&job.output_path)
             .await
             .map_err(|_| ConverterError::conversion_failed("Output file not created", None))?;
 
-        let output_format = match &job.constraints {
-            ConversionConstraints::Audio(a) => a.format.extension().to_string(),
-            ConversionConstraints::Video(v) => v
-                .container
-                .extension(v.audio.as_ref().map(|a| &a.format))
-                .to_string(),
-        };
+        let output_format = job.constraints.output_extension().to_string();
 
         let input_format = input_info
             .map(|i| i.format)
@@ -530,6 +736,76 @@ impl Converter for FfmpegConverter {
         Self::parse_probe_output(path, &stdout)
     }
 
+    async fn decode_pcm(
+        &self,
+        path: &Path,
+        segment: Option<TimeSegment>,
+    ) -> Result<PcmAudio, ConverterError> {
+        let info = self.probe(path).await?;
+        let sample_rate = info
+            .audio_sample_rate
+            .ok_or_else(|| ConverterError::probe_failed("no audio stream"))?;
+        let channels = info.audio_channels.unwrap_or(2) as u16;
+
+        let mut args: Vec<String> = vec![
+            "-v".to_string(),
+            "error".to_string(),
+            "-i".to_string(),
+            path.to_string_lossy().to_string(),
+            "-map".to_string(),
+            "0:a:0".to_string(),
+            "-f".to_string(),
+            "s32le".to_string(),
+            "-acodec".to_string(),
+            "pcm_s32le".to_string(),
+            "pipe:1".to_string(),
+        ];
+        if let Some(segment) = &segment {
+            add_segment_args(&mut args, segment);
+        }
+
+        let output = timeout(
+            Duration::from_secs(self.config.timeout_secs),
+            Command::new(&self.config.ffmpeg_path)
+                .args(&args)
+                .stdin(Stdio::null())
+                .output(),
+        )
+        .await
+        .map_err(|_| ConverterError::Timeout {
+            timeout_secs: self.config.timeout_secs,
+        })?
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                ConverterError::FfmpegNotFound {
+                    path: self.config.ffmpeg_path.clone(),
+                }
+            } else {
+                ConverterError::Io(e)
+            }
+        })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            return Err(ConverterError::conversion_failed(
+                "PCM decoding failed",
+                Some(stderr),
+            ));
+        }
+
+        let samples = output
+            .stdout
+            .chunks_exact(4)
+            .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect();
+
+        Ok(PcmAudio {
+            sample_rate,
+            channels,
+            samples,
+        })
+    }
+
     async fn convert(&self, job: ConversionJob) -> Result<ConversionResult, ConverterError> {
         self.run_conversion(&job, None).await
     }
@@ -542,6 +818,14 @@ impl Converter for FfmpegConverter {
         self.run_conversion(&job, Some(progress_tx)).await
     }
 
+    async fn merge_chapters(
+        &self,
+        job: ChapterMergeJob,
+        progress_tx: mpsc::Sender<ConversionProgress>,
+    ) -> Result<ConversionResult, ConverterError> {
+        self.run_chapter_merge(&job, Some(progress_tx)).await
+    }
+
     async fn validate(&self) -> Result<(), ConverterError> {
         // Check ffmpeg exists
         let ffmpeg_result = Command::new(&self.config.ffmpeg_path)
@@ -580,10 +864,94 @@ impl Converter for FfmpegConverter {
     }
 }
 
+/// Adds `-ss`/`-to` input options for a segment before the first input, so
+/// only that part of the input is decoded.
+fn add_segment_args(args: &mut Vec<String>, segment: &TimeSegment) {
+    let mut seek = vec!["-ss".to_string(), format!("{:.3}", segment.start_secs)];
+    if let Some(end) = segment.end_secs {
+        seek.extend(["-to".to_string(), format!("{:.3}", end)]);
+    }
+    let position = args.iter().position(|a| a == "-i").unwrap_or(0);
+    args.splice(position..position, seek);
+}
+
+/// Builds an ffconcat list of the given files.
+fn build_concat_list(paths: &[&Path]) -> String {
+    let mut list = String::from("ffconcat version 1.0\n");
+    for path in paths {
+        let escaped = path.to_string_lossy().replace('\'', "'\\''");
+        list.push_str(&format!("file '{}'\n", escaped));
+    }
+    list
+}
+
+/// Builds an FFMETADATA file with one chapter per `(title, duration_secs)`.
+fn build_chapter_metadata(chapters: &[(String, f64)]) -> String {
+    let mut metadata = String::from(";FFMETADATA1\n");
+    let mut start_ms: u64 = 0;
+    for (title, duration_secs) in chapters {
+        let end_ms = start_ms + (duration_secs * 1000.0).round() as u64;
+        metadata.push_str(&format!(
+            "\n[CHAPTER]\nTIMEBASE=1/1000\nSTART={}\nEND={}\ntitle={}\n",
+            start_ms,
+            end_ms,
+            escape_ffmetadata(title)
+        ));
+        start_ms = end_ms;
+    }
+    metadata
+}
+
+/// Escapes the characters FFMETADATA treats specially.
+fn escape_ffmetadata(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '=' | ';' | '#' | '\\' | '\n') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_segment_args_precede_input() {
+        let converter = FfmpegConverter::with_defaults();
+        let constraints = AudioConstraints {
+            format: AudioFormat::Flac,
+            bitrate_kbps: None,
+            sample_rate_hz: None,
+            channels: None,
+            compression_level: None,
+        };
+        let mut args = converter.build_audio_args(
+            Path::new("/album.ape"),
+            Path::new("/track-02.flac"),
+            &constraints,
+            &[],
+            None,
+        );
+
+        add_segment_args(
+            &mut args,
+            &TimeSegment {
+                start_secs: 215.4,
+                end_secs: Some(431.0),
+            },
+        );
+
+        let input = args.iter().position(|a| a == "-i").unwrap();
+        assert_eq!(
+            &args[input - 4..input],
+            ["-ss", "215.400", "-to", "431.000"]
+        );
+        assert_eq!(args[input + 1], "/album.ape");
+    }
+
     #[test]
     fn test_build_audio_args_mp3() {
         let converter = FfmpegConverter::with_defaults();
@@ -673,6 +1041,45 @@ mod tests {
         assert!(args.contains(&"aac".to_string()));
     }
 
+    #[test]
+    fn test_build_audiobook_args_with_chapters_and_cover() {
+        let converter = FfmpegConverter::with_defaults();
+        let args = converter.build_audiobook_args(
+            vec!["-i".to_string(), "/list.txt".to_string()],
+            Path::new("/book.m4b"),
+            &AudiobookConstraints::default(),
+            &[],
+            Some(Path::new("/chapters.txt")),
+            Some(Path::new("/cover.jpg")),
+        );
+
+        let joined = args.join(" ");
+        assert!(joined.contains("-map 0:a -map_metadata 1 -map_chapters 1 -map 2:v"));
+        assert!(joined.contains("-c:a aac -b:a 64k"));
+        assert!(joined.ends_with("-f mp4 /book.m4b"));
+    }
+
+    #[test]
+    fn test_build_chapter_metadata() {
+        let metadata = build_chapter_metadata(&[
+            ("Chapter 1".to_string(), 61.5),
+            ("Part 2; The End".to_string(), 30.0),
+        ]);
+
+        assert!(metadata.starts_with(";FFMETADATA1\n"));
+        assert!(metadata.contains("START=0\nEND=61500\ntitle=Chapter 1\n"));
+        assert!(metadata.contains("START=61500\nEND=91500\ntitle=Part 2\\; The End\n"));
+    }
+
+    #[test]
+    fn test_build_concat_list_escapes_quotes() {
+        let list = build_concat_list(&[Path::new("/books/It's Here/01.mp3")]);
+        assert_eq!(
+            list,
+            "ffconcat version 1.0\nfile '/books/It'\\''s Here/01.mp3'\n"
+        );
+    }
+
     #[test]
     fn test_parse_probe_output() {
         let json = r#"{
@@ -700,6 +1107,33 @@ mod tests {
         assert_eq!(info.audio_codec, Some("flac".to_string()));
         assert_eq!(info.audio_sample_rate, Some(44100));
         assert_eq!(info.audio_channels, Some(2));
+        assert_eq!(info.audio_bit_depth, None);
+    }
+
+    #[test]
+    fn test_parse_probe_output_bit_depth() {
+        let json = r#"{
+            "format": {
+                "filename": "test.flac",
+                "format_name": "flac",
+                "duration": "180.5",
+                "size": "90000000"
+            },
+            "streams": [
+                {
+                    "codec_type": "audio",
+                    "codec_name": "flac",
+                    "sample_rate": "96000",
+                    "channels": 2,
+                    "bits_per_sample": 0,
+                    "bits_per_raw_sample": "24"
+                }
+            ]
+        }"#;
+
+        let info = FfmpegConverter::parse_probe_output(Path::new("test.flac"), json).unwrap();
+        assert_eq!(info.audio_sample_rate, Some(96000));
+        assert_eq!(info.audio_bit_depth, Some(24));
     }
 
     #[test]
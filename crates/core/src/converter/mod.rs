@@ -9,6 +9,9 @@
 //! - Video transcoding (H.264, H.265, VP9, AV1)
 //! - Metadata embedding
 //! - Cover art embedding for audio files
+//! - Merging audiobook chapters into a single chaptered M4B
+//! - Cutting time segments, e.g. tracks of a CUE + single-file album image
+//! - Spectral analysis of decoded audio, to spot transcoded or upsampled lossless files
 //! - Progress reporting during conversion
 //!
 //! # Example
@@ -41,6 +44,7 @@
 //!         ..Default::default()
 //!     }),
 //!     cover_art_path: None,
+//!     segment: None,
 //! };
 //!
 //! let result = converter.convert(job).await?;
@@ -51,6 +55,7 @@ mod capabilities;
 mod config;
 mod error;
 mod ffmpeg;
+mod spectrum;
 mod traits;
 mod types;
 
@@ -58,9 +63,10 @@ pub use capabilities::EncoderCapabilities;
 pub use config::ConverterConfig;
 pub use error::ConverterError;
 pub use ffmpeg::FfmpegConverter;
+pub use spectrum::{analyze_spectrum, SpectrumAnalysis};
 pub use traits::Converter;
 pub use types::{
-    AudioConstraints, AudioFormat, ContainerFormat, ConversionConstraints, ConversionJob,
-    ConversionProgress, ConversionResult, EmbeddedMetadata, MediaInfo, VideoConstraints,
-    VideoFormat,
+    AudioConstraints, AudioFormat, AudiobookConstraints, ChapterInput, ChapterMergeJob,
+    ContainerFormat, ConversionConstraints, ConversionJob, ConversionProgress, ConversionResult,
+    EmbeddedMetadata, MediaInfo, PcmAudio, TimeSegment, VideoConstraints, VideoFormat,
 };
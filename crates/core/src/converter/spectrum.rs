@@ -0,0 +1,287 @@
+//! Spectral analysis of decoded PCM audio.
+//!
+//! Used to spot lossless files that were not made from a lossless source:
+//! - **Lossy source**: MP3/AAC encoders low-pass the signal (around 16 kHz at
+//!   128 kbps, 19-20 kHz at 320 kbps), and the cut survives decoding
+//! - **Upsampling**: audio resampled to a hi-res rate has nothing above the
+//!   original Nyquist frequency
+//! - **Bit-depth padding**: 16-bit audio stored as 24-bit leaves the low bits
+//!   of every sample zero
+
+use super::types::PcmAudio;
+
+/// FFT frame size (about 93 ms at 44.1 kHz).
+const FFT_SIZE: usize = 4096;
+
+/// Content must rise this far above the top-of-band noise floor to count.
+const CONTENT_ABOVE_FLOOR_DB: f64 = 20.0;
+
+/// A floor this close to the midrange level means content reaches Nyquist.
+const FULL_BAND_MARGIN_DB: f64 = 30.0;
+
+/// Midrange level below which the segment is too quiet to judge.
+const SILENCE_DB: f64 = -120.0;
+
+/// Cutoffs below this suggest a lossy source.
+const LOSSY_CUTOFF_HZ: f32 = 19_000.0;
+
+/// Hi-res files with no content above this were likely upsampled from
+/// 44.1/48 kHz.
+const UPSAMPLED_CUTOFF_HZ: f32 = 24_000.0;
+
+/// Result of analyzing a stretch of decoded audio.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpectrumAnalysis {
+    /// Sample rate of the analyzed audio, in Hz.
+    pub sample_rate: u32,
+    /// Highest frequency with content, in Hz (None if too quiet to tell).
+    pub cutoff_hz: Option<f32>,
+    /// Bits carrying information (None for digital silence).
+    pub effective_bit_depth: Option<u8>,
+}
+
+impl SpectrumAnalysis {
+    /// Whether the spectrum is cut off like a lossy encode.
+    pub fn likely_lossy_source(&self) -> bool {
+        self.cutoff_hz.is_some_and(|hz| hz < LOSSY_CUTOFF_HZ)
+    }
+
+    /// Whether a hi-res file has no content above CD/DAT frequencies.
+    pub fn likely_upsampled(&self) -> bool {
+        self.sample_rate > 48_000
+            && !self.likely_lossy_source()
+            && self.cutoff_hz.is_some_and(|hz| hz < UPSAMPLED_CUTOFF_HZ)
+    }
+}
+
+/// Analyze decoded audio.
+pub fn analyze_spectrum(pcm: &PcmAudio) -> SpectrumAnalysis {
+    SpectrumAnalysis {
+        sample_rate: pcm.sample_rate,
+        cutoff_hz: cutoff_frequency(pcm),
+        effective_bit_depth: effective_bit_depth(&pcm.samples),
+    }
+}
+
+/// Bits in use across all samples, counted from the top.
+fn effective_bit_depth(samples: &[i32]) -> Option<u8> {
+    let used = samples.iter().fold(0i32, |acc, s| acc | s);
+    (used != 0).then(|| (32 - used.trailing_zeros()) as u8)
+}
+
+/// Highest frequency with content above the top-of-band noise floor.
+fn cutoff_frequency(pcm: &PcmAudio) -> Option<f32> {
+    let channels = pcm.channels.max(1) as usize;
+    let mono: Vec<f64> = pcm
+        .samples
+        .chunks_exact(channels)
+        .map(|frame| frame.iter().map(|&s| s as f64).sum::<f64>() / channels as f64 / 2f64.powi(31))
+        .collect();
+    if mono.len() < FFT_SIZE || pcm.sample_rate == 0 {
+        return None;
+    }
+
+    // Average power spectrum over Hann-windowed frames
+    let window: Vec<f64> = (0..FFT_SIZE)
+        .map(|i| 0.5 - 0.5 * (2.0 * std::f64::consts::PI * i as f64 / FFT_SIZE as f64).cos())
+        .collect();
+    let bins = FFT_SIZE / 2;
+    let mut power = vec![0.0f64; bins];
+    let mut frames = 0;
+    for frame in mono.chunks_exact(FFT_SIZE) {
+        let mut re: Vec<f64> = frame.iter().zip(&window).map(|(s, w)| s * w).collect();
+        let mut im = vec![0.0f64; FFT_SIZE];
+        fft(&mut re, &mut im);
+        for (k, p) in power.iter_mut().enumerate() {
+            *p += re[k] * re[k] + im[k] * im[k];
+        }
+        frames += 1;
+    }
+    for p in power.iter_mut() {
+        *p /= frames as f64;
+    }
+
+    // Smooth over ~100 Hz so single bins don't decide the cutoff
+    let hz_per_bin = pcm.sample_rate as f64 / FFT_SIZE as f64;
+    let radius = ((100.0 / hz_per_bin).round() as usize).max(1);
+    let smoothed: Vec<f64> = (0..bins)
+        .map(|k| {
+            let lo = k.saturating_sub(radius);
+            let hi = (k + radius + 1).min(bins);
+            power[lo..hi].iter().sum::<f64>() / (hi - lo) as f64
+        })
+        .collect();
+
+    let band_db = |from_hz: f64, to_hz: f64| {
+        let lo = ((from_hz / hz_per_bin) as usize).min(bins - 1);
+        let hi = ((to_hz / hz_per_bin) as usize).clamp(lo + 1, bins);
+        to_db(power[lo..hi].iter().sum::<f64>() / (hi - lo) as f64)
+    };
+
+    let midrange_db = band_db(1_000.0, 8_000.0);
+    if midrange_db < SILENCE_DB {
+        return None;
+    }
+
+    // Noise floor: the top 1% of the band
+    let nyquist = pcm.sample_rate as f64 / 2.0;
+    let floor_db = band_db(nyquist * 0.99, nyquist);
+    if floor_db >= midrange_db - FULL_BAND_MARGIN_DB {
+        return Some(nyquist as f32);
+    }
+
+    let threshold = floor_db + CONTENT_ABOVE_FLOOR_DB;
+    smoothed
+        .iter()
+        .rposition(|&p| to_db(p) > threshold)
+        .map(|k| (k as f64 * hz_per_bin) as f32)
+}
+
+fn to_db(power: f64) -> f64 {
+    10.0 * (power + 1e-30).log10()
+}
+
+/// In-place iterative radix-2 FFT. Length must be a power of two.
+fn fft(re: &mut [f64], im: &mut [f64]) {
+    let n = re.len();
+
+    // Bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -2.0 * std::f64::consts::PI / len as f64;
+        let (w_re, w_im) = (angle.cos(), angle.sin());
+        for start in (0..n).step_by(len) {
+            let (mut cur_re, mut cur_im) = (1.0, 0.0);
+            for k in 0..len / 2 {
+                let a = start + k;
+                let b = a + len / 2;
+                let t_re = re[b] * cur_re - im[b] * cur_im;
+                let t_im = re[b] * cur_im + im[b] * cur_re;
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
+                let next_re = cur_re * w_re - cur_im * w_im;
+                cur_im = cur_re * w_im + cur_im * w_re;
+                cur_re = next_re;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two seconds of stereo sines at the given frequencies, as dithered
+    /// 16-bit audio.
+    fn sines(sample_rate: u32, freqs: &[f64]) -> PcmAudio {
+        let amplitude = 0.5 / freqs.len() as f64;
+        let mut state: u32 = 1;
+        let mut dither = || {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (state >> 8) as f64 / (1u32 << 24) as f64 - 0.5
+        };
+        let mut samples = Vec::new();
+        for i in 0..sample_rate as usize * 2 {
+            let t = i as f64 / sample_rate as f64;
+            let value: f64 = freqs
+                .iter()
+                .map(|f| amplitude * (2.0 * std::f64::consts::PI * f * t).sin())
+                .sum();
+            let sample = ((value * i16::MAX as f64 + dither() + dither()).round() as i32) << 16;
+            samples.push(sample);
+            samples.push(sample);
+        }
+        PcmAudio {
+            sample_rate,
+            channels: 2,
+            samples,
+        }
+    }
+
+    fn up_to(max_hz: f64) -> Vec<f64> {
+        (1..)
+            .map(|i| i as f64 * 500.0)
+            .take_while(|f| *f <= max_hz)
+            .collect()
+    }
+
+    #[test]
+    fn test_fft_finds_sine() {
+        let mut re: Vec<f64> = (0..64)
+            .map(|i| (2.0 * std::f64::consts::PI * 4.0 * i as f64 / 64.0).cos())
+            .collect();
+        let mut im = vec![0.0; 64];
+        fft(&mut re, &mut im);
+
+        assert!((re[4] - 32.0).abs() < 1e-9);
+        assert!(re[5].abs() < 1e-9 && im[5].abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_full_band_audio_is_clean() {
+        let analysis = analyze_spectrum(&sines(44100, &up_to(21_000.0)));
+
+        let cutoff = analysis.cutoff_hz.unwrap();
+        assert!(cutoff > 20_900.0 && cutoff < 21_300.0, "cutoff {}", cutoff);
+        assert!(!analysis.likely_lossy_source());
+        assert!(!analysis.likely_upsampled());
+        assert_eq!(analysis.effective_bit_depth, Some(16));
+    }
+
+    #[test]
+    fn test_lowpassed_audio_looks_lossy() {
+        let analysis = analyze_spectrum(&sines(44100, &up_to(16_000.0)));
+
+        let cutoff = analysis.cutoff_hz.unwrap();
+        assert!(cutoff > 15_900.0 && cutoff < 16_300.0, "cutoff {}", cutoff);
+        assert!(analysis.likely_lossy_source());
+    }
+
+    #[test]
+    fn test_upsampled_audio() {
+        let analysis = analyze_spectrum(&sines(96000, &up_to(21_000.0)));
+
+        assert!(analysis.likely_upsampled());
+        assert!(!analysis.likely_lossy_source());
+
+        let analysis = analyze_spectrum(&sines(96000, &up_to(40_000.0)));
+        assert!(!analysis.likely_upsampled());
+    }
+
+    #[test]
+    fn test_effective_bit_depth() {
+        assert_eq!(effective_bit_depth(&[1 << 8, -(3 << 8)]), Some(24));
+        assert_eq!(effective_bit_depth(&[1 << 16, 5 << 16]), Some(16));
+        assert_eq!(effective_bit_depth(&[0, 0]), None);
+    }
+
+    #[test]
+    fn test_silence_is_inconclusive() {
+        let silence = PcmAudio {
+            sample_rate: 44100,
+            channels: 2,
+            samples: vec![0; 44100 * 2],
+        };
+
+        let analysis = analyze_spectrum(&silence);
+        assert_eq!(analysis.cutoff_hz, None);
+        assert!(!analysis.likely_lossy_source());
+    }
+}
@@ -5,7 +5,10 @@ use std::path::Path;
 use tokio::sync::mpsc;
 
 use super::error::ConverterError;
-use super::types::{ConversionJob, ConversionProgress, ConversionResult, MediaInfo};
+use super::types::{
+    ChapterMergeJob, ConversionJob, ConversionProgress, ConversionResult, MediaInfo, PcmAudio,
+    TimeSegment,
+};
 
 /// A converter that can transcode media files.
 #[async_trait]
@@ -16,6 +19,13 @@ pub trait Converter: Send + Sync {
     /// Probes a media file to get its information.
     async fn probe(&self, path: &Path) -> Result<MediaInfo, ConverterError>;
 
+    /// Decodes the first audio stream of a file (or a segment of it) to PCM.
+    async fn decode_pcm(
+        &self,
+        path: &Path,
+        segment: Option<TimeSegment>,
+    ) -> Result<PcmAudio, ConverterError>;
+
     /// Converts a media file according to the job specification.
     async fn convert(&self, job: ConversionJob) -> Result<ConversionResult, ConverterError>;
 
@@ -29,6 +39,15 @@ pub trait Converter: Send + Sync {
         progress_tx: mpsc::Sender<ConversionProgress>,
     ) -> Result<ConversionResult, ConverterError>;
 
+    /// Merges audio files into a single chaptered audiobook, one chapter per input.
+    ///
+    /// The progress sender will receive updates during conversion.
+    async fn merge_chapters(
+        &self,
+        job: ChapterMergeJob,
+        progress_tx: mpsc::Sender<ConversionProgress>,
+    ) -> Result<ConversionResult, ConverterError>;
+
     /// Validates that the converter is properly configured and ready.
     async fn validate(&self) -> Result<(), ConverterError>;
 
@@ -37,7 +56,7 @@ pub trait Converter: Send + Sync {
         // Common formats supported by ffmpeg
         &[
             // Audio
-            "flac", "mp3", "m4a", "aac", "ogg", "opus", "wav", "wma", "ape", "alac",
+            "flac", "mp3", "m4a", "m4b", "aac", "ogg", "opus", "wav", "wma", "ape", "alac",
             // Video
             "mkv", "mp4", "avi", "mov", "wmv", "webm", "ts", "m2ts",
         ]
@@ -47,7 +66,7 @@ pub trait Converter: Send + Sync {
     fn supported_output_formats(&self) -> &[&str] {
         &[
             // Audio
-            "flac", "mp3", "m4a", "ogg", "opus", "wav", // Video
+            "flac", "mp3", "m4a", "m4b", "ogg", "opus", "wav", // Video
             "mkv", "mp4", "webm",
         ]
     }
@@ -76,6 +95,7 @@ mod tests {
                 audio_bitrate_kbps: Some(1411),
                 audio_sample_rate: Some(44100),
                 audio_channels: Some(2),
+                audio_bit_depth: Some(16),
                 video_codec: None,
                 video_width: None,
                 video_height: None,
@@ -83,6 +103,18 @@ mod tests {
             })
         }
 
+        async fn decode_pcm(
+            &self,
+            _path: &Path,
+            _segment: Option<TimeSegment>,
+        ) -> Result<PcmAudio, ConverterError> {
+            Ok(PcmAudio {
+                sample_rate: 44100,
+                channels: 2,
+                samples: vec![0; 88200],
+            })
+        }
+
         async fn convert(&self, job: ConversionJob) -> Result<ConversionResult, ConverterError> {
             Ok(ConversionResult {
                 job_id: job.job_id,
@@ -102,6 +134,21 @@ mod tests {
             self.convert(job).await
         }
 
+        async fn merge_chapters(
+            &self,
+            job: ChapterMergeJob,
+            _progress_tx: mpsc::Sender<ConversionProgress>,
+        ) -> Result<ConversionResult, ConverterError> {
+            Ok(ConversionResult {
+                job_id: job.job_id,
+                output_path: job.output_path,
+                output_size_bytes: 512,
+                duration_ms: 1000,
+                input_format: "mp3".to_string(),
+                output_format: "m4b".to_string(),
+            })
+        }
+
         async fn validate(&self) -> Result<(), ConverterError> {
             Ok(())
         }
@@ -125,6 +172,7 @@ mod tests {
             constraints: super::super::types::ConversionConstraints::default(),
             metadata: None,
             cover_art_path: None,
+            segment: None,
         };
         let result = converter.convert(job).await.unwrap();
         assert_eq!(result.job_id, "test-job");
@@ -171,6 +171,26 @@ impl Default for AudioConstraints {
     }
 }
 
+/// Constraints for audiobook conversion to a chaptered M4B (AAC).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudiobookConstraints {
+    /// Target AAC bitrate in kbps.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bitrate_kbps: Option<u32>,
+    /// Number of audio channels (1 = mono, 2 = stereo).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channels: Option<u8>,
+}
+
+impl Default for AudiobookConstraints {
+    fn default() -> Self {
+        Self {
+            bitrate_kbps: Some(64),
+            channels: None, // Keep original
+        }
+    }
+}
+
 /// Constraints for video conversion.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VideoConstraints {
@@ -227,6 +247,19 @@ pub enum ConversionConstraints {
     Audio(AudioConstraints),
     /// Video conversion (includes audio track).
     Video(VideoConstraints),
+    /// Audiobook conversion: all chapters merged into one M4B.
+    Audiobook(AudiobookConstraints),
+}
+
+impl ConversionConstraints {
+    /// Returns the file extension of the converted output.
+    pub fn output_extension(&self) -> &'static str {
+        match self {
+            Self::Audio(a) => a.format.extension(),
+            Self::Video(v) => v.container.extension(v.audio.as_ref().map(|a| &a.format)),
+            Self::Audiobook(_) => "m4b",
+        }
+    }
 }
 
 impl Default for ConversionConstraints {
@@ -345,6 +378,51 @@ pub struct ConversionJob {
     pub metadata: Option<EmbeddedMetadata>,
     /// Cover art to embed (for audio).
     pub cover_art_path: Option<PathBuf>,
+    /// Part of the input to convert (None for the whole file).
+    pub segment: Option<TimeSegment>,
+}
+
+/// A time range within an input file, such as one track of a single-file
+/// album image.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TimeSegment {
+    /// Start offset in seconds.
+    pub start_secs: f64,
+    /// End offset in seconds (None for the end of the file).
+    pub end_secs: Option<f64>,
+}
+
+impl TimeSegment {
+    /// Length in seconds, if the end is known.
+    pub fn duration_secs(&self) -> Option<f64> {
+        self.end_secs.map(|end| (end - self.start_secs).max(0.0))
+    }
+}
+
+/// One chapter of a [`ChapterMergeJob`].
+#[derive(Debug, Clone)]
+pub struct ChapterInput {
+    /// Input file path.
+    pub path: PathBuf,
+    /// Chapter title embedded in the output.
+    pub title: String,
+}
+
+/// A request to merge several audio files into one chaptered M4B.
+#[derive(Debug, Clone)]
+pub struct ChapterMergeJob {
+    /// Unique job ID.
+    pub job_id: String,
+    /// Chapters in playback order.
+    pub chapters: Vec<ChapterInput>,
+    /// Output file path.
+    pub output_path: PathBuf,
+    /// Conversion constraints.
+    pub constraints: AudiobookConstraints,
+    /// Metadata to embed.
+    pub metadata: Option<EmbeddedMetadata>,
+    /// Cover art to embed.
+    pub cover_art_path: Option<PathBuf>,
 }
 
 /// Result of a successful conversion.
@@ -387,6 +465,9 @@ pub struct MediaInfo {
     /// Audio channels (if present).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub audio_channels: Option<u8>,
+    /// Audio bit depth (if present and meaningful, i.e. lossless or PCM).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_bit_depth: Option<u8>,
     /// Video codec (if present).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub video_codec: Option<String>,
@@ -401,6 +482,18 @@ pub struct MediaInfo {
     pub video_fps: Option<f32>,
 }
 
+/// Decoded audio for in-process analysis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PcmAudio {
+    /// Sample rate in Hz.
+    pub sample_rate: u32,
+    /// Number of interleaved channels.
+    pub channels: u16,
+    /// Interleaved samples, left-justified in 32 bits (16-bit audio has the
+    /// low 16 bits zero).
+    pub samples: Vec<i32>,
+}
+
 /// Progress update during conversion.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConversionProgress {
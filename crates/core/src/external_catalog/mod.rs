@@ -4,10 +4,14 @@
 //! to enrich ticket creation and improve matching accuracy.
 
 mod musicbrainz;
+mod resolve;
 mod tmdb;
 mod types;
 
 pub use musicbrainz::{MusicBrainzClient, MusicBrainzConfig};
+pub use resolve::{
+    resolve_absolute_offset, resolve_discography, resolve_tv_episodes, ResolvedEpisodes,
+};
 pub use tmdb::{TmdbClient, TmdbConfig};
 pub use types::*;
 
@@ -60,6 +64,13 @@ pub trait ExternalCatalog: Send + Sync {
     /// Get a specific release by MusicBrainz ID.
     async fn get_release(&self, mbid: &str) -> Result<MusicBrainzRelease, ExternalCatalogError>;
 
+    /// List an artist's release groups (albums, EPs, ...).
+    async fn search_release_groups(
+        &self,
+        artist: &str,
+        limit: u32,
+    ) -> Result<Vec<MusicBrainzReleaseGroup>, ExternalCatalogError>;
+
     // TMDB operations
 
     /// Search for movies by query.
@@ -133,6 +144,19 @@ impl ExternalCatalog for CombinedCatalogClient {
         }
     }
 
+    async fn search_release_groups(
+        &self,
+        artist: &str,
+        limit: u32,
+    ) -> Result<Vec<MusicBrainzReleaseGroup>, ExternalCatalogError> {
+        match &self.musicbrainz {
+            Some(client) => client.search_release_groups(artist, limit).await,
+            None => Err(ExternalCatalogError::NotConfigured(
+                "MusicBrainz client not configured".to_string(),
+            )),
+        }
+    }
+
     async fn search_movies(
         &self,
         query: &str,
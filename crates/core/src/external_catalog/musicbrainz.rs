@@ -13,7 +13,7 @@ use tokio::sync::Mutex;
 use tokio::time::{sleep, Instant};
 use tracing::{debug, warn};
 
-use super::types::{MusicBrainzRelease, MusicBrainzTrack};
+use super::types::{MusicBrainzRelease, MusicBrainzReleaseGroup, MusicBrainzTrack};
 use super::ExternalCatalogError;
 
 /// MusicBrainz API client configuration.
@@ -190,6 +190,64 @@ impl MusicBrainzClient {
 
         Ok(release.into())
     }
+
+    /// Search for an artist's release groups.
+    pub async fn search_release_groups(
+        &self,
+        artist: &str,
+        limit: u32,
+    ) -> Result<Vec<MusicBrainzReleaseGroup>, ExternalCatalogError> {
+        self.wait_for_rate_limit().await;
+
+        let url = format!("{}/release-group", self.base_url);
+        let limit = limit.min(100); // MusicBrainz max is 100
+        let query = format!("artist:\"{}\"", artist.replace('"', ""));
+
+        debug!(
+            "MusicBrainz release group search: artist='{}', limit={}",
+            artist, limit
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&[
+                ("query", query.as_str()),
+                ("fmt", "json"),
+                ("limit", &limit.to_string()),
+            ])
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status == 429 {
+            warn!("MusicBrainz rate limit exceeded");
+            return Err(ExternalCatalogError::RateLimitExceeded);
+        }
+        if status == 404 {
+            return Err(ExternalCatalogError::NotFound(artist.to_string()));
+        }
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ExternalCatalogError::ApiError {
+                status: status.as_u16(),
+                message: body,
+            });
+        }
+
+        let search_result: MbReleaseGroupSearchResponse = response.json().await.map_err(|e| {
+            ExternalCatalogError::ParseError(format!(
+                "Failed to parse release group response: {}",
+                e
+            ))
+        })?;
+
+        Ok(search_result
+            .release_groups
+            .into_iter()
+            .map(|rg| rg.into())
+            .collect())
+    }
 }
 
 // ============================================================================
@@ -220,6 +278,26 @@ struct MbRelease {
     media: Vec<MbMedium>,
 }
 
+#[derive(Debug, Deserialize)]
+struct MbReleaseGroupSearchResponse {
+    #[serde(rename = "release-groups", default)]
+    release_groups: Vec<MbReleaseGroup>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MbReleaseGroup {
+    id: String,
+    title: String,
+    #[serde(rename = "artist-credit", default)]
+    artist_credit: Vec<MbArtistCredit>,
+    #[serde(rename = "primary-type", default)]
+    primary_type: Option<String>,
+    #[serde(rename = "secondary-types", default)]
+    secondary_types: Vec<String>,
+    #[serde(rename = "first-release-date", default)]
+    first_release_date: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct MbArtistCredit {
     #[serde(default)]
@@ -325,6 +403,30 @@ impl From<MbRelease> for MusicBrainzRelease {
     }
 }
 
+impl From<MbReleaseGroup> for MusicBrainzReleaseGroup {
+    fn from(mb: MbReleaseGroup) -> Self {
+        let artist_credit = mb
+            .artist_credit
+            .iter()
+            .map(|ac| {
+                let name = ac.name.clone().unwrap_or_else(|| ac.artist.name.clone());
+                let join = ac.joinphrase.clone().unwrap_or_default();
+                format!("{}{}", name, join)
+            })
+            .collect::<String>();
+
+        MusicBrainzReleaseGroup {
+            mbid: mb.id,
+            title: mb.title,
+            artist_credit,
+            primary_type: mb.primary_type,
+            secondary_types: mb.secondary_types,
+            // MusicBrainz returns "" for unknown dates
+            first_release_date: mb.first_release_date.filter(|d| !d.is_empty()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -414,4 +516,36 @@ mod tests {
         assert_eq!(release.tracks[2].disc_number, Some(2));
         assert_eq!(release.tracks[2].title, "Track 3");
     }
+
+    #[test]
+    fn test_release_group_search_parsing() {
+        let json = r#"{
+            "release-groups": [{
+                "id": "rg-1",
+                "title": "The Dark Side of the Moon",
+                "primary-type": "Album",
+                "first-release-date": "1973-03-01",
+                "artist-credit": [{"name": "Pink Floyd", "artist": {"name": "Pink Floyd"}}]
+            }, {
+                "id": "rg-2",
+                "title": "Pulse",
+                "primary-type": "Album",
+                "secondary-types": ["Live"],
+                "first-release-date": ""
+            }]
+        }"#;
+
+        let response: MbReleaseGroupSearchResponse = serde_json::from_str(json).unwrap();
+        let groups: Vec<MusicBrainzReleaseGroup> = response
+            .release_groups
+            .into_iter()
+            .map(|rg| rg.into())
+            .collect();
+
+        assert_eq!(groups[0].artist_credit, "Pink Floyd");
+        assert_eq!(groups[0].year(), Some(1973));
+        assert!(groups[0].is_studio_album());
+        assert_eq!(groups[1].first_release_date, None);
+        assert!(!groups[1].is_studio_album());
+    }
 }
@@ -0,0 +1,308 @@
+//! Item list resolution for multi-item tickets.
+//!
+//! - Whole-season and complete-series episode lists
+//! - Absolute episode offsets for anime, which numbers episodes across seasons
+//! - Discography album lists from MusicBrainz release groups
+
+use crate::ticket::{ExpectedAlbum, ExpectedContent, ExpectedSeason};
+
+use super::{ExternalCatalog, ExternalCatalogError, TmdbEpisode};
+
+/// Series details found while resolving an episode list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolvedEpisodes {
+    /// TMDB ID of the series.
+    pub tmdb_id: u32,
+    /// Mean runtime of the resolved episodes, when TMDB lists one.
+    pub episode_runtime_minutes: Option<u32>,
+}
+
+/// Fill in the episode lists of a `TvSeason` or `TvSeries` expectation from
+/// TMDB.
+///
+/// The series is looked up by `tmdb_id` when given, otherwise by searching
+/// for the series name and taking the first result. Other content types are
+/// left untouched (and return None). For a complete series (`seasons: None`)
+/// the specials season (0) is skipped.
+pub async fn resolve_tv_episodes(
+    catalog: &dyn ExternalCatalog,
+    expected: &mut ExpectedContent,
+    tmdb_id: Option<u32>,
+) -> Result<Option<ResolvedEpisodes>, ExternalCatalogError> {
+    match expected {
+        ExpectedContent::TvSeason {
+            series,
+            season,
+            episodes,
+        } => {
+            let id = series_id(catalog, series, tmdb_id).await?;
+            let details = catalog.get_tv_season(id, *season).await?;
+            *episodes = details.episodes.iter().map(|e| e.episode_number).collect();
+            episodes.sort_unstable();
+            Ok(Some(ResolvedEpisodes {
+                tmdb_id: id,
+                episode_runtime_minutes: mean_runtime(&details.episodes),
+            }))
+        }
+        ExpectedContent::TvSeries {
+            series,
+            seasons,
+            resolved_seasons,
+        } => {
+            let id = series_id(catalog, series, tmdb_id).await?;
+            let details = catalog.get_tv(id).await?;
+            let mut resolved = Vec::new();
+            let mut all_episodes = Vec::new();
+            for summary in details.seasons.iter().filter(|s| match seasons {
+                Some(wanted) => wanted.contains(&s.season_number),
+                None => s.season_number > 0,
+            }) {
+                let season = catalog.get_tv_season(id, summary.season_number).await?;
+                let mut episodes: Vec<u32> =
+                    season.episodes.iter().map(|e| e.episode_number).collect();
+                episodes.sort_unstable();
+                resolved.push(ExpectedSeason {
+                    season: summary.season_number,
+                    episodes,
+                });
+                all_episodes.extend(season.episodes);
+            }
+            resolved.sort_by_key(|s| s.season);
+            *resolved_seasons = resolved;
+            Ok(Some(ResolvedEpisodes {
+                tmdb_id: id,
+                episode_runtime_minutes: mean_runtime(&all_episodes),
+            }))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Mean runtime of the episodes that list one, rounded to the minute.
+fn mean_runtime(episodes: &[TmdbEpisode]) -> Option<u32> {
+    let runtimes: Vec<u32> = episodes.iter().filter_map(|e| e.runtime_minutes).collect();
+    if runtimes.is_empty() {
+        return None;
+    }
+    let total: u32 = runtimes.iter().sum();
+    Some((total as f64 / runtimes.len() as f64).round() as u32)
+}
+
+/// Look up the series and count the episodes aired before `season`.
+///
+/// Returns the TMDB ID together with the offset, so that absolute episode
+/// `offset + n` corresponds to episode `n` of the season. Specials are not
+/// counted.
+pub async fn resolve_absolute_offset(
+    catalog: &dyn ExternalCatalog,
+    series: &str,
+    season: u32,
+    tmdb_id: Option<u32>,
+) -> Result<(u32, u32), ExternalCatalogError> {
+    let id = series_id(catalog, series, tmdb_id).await?;
+    let details = catalog.get_tv(id).await?;
+    let offset = details
+        .seasons
+        .iter()
+        .filter(|s| s.season_number > 0 && s.season_number < season)
+        .map(|s| s.episode_count)
+        .sum();
+    Ok((id, offset))
+}
+
+/// Fill in the album list of a `Discography` expectation from MusicBrainz.
+///
+/// Only studio albums (release groups of primary type Album without
+/// secondary types such as Live or Compilation) are kept, in release order.
+/// Other content types are left untouched.
+pub async fn resolve_discography(
+    catalog: &dyn ExternalCatalog,
+    expected: &mut ExpectedContent,
+) -> Result<(), ExternalCatalogError> {
+    let ExpectedContent::Discography { artist, albums } = expected else {
+        return Ok(());
+    };
+
+    let mut groups: Vec<_> = catalog
+        .search_release_groups(artist, 100)
+        .await?
+        .into_iter()
+        .filter(|g| g.is_studio_album())
+        .collect();
+    if groups.is_empty() {
+        return Err(ExternalCatalogError::NotFound(format!(
+            "No albums found for '{}'",
+            artist
+        )));
+    }
+    groups.sort_by_key(|g| g.year().unwrap_or(u32::MAX));
+
+    *albums = groups
+        .into_iter()
+        .map(|g| ExpectedAlbum {
+            year: g.year(),
+            title: g.title,
+            mbid: Some(g.mbid),
+            tracks: Vec::new(),
+        })
+        .collect();
+    Ok(())
+}
+
+/// TMDB ID for a series, searching by name when none is given.
+async fn series_id(
+    catalog: &dyn ExternalCatalog,
+    series: &str,
+    tmdb_id: Option<u32>,
+) -> Result<u32, ExternalCatalogError> {
+    if let Some(id) = tmdb_id {
+        return Ok(id);
+    }
+
+    catalog
+        .search_tv(series)
+        .await?
+        .first()
+        .map(|s| s.id)
+        .ok_or_else(|| ExternalCatalogError::NotFound(format!("Series '{}' not found", series)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external_catalog::TmdbSeasonSummary;
+    use crate::testing::{fixtures, MockExternalCatalog};
+
+    async fn catalog() -> (MockExternalCatalog, u32) {
+        let catalog = MockExternalCatalog::new();
+        let mut series = fixtures::tmdb_series("Firefly", 2);
+        series.seasons.insert(
+            0,
+            TmdbSeasonSummary {
+                season_number: 0,
+                name: Some("Specials".to_string()),
+                episode_count: 3,
+                air_date: None,
+                poster_path: None,
+            },
+        );
+        let id = series.id;
+        catalog.add_series(series).await;
+        catalog.add_season(id, fixtures::tmdb_season(1, 14)).await;
+        // Season 2 skips episode 5, which TMDB lists as a special
+        let mut season = fixtures::tmdb_season(2, 9);
+        season.episodes.retain(|e| e.episode_number != 5);
+        catalog.add_season(id, season).await;
+        (catalog, id)
+    }
+
+    #[tokio::test]
+    async fn test_resolve_season_by_search() {
+        let (catalog, id) = catalog().await;
+        let mut expected = ExpectedContent::tv_season("Firefly", 1);
+
+        let resolved = resolve_tv_episodes(&catalog, &mut expected, None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            expected,
+            ExpectedContent::TvSeason {
+                series: "Firefly".to_string(),
+                season: 1,
+                episodes: (1..=14).collect(),
+            }
+        );
+        assert_eq!(
+            resolved,
+            Some(ResolvedEpisodes {
+                tmdb_id: id,
+                episode_runtime_minutes: Some(45),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_series_skips_specials() {
+        let (catalog, id) = catalog().await;
+        let mut expected = ExpectedContent::tv_series("Firefly");
+
+        let resolved = resolve_tv_episodes(&catalog, &mut expected, Some(id))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(resolved.episode_runtime_minutes, Some(45));
+
+        let ExpectedContent::TvSeries {
+            resolved_seasons, ..
+        } = &expected
+        else {
+            panic!("expected TvSeries");
+        };
+        let seasons: Vec<u32> = resolved_seasons.iter().map(|s| s.season).collect();
+        assert_eq!(seasons, [1, 2]);
+        assert_eq!(resolved_seasons[1].episodes, [1, 2, 3, 4, 6, 7, 8, 9]);
+        assert_eq!(expected.expected_file_count(), 22);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_absolute_offset() {
+        let (catalog, id) = catalog().await;
+
+        let resolved = resolve_absolute_offset(&catalog, "Firefly", 2, None)
+            .await
+            .unwrap();
+        assert_eq!(resolved, (id, 10));
+
+        let resolved = resolve_absolute_offset(&catalog, "Firefly", 1, Some(id))
+            .await
+            .unwrap();
+        assert_eq!(resolved, (id, 0));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_discography_keeps_studio_albums() {
+        let catalog = MockExternalCatalog::new();
+        let mut live = fixtures::musicbrainz_release_group("Portishead", "Roseland NYC Live", 1998);
+        live.secondary_types = vec!["Live".to_string()];
+        catalog.add_release_group(live).await;
+        catalog
+            .add_release_group(fixtures::musicbrainz_release_group(
+                "Portishead",
+                "Third",
+                2008,
+            ))
+            .await;
+        catalog
+            .add_release_group(fixtures::musicbrainz_release_group(
+                "Portishead",
+                "Dummy",
+                1994,
+            ))
+            .await;
+        let mut expected = ExpectedContent::discography("Portishead");
+
+        resolve_discography(&catalog, &mut expected).await.unwrap();
+
+        let ExpectedContent::Discography { albums, .. } = &expected else {
+            panic!("expected Discography");
+        };
+        let titles: Vec<(&str, Option<u32>)> =
+            albums.iter().map(|a| (a.title.as_str(), a.year)).collect();
+        assert_eq!(titles, [("Dummy", Some(1994)), ("Third", Some(2008))]);
+        assert!(!expected.needs_album_resolution());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_unknown_series() {
+        let catalog = MockExternalCatalog::new();
+        let mut expected = ExpectedContent::tv_season("Nonexistent", 1);
+
+        let err = resolve_tv_episodes(&catalog, &mut expected, None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ExternalCatalogError::NotFound(_)));
+        assert!(expected.needs_episode_resolution());
+    }
+}
@@ -45,6 +45,44 @@ impl MusicBrainzRelease {
     }
 }
 
+/// A MusicBrainz release group (an album across all of its editions).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MusicBrainzReleaseGroup {
+    /// MusicBrainz Release Group ID (MBID).
+    pub mbid: String,
+    /// Release group title.
+    pub title: String,
+    /// Artist credit (combined artist name).
+    pub artist_credit: String,
+    /// Primary type ("Album", "EP", "Single", ...).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_type: Option<String>,
+    /// Secondary types ("Compilation", "Live", ...).
+    #[serde(default)]
+    pub secondary_types: Vec<String>,
+    /// Date of the earliest release (YYYY-MM-DD or partial).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_release_date: Option<String>,
+}
+
+impl MusicBrainzReleaseGroup {
+    /// Extract year from the first release date.
+    pub fn year(&self) -> Option<u32> {
+        self.first_release_date
+            .as_ref()
+            .and_then(|d| d.get(..4))
+            .and_then(|y| y.parse().ok())
+    }
+
+    /// Whether this is a studio album (an album with no secondary types).
+    pub fn is_studio_album(&self) -> bool {
+        self.primary_type
+            .as_deref()
+            .is_some_and(|t| t.eq_ignore_ascii_case("album"))
+            && self.secondary_types.is_empty()
+    }
+}
+
 /// A track from a MusicBrainz release.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MusicBrainzTrack {
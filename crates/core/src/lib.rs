@@ -7,6 +7,8 @@
 
 pub mod audit;
 pub mod auth;
+pub mod blacklist;
+pub mod calibration;
 pub mod catalog;
 pub mod config;
 pub mod content;
@@ -29,12 +31,21 @@ pub mod testing;
 
 pub use audit::{
     create_audit_system, AuditError, AuditEvent, AuditEventEnvelope, AuditFilter, AuditHandle,
-    AuditRecord, AuditStore, AuditWriter, SqliteAuditStore,
+    AuditRecord, AuditStore, AuditWriter, SqliteAuditStore, TrainingCandidate,
 };
 pub use auth::{
     create_authenticator, ApiKeyAuthenticator, AuthError, AuthRequest, Authenticator, Identity,
     NoneAuthenticator,
 };
+pub use blacklist::{
+    Blacklist, BlacklistEntry, BlacklistError, BlacklistFilter, BlacklistKind, BlacklistStore,
+    CreateBlacklistEntry, SqliteBlacklistStore,
+};
+pub use calibration::{
+    CalibrationError, CalibrationMetrics, CalibrationPreview, CalibrationSample,
+    CreateScoringProfile, ReplayChange, ReplayMetrics, ReplayReport, ScoringProfile,
+    ScoringProfileStore, SqliteScoringProfileStore,
+};
 pub use catalog::{
     CachedTorrent, CachedTorrentFile, CachedTorrentSource, CatalogError, CatalogSearchQuery,
     CatalogStats, SearchMode, SqliteCatalog, TorrentCatalog,
@@ -55,6 +66,9 @@ pub use converter::{
     // Types
     AudioConstraints,
     AudioFormat,
+    AudiobookConstraints,
+    ChapterInput,
+    ChapterMergeJob,
     ContainerFormat,
     ConversionConstraints,
     ConversionJob,
@@ -72,10 +86,15 @@ pub use converter::{
     // Implementations
     FfmpegConverter,
     MediaInfo,
+    TimeSegment,
     VideoConstraints,
     VideoFormat,
 };
 pub use external_catalog::{
+    // Resolution
+    resolve_absolute_offset,
+    resolve_discography,
+    resolve_tv_episodes,
     // Clients
     CombinedCatalogClient,
     // Trait
@@ -87,7 +106,9 @@ pub use external_catalog::{
     MusicBrainzConfig,
     // MusicBrainz types
     MusicBrainzRelease,
+    MusicBrainzReleaseGroup,
     MusicBrainzTrack,
+    ResolvedEpisodes,
     TmdbClient,
     TmdbConfig,
     // TMDB types
@@ -101,11 +122,19 @@ pub use orchestrator::{
     // Types
     ActiveDownload,
     // Configuration
+    FailoverConfig,
+    // Failover
+    FailoverDecision,
+    FailoverStrategy,
+    FailoverStrategyKind,
     OrchestratorConfig,
     OrchestratorError,
     OrchestratorStatus,
     // Orchestrator
     TicketOrchestrator,
+    VerificationAction,
+    VerificationConfig,
+    VerificationFailure,
 };
 pub use placer::{
     // Types
@@ -155,8 +184,12 @@ pub use textbrain::{
     AcquisitionResult,
     // LLM client types
     AnthropicClient,
+    AuditedLlmClient,
+    BudgetedLlmClient,
+    CachedLlmClient,
     // Traits
     CandidateMatcher,
+    CircuitState,
     CompletionRequest,
     CompletionResponse,
     // Dumb implementations
@@ -164,31 +197,68 @@ pub use textbrain::{
     DumbMatcherConfig,
     DumbQueryBuilder,
     DumbQueryBuilderConfig,
+    DumbScoringWeights,
+    FallbackLlmClient,
+    FallbackProvider,
+    FileMapper,
     FileMapping,
+    FileMappingResult,
+    LlmBudget,
+    LlmBudgetConfig,
+    LlmBudgetPeriod,
+    LlmBudgetStatus,
+    LlmCache,
+    LlmCacheConfig,
+    LlmCircuitBreakerConfig,
     LlmClient,
     // Configuration
     LlmConfig,
     LlmError,
+    LlmFileMapper,
     LlmProvider,
+    LlmProviderConfig,
+    LlmProviderHealth,
+    LlmProviderStatus,
     LlmUsage,
+    LlmUsageStore,
     MatchResult,
+    ModelPricing,
+    MusicScoringWeights,
     OllamaClient,
+    OpenAiClient,
+    PromptLibrary,
+    PromptTask,
+    PromptTemplate,
+    PromptTemplateInfo,
     QueryBuildResult,
     QueryBuilder,
+    ResponseSchema,
     ScoredCandidate,
     ScoredCandidateSummary,
+    ScoringWeights,
+    SqliteLlmCache,
+    SqliteLlmUsageStore,
     // Coordinator
     TextBrain,
     TextBrainConfig,
     TextBrainError,
     TextBrainMode,
+    TextBrainOverrides,
+    // Training dataset export
+    TrainingExport,
+    TrainingExportFormat,
+    TrainingExportOptions,
+    TrainingTask,
+    VideoScoringWeights,
 };
 pub use ticket::{
-    AcquisitionPhase, AudioSearchConstraints, CatalogReference, CompletionStats,
-    CreateTicketRequest, ExpectedContent, ExpectedTrack, LanguagePreference, LanguagePriority,
-    OutputConstraints, QueryContext, Resolution, SearchConstraints, SelectedCandidate,
-    SqliteTicketStore, Ticket, TicketError, TicketFilter, TicketState, TicketStore, TmdbMediaType,
-    VideoCodec, VideoSearchConstraints, VideoSource,
+    AcquisitionPhase, AnimeConstraints, AudioQualityReport, AudioSearchConstraints, BookFormat,
+    CatalogReference, CompletedDownload, CompletionStats, CreateTicketRequest, Edition,
+    ExpectedAlbum, ExpectedChapter, ExpectedContent, ExpectedSeason, ExpectedTrack, FailoverRecord,
+    HdrPreference, LanguagePreference, LanguagePriority, OutputConstraints, QueryContext,
+    RaceEntrant, Resolution, SearchConstraints, SelectedCandidate, SqliteTicketStore, Ticket,
+    TicketError, TicketFilter, TicketState, TicketStore, TmdbMediaType, VideoCodec,
+    VideoSearchConstraints, VideoSource,
 };
 pub use torrent_client::{
     AddTorrentRequest, AddTorrentResult, LibrqbitClient, QBittorrentClient, TorrentClient,
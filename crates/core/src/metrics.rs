@@ -6,7 +6,9 @@
 //! - External services (Jackett, torrent client, LLM)
 
 use once_cell::sync::Lazy;
-use prometheus::{HistogramOpts, HistogramVec, IntCounter, IntCounterVec, Opts};
+use prometheus::{
+    CounterVec, GaugeVec, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, Opts,
+};
 
 // =============================================================================
 // Orchestrator - Acquisition Metrics
@@ -134,6 +136,34 @@ pub static FAILOVER_ATTEMPTS: Lazy<IntCounter> = Lazy::new(|| {
     .unwrap()
 });
 
+/// Completed downloads that failed verification.
+pub static VERIFICATION_FAILURES: Lazy<IntCounter> = Lazy::new(|| {
+    IntCounter::new(
+        "quentin_verification_failures_total",
+        "Total completed downloads that failed verification",
+    )
+    .unwrap()
+});
+
+/// Completed music downloads with lossless files that look transcoded,
+/// upsampled or padded.
+pub static SUSPECT_LOSSLESS: Lazy<IntCounter> = Lazy::new(|| {
+    IntCounter::new(
+        "quentin_suspect_lossless_total",
+        "Total completed downloads with suspect lossless files",
+    )
+    .unwrap()
+});
+
+/// Downloads paused to make room for higher-priority tickets.
+pub static DOWNLOADS_PREEMPTED: Lazy<IntCounter> = Lazy::new(|| {
+    IntCounter::new(
+        "quentin_downloads_preempted_total",
+        "Total downloads paused by priority preemption",
+    )
+    .unwrap()
+});
+
 /// Retry attempts total by phase.
 pub static RETRY_ATTEMPTS: Lazy<IntCounterVec> = Lazy::new(|| {
     IntCounterVec::new(
@@ -249,6 +279,36 @@ pub static LLM_TOKENS: Lazy<IntCounterVec> = Lazy::new(|| {
     .unwrap()
 });
 
+/// LLM completion cache lookups.
+pub static LLM_CACHE_REQUESTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    IntCounterVec::new(
+        Opts::new(
+            "quentin_llm_cache_requests_total",
+            "Total LLM completion cache lookups",
+        ),
+        &["provider", "result"], // result: "hit", "miss"
+    )
+    .unwrap()
+});
+
+/// LLM cost, priced with the configured per-model pricing.
+pub static LLM_COST: Lazy<CounterVec> = Lazy::new(|| {
+    CounterVec::new(
+        Opts::new("quentin_llm_cost_total", "Total LLM cost"),
+        &["provider"],
+    )
+    .unwrap()
+});
+
+/// Remaining LLM budget (only set for configured limits).
+pub static LLM_BUDGET_REMAINING: Lazy<GaugeVec> = Lazy::new(|| {
+    GaugeVec::new(
+        Opts::new("quentin_llm_budget_remaining", "Remaining LLM budget"),
+        &["period", "unit"], // period: "daily", "monthly"; unit: "tokens", "cost"
+    )
+    .unwrap()
+});
+
 // =============================================================================
 // Helper functions
 // =============================================================================
@@ -269,6 +329,9 @@ pub fn all_metrics() -> Vec<Box<dyn prometheus::core::Collector>> {
         Box::new(DOWNLOAD_DURATION.clone()),
         Box::new(STALL_DETECTIONS.clone()),
         Box::new(FAILOVER_ATTEMPTS.clone()),
+        Box::new(VERIFICATION_FAILURES.clone()),
+        Box::new(SUSPECT_LOSSLESS.clone()),
+        Box::new(DOWNLOADS_PREEMPTED.clone()),
         Box::new(RETRY_ATTEMPTS.clone()),
         // Pipeline
         Box::new(CONVERSIONS_TOTAL.clone()),
@@ -281,5 +344,8 @@ pub fn all_metrics() -> Vec<Box<dyn prometheus::core::Collector>> {
         Box::new(EXTERNAL_SERVICE_REQUESTS.clone()),
         Box::new(SEARCH_RESULTS.clone()),
         Box::new(LLM_TOKENS.clone()),
+        Box::new(LLM_CACHE_REQUESTS.clone()),
+        Box::new(LLM_COST.clone()),
+        Box::new(LLM_BUDGET_REMAINING.clone()),
     ]
 }
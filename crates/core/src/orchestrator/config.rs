@@ -105,6 +105,154 @@ impl RetryConfig {
     }
 }
 
+/// Built-in failover strategies, selectable via `failover.strategy`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FailoverStrategyKind {
+    /// Try candidates in acquisition order, escalating stall timeouts each round.
+    #[default]
+    Sequential,
+    /// Try the untried candidate with the most seeders first.
+    BySeeders,
+    /// Try the untried candidate with the highest match score first.
+    ByScore,
+    /// Start the top `race_size` untried candidates at once and keep the first
+    /// to reach `race_win_pct`.
+    Race,
+    /// Try each candidate once, then send the ticket back through acquisition.
+    Research,
+}
+
+/// Configuration for failover when a download stalls.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailoverConfig {
+    /// Strategy used to pick the next candidate.
+    #[serde(default)]
+    pub strategy: FailoverStrategyKind,
+
+    /// Number of candidates started at once by the race strategy (default: 3).
+    #[serde(default = "default_race_size")]
+    pub race_size: usize,
+
+    /// Progress percentage at which a race entrant wins (default: 5.0).
+    /// The other entrants are removed as soon as one reaches this.
+    #[serde(default = "default_race_win_pct")]
+    pub race_win_pct: f32,
+
+    /// Send the ticket back through acquisition instead of failing it once
+    /// every candidate has stalled (default: false). Stalled info hashes are
+    /// excluded from the new search.
+    #[serde(default)]
+    pub reacquire_on_exhaustion: bool,
+
+    /// Maximum number of times a ticket is sent back to acquisition after its
+    /// candidates stalled (default: 2). Applies to the research strategy too.
+    #[serde(default = "default_max_reacquisitions")]
+    pub max_reacquisitions: u32,
+}
+
+fn default_race_size() -> usize {
+    3
+}
+
+fn default_race_win_pct() -> f32 {
+    5.0
+}
+
+fn default_max_reacquisitions() -> u32 {
+    2
+}
+
+impl Default for FailoverConfig {
+    fn default() -> Self {
+        Self {
+            strategy: FailoverStrategyKind::default(),
+            race_size: default_race_size(),
+            race_win_pct: default_race_win_pct(),
+            reacquire_on_exhaustion: false,
+            max_reacquisitions: default_max_reacquisitions(),
+        }
+    }
+}
+
+/// What to do when a completed download fails verification.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationAction {
+    /// Drop the download and fail over to the next candidate, like a stall.
+    #[default]
+    Failover,
+    /// Fail the ticket.
+    Fail,
+}
+
+/// Configuration for verifying completed downloads before conversion.
+///
+/// Mapped files are probed and compared against the ticket's expected track
+/// durations, catalog runtime and episode count, minimum resolution, and
+/// minimum bit depth and sample rate. Lossless music files are also checked
+/// for lossy sources, upsampling and bit-depth padding.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationConfig {
+    /// Probe downloaded files before conversion (default: true).
+    #[serde(default = "default_verification_enabled")]
+    pub enabled: bool,
+
+    /// Allowed duration difference as a percentage of the expected duration
+    /// (default: 10.0).
+    #[serde(default = "default_duration_tolerance_pct")]
+    pub duration_tolerance_pct: f32,
+
+    /// Minimum allowed duration difference in seconds, so short tracks are
+    /// not held to a few seconds (default: 5).
+    #[serde(default = "default_duration_tolerance_secs")]
+    pub duration_tolerance_secs: u32,
+
+    /// What to do when verification fails (default: failover).
+    #[serde(default)]
+    pub on_mismatch: VerificationAction,
+
+    /// Decode lossless music files and analyze their spectrum and bit depth
+    /// (default: true). Findings are recorded on the ticket.
+    #[serde(default = "default_lossless_analysis")]
+    pub lossless_analysis: bool,
+
+    /// Fail verification for lossless files that look transcoded from a
+    /// lossy source, upsampled or padded, instead of only recording them
+    /// (default: false).
+    #[serde(default)]
+    pub reject_suspect_lossless: bool,
+}
+
+fn default_verification_enabled() -> bool {
+    true
+}
+
+fn default_duration_tolerance_pct() -> f32 {
+    10.0
+}
+
+fn default_duration_tolerance_secs() -> u32 {
+    5
+}
+
+fn default_lossless_analysis() -> bool {
+    true
+}
+
+impl Default for VerificationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_verification_enabled(),
+            duration_tolerance_pct: default_duration_tolerance_pct(),
+            duration_tolerance_secs: default_duration_tolerance_secs(),
+            on_mismatch: VerificationAction::default(),
+            lossless_analysis: default_lossless_analysis(),
+            reject_suspect_lossless: false,
+        }
+    }
+}
+
 /// Configuration for the ticket orchestrator.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrchestratorConfig {
@@ -134,6 +282,12 @@ pub struct OrchestratorConfig {
     #[serde(default)]
     pub max_concurrent_downloads: usize,
 
+    /// Allow higher-priority tickets to preempt running downloads.
+    /// When `max_concurrent_downloads` is reached, the lowest-priority active
+    /// download is paused to free a slot, and resumed once a slot is free.
+    #[serde(default)]
+    pub preemption_enabled: bool,
+
     // ========================================================================
     // Stall detection and failover
     // ========================================================================
@@ -156,6 +310,14 @@ pub struct OrchestratorConfig {
     #[serde(default = "default_max_failover_candidates")]
     pub max_failover_candidates: usize,
 
+    /// Failover strategy configuration.
+    #[serde(default)]
+    pub failover: FailoverConfig,
+
+    /// Verification of completed downloads.
+    #[serde(default)]
+    pub verification: VerificationConfig,
+
     /// Retry configuration for transient failures.
     #[serde(default)]
     pub retry: RetryConfig,
@@ -197,10 +359,13 @@ impl Default for OrchestratorConfig {
             download_poll_interval_ms: default_download_interval(),
             auto_approve_threshold: default_threshold(),
             max_concurrent_downloads: 0,
+            preemption_enabled: false,
             stall_timeout_round1_secs: default_stall_timeout_round1(),
             stall_timeout_round2_secs: default_stall_timeout_round2(),
             stall_timeout_round3_secs: default_stall_timeout_round3(),
             max_failover_candidates: default_max_failover_candidates(),
+            failover: FailoverConfig::default(),
+            verification: VerificationConfig::default(),
             retry: RetryConfig::default(),
         }
     }
@@ -218,11 +383,26 @@ mod tests {
         assert_eq!(config.download_poll_interval_ms, 3000);
         assert_eq!(config.auto_approve_threshold, 0.85);
         assert_eq!(config.max_concurrent_downloads, 0);
+        assert!(!config.preemption_enabled);
         // Stall detection defaults
         assert_eq!(config.stall_timeout_round1_secs, 300); // 5 min
         assert_eq!(config.stall_timeout_round2_secs, 1800); // 30 min
         assert_eq!(config.stall_timeout_round3_secs, 7200); // 2 hours
         assert_eq!(config.max_failover_candidates, 5);
+        assert_eq!(config.failover.strategy, FailoverStrategyKind::Sequential);
+        assert_eq!(config.failover.race_size, 3);
+        assert!(!config.failover.reacquire_on_exhaustion);
+        assert_eq!(config.failover.max_reacquisitions, 2);
+        // Verification defaults
+        assert!(config.verification.enabled);
+        assert!((config.verification.duration_tolerance_pct - 10.0).abs() < 0.001);
+        assert_eq!(config.verification.duration_tolerance_secs, 5);
+        assert_eq!(
+            config.verification.on_mismatch,
+            VerificationAction::Failover
+        );
+        assert!(config.verification.lossless_analysis);
+        assert!(!config.verification.reject_suspect_lossless);
         // Retry defaults
         assert_eq!(config.retry.max_attempts, 10);
         assert_eq!(config.retry.initial_delay_ms, 5000);
@@ -252,6 +432,7 @@ mod tests {
             download_poll_interval_ms = 5000
             auto_approve_threshold = 0.90
             max_concurrent_downloads = 3
+            preemption_enabled = true
         "#;
         let config: OrchestratorConfig = toml::from_str(toml).unwrap();
         assert!(config.enabled);
@@ -259,6 +440,7 @@ mod tests {
         assert_eq!(config.download_poll_interval_ms, 5000);
         assert_eq!(config.auto_approve_threshold, 0.90);
         assert_eq!(config.max_concurrent_downloads, 3);
+        assert!(config.preemption_enabled);
     }
 
     #[test]
@@ -281,6 +463,44 @@ mod tests {
         assert!((config.retry.jitter_factor - 0.1).abs() < 0.001);
     }
 
+    #[test]
+    fn test_deserialize_with_failover() {
+        let toml = r#"
+            enabled = true
+
+            [failover]
+            strategy = "race"
+            race_size = 2
+            race_win_pct = 10.0
+            reacquire_on_exhaustion = true
+        "#;
+        let config: OrchestratorConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.failover.strategy, FailoverStrategyKind::Race);
+        assert_eq!(config.failover.race_size, 2);
+        assert!((config.failover.race_win_pct - 10.0).abs() < 0.001);
+        assert!(config.failover.reacquire_on_exhaustion);
+        assert_eq!(config.failover.max_reacquisitions, 2);
+    }
+
+    #[test]
+    fn test_deserialize_with_verification() {
+        let toml = r#"
+            enabled = true
+
+            [verification]
+            duration_tolerance_pct = 5.0
+            on_mismatch = "fail"
+            reject_suspect_lossless = true
+        "#;
+        let config: OrchestratorConfig = toml::from_str(toml).unwrap();
+        assert!(config.verification.enabled);
+        assert!((config.verification.duration_tolerance_pct - 5.0).abs() < 0.001);
+        assert_eq!(config.verification.duration_tolerance_secs, 5);
+        assert_eq!(config.verification.on_mismatch, VerificationAction::Fail);
+        assert!(config.verification.lossless_analysis);
+        assert!(config.verification.reject_suspect_lossless);
+    }
+
     #[test]
     fn test_retry_config_default() {
         let config = RetryConfig::default();
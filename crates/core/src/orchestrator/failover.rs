@@ -0,0 +1,419 @@
+//! Failover strategies for stalled downloads.
+//!
+//! When a download stops making progress, the orchestrator asks the configured
+//! [`FailoverStrategy`] what to do next. Strategies only decide; the runner
+//! carries out the decision (removing and adding torrents, updating state).
+
+use std::sync::Arc;
+
+use crate::ticket::SelectedCandidate;
+
+use super::config::{FailoverConfig, FailoverStrategyKind};
+
+/// Maximum number of failover rounds before giving up.
+/// Each round uses a longer stall timeout (see `stall_timeout_round{1,2,3}_secs`).
+pub const MAX_FAILOVER_ROUNDS: u8 = 3;
+
+/// Information available to a strategy when a download stalls.
+#[derive(Debug, Clone)]
+pub struct FailoverContext<'a> {
+    /// All candidates retained for failover.
+    pub candidates: &'a [SelectedCandidate],
+    /// Index of the candidate that stalled.
+    pub current_idx: usize,
+    /// Current failover round (1-based).
+    pub round: u8,
+    /// Candidates already tried in the current round (includes `current_idx`).
+    pub tried: &'a [usize],
+}
+
+/// What to do about a stalled download.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FailoverDecision {
+    /// Switch to a single candidate.
+    Switch {
+        candidate_idx: usize,
+        round: u8,
+        reason: String,
+    },
+    /// Start several candidates at once and keep the first to make progress.
+    Race {
+        candidate_idxs: Vec<usize>,
+        round: u8,
+        reason: String,
+    },
+    /// Send the ticket back through acquisition for a fresh search.
+    Research { reason: String },
+    /// Give up and fail the ticket.
+    GiveUp { reason: String },
+}
+
+/// Decides which candidate(s) to try when a download stalls.
+pub trait FailoverStrategy: Send + Sync {
+    /// Short name recorded alongside each decision.
+    fn name(&self) -> &'static str;
+
+    /// Decide what to do after the current download stalled.
+    fn on_stall(&self, ctx: &FailoverContext<'_>) -> FailoverDecision;
+}
+
+/// Build the strategy selected in config.
+pub fn build_failover_strategy(config: &FailoverConfig) -> Arc<dyn FailoverStrategy> {
+    match config.strategy {
+        FailoverStrategyKind::Sequential => Arc::new(SequentialFailover),
+        FailoverStrategyKind::BySeeders => Arc::new(BySeedersFailover),
+        FailoverStrategyKind::ByScore => Arc::new(ByScoreFailover),
+        FailoverStrategyKind::Race => Arc::new(RaceFailover {
+            race_size: config.race_size.max(1),
+        }),
+        FailoverStrategyKind::Research => Arc::new(ResearchFailover),
+    }
+}
+
+/// Pick the first untried candidate in `order`, starting a new round once every
+/// candidate has been tried. Returns None once all rounds are used up.
+fn next_in_order(ctx: &FailoverContext<'_>, order: &[usize]) -> Option<(usize, u8)> {
+    if let Some(&idx) = order.iter().find(|idx| !ctx.tried.contains(idx)) {
+        return Some((idx, ctx.round));
+    }
+
+    let round = ctx.round + 1;
+    if round > MAX_FAILOVER_ROUNDS {
+        return None;
+    }
+    order.first().map(|&idx| (idx, round))
+}
+
+/// Candidate indices sorted by a key, highest first (ties keep acquisition order).
+fn order_by_desc<K: PartialOrd>(
+    candidates: &[SelectedCandidate],
+    key: impl Fn(&SelectedCandidate) -> K,
+) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..candidates.len()).collect();
+    order.sort_by(|&a, &b| {
+        key(&candidates[b])
+            .partial_cmp(&key(&candidates[a]))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    order
+}
+
+fn give_up(ctx: &FailoverContext<'_>) -> FailoverDecision {
+    FailoverDecision::GiveUp {
+        reason: format!(
+            "tried {} candidates over {} rounds",
+            ctx.candidates.len(),
+            MAX_FAILOVER_ROUNDS
+        ),
+    }
+}
+
+/// Describe a switch, noting when a new round with a longer timeout starts.
+fn switch(
+    ctx: &FailoverContext<'_>,
+    candidate_idx: usize,
+    round: u8,
+    why: String,
+) -> FailoverDecision {
+    let reason = if round > ctx.round {
+        format!(
+            "all candidates tried, starting round {} with candidate {}: {}",
+            round,
+            candidate_idx + 1,
+            why
+        )
+    } else {
+        format!("candidate {}: {}", candidate_idx + 1, why)
+    };
+    FailoverDecision::Switch {
+        candidate_idx,
+        round,
+        reason,
+    }
+}
+
+/// Try candidates in acquisition order (the original three-round scheme).
+pub struct SequentialFailover;
+
+impl FailoverStrategy for SequentialFailover {
+    fn name(&self) -> &'static str {
+        "sequential"
+    }
+
+    fn on_stall(&self, ctx: &FailoverContext<'_>) -> FailoverDecision {
+        let order: Vec<usize> = (0..ctx.candidates.len()).collect();
+        match next_in_order(ctx, &order) {
+            Some((idx, round)) => switch(ctx, idx, round, "next in acquisition order".into()),
+            None => give_up(ctx),
+        }
+    }
+}
+
+/// Try the best-seeded untried candidate first.
+pub struct BySeedersFailover;
+
+impl FailoverStrategy for BySeedersFailover {
+    fn name(&self) -> &'static str {
+        "by_seeders"
+    }
+
+    fn on_stall(&self, ctx: &FailoverContext<'_>) -> FailoverDecision {
+        let order = order_by_desc(ctx.candidates, |c| c.seeders);
+        match next_in_order(ctx, &order) {
+            Some((idx, round)) => {
+                let why = format!("most seeders ({})", ctx.candidates[idx].seeders);
+                switch(ctx, idx, round, why)
+            }
+            None => give_up(ctx),
+        }
+    }
+}
+
+/// Try the highest-scored untried candidate first.
+pub struct ByScoreFailover;
+
+impl FailoverStrategy for ByScoreFailover {
+    fn name(&self) -> &'static str {
+        "by_score"
+    }
+
+    fn on_stall(&self, ctx: &FailoverContext<'_>) -> FailoverDecision {
+        let order = order_by_desc(ctx.candidates, |c| c.score);
+        match next_in_order(ctx, &order) {
+            Some((idx, round)) => {
+                let why = format!("highest score ({:.2})", ctx.candidates[idx].score);
+                switch(ctx, idx, round, why)
+            }
+            None => give_up(ctx),
+        }
+    }
+}
+
+/// Start the top N untried candidates at once and keep the first to make progress.
+pub struct RaceFailover {
+    /// Number of candidates to start at once.
+    pub race_size: usize,
+}
+
+impl FailoverStrategy for RaceFailover {
+    fn name(&self) -> &'static str {
+        "race"
+    }
+
+    fn on_stall(&self, ctx: &FailoverContext<'_>) -> FailoverDecision {
+        let order = order_by_desc(ctx.candidates, |c| c.score);
+
+        let untried: Vec<usize> = order
+            .iter()
+            .copied()
+            .filter(|idx| !ctx.tried.contains(idx))
+            .collect();
+        let (pool, round) = if untried.is_empty() {
+            if ctx.round >= MAX_FAILOVER_ROUNDS {
+                return give_up(ctx);
+            }
+            (order, ctx.round + 1)
+        } else {
+            (untried, ctx.round)
+        };
+
+        let entrants: Vec<usize> = pool.into_iter().take(self.race_size).collect();
+        match entrants.as_slice() {
+            [] => give_up(ctx),
+            [idx] => switch(ctx, *idx, round, "only remaining candidate".into()),
+            _ => FailoverDecision::Race {
+                reason: format!(
+                    "racing candidates {} (top {} by score)",
+                    entrants
+                        .iter()
+                        .map(|i| (i + 1).to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    entrants.len()
+                ),
+                candidate_idxs: entrants,
+                round,
+            },
+        }
+    }
+}
+
+/// Try each candidate once, then go back to acquisition for a fresh search.
+pub struct ResearchFailover;
+
+impl FailoverStrategy for ResearchFailover {
+    fn name(&self) -> &'static str {
+        "research"
+    }
+
+    fn on_stall(&self, ctx: &FailoverContext<'_>) -> FailoverDecision {
+        match (0..ctx.candidates.len()).find(|idx| !ctx.tried.contains(idx)) {
+            Some(idx) => switch(ctx, idx, ctx.round, "next in acquisition order".into()),
+            None => FailoverDecision::Research {
+                reason: format!(
+                    "all {} candidates stalled, searching again",
+                    ctx.candidates.len()
+                ),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(score: f32, seeders: u32) -> SelectedCandidate {
+        SelectedCandidate {
+            title: format!("candidate {}", score),
+            info_hash: format!("hash{}", seeders),
+            magnet_uri: format!("magnet:?xt=urn:btih:hash{}", seeders),
+            torrent_url: None,
+            size_bytes: 1024,
+            score,
+            seeders,
+            file_mappings: vec![],
+        }
+    }
+
+    fn ctx<'a>(
+        candidates: &'a [SelectedCandidate],
+        current_idx: usize,
+        round: u8,
+        tried: &'a [usize],
+    ) -> FailoverContext<'a> {
+        FailoverContext {
+            candidates,
+            current_idx,
+            round,
+            tried,
+        }
+    }
+
+    #[test]
+    fn test_sequential_walks_candidates_then_rounds() {
+        let candidates = vec![candidate(0.9, 1), candidate(0.8, 2)];
+        let strategy = SequentialFailover;
+
+        let decision = strategy.on_stall(&ctx(&candidates, 0, 1, &[0]));
+        assert!(matches!(
+            decision,
+            FailoverDecision::Switch {
+                candidate_idx: 1,
+                round: 1,
+                ..
+            }
+        ));
+
+        let decision = strategy.on_stall(&ctx(&candidates, 1, 1, &[0, 1]));
+        assert!(matches!(
+            decision,
+            FailoverDecision::Switch {
+                candidate_idx: 0,
+                round: 2,
+                ..
+            }
+        ));
+
+        let decision = strategy.on_stall(&ctx(&candidates, 1, 3, &[0, 1]));
+        assert!(matches!(decision, FailoverDecision::GiveUp { .. }));
+    }
+
+    #[test]
+    fn test_by_seeders_prefers_most_seeded() {
+        let candidates = vec![candidate(0.9, 5), candidate(0.8, 50), candidate(0.7, 20)];
+        let strategy = BySeedersFailover;
+
+        match strategy.on_stall(&ctx(&candidates, 0, 1, &[0])) {
+            FailoverDecision::Switch {
+                candidate_idx,
+                reason,
+                ..
+            } => {
+                assert_eq!(candidate_idx, 1);
+                assert!(reason.contains("most seeders (50)"));
+            }
+            other => panic!("unexpected decision: {:?}", other),
+        }
+
+        let decision = strategy.on_stall(&ctx(&candidates, 1, 1, &[0, 1]));
+        assert!(matches!(
+            decision,
+            FailoverDecision::Switch {
+                candidate_idx: 2,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_by_score_prefers_highest_score() {
+        let candidates = vec![candidate(0.5, 1), candidate(0.6, 2), candidate(0.9, 3)];
+        let decision = ByScoreFailover.on_stall(&ctx(&candidates, 0, 1, &[0]));
+        assert!(matches!(
+            decision,
+            FailoverDecision::Switch {
+                candidate_idx: 2,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_race_starts_top_untried() {
+        let candidates = vec![
+            candidate(0.9, 1),
+            candidate(0.5, 2),
+            candidate(0.8, 3),
+            candidate(0.7, 4),
+        ];
+        let strategy = RaceFailover { race_size: 2 };
+
+        let decision = strategy.on_stall(&ctx(&candidates, 0, 1, &[0]));
+        assert!(matches!(
+            decision,
+            FailoverDecision::Race { ref candidate_idxs, round: 1, .. } if candidate_idxs == &vec![2, 3]
+        ));
+
+        // One left: no point racing
+        let decision = strategy.on_stall(&ctx(&candidates, 2, 1, &[0, 2, 3]));
+        assert!(matches!(
+            decision,
+            FailoverDecision::Switch {
+                candidate_idx: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_research_after_exhausting_candidates() {
+        let candidates = vec![candidate(0.9, 1), candidate(0.8, 2)];
+        let strategy = ResearchFailover;
+
+        let decision = strategy.on_stall(&ctx(&candidates, 0, 1, &[0]));
+        assert!(matches!(
+            decision,
+            FailoverDecision::Switch {
+                candidate_idx: 1,
+                ..
+            }
+        ));
+
+        let decision = strategy.on_stall(&ctx(&candidates, 1, 1, &[0, 1]));
+        assert!(matches!(decision, FailoverDecision::Research { .. }));
+    }
+
+    #[test]
+    fn test_build_from_config() {
+        let config = FailoverConfig {
+            strategy: FailoverStrategyKind::BySeeders,
+            ..Default::default()
+        };
+        assert_eq!(build_failover_strategy(&config).name(), "by_seeders");
+        assert_eq!(
+            build_failover_strategy(&FailoverConfig::default()).name(),
+            "sequential"
+        );
+    }
+}
@@ -3,12 +3,23 @@
 //! The orchestrator drives tickets through the state machine automatically:
 //! - **Acquisition**: Sequential (one ticket at a time) - CPU-bound
 //! - **Download**: Concurrent monitoring (many downloads) - IO-bound
+//! - **Verification**: Completed downloads are probed against the expected content
 //! - **Pipeline**: Sequential (one ticket at a time) - CPU-bound (handled by PipelineProcessor)
 
 mod config;
+mod failover;
 mod runner;
 mod types;
+mod verification;
 
-pub use config::{OrchestratorConfig, RetryConfig};
+pub use config::{
+    FailoverConfig, FailoverStrategyKind, OrchestratorConfig, RetryConfig, VerificationAction,
+    VerificationConfig,
+};
+pub use failover::{
+    build_failover_strategy, ByScoreFailover, BySeedersFailover, FailoverContext, FailoverDecision,
+    FailoverStrategy, RaceFailover, ResearchFailover, SequentialFailover, MAX_FAILOVER_ROUNDS,
+};
 pub use runner::{TicketOrchestrator, TicketUpdateCallback};
 pub use types::{ActiveDownload, OrchestratorError, OrchestratorStatus};
+pub use verification::{verify_download, VerificationFailure};
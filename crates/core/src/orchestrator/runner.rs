@@ -16,23 +16,36 @@ use tokio::sync::{broadcast, RwLock};
 use tracing::{debug, error, info, warn};
 
 use crate::audit::{AuditEvent, AuditHandle};
+use crate::blacklist::{Blacklist, BlacklistKind, BlacklistStore, CreateBlacklistEntry};
+use crate::calibration::ScoringProfileStore;
 use crate::catalog::TorrentCatalog;
+use crate::content;
+use crate::converter::{AudioConstraints, AudioFormat, ConversionConstraints};
 use crate::metrics;
 use crate::processor::{PipelineJob, PipelineProcessor, SourceFile};
 use crate::searcher::{FileEnricher, Searcher};
 use crate::textbrain::training::create_acquisition_training_events;
 use crate::textbrain::{
     AcquisitionAuditContext, AcquisitionProgress, AcquisitionStateUpdater, AnthropicClient,
-    DumbMatcher, DumbQueryBuilder, LlmMatcher, LlmProvider, LlmQueryBuilder, OllamaClient,
-    ScoredCandidate, ScoredCandidateSummary, TextBrain, TextBrainConfig,
+    AuditedLlmClient, BudgetedLlmClient, CachedLlmClient, DumbMatcher, DumbMatcherConfig,
+    DumbQueryBuilder, FallbackLlmClient, FallbackProvider, LlmBudget, LlmCache, LlmClient,
+    LlmFileMapper, LlmMatcher, LlmProvider, LlmProviderConfig, LlmProviderHealth, LlmQueryBuilder,
+    OllamaClient, OpenAiClient, PromptLibrary, ScoredCandidate, ScoredCandidateSummary, TextBrain,
+    TextBrainConfig, TextBrainMode,
 };
 use crate::ticket::{
-    AcquisitionPhase, RetryPhase, SelectedCandidate, Ticket, TicketFilter, TicketState, TicketStore,
+    AcquisitionPhase, AudioQualityReport, CompletedDownload, ExpectedContent, FailoverRecord,
+    OutputConstraints, RaceEntrant, RetryPhase, SelectedCandidate, Ticket, TicketFilter,
+    TicketState, TicketStore,
 };
 use crate::torrent_client::{AddTorrentRequest, TorrentClient, TorrentInfo, TorrentState};
 
-use super::config::OrchestratorConfig;
+use super::config::{OrchestratorConfig, VerificationAction, VerificationConfig};
+use super::failover::{
+    build_failover_strategy, FailoverContext, FailoverDecision, FailoverStrategy,
+};
 use super::types::{ActiveDownload, OrchestratorError, OrchestratorStatus};
+use super::verification::{verify_download, VerificationFailure};
 
 /// Callback type for ticket update notifications.
 /// Called with (ticket_id, state_type) whenever a ticket's state changes.
@@ -141,6 +154,15 @@ impl AcquisitionStateUpdater for TicketStateUpdater {
     }
 }
 
+/// Optional wrappers applied around each LLM provider of an acquisition.
+struct LlmClientLayers {
+    cache: Option<(Arc<dyn LlmCache>, Duration)>,
+    budget: Option<Arc<LlmBudget>>,
+    /// Audit handle and the ticket the calls are attributed to
+    audit: Option<(AuditHandle, String)>,
+    prompts: Arc<PromptLibrary>,
+}
+
 /// The ticket orchestrator - drives tickets through the processing pipeline.
 pub struct TicketOrchestrator<C, P>
 where
@@ -159,6 +181,28 @@ where
     /// Optional callback for ticket update notifications (for WebSocket broadcast)
     on_ticket_update: Option<TicketUpdateCallback>,
 
+    /// Strategy used to pick the next candidate when a download stalls
+    failover_strategy: Arc<dyn FailoverStrategy>,
+
+    /// Optional blacklist consulted during acquisition and fed by stalls
+    blacklist_store: Option<Arc<dyn BlacklistStore>>,
+
+    /// Optional store of calibrated scoring profiles; the active one overrides
+    /// the configured scoring weights and auto-approve threshold
+    scoring_profile_store: Option<Arc<dyn ScoringProfileStore>>,
+
+    /// Optional cache for LLM completions
+    llm_cache: Option<Arc<dyn LlmCache>>,
+
+    /// Optional LLM usage budget
+    llm_budget: Option<Arc<LlmBudget>>,
+
+    /// Optional shared circuit breaker state of the LLM providers
+    llm_health: Option<Arc<LlmProviderHealth>>,
+
+    /// Optional prompt templates for the LLM components
+    prompts: Option<Arc<PromptLibrary>>,
+
     // Runtime state
     running: Arc<AtomicBool>,
     active_downloads: Arc<RwLock<HashMap<String, ActiveDownload>>>,
@@ -182,6 +226,7 @@ where
         textbrain_config: TextBrainConfig,
     ) -> Self {
         let (shutdown_tx, _) = broadcast::channel(1);
+        let failover_strategy = build_failover_strategy(&config.failover);
 
         Self {
             config,
@@ -193,6 +238,13 @@ where
             audit,
             textbrain_config,
             on_ticket_update: None,
+            failover_strategy,
+            blacklist_store: None,
+            scoring_profile_store: None,
+            llm_cache: None,
+            llm_budget: None,
+            llm_health: None,
+            prompts: None,
             running: Arc::new(AtomicBool::new(false)),
             active_downloads: Arc::new(RwLock::new(HashMap::new())),
             shutdown_tx,
@@ -205,6 +257,55 @@ where
         self
     }
 
+    /// Override the failover strategy selected in config.
+    pub fn with_failover_strategy(mut self, strategy: Arc<dyn FailoverStrategy>) -> Self {
+        self.failover_strategy = strategy;
+        self
+    }
+
+    /// Set the blacklist store consulted during acquisition.
+    ///
+    /// Info hashes that stall are added to it as per-ticket entries.
+    pub fn with_blacklist_store(mut self, store: Arc<dyn BlacklistStore>) -> Self {
+        self.blacklist_store = Some(store);
+        self
+    }
+
+    /// Set the scoring profile store.
+    ///
+    /// The active profile, if any, is applied to the TextBrain config for each acquisition.
+    pub fn with_scoring_profile_store(mut self, store: Arc<dyn ScoringProfileStore>) -> Self {
+        self.scoring_profile_store = Some(store);
+        self
+    }
+
+    /// Set the cache used for LLM completions (when enabled in the LLM config).
+    pub fn with_llm_cache(mut self, cache: Arc<dyn LlmCache>) -> Self {
+        self.llm_cache = Some(cache);
+        self
+    }
+
+    /// Set the budget that LLM usage is recorded against.
+    /// Once it is exhausted, tickets are acquired with heuristics only.
+    pub fn with_llm_budget(mut self, budget: Arc<LlmBudget>) -> Self {
+        self.llm_budget = Some(budget);
+        self
+    }
+
+    /// Set the circuit breaker state shared by the LLM providers.
+    /// Without it, breakers only last for a single acquisition.
+    pub fn with_llm_health(mut self, health: Arc<LlmProviderHealth>) -> Self {
+        self.llm_health = Some(health);
+        self
+    }
+
+    /// Set the prompt templates used by the LLM components.
+    /// The library is reloaded before each acquisition if its files changed.
+    pub fn with_prompt_library(mut self, prompts: Arc<PromptLibrary>) -> Self {
+        self.prompts = Some(prompts);
+        self
+    }
+
     /// Start the orchestrator (spawns background tasks).
     pub async fn start(&self) {
         if self.running.swap(true, Ordering::SeqCst) {
@@ -214,8 +315,8 @@ where
 
         info!("Starting ticket orchestrator");
 
-        // Recover any downloads that were in progress when we shut down
-        self.recover_downloading_tickets().await;
+        // Recover any work that was in flight when we shut down
+        self.recover_in_flight_tickets().await;
 
         // Spawn acquisition loop
         self.spawn_acquisition_loop();
@@ -272,6 +373,11 @@ where
             .count(&TicketFilter::new().with_state("downloading"))
             .unwrap_or(0) as usize;
 
+        let preempted_count = self
+            .ticket_store
+            .count(&TicketFilter::new().with_state("preempted"))
+            .unwrap_or(0) as usize;
+
         OrchestratorStatus {
             running: self.running.load(Ordering::Relaxed),
             active_downloads,
@@ -279,6 +385,136 @@ where
             pending_count,
             needs_approval_count,
             downloading_count,
+            preempted_count,
+        }
+    }
+
+    /// Recover tickets that were mid-flight when we shut down.
+    ///
+    /// Downloads are re-attached to the torrent client, interrupted
+    /// acquisitions are re-queued, and interrupted conversions/placements
+    /// are re-run from the completed download.
+    async fn recover_in_flight_tickets(&self) {
+        self.recover_acquiring_tickets().await;
+        self.recover_downloading_tickets().await;
+        self.recover_pipeline_tickets().await;
+    }
+
+    /// Reset tickets that were acquiring when we shut down back to Pending.
+    ///
+    /// Acquisition has no side effects outside the ticket state, so it is
+    /// simply restarted from scratch by the acquisition loop.
+    async fn recover_acquiring_tickets(&self) {
+        let filter = TicketFilter::new().with_state("acquiring").with_limit(100);
+
+        let tickets = match self.ticket_store.list(&filter) {
+            Ok(tickets) => tickets,
+            Err(e) => {
+                error!("Failed to recover acquiring tickets: {}", e);
+                return;
+            }
+        };
+
+        for ticket in tickets {
+            if let Err(e) = update_and_notify_static(
+                &self.ticket_store,
+                &self.on_ticket_update,
+                &ticket.id,
+                TicketState::Pending,
+            ) {
+                warn!("Failed to reset acquiring ticket {}: {}", ticket.id, e);
+                continue;
+            }
+
+            if let Some(ref audit) = self.audit {
+                audit
+                    .emit(AuditEvent::TicketStateChanged {
+                        ticket_id: ticket.id.clone(),
+                        from_state: "acquiring".to_string(),
+                        to_state: "pending".to_string(),
+                        reason: Some("Acquisition interrupted by restart".to_string()),
+                    })
+                    .await;
+            }
+
+            info!("Recovered acquiring ticket: {}", ticket.id);
+        }
+    }
+
+    /// Re-run the pipeline for tickets that were converting or placing when we
+    /// shut down.
+    ///
+    /// Partially written temp files are removed first. Placement overwrites
+    /// its destinations, so re-running it over a partial placement is safe.
+    /// Tickets without a persisted download (written by older versions) cannot
+    /// be resumed and are failed as retryable.
+    async fn recover_pipeline_tickets(&self) {
+        for state in ["converting", "placing"] {
+            let filter = TicketFilter::new().with_state(state).with_limit(100);
+
+            let tickets = match self.ticket_store.list(&filter) {
+                Ok(tickets) => tickets,
+                Err(e) => {
+                    error!("Failed to recover {} tickets: {}", state, e);
+                    continue;
+                }
+            };
+
+            for ticket in tickets {
+                let download = match &ticket.state {
+                    TicketState::Converting { download, .. }
+                    | TicketState::Placing { download, .. } => download.clone(),
+                    _ => continue,
+                };
+
+                if let Err(e) = self.pipeline.cleanup_temp_files(&ticket.id).await {
+                    warn!(
+                        "Failed to clean up temp files for ticket {}: {}",
+                        ticket.id, e
+                    );
+                }
+
+                let result = match download {
+                    Some(download) => self
+                        .pipeline
+                        .process(Self::build_pipeline_job(&ticket, download), None)
+                        .await
+                        .map_err(OrchestratorError::from),
+                    None => Err(OrchestratorError::MissingData(
+                        "no persisted download to resume from".to_string(),
+                    )),
+                };
+
+                match result {
+                    Ok(()) => info!("Resumed {} ticket: {}", state, ticket.id),
+                    Err(e) => {
+                        warn!("Failed to resume {} ticket {}: {}", state, ticket.id, e);
+                        let error_msg = format!("Failed to resume {} after restart: {}", state, e);
+                        let _ = update_and_notify_static(
+                            &self.ticket_store,
+                            &self.on_ticket_update,
+                            &ticket.id,
+                            TicketState::Failed {
+                                error: error_msg.clone(),
+                                retryable: true,
+                                retry_count: ticket.retry_count,
+                                failed_at: Utc::now(),
+                            },
+                        );
+
+                        if let Some(ref audit) = self.audit {
+                            audit
+                                .emit(AuditEvent::TicketStateChanged {
+                                    ticket_id: ticket.id.clone(),
+                                    from_state: state.to_string(),
+                                    to_state: "failed".to_string(),
+                                    reason: Some(error_msg),
+                                })
+                                .await;
+                        }
+                    }
+                }
+            }
         }
     }
 
@@ -299,6 +535,7 @@ where
                         failover_round,
                         last_progress_pct,
                         last_progress_at,
+                        failover,
                         ..
                     } = &ticket.state
                     {
@@ -312,6 +549,9 @@ where
                                 failover_round: *failover_round,
                                 last_progress_pct: *last_progress_pct,
                                 last_progress_at: *last_progress_at,
+                                priority: ticket.priority,
+                                failover: failover.clone(),
+                                verifying: false,
                             },
                         );
                         info!("Recovered downloading ticket: {}", ticket.id);
@@ -335,6 +575,12 @@ where
         let catalog = Arc::clone(&self.catalog);
         let config = self.config.clone();
         let textbrain_config = self.textbrain_config.clone();
+        let blacklist_store = self.blacklist_store.clone();
+        let scoring_profile_store = self.scoring_profile_store.clone();
+        let llm_cache = self.llm_cache.clone();
+        let llm_budget = self.llm_budget.clone();
+        let llm_health = self.llm_health.clone();
+        let prompts = self.prompts.clone();
         let audit = self.audit.clone();
         let on_update = self.on_ticket_update.clone();
         let mut shutdown_rx = self.shutdown_tx.subscribe();
@@ -357,6 +603,12 @@ where
                             &catalog,
                             &config,
                             &textbrain_config,
+                            &blacklist_store,
+                            &scoring_profile_store,
+                            &llm_cache,
+                            &llm_budget,
+                            &llm_health,
+                            &prompts,
                             &audit,
                             &on_update,
                         ).await {
@@ -376,6 +628,8 @@ where
         let torrent_client = Arc::clone(&self.torrent_client);
         let pipeline = Arc::clone(&self.pipeline);
         let active_downloads = Arc::clone(&self.active_downloads);
+        let failover_strategy = Arc::clone(&self.failover_strategy);
+        let blacklist_store = self.blacklist_store.clone();
         let config = self.config.clone();
         let audit = self.audit.clone();
         let on_update = self.on_ticket_update.clone();
@@ -394,6 +648,19 @@ where
                             break;
                         }
 
+                        // Resume preempted downloads first so they get free slots
+                        // ahead of lower-priority approved tickets
+                        if let Err(e) = Self::resume_preempted_downloads(
+                            &ticket_store,
+                            &torrent_client,
+                            &active_downloads,
+                            &config,
+                            &audit,
+                            &on_update,
+                        ).await {
+                            warn!("Failed to resume preempted downloads: {}", e);
+                        }
+
                         // Start approved downloads
                         if let Err(e) = Self::start_approved_downloads(
                             &ticket_store,
@@ -412,6 +679,8 @@ where
                             &torrent_client,
                             &pipeline,
                             &active_downloads,
+                            &failover_strategy,
+                            &blacklist_store,
                             &config,
                             &audit,
                             &on_update,
@@ -552,6 +821,12 @@ where
         catalog: &Arc<dyn TorrentCatalog>,
         config: &OrchestratorConfig,
         textbrain_config: &TextBrainConfig,
+        blacklist_store: &Option<Arc<dyn BlacklistStore>>,
+        scoring_profile_store: &Option<Arc<dyn ScoringProfileStore>>,
+        llm_cache: &Option<Arc<dyn LlmCache>>,
+        llm_budget: &Option<Arc<LlmBudget>>,
+        llm_health: &Option<Arc<LlmProviderHealth>>,
+        prompts: &Option<Arc<PromptLibrary>>,
         audit: &Option<AuditHandle>,
         on_update: &Option<TicketUpdateCallback>,
     ) -> Result<(), OrchestratorError> {
@@ -591,8 +866,58 @@ where
                 .await;
         }
 
-        // Build TextBrain with configured implementations
-        let textbrain = Self::build_textbrain(textbrain_config, Arc::clone(catalog));
+        // Apply the active scoring profile, if any
+        let mut textbrain_config = match scoring_profile_store {
+            Some(store) => match store.active() {
+                Ok(Some(profile)) => profile.apply(textbrain_config),
+                Ok(None) => textbrain_config.clone(),
+                Err(e) => {
+                    warn!("Failed to load active scoring profile: {}", e);
+                    textbrain_config.clone()
+                }
+            },
+            None => textbrain_config.clone(),
+        };
+
+        // Apply the ticket's own overrides on top of the global settings
+        let require_approval = match ticket.textbrain_overrides {
+            Some(ref overrides) => {
+                textbrain_config = overrides.apply(&textbrain_config);
+                overrides.require_approval
+            }
+            None => false,
+        };
+
+        // Fall back to heuristics once the LLM budget is exhausted
+        if textbrain_config.mode.can_use_llm()
+            && llm_budget.as_ref().is_some_and(|b| b.is_exhausted())
+        {
+            warn!(
+                "LLM budget exhausted, acquiring ticket {} with heuristics only",
+                ticket.id
+            );
+            textbrain_config.mode = TextBrainMode::DumbOnly;
+        }
+
+        // Build TextBrain with configured implementations, skipping blacklisted
+        // releases (including those that already stalled for this ticket)
+        let blacklist = match blacklist_store {
+            Some(store) => store.blacklist_for_ticket(&ticket.id).unwrap_or_else(|e| {
+                warn!("Failed to load blacklist for ticket {}: {}", ticket.id, e);
+                Blacklist::default()
+            }),
+            None => Blacklist::default(),
+        };
+        let textbrain = Self::build_textbrain(
+            &textbrain_config,
+            Arc::clone(catalog),
+            llm_cache,
+            llm_budget,
+            llm_health,
+            prompts,
+            audit.as_ref().map(|handle| (handle, ticket.id.as_str())),
+        )
+        .with_blacklist(blacklist);
 
         // Create state updater for persisting acquisition progress
         let state_updater: Arc<dyn AcquisitionStateUpdater> = Arc::new(TicketStateUpdater {
@@ -682,7 +1007,7 @@ where
                     }
                 }
 
-                if acq.auto_approved {
+                if acq.auto_approved && !require_approval {
                     if let Some(ref candidate) = acq.best_candidate {
                         // Auto-approved - high confidence match
                         // Build all candidates for failover (up to max_failover_candidates)
@@ -793,10 +1118,11 @@ where
                                 ticket_id: ticket.id.clone(),
                                 from_state: "acquiring".to_string(),
                                 to_state: "needs_approval".to_string(),
-                                reason: Some(format!(
-                                    "Best score {:.2} below threshold",
-                                    candidate.score
-                                )),
+                                reason: Some(if require_approval {
+                                    "Ticket requires manual approval".to_string()
+                                } else {
+                                    format!("Best score {:.2} below threshold", candidate.score)
+                                }),
                             })
                             .await;
                     }
@@ -809,10 +1135,17 @@ where
                         .with_label_values(&["needs_approval"])
                         .observe(duration_secs);
 
-                    info!(
-                        "Ticket {} needs approval, best score {:.2} < threshold {:.2}",
-                        ticket.id, candidate.score, config.auto_approve_threshold
-                    );
+                    if require_approval {
+                        info!(
+                            "Ticket {} needs approval (manual approval required), best score {:.2}",
+                            ticket.id, candidate.score
+                        );
+                    } else {
+                        info!(
+                            "Ticket {} needs approval, best score {:.2} < threshold {:.2}",
+                            ticket.id, candidate.score, textbrain_config.auto_approve_threshold
+                        );
+                    }
                 } else {
                     // No candidate found - record failure
                     metrics::ACQUISITION_ATTEMPTS
@@ -962,10 +1295,40 @@ where
 
                 // Check max concurrent limit
                 if config.max_concurrent_downloads > 0 {
-                    let downloads = active_downloads.read().await;
-                    if downloads.len() >= config.max_concurrent_downloads {
-                        debug!("Max concurrent downloads reached, waiting...");
-                        break;
+                    let active = active_downloads.read().await.len();
+                    if active >= config.max_concurrent_downloads {
+                        if !config.preemption_enabled {
+                            debug!("Max concurrent downloads reached, waiting...");
+                            break;
+                        }
+
+                        // Tickets are listed by priority, so if this one can't
+                        // preempt anything, the ones after it can't either
+                        match Self::preempt_lowest_priority(
+                            ticket_store,
+                            torrent_client,
+                            active_downloads,
+                            &ticket,
+                            audit,
+                            on_update,
+                        )
+                        .await
+                        {
+                            Ok(true) => {}
+                            Ok(false) => {
+                                debug!(
+                                    "Max concurrent downloads reached, nothing to preempt, waiting..."
+                                );
+                                break;
+                            }
+                            Err(e) => {
+                                warn!(
+                                    "Failed to preempt a download for ticket {}: {}",
+                                    ticket.id, e
+                                );
+                                break;
+                            }
+                        }
                     }
                 }
 
@@ -1004,6 +1367,9 @@ where
                                         failover_round: 1,
                                         last_progress_pct: 0.0,
                                         last_progress_at: now,
+                                        priority: ticket.priority,
+                                        failover: None,
+                                        verifying: false,
                                     },
                                 );
                             }
@@ -1024,6 +1390,7 @@ where
                                     last_progress_pct: 0.0,
                                     last_progress_at: now,
                                     candidates: candidates.clone(),
+                                    failover: None,
                                 },
                             )?;
 
@@ -1149,126 +1516,444 @@ where
         Ok(())
     }
 
-    /// Check progress of active downloads.
-    async fn check_download_progress<C2, P2>(
+    /// Pause the lowest-priority active download to free a slot for `ticket`.
+    ///
+    /// Only downloads with a strictly lower priority are considered. Among
+    /// equal priorities, the most recently started download is paused since
+    /// it has the least progress to lose. Returns Ok(false) if nothing could
+    /// be preempted.
+    async fn preempt_lowest_priority(
         ticket_store: &Arc<dyn TicketStore>,
         torrent_client: &Arc<dyn TorrentClient>,
-        pipeline: &Arc<PipelineProcessor<C2, P2>>,
         active_downloads: &Arc<RwLock<HashMap<String, ActiveDownload>>>,
-        config: &OrchestratorConfig,
+        ticket: &Ticket,
         audit: &Option<AuditHandle>,
         on_update: &Option<TicketUpdateCallback>,
-    ) -> Result<(), OrchestratorError>
-    where
-        C2: crate::converter::Converter + 'static,
-        P2: crate::placer::Placer + 'static,
-    {
-        // Collect downloads to check (avoid holding lock during API calls)
-        let downloads: Vec<ActiveDownload> = {
+    ) -> Result<bool, OrchestratorError> {
+        let victim = {
             let downloads = active_downloads.read().await;
-            downloads.values().cloned().collect()
+            downloads
+                .values()
+                // Races are short-lived and span several torrents, and finished
+                // downloads being verified no longer need a slot
+                .filter(|d| d.priority < ticket.priority && !Self::is_racing(d) && !d.verifying)
+                .min_by(|a, b| {
+                    a.priority
+                        .cmp(&b.priority)
+                        .then(b.started_at.cmp(&a.started_at))
+                })
+                .cloned()
         };
 
-        for download in downloads {
-            let info = match torrent_client.get_torrent(&download.info_hash).await {
-                Ok(info) => info,
-                Err(e) => {
-                    // Check if this is a "torrent not found" error (torrent was deleted externally)
-                    let error_str = e.to_string().to_lowercase();
-                    if error_str.contains("not found")
-                        || error_str.contains("404")
-                        || error_str.contains("no such")
-                    {
-                        warn!(
-                            "Torrent {} was deleted externally for ticket {}, triggering failover",
-                            download.info_hash, download.ticket_id
-                        );
-                        // Handle this like a stall - try the next candidate
-                        if let Err(failover_err) = Self::handle_stall(
-                            ticket_store,
-                            torrent_client,
-                            active_downloads,
-                            &download,
-                            config,
-                            audit,
-                            on_update,
-                        )
-                        .await
-                        {
-                            warn!(
-                                "Failed to handle deleted torrent for ticket {}: {}",
-                                download.ticket_id, failover_err
-                            );
-                        }
-                    } else {
-                        warn!(
-                            "Failed to get torrent {} for ticket {}: {}",
-                            download.info_hash, download.ticket_id, e
-                        );
-                    }
-                    continue;
-                }
-            };
+        let Some(victim) = victim else {
+            return Ok(false);
+        };
 
-            if info.progress >= 1.0 || info.state == TorrentState::Seeding {
-                // Download complete!
-                let download_duration = Utc::now().signed_duration_since(download.started_at);
-                metrics::DOWNLOADS_COMPLETED.inc();
-                metrics::DOWNLOAD_DURATION
-                    .with_label_values(&["success"])
-                    .observe(download_duration.num_seconds() as f64);
+        let victim_ticket = ticket_store
+            .get(&victim.ticket_id)?
+            .ok_or_else(|| OrchestratorError::TicketNotFound(victim.ticket_id.clone()))?;
 
-                info!("Download complete for ticket {}", download.ticket_id);
+        let (progress_pct, candidates) = match &victim_ticket.state {
+            TicketState::Downloading {
+                progress_pct,
+                candidates,
+                ..
+            } => (*progress_pct, candidates.clone()),
+            _ => {
+                return Err(OrchestratorError::InvalidState {
+                    expected: "Downloading".to_string(),
+                    actual: victim_ticket.state.state_type().to_string(),
+                });
+            }
+        };
 
-                // Remove from tracking
-                {
-                    let mut downloads = active_downloads.write().await;
-                    downloads.remove(&download.ticket_id);
-                }
+        torrent_client.pause_torrent(&victim.info_hash).await?;
+        active_downloads.write().await.remove(&victim.ticket_id);
 
-                // Trigger pipeline
-                if let Err(e) =
-                    Self::trigger_pipeline(ticket_store, pipeline, &download.ticket_id, &info).await
-                {
-                    warn!(
-                        "Failed to trigger pipeline for ticket {}: {}",
-                        download.ticket_id, e
-                    );
-                    // Update ticket to failed state
-                    let error_msg = format!("Failed to start pipeline: {}", e);
-                    let _ = update_and_notify_static(
-                        &ticket_store,
-                        &on_update,
-                        &download.ticket_id,
-                        TicketState::Failed {
-                            error: error_msg.clone(),
-                            retryable: true,
-                            retry_count: 0,
-                            failed_at: Utc::now(),
-                        },
-                    );
+        update_and_notify_static(
+            ticket_store,
+            on_update,
+            &victim.ticket_id,
+            TicketState::Preempted {
+                info_hash: victim.info_hash.clone(),
+                progress_pct,
+                started_at: victim.started_at,
+                candidate_idx: victim.candidate_idx,
+                failover_round: victim.failover_round,
+                candidates,
+                preempted_by: ticket.id.clone(),
+                preempted_at: Utc::now(),
+                failover: victim.failover.clone(),
+            },
+        )?;
 
-                    // Emit state change event
-                    if let Some(ref audit_handle) = audit {
-                        audit_handle
-                            .emit(AuditEvent::TicketStateChanged {
-                                ticket_id: download.ticket_id.clone(),
-                                from_state: "downloading".to_string(),
-                                to_state: "failed".to_string(),
-                                reason: Some(error_msg),
-                            })
-                            .await;
-                    }
-                }
-            } else {
-                let now = Utc::now();
-                let current_progress = (info.progress * 100.0) as f32;
+        metrics::DOWNLOADS_PREEMPTED.inc();
 
-                // Check if progress changed
-                let (new_last_pct, new_last_at) = if current_progress > download.last_progress_pct {
-                    (current_progress, now) // Progress! Reset timer
-                } else {
-                    (download.last_progress_pct, download.last_progress_at) // No change
+        if let Some(ref audit_handle) = audit {
+            audit_handle
+                .emit(AuditEvent::DownloadPreempted {
+                    ticket_id: victim.ticket_id.clone(),
+                    info_hash: victim.info_hash.clone(),
+                    priority: victim.priority,
+                    preempted_by: ticket.id.clone(),
+                    preempted_by_priority: ticket.priority,
+                    progress_pct,
+                })
+                .await;
+            audit_handle
+                .emit(AuditEvent::TicketStateChanged {
+                    ticket_id: victim.ticket_id.clone(),
+                    from_state: "downloading".to_string(),
+                    to_state: "preempted".to_string(),
+                    reason: Some(format!(
+                        "Preempted by ticket {} (priority {} > {})",
+                        ticket.id, ticket.priority, victim.priority
+                    )),
+                })
+                .await;
+        }
+
+        info!(
+            "Preempted download for ticket {} (priority {}) in favor of ticket {} (priority {})",
+            victim.ticket_id, victim.priority, ticket.id, ticket.priority
+        );
+
+        Ok(true)
+    }
+
+    /// Resume preempted downloads while download slots are free.
+    ///
+    /// Preempted tickets are resumed in priority order, but yield to approved
+    /// tickets with a higher priority so a resumed download isn't immediately
+    /// preempted again.
+    async fn resume_preempted_downloads(
+        ticket_store: &Arc<dyn TicketStore>,
+        torrent_client: &Arc<dyn TorrentClient>,
+        active_downloads: &Arc<RwLock<HashMap<String, ActiveDownload>>>,
+        config: &OrchestratorConfig,
+        audit: &Option<AuditHandle>,
+        on_update: &Option<TicketUpdateCallback>,
+    ) -> Result<(), OrchestratorError> {
+        let filter = TicketFilter::new().with_state("preempted").with_limit(50);
+        let tickets = ticket_store.list(&filter)?;
+        if tickets.is_empty() {
+            return Ok(());
+        }
+
+        // Highest priority among approved tickets waiting for a slot
+        let mut waiting_priority = None;
+        for state_type in ["auto_approved", "approved"] {
+            let filter = TicketFilter::new().with_state(state_type).with_limit(1);
+            if let Some(waiting) = ticket_store.list(&filter)?.first() {
+                waiting_priority = waiting_priority.max(Some(waiting.priority));
+            }
+        }
+
+        for ticket in tickets {
+            if config.max_concurrent_downloads > 0
+                && active_downloads.read().await.len() >= config.max_concurrent_downloads
+            {
+                break;
+            }
+            if waiting_priority.is_some_and(|p| p > ticket.priority) {
+                break;
+            }
+
+            if let Err(e) = Self::resume_preempted_download(
+                ticket_store,
+                torrent_client,
+                active_downloads,
+                &ticket,
+                audit,
+                on_update,
+            )
+            .await
+            {
+                warn!(
+                    "Failed to resume preempted download for ticket {}: {}",
+                    ticket.id, e
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resume a single preempted download and move the ticket back to Downloading.
+    async fn resume_preempted_download(
+        ticket_store: &Arc<dyn TicketStore>,
+        torrent_client: &Arc<dyn TorrentClient>,
+        active_downloads: &Arc<RwLock<HashMap<String, ActiveDownload>>>,
+        ticket: &Ticket,
+        audit: &Option<AuditHandle>,
+        on_update: &Option<TicketUpdateCallback>,
+    ) -> Result<(), OrchestratorError> {
+        let TicketState::Preempted {
+            info_hash,
+            progress_pct,
+            started_at,
+            candidate_idx,
+            failover_round,
+            candidates,
+            preempted_at,
+            failover,
+            ..
+        } = &ticket.state
+        else {
+            return Err(OrchestratorError::InvalidState {
+                expected: "Preempted".to_string(),
+                actual: ticket.state.state_type().to_string(),
+            });
+        };
+
+        let info_hash = match torrent_client.resume_torrent(info_hash).await {
+            Ok(()) => info_hash.clone(),
+            Err(e) => {
+                // The torrent may have been removed while paused, re-add it
+                warn!(
+                    "Failed to resume torrent {} for ticket {}, re-adding: {}",
+                    info_hash, ticket.id, e
+                );
+                let candidate = candidates.get(*candidate_idx).ok_or_else(|| {
+                    OrchestratorError::MissingData(
+                        "No candidate at current index in Preempted state".to_string(),
+                    )
+                })?;
+                Self::add_torrent_from_candidate(torrent_client.as_ref(), candidate)
+                    .await?
+                    .hash
+            }
+        };
+
+        let now = Utc::now();
+        active_downloads.write().await.insert(
+            ticket.id.clone(),
+            ActiveDownload {
+                ticket_id: ticket.id.clone(),
+                info_hash: info_hash.clone(),
+                started_at: *started_at,
+                candidate_idx: *candidate_idx,
+                failover_round: *failover_round,
+                last_progress_pct: *progress_pct,
+                // Restart the stall timer, the pause was not a stall
+                last_progress_at: now,
+                priority: ticket.priority,
+                failover: failover.clone(),
+                verifying: false,
+            },
+        );
+
+        update_and_notify_static(
+            ticket_store,
+            on_update,
+            &ticket.id,
+            TicketState::Downloading {
+                info_hash: info_hash.clone(),
+                progress_pct: *progress_pct,
+                speed_bps: 0,
+                eta_secs: None,
+                started_at: *started_at,
+                candidate_idx: *candidate_idx,
+                failover_round: *failover_round,
+                last_progress_pct: *progress_pct,
+                last_progress_at: now,
+                candidates: candidates.clone(),
+                failover: failover.clone(),
+            },
+        )?;
+
+        let paused_secs = now
+            .signed_duration_since(*preempted_at)
+            .num_seconds()
+            .max(0) as u64;
+
+        if let Some(ref audit_handle) = audit {
+            audit_handle
+                .emit(AuditEvent::DownloadResumed {
+                    ticket_id: ticket.id.clone(),
+                    info_hash: info_hash.clone(),
+                    paused_secs,
+                })
+                .await;
+            audit_handle
+                .emit(AuditEvent::TicketStateChanged {
+                    ticket_id: ticket.id.clone(),
+                    from_state: "preempted".to_string(),
+                    to_state: "downloading".to_string(),
+                    reason: Some(format!("Resumed after {}s preemption", paused_secs)),
+                })
+                .await;
+        }
+
+        info!(
+            "Resumed preempted download for ticket {} after {}s",
+            ticket.id, paused_secs
+        );
+
+        Ok(())
+    }
+
+    /// Check progress of active downloads.
+    async fn check_download_progress<C2, P2>(
+        ticket_store: &Arc<dyn TicketStore>,
+        torrent_client: &Arc<dyn TorrentClient>,
+        pipeline: &Arc<PipelineProcessor<C2, P2>>,
+        active_downloads: &Arc<RwLock<HashMap<String, ActiveDownload>>>,
+        failover_strategy: &Arc<dyn FailoverStrategy>,
+        blacklist_store: &Option<Arc<dyn BlacklistStore>>,
+        config: &OrchestratorConfig,
+        audit: &Option<AuditHandle>,
+        on_update: &Option<TicketUpdateCallback>,
+    ) -> Result<(), OrchestratorError>
+    where
+        C2: crate::converter::Converter + 'static,
+        P2: crate::placer::Placer + 'static,
+    {
+        // Collect downloads to check (avoid holding lock during API calls)
+        let downloads: Vec<ActiveDownload> = {
+            let downloads = active_downloads.read().await;
+            downloads.values().cloned().collect()
+        };
+
+        for download in downloads {
+            // Finished downloads are handled by their verification task
+            if download.verifying {
+                continue;
+            }
+
+            if Self::is_racing(&download) {
+                if let Err(e) = Self::check_race(
+                    ticket_store,
+                    torrent_client,
+                    active_downloads,
+                    failover_strategy,
+                    blacklist_store,
+                    &download,
+                    config,
+                    audit,
+                    on_update,
+                )
+                .await
+                {
+                    warn!(
+                        "Failed to check failover race for ticket {}: {}",
+                        download.ticket_id, e
+                    );
+                }
+                continue;
+            }
+
+            let info = match torrent_client.get_torrent(&download.info_hash).await {
+                Ok(info) => info,
+                Err(e) => {
+                    // Check if this is a "torrent not found" error (torrent was deleted externally)
+                    let error_str = e.to_string().to_lowercase();
+                    if error_str.contains("not found")
+                        || error_str.contains("404")
+                        || error_str.contains("no such")
+                    {
+                        warn!(
+                            "Torrent {} was deleted externally for ticket {}, triggering failover",
+                            download.info_hash, download.ticket_id
+                        );
+                        // Handle this like a stall - try the next candidate
+                        if let Err(failover_err) = Self::handle_stall(
+                            ticket_store,
+                            torrent_client,
+                            active_downloads,
+                            failover_strategy,
+                            blacklist_store,
+                            &download,
+                            "stalled",
+                            config,
+                            audit,
+                            on_update,
+                        )
+                        .await
+                        {
+                            warn!(
+                                "Failed to handle deleted torrent for ticket {}: {}",
+                                download.ticket_id, failover_err
+                            );
+                        }
+                    } else {
+                        warn!(
+                            "Failed to get torrent {} for ticket {}: {}",
+                            download.info_hash, download.ticket_id, e
+                        );
+                    }
+                    continue;
+                }
+            };
+
+            if info.progress >= 1.0 || info.state == TorrentState::Seeding {
+                // Download complete!
+                let download_duration = Utc::now().signed_duration_since(download.started_at);
+                metrics::DOWNLOADS_COMPLETED.inc();
+                metrics::DOWNLOAD_DURATION
+                    .with_label_values(&["success"])
+                    .observe(download_duration.num_seconds() as f64);
+
+                info!("Download complete for ticket {}", download.ticket_id);
+
+                if !config.verification.enabled {
+                    Self::finish_download(
+                        ticket_store,
+                        torrent_client,
+                        pipeline,
+                        active_downloads,
+                        failover_strategy,
+                        blacklist_store,
+                        &download,
+                        &info,
+                        config,
+                        audit,
+                        on_update,
+                    )
+                    .await;
+                    continue;
+                }
+
+                // Probing every file and decoding lossless audio can take
+                // minutes for a large album; verify in a separate task so
+                // the other downloads keep being monitored meanwhile
+                if let Some(d) = active_downloads.write().await.get_mut(&download.ticket_id) {
+                    d.verifying = true;
+                }
+                let ticket_store = Arc::clone(ticket_store);
+                let torrent_client = Arc::clone(torrent_client);
+                let pipeline = Arc::clone(pipeline);
+                let active_downloads = Arc::clone(active_downloads);
+                let failover_strategy = Arc::clone(failover_strategy);
+                let blacklist_store = blacklist_store.clone();
+                let config = config.clone();
+                let audit = audit.clone();
+                let on_update = on_update.clone();
+                tokio::spawn(async move {
+                    Self::finish_download(
+                        &ticket_store,
+                        &torrent_client,
+                        &pipeline,
+                        &active_downloads,
+                        &failover_strategy,
+                        &blacklist_store,
+                        &download,
+                        &info,
+                        &config,
+                        &audit,
+                        &on_update,
+                    )
+                    .await;
+                });
+            } else {
+                let now = Utc::now();
+                let current_progress = (info.progress * 100.0) as f32;
+
+                // Check if progress changed
+                let (new_last_pct, new_last_at) = if current_progress > download.last_progress_pct {
+                    (current_progress, now) // Progress! Reset timer
+                } else {
+                    (download.last_progress_pct, download.last_progress_at) // No change
                 };
 
                 // Check for stall
@@ -1283,7 +1968,10 @@ where
                         ticket_store,
                         torrent_client,
                         active_downloads,
+                        failover_strategy,
+                        blacklist_store,
                         &download,
+                        "stalled",
                         config,
                         audit,
                         on_update,
@@ -1328,6 +2016,7 @@ where
                                 last_progress_pct: new_last_pct,
                                 last_progress_at: new_last_at,
                                 candidates,
+                                failover: download.failover.clone(),
                             },
                         );
                     }
@@ -1338,12 +2027,158 @@ where
         Ok(())
     }
 
+    /// Verify a finished download if enabled, then hand it to the pipeline
+    /// or fail it over.
+    async fn finish_download<C2, P2>(
+        ticket_store: &Arc<dyn TicketStore>,
+        torrent_client: &Arc<dyn TorrentClient>,
+        pipeline: &Arc<PipelineProcessor<C2, P2>>,
+        active_downloads: &Arc<RwLock<HashMap<String, ActiveDownload>>>,
+        failover_strategy: &Arc<dyn FailoverStrategy>,
+        blacklist_store: &Option<Arc<dyn BlacklistStore>>,
+        download: &ActiveDownload,
+        info: &TorrentInfo,
+        config: &OrchestratorConfig,
+        audit: &Option<AuditHandle>,
+        on_update: &Option<TicketUpdateCallback>,
+    ) where
+        C2: crate::converter::Converter + 'static,
+        P2: crate::placer::Placer + 'static,
+    {
+        // Check the files against what the ticket expects before converting
+        let mut audio_quality = None;
+        if config.verification.enabled {
+            match Self::verify_completed_download(
+                ticket_store,
+                pipeline,
+                &download.ticket_id,
+                info,
+                &config.verification,
+            )
+            .await
+            {
+                Ok(Ok(quality)) => {
+                    if let Some(report) = quality.as_ref().filter(|r| r.is_suspect()) {
+                        metrics::SUSPECT_LOSSLESS.inc();
+                        warn!(
+                            "Download for ticket {} has suspect lossless files: {}",
+                            download.ticket_id,
+                            report.warnings.join("; ")
+                        );
+                    }
+                    audio_quality = quality;
+                }
+                Ok(Err(failure)) => {
+                    metrics::VERIFICATION_FAILURES.inc();
+                    warn!(
+                        "Download for ticket {} failed verification: {}",
+                        download.ticket_id, failure
+                    );
+                    let result = match config.verification.on_mismatch {
+                        VerificationAction::Failover => {
+                            Self::handle_stall(
+                                ticket_store,
+                                torrent_client,
+                                active_downloads,
+                                failover_strategy,
+                                blacklist_store,
+                                download,
+                                &format!("failed verification ({})", failure),
+                                config,
+                                audit,
+                                on_update,
+                            )
+                            .await
+                        }
+                        VerificationAction::Fail => {
+                            Self::fail_stalled_download(
+                                ticket_store,
+                                active_downloads,
+                                download,
+                                format!("Download failed verification: {}", failure),
+                                audit,
+                                on_update,
+                            )
+                            .await
+                        }
+                    };
+                    if let Err(e) = result {
+                        warn!(
+                            "Failed to handle verification failure for ticket {}: {}",
+                            download.ticket_id, e
+                        );
+                    }
+                    return;
+                }
+                Err(e) => {
+                    warn!(
+                        "Failed to verify download for ticket {}: {}",
+                        download.ticket_id, e
+                    );
+                }
+            }
+        }
+
+        // Remove from tracking; if it is already gone, the ticket was
+        // cancelled while its files were being verified
+        if active_downloads
+            .write()
+            .await
+            .remove(&download.ticket_id)
+            .is_none()
+        {
+            return;
+        }
+
+        // Trigger pipeline
+        if let Err(e) = Self::trigger_pipeline(
+            ticket_store,
+            pipeline,
+            &download.ticket_id,
+            info,
+            audio_quality,
+        )
+        .await
+        {
+            warn!(
+                "Failed to trigger pipeline for ticket {}: {}",
+                download.ticket_id, e
+            );
+            // Update ticket to failed state
+            let error_msg = format!("Failed to start pipeline: {}", e);
+            let _ = update_and_notify_static(
+                ticket_store,
+                on_update,
+                &download.ticket_id,
+                TicketState::Failed {
+                    error: error_msg.clone(),
+                    retryable: true,
+                    retry_count: 0,
+                    failed_at: Utc::now(),
+                },
+            );
+
+            // Emit state change event
+            if let Some(ref audit_handle) = audit {
+                audit_handle
+                    .emit(AuditEvent::TicketStateChanged {
+                        ticket_id: download.ticket_id.clone(),
+                        from_state: "downloading".to_string(),
+                        to_state: "failed".to_string(),
+                        reason: Some(error_msg),
+                    })
+                    .await;
+            }
+        }
+    }
+
     /// Trigger the pipeline for a completed download.
     async fn trigger_pipeline<C2, P2>(
         ticket_store: &Arc<dyn TicketStore>,
         pipeline: &Arc<PipelineProcessor<C2, P2>>,
         ticket_id: &str,
         torrent_info: &TorrentInfo,
+        audio_quality: Option<AudioQualityReport>,
     ) -> Result<(), OrchestratorError>
     where
         C2: crate::converter::Converter + 'static,
@@ -1352,9 +2187,55 @@ where
         let ticket = ticket_store
             .get(ticket_id)?
             .ok_or_else(|| OrchestratorError::TicketNotFound(ticket_id.to_string()))?;
+        let mut download = Self::completed_download(&ticket, torrent_info)?;
+        download.audio_quality = audio_quality;
 
+        // Submit to pipeline (non-blocking)
+        pipeline
+            .process(Self::build_pipeline_job(&ticket, download), None)
+            .await?;
+
+        info!("Pipeline triggered for ticket {}", ticket_id);
+
+        Ok(())
+    }
+
+    /// Probe a completed download against the ticket's expected content.
+    ///
+    /// Returns the lossless quality report if the files match, or the failure
+    /// if they don't.
+    async fn verify_completed_download<C2, P2>(
+        ticket_store: &Arc<dyn TicketStore>,
+        pipeline: &Arc<PipelineProcessor<C2, P2>>,
+        ticket_id: &str,
+        torrent_info: &TorrentInfo,
+        config: &VerificationConfig,
+    ) -> Result<Result<Option<AudioQualityReport>, VerificationFailure>, OrchestratorError>
+    where
+        C2: crate::converter::Converter + 'static,
+        P2: crate::placer::Placer + 'static,
+    {
+        let ticket = ticket_store
+            .get(ticket_id)?
+            .ok_or_else(|| OrchestratorError::TicketNotFound(ticket_id.to_string()))?;
+        let download = Self::completed_download(&ticket, torrent_info)?;
+
+        Ok(verify_download(
+            pipeline.converter(),
+            &ticket.query_context,
+            &download,
+            config,
+        )
+        .await)
+    }
+
+    /// Describe a finished torrent with the file mappings of the selected candidate.
+    fn completed_download(
+        ticket: &Ticket,
+        torrent_info: &TorrentInfo,
+    ) -> Result<CompletedDownload, OrchestratorError> {
         // Get file mappings from the selected candidate in ticket state
-        let selected = Self::extract_selected_candidate(&ticket)?;
+        let selected = Self::extract_selected_candidate(ticket)?;
 
         // Get save path from torrent info
         let save_path = torrent_info
@@ -1362,33 +2243,109 @@ where
             .as_ref()
             .ok_or_else(|| OrchestratorError::MissingData("save_path not available".to_string()))?;
 
+        Ok(CompletedDownload {
+            info_hash: torrent_info.hash.clone(),
+            path: PathBuf::from(save_path)
+                .join(&torrent_info.name)
+                .to_string_lossy()
+                .to_string(),
+            file_mappings: selected.file_mappings,
+            audio_quality: None,
+        })
+    }
+
+    /// Build a pipeline job for a ticket from its completed download.
+    fn build_pipeline_job(ticket: &Ticket, download: CompletedDownload) -> PipelineJob {
+        let path = PathBuf::from(&download.path);
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| ticket.id.clone());
+
+        // Audiobooks are merged from their chapter files in playback order
+        let is_audiobook = matches!(
+            ticket.output_constraints,
+            Some(OutputConstraints::Audiobook(_))
+        );
+        let metadata = ticket
+            .query_context
+            .expected
+            .as_ref()
+            .filter(|_| is_audiobook)
+            .and_then(content::audiobook::pipeline_metadata);
+
+        let mut constraints = ticket
+            .output_constraints
+            .as_ref()
+            .and_then(|c| c.to_conversion_constraints());
+
+        // Discographies are placed one folder per album
+        let album_files = match &ticket.query_context.expected {
+            Some(ExpectedContent::Discography { albums, .. }) => content::discography::album_files(
+                &path,
+                albums,
+                &download.file_mappings,
+                constraints.as_ref().map(|c| c.output_extension()),
+            ),
+            _ => Vec::new(),
+        };
+
+        // Single-file album images are split into tracks by their CUE sheet,
+        // as FLAC when no output format was requested
+        let cue_tracks = ticket.query_context.expected.as_ref().and_then(|expected| {
+            let extension = constraints
+                .as_ref()
+                .map_or("flac", |c| c.output_extension());
+            content::music::cue_track_files(&path, expected, extension)
+        });
+        if cue_tracks.is_some() && constraints.is_none() {
+            constraints = Some(ConversionConstraints::Audio(AudioConstraints {
+                format: AudioFormat::Flac,
+                bitrate_kbps: None,
+                sample_rate_hz: None,
+                channels: None,
+                compression_level: None,
+            }));
+        }
+
         // Build source files from download
         // For now, we assume single file or we use the torrent name as directory
-        let source_files = vec![SourceFile {
-            path: PathBuf::from(save_path).join(&torrent_info.name),
-            item_id: "main".to_string(),
-            dest_filename: format!("{}.converted", torrent_info.name),
-        }];
+        let source_files = if is_audiobook {
+            content::audiobook::chapter_files(&path, &download.file_mappings)
+                .into_iter()
+                .enumerate()
+                .map(|(idx, chapter)| SourceFile {
+                    path: chapter,
+                    item_id: format!("chapter-{}", idx + 1),
+                    dest_filename: format!("{}.m4b", name),
+                    segment: None,
+                    metadata: None,
+                })
+                .collect()
+        } else if !album_files.is_empty() {
+            album_files
+        } else if let Some(tracks) = cue_tracks {
+            tracks
+        } else {
+            vec![SourceFile {
+                path,
+                item_id: "main".to_string(),
+                dest_filename: format!("{}.converted", name),
+                segment: None,
+                metadata: None,
+            }]
+        };
 
         // Build pipeline job with file mappings from acquisition
-        let job = PipelineJob {
+        PipelineJob {
             ticket_id: ticket.id.clone(),
             source_files,
-            file_mappings: selected.file_mappings,
-            constraints: ticket
-                .output_constraints
-                .as_ref()
-                .and_then(|c| c.to_conversion_constraints()),
+            file_mappings: download.file_mappings.clone(),
+            constraints,
             dest_dir: PathBuf::from(&ticket.dest_path),
-            metadata: None,
-        };
-
-        // Submit to pipeline (non-blocking)
-        pipeline.process(job, None).await?;
-
-        info!("Pipeline triggered for ticket {}", ticket_id);
-
-        Ok(())
+            metadata,
+            download: Some(download),
+        }
     }
 
     /// Add a torrent from a SelectedCandidate, handling both magnet URIs and .torrent URLs.
@@ -1514,6 +2471,7 @@ where
             torrent_url,
             size_bytes: candidate.candidate.size_bytes,
             score: candidate.score,
+            seeders: candidate.candidate.seeders,
             file_mappings: candidate.file_mappings.clone(),
         }
     }
@@ -1535,314 +2493,666 @@ where
                     )
                 })
             }
-            _ => Err(OrchestratorError::InvalidState {
-                expected: "AutoApproved, Approved, or Downloading".to_string(),
-                actual: ticket.state.state_type().to_string(),
-            }),
+            _ => Err(OrchestratorError::InvalidState {
+                expected: "AutoApproved, Approved, or Downloading".to_string(),
+                actual: ticket.state.state_type().to_string(),
+            }),
+        }
+    }
+
+    /// Extract selected candidate and all candidates for failover from ticket state.
+    fn extract_candidates(
+        ticket: &Ticket,
+    ) -> Result<(SelectedCandidate, Vec<SelectedCandidate>), OrchestratorError> {
+        match &ticket.state {
+            TicketState::AutoApproved {
+                selected,
+                candidates,
+                ..
+            } => {
+                // Use stored candidates, or fallback to just the selected one
+                let all_candidates = if candidates.is_empty() {
+                    vec![selected.clone()]
+                } else {
+                    candidates.clone()
+                };
+                Ok((selected.clone(), all_candidates))
+            }
+            TicketState::Approved {
+                selected,
+                candidates,
+                ..
+            } => {
+                let all_candidates = if candidates.is_empty() {
+                    vec![selected.clone()]
+                } else {
+                    candidates.clone()
+                };
+                Ok((selected.clone(), all_candidates))
+            }
+            _ => Err(OrchestratorError::InvalidState {
+                expected: "AutoApproved or Approved".to_string(),
+                actual: ticket.state.state_type().to_string(),
+            }),
+        }
+    }
+
+    /// Get stall timeout for the given failover round.
+    fn get_stall_timeout(config: &OrchestratorConfig, round: u8) -> u64 {
+        match round {
+            1 => config.stall_timeout_round1_secs,
+            2 => config.stall_timeout_round2_secs,
+            _ => config.stall_timeout_round3_secs,
+        }
+    }
+
+    /// Returns true if the download is a failover race between several torrents.
+    fn is_racing(download: &ActiveDownload) -> bool {
+        download
+            .failover
+            .as_ref()
+            .is_some_and(|f| !f.racing.is_empty())
+    }
+
+    /// Returns true if the ticket may be sent back to acquisition once more.
+    ///
+    /// Re-acquisition needs a blacklist store: without one the stalled
+    /// torrents can't be excluded and a fresh search would pick them again.
+    fn can_reacquire(
+        config: &OrchestratorConfig,
+        ticket: &Ticket,
+        blacklist_store: &Option<Arc<dyn BlacklistStore>>,
+    ) -> bool {
+        if ticket.reacquire_count >= config.failover.max_reacquisitions {
+            return false;
+        }
+        if blacklist_store.is_none() {
+            warn!(
+                "Ticket {}: not searching again because no blacklist store is configured",
+                ticket.id
+            );
+            return false;
+        }
+        true
+    }
+
+    /// Handle a stalled download by asking the failover strategy what to do next.
+    ///
+    /// `cause` describes what went wrong ("stalled", "failed verification")
+    /// and is used in blacklist reasons and failure messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn handle_stall(
+        ticket_store: &Arc<dyn TicketStore>,
+        torrent_client: &Arc<dyn TorrentClient>,
+        active_downloads: &Arc<RwLock<HashMap<String, ActiveDownload>>>,
+        failover_strategy: &Arc<dyn FailoverStrategy>,
+        blacklist_store: &Option<Arc<dyn BlacklistStore>>,
+        download: &ActiveDownload,
+        cause: &str,
+        config: &OrchestratorConfig,
+        audit: &Option<AuditHandle>,
+        on_update: &Option<TicketUpdateCallback>,
+    ) -> Result<(), OrchestratorError> {
+        // Get ticket to access candidates
+        let ticket = ticket_store
+            .get(&download.ticket_id)?
+            .ok_or_else(|| OrchestratorError::TicketNotFound(download.ticket_id.clone()))?;
+
+        let candidates = match &ticket.state {
+            TicketState::Downloading { candidates, .. } => candidates.clone(),
+            _ => {
+                return Err(OrchestratorError::InvalidState {
+                    expected: "Downloading".to_string(),
+                    actual: ticket.state.state_type().to_string(),
+                });
+            }
+        };
+
+        // Remove the stalled torrent (every entrant if this was a race)
+        let _ = torrent_client
+            .remove_torrent(&download.info_hash, false)
+            .await;
+        let mut stalled_hashes = vec![download.info_hash.clone()];
+        if let Some(ref failover) = download.failover {
+            for entrant in &failover.racing {
+                if entrant.info_hash != download.info_hash {
+                    let _ = torrent_client
+                        .remove_torrent(&entrant.info_hash, false)
+                        .await;
+                    stalled_hashes.push(entrant.info_hash.clone());
+                }
+            }
+        }
+
+        // Blacklist what stalled for this ticket so a fresh search won't pick it again
+        if let Some(store) = blacklist_store {
+            for hash in &stalled_hashes {
+                if let Err(e) = store.add(CreateBlacklistEntry {
+                    kind: BlacklistKind::InfoHash,
+                    value: hash.clone(),
+                    ticket_id: Some(download.ticket_id.clone()),
+                    reason: Some(format!("download {}", cause)),
+                    created_by: "system".to_string(),
+                }) {
+                    warn!(
+                        "Failed to blacklist stalled torrent {} for ticket {}: {}",
+                        hash, download.ticket_id, e
+                    );
+                }
+            }
+        }
+
+        if candidates.is_empty() {
+            if config.failover.reacquire_on_exhaustion
+                && Self::can_reacquire(config, &ticket, blacklist_store)
+            {
+                return Self::research_stalled_download(
+                    ticket_store,
+                    active_downloads,
+                    download,
+                    "no candidates available, searching again".to_string(),
+                    audit,
+                    on_update,
+                )
+                .await;
+            }
+
+            // No candidates to failover to - fail immediately
+            return Self::fail_stalled_download(
+                ticket_store,
+                active_downloads,
+                download,
+                format!("Download {}: no candidates available", cause),
+                audit,
+                on_update,
+            )
+            .await;
+        }
+
+        // Downloads that predate failover records tried candidates in order
+        let tried = download
+            .failover
+            .as_ref()
+            .map(|f| f.tried.clone())
+            .unwrap_or_else(|| (0..=download.candidate_idx).collect());
+
+        let decision = failover_strategy.on_stall(&FailoverContext {
+            candidates: &candidates,
+            current_idx: download.candidate_idx,
+            round: download.failover_round,
+            tried: &tried,
+        });
+
+        let (candidate_idxs, round, reason) = match decision {
+            FailoverDecision::Switch {
+                candidate_idx,
+                round,
+                reason,
+            } => (vec![candidate_idx], round, reason),
+            FailoverDecision::Race {
+                candidate_idxs,
+                round,
+                reason,
+            } => (candidate_idxs, round, reason),
+            FailoverDecision::Research { reason } => {
+                if Self::can_reacquire(config, &ticket, blacklist_store) {
+                    return Self::research_stalled_download(
+                        ticket_store,
+                        active_downloads,
+                        download,
+                        reason,
+                        audit,
+                        on_update,
+                    )
+                    .await;
+                }
+                return Self::fail_stalled_download(
+                    ticket_store,
+                    active_downloads,
+                    download,
+                    format!(
+                        "Download {}: all {} candidates tried after {} re-acquisitions",
+                        cause,
+                        candidates.len(),
+                        ticket.reacquire_count
+                    ),
+                    audit,
+                    on_update,
+                )
+                .await;
+            }
+            FailoverDecision::GiveUp { reason } => {
+                if config.failover.reacquire_on_exhaustion
+                    && Self::can_reacquire(config, &ticket, blacklist_store)
+                {
+                    return Self::research_stalled_download(
+                        ticket_store,
+                        active_downloads,
+                        download,
+                        format!("{}, searching again", reason),
+                        audit,
+                        on_update,
+                    )
+                    .await;
+                }
+                Self::fail_stalled_download(
+                    ticket_store,
+                    active_downloads,
+                    download,
+                    format!("Download {}: {}", cause, reason),
+                    audit,
+                    on_update,
+                )
+                .await?;
+                info!(
+                    "Ticket {} failed after exhausting all failover attempts",
+                    download.ticket_id
+                );
+                return Ok(());
+            }
+        };
+
+        metrics::FAILOVER_ATTEMPTS.inc();
+        info!(
+            "Ticket {}: download {} (round {}), {} failover: {}",
+            download.ticket_id,
+            cause,
+            download.failover_round,
+            failover_strategy.name(),
+            reason
+        );
+
+        // Candidates chosen in this round count as tried even if adding them fails
+        let mut tried = if round > download.failover_round {
+            Vec::new()
+        } else {
+            tried
+        };
+        for idx in &candidate_idxs {
+            if !tried.contains(idx) {
+                tried.push(*idx);
+            }
+        }
+
+        // Start every chosen candidate
+        let mut entrants = Vec::new();
+        for &idx in &candidate_idxs {
+            let Some(candidate) = candidates.get(idx) else {
+                continue;
+            };
+            match Self::add_torrent_from_candidate(torrent_client.as_ref(), candidate).await {
+                Ok(result) => entrants.push(RaceEntrant {
+                    candidate_idx: idx,
+                    info_hash: result.hash,
+                }),
+                Err(e) => {
+                    warn!(
+                        "Failed to add failover torrent for candidate {}: {}",
+                        idx, e
+                    );
+                }
+            }
         }
-    }
 
-    /// Extract selected candidate and all candidates for failover from ticket state.
-    fn extract_candidates(
-        ticket: &Ticket,
-    ) -> Result<(SelectedCandidate, Vec<SelectedCandidate>), OrchestratorError> {
-        match &ticket.state {
-            TicketState::AutoApproved {
-                selected,
-                candidates,
-                ..
-            } => {
-                // Use stored candidates, or fallback to just the selected one
-                let all_candidates = if candidates.is_empty() {
-                    vec![selected.clone()]
-                } else {
-                    candidates.clone()
-                };
-                Ok((selected.clone(), all_candidates))
+        let Some(primary) = entrants.first().cloned() else {
+            // Failed to add any of them - trigger another failover right away
+            let mut downloads = active_downloads.write().await;
+            if let Some(d) = downloads.get_mut(&download.ticket_id) {
+                d.candidate_idx = candidate_idxs.first().copied().unwrap_or(0);
+                d.failover_round = round;
+                d.failover = Some(FailoverRecord {
+                    strategy: failover_strategy.name().to_string(),
+                    reason,
+                    tried,
+                    racing: vec![],
+                });
+                // Reset stall timer to trigger immediate retry of next candidate
+                d.last_progress_at = Utc::now()
+                    - chrono::Duration::seconds(Self::get_stall_timeout(config, round) as i64 + 1);
             }
-            TicketState::Approved {
-                selected,
+            return Ok(());
+        };
+
+        let now = Utc::now();
+        let failover = FailoverRecord {
+            strategy: failover_strategy.name().to_string(),
+            reason,
+            tried,
+            racing: if entrants.len() > 1 { entrants } else { vec![] },
+        };
+
+        // Update tracking
+        active_downloads.write().await.insert(
+            download.ticket_id.clone(),
+            ActiveDownload {
+                ticket_id: download.ticket_id.clone(),
+                info_hash: primary.info_hash.clone(),
+                started_at: now,
+                candidate_idx: primary.candidate_idx,
+                failover_round: round,
+                last_progress_pct: 0.0,
+                last_progress_at: now,
+                priority: download.priority,
+                failover: Some(failover.clone()),
+                verifying: false,
+            },
+        );
+
+        // Update ticket state
+        update_and_notify_static(
+            ticket_store,
+            on_update,
+            &download.ticket_id,
+            TicketState::Downloading {
+                info_hash: primary.info_hash,
+                progress_pct: 0.0,
+                speed_bps: 0,
+                eta_secs: None,
+                started_at: now,
+                candidate_idx: primary.candidate_idx,
+                failover_round: round,
+                last_progress_pct: 0.0,
+                last_progress_at: now,
                 candidates,
-                ..
-            } => {
-                let all_candidates = if candidates.is_empty() {
-                    vec![selected.clone()]
-                } else {
-                    candidates.clone()
-                };
-                Ok((selected.clone(), all_candidates))
-            }
-            _ => Err(OrchestratorError::InvalidState {
-                expected: "AutoApproved or Approved".to_string(),
-                actual: ticket.state.state_type().to_string(),
-            }),
-        }
-    }
+                failover: Some(failover),
+            },
+        )?;
 
-    /// Get stall timeout for the given failover round.
-    fn get_stall_timeout(config: &OrchestratorConfig, round: u8) -> u64 {
-        match round {
-            1 => config.stall_timeout_round1_secs,
-            2 => config.stall_timeout_round2_secs,
-            _ => config.stall_timeout_round3_secs,
-        }
+        Ok(())
     }
 
-    /// Handle a stalled download by trying the next candidate or failing.
-    async fn handle_stall(
+    /// Check a failover race, keeping the first entrant to reach the win threshold.
+    #[allow(clippy::too_many_arguments)]
+    async fn check_race(
         ticket_store: &Arc<dyn TicketStore>,
         torrent_client: &Arc<dyn TorrentClient>,
         active_downloads: &Arc<RwLock<HashMap<String, ActiveDownload>>>,
+        failover_strategy: &Arc<dyn FailoverStrategy>,
+        blacklist_store: &Option<Arc<dyn BlacklistStore>>,
         download: &ActiveDownload,
         config: &OrchestratorConfig,
         audit: &Option<AuditHandle>,
         on_update: &Option<TicketUpdateCallback>,
     ) -> Result<(), OrchestratorError> {
-        // Get ticket to access candidates
-        let ticket = ticket_store
-            .get(&download.ticket_id)?
-            .ok_or_else(|| OrchestratorError::TicketNotFound(download.ticket_id.clone()))?;
-
-        let candidates = match &ticket.state {
-            TicketState::Downloading { candidates, .. } => candidates.clone(),
-            _ => {
-                return Err(OrchestratorError::InvalidState {
-                    expected: "Downloading".to_string(),
-                    actual: ticket.state.state_type().to_string(),
-                });
-            }
+        let Some(mut failover) = download.failover.clone() else {
+            return Ok(());
         };
 
-        let num_candidates = candidates.len();
-        if num_candidates == 0 {
-            // No candidates to failover to - fail immediately
-            let error_msg = "Download stalled: no candidates available".to_string();
-            active_downloads.write().await.remove(&download.ticket_id);
-            let _ = torrent_client
-                .remove_torrent(&download.info_hash, false)
-                .await;
-            update_and_notify_static(
-                &ticket_store,
-                &on_update,
-                &download.ticket_id,
-                TicketState::Failed {
-                    error: error_msg.clone(),
-                    retryable: false,
-                    retry_count: 0,
-                    failed_at: Utc::now(),
-                },
-            )?;
-
-            // Emit state change event
-            if let Some(ref audit_handle) = audit {
-                audit_handle
-                    .emit(AuditEvent::TicketStateChanged {
-                        ticket_id: download.ticket_id.clone(),
-                        from_state: "downloading".to_string(),
-                        to_state: "failed".to_string(),
-                        reason: Some(error_msg),
-                    })
-                    .await;
+        // Find the entrant furthest ahead
+        let mut leader: Option<(RaceEntrant, TorrentInfo)> = None;
+        for entrant in &failover.racing {
+            if let Ok(info) = torrent_client.get_torrent(&entrant.info_hash).await {
+                if leader
+                    .as_ref()
+                    .is_none_or(|(_, best)| info.progress > best.progress)
+                {
+                    leader = Some((entrant.clone(), info));
+                }
             }
-
-            return Ok(());
         }
 
-        // Calculate next candidate and round
-        let mut next_idx = download.candidate_idx + 1;
-        let mut next_round = download.failover_round;
+        let Some((leader, info)) = leader else {
+            // Every entrant disappeared - treat like a stall
+            return Self::handle_stall(
+                ticket_store,
+                torrent_client,
+                active_downloads,
+                failover_strategy,
+                blacklist_store,
+                download,
+                "stalled",
+                config,
+                audit,
+                on_update,
+            )
+            .await;
+        };
 
-        if next_idx >= num_candidates {
-            next_idx = 0;
-            next_round += 1;
-        }
+        let now = Utc::now();
+        let progress_pct = (info.progress * 100.0) as f32;
+        let won = progress_pct >= config.failover.race_win_pct
+            || info.progress >= 1.0
+            || info.state == TorrentState::Seeding;
+
+        if !won {
+            if progress_pct > download.last_progress_pct {
+                let mut downloads = active_downloads.write().await;
+                if let Some(d) = downloads.get_mut(&download.ticket_id) {
+                    d.last_progress_pct = progress_pct;
+                    d.last_progress_at = now;
+                }
+                return Ok(());
+            }
 
-        // Check if we've exhausted all rounds
-        if next_round > 3 {
-            // All candidates tried in all rounds - fail permanently
-            let error_msg = format!(
-                "Download stalled: tried {} candidates over 3 rounds (~{} hours)",
-                num_candidates,
-                (config.stall_timeout_round1_secs * num_candidates as u64
-                    + config.stall_timeout_round2_secs * num_candidates as u64
-                    + config.stall_timeout_round3_secs * num_candidates as u64)
-                    / 3600
-            );
-            active_downloads.write().await.remove(&download.ticket_id);
-            let _ = torrent_client
-                .remove_torrent(&download.info_hash, false)
+            let timeout = Self::get_stall_timeout(config, download.failover_round);
+            if now.signed_duration_since(download.last_progress_at)
+                > chrono::Duration::seconds(timeout as i64)
+            {
+                metrics::STALL_DETECTIONS.inc();
+                return Self::handle_stall(
+                    ticket_store,
+                    torrent_client,
+                    active_downloads,
+                    failover_strategy,
+                    blacklist_store,
+                    download,
+                    "stalled",
+                    config,
+                    audit,
+                    on_update,
+                )
                 .await;
-
-            update_and_notify_static(
-                &ticket_store,
-                &on_update,
-                &download.ticket_id,
-                TicketState::Failed {
-                    error: error_msg.clone(),
-                    retryable: false,
-                    retry_count: 0,
-                    failed_at: Utc::now(),
-                },
-            )?;
-
-            // Emit state change event
-            if let Some(ref audit_handle) = audit {
-                audit_handle
-                    .emit(AuditEvent::TicketStateChanged {
-                        ticket_id: download.ticket_id.clone(),
-                        from_state: "downloading".to_string(),
-                        to_state: "failed".to_string(),
-                        reason: Some(error_msg),
-                    })
-                    .await;
             }
-
-            info!(
-                "Ticket {} failed after exhausting all failover attempts",
-                download.ticket_id
-            );
             return Ok(());
         }
 
-        // Remove current stalled torrent
-        let _ = torrent_client
-            .remove_torrent(&download.info_hash, false)
-            .await;
-
-        // Try next candidate
-        metrics::FAILOVER_ATTEMPTS.inc();
+        // We have a winner - drop the rest of the field
+        for entrant in &failover.racing {
+            if entrant.info_hash != leader.info_hash {
+                let _ = torrent_client
+                    .remove_torrent(&entrant.info_hash, false)
+                    .await;
+            }
+        }
 
-        let next_candidate = &candidates[next_idx];
         info!(
-            "Ticket {}: stall detected (round {}), failing over to candidate {} of {}",
+            "Ticket {}: candidate {} won the failover race at {:.1}%",
             download.ticket_id,
-            download.failover_round,
-            next_idx + 1,
-            num_candidates
+            leader.candidate_idx + 1,
+            progress_pct
         );
 
-        // Add new torrent
-        let request = AddTorrentRequest::magnet(&next_candidate.magnet_uri);
-        match torrent_client.add_torrent(request).await {
-            Ok(result) => {
-                let now = Utc::now();
+        failover.racing.clear();
+        failover.reason = format!(
+            "{}; candidate {} won the race",
+            failover.reason,
+            leader.candidate_idx + 1
+        );
 
-                // Update tracking
-                {
-                    let mut downloads = active_downloads.write().await;
-                    downloads.insert(
-                        download.ticket_id.clone(),
-                        ActiveDownload {
-                            ticket_id: download.ticket_id.clone(),
-                            info_hash: result.hash.clone(),
-                            started_at: now,
-                            candidate_idx: next_idx,
-                            failover_round: next_round,
-                            last_progress_pct: 0.0,
-                            last_progress_at: now,
-                        },
-                    );
-                }
+        active_downloads.write().await.insert(
+            download.ticket_id.clone(),
+            ActiveDownload {
+                info_hash: leader.info_hash.clone(),
+                candidate_idx: leader.candidate_idx,
+                last_progress_pct: progress_pct,
+                last_progress_at: now,
+                failover: Some(failover.clone()),
+                verifying: false,
+                ..download.clone()
+            },
+        );
 
-                // Update ticket state
+        if let Ok(Some(ticket)) = ticket_store.get(&download.ticket_id) {
+            if let TicketState::Downloading { candidates, .. } = ticket.state {
                 update_and_notify_static(
-                    &ticket_store,
-                    &on_update,
+                    ticket_store,
+                    on_update,
                     &download.ticket_id,
                     TicketState::Downloading {
-                        info_hash: result.hash,
-                        progress_pct: 0.0,
-                        speed_bps: 0,
-                        eta_secs: None,
-                        started_at: now,
-                        candidate_idx: next_idx,
-                        failover_round: next_round,
-                        last_progress_pct: 0.0,
+                        info_hash: leader.info_hash,
+                        progress_pct,
+                        speed_bps: info.download_speed,
+                        eta_secs: info.eta_secs.map(|e| e as u32),
+                        started_at: download.started_at,
+                        candidate_idx: leader.candidate_idx,
+                        failover_round: download.failover_round,
+                        last_progress_pct: progress_pct,
                         last_progress_at: now,
                         candidates,
+                        failover: Some(failover),
                     },
                 )?;
             }
-            Err(e) => {
-                // Failed to add the next candidate - try the one after that
-                warn!(
-                    "Failed to add failover torrent for candidate {}: {}",
-                    next_idx, e
-                );
-                // Update download tracking to skip this candidate
-                {
-                    let mut downloads = active_downloads.write().await;
-                    if let Some(d) = downloads.get_mut(&download.ticket_id) {
-                        d.candidate_idx = next_idx;
-                        d.failover_round = next_round;
-                        // Reset stall timer to trigger immediate retry of next candidate
-                        d.last_progress_at = Utc::now()
-                            - chrono::Duration::seconds(
-                                Self::get_stall_timeout(config, next_round) as i64 + 1,
-                            );
-                    }
-                }
-            }
         }
 
         Ok(())
     }
 
+    /// Fail a ticket whose download stalled or failed verification with no way forward.
+    async fn fail_stalled_download(
+        ticket_store: &Arc<dyn TicketStore>,
+        active_downloads: &Arc<RwLock<HashMap<String, ActiveDownload>>>,
+        download: &ActiveDownload,
+        error_msg: String,
+        audit: &Option<AuditHandle>,
+        on_update: &Option<TicketUpdateCallback>,
+    ) -> Result<(), OrchestratorError> {
+        active_downloads.write().await.remove(&download.ticket_id);
+
+        update_and_notify_static(
+            ticket_store,
+            on_update,
+            &download.ticket_id,
+            TicketState::Failed {
+                error: error_msg.clone(),
+                retryable: false,
+                retry_count: 0,
+                failed_at: Utc::now(),
+            },
+        )?;
+
+        // Emit state change event
+        if let Some(ref audit_handle) = audit {
+            audit_handle
+                .emit(AuditEvent::TicketStateChanged {
+                    ticket_id: download.ticket_id.clone(),
+                    from_state: "downloading".to_string(),
+                    to_state: "failed".to_string(),
+                    reason: Some(error_msg),
+                })
+                .await;
+        }
+
+        Ok(())
+    }
+
+    /// Send a ticket whose candidates all stalled back through acquisition.
+    async fn research_stalled_download(
+        ticket_store: &Arc<dyn TicketStore>,
+        active_downloads: &Arc<RwLock<HashMap<String, ActiveDownload>>>,
+        download: &ActiveDownload,
+        reason: String,
+        audit: &Option<AuditHandle>,
+        on_update: &Option<TicketUpdateCallback>,
+    ) -> Result<(), OrchestratorError> {
+        active_downloads.write().await.remove(&download.ticket_id);
+
+        ticket_store.increment_reacquire_count(&download.ticket_id)?;
+        update_and_notify_static(
+            ticket_store,
+            on_update,
+            &download.ticket_id,
+            TicketState::Pending,
+        )?;
+
+        if let Some(ref audit_handle) = audit {
+            audit_handle
+                .emit(AuditEvent::TicketStateChanged {
+                    ticket_id: download.ticket_id.clone(),
+                    from_state: "downloading".to_string(),
+                    to_state: "pending".to_string(),
+                    reason: Some(reason.clone()),
+                })
+                .await;
+        }
+
+        info!(
+            "Ticket {} sent back to acquisition: {}",
+            download.ticket_id, reason
+        );
+
+        Ok(())
+    }
+
     /// Build a TextBrain instance with appropriate query builder and matcher
     /// based on the configuration.
-    fn build_textbrain(config: &TextBrainConfig, catalog: Arc<dyn TorrentCatalog>) -> TextBrain {
+    fn build_textbrain(
+        config: &TextBrainConfig,
+        catalog: Arc<dyn TorrentCatalog>,
+        llm_cache: &Option<Arc<dyn LlmCache>>,
+        llm_budget: &Option<Arc<LlmBudget>>,
+        llm_health: &Option<Arc<LlmProviderHealth>>,
+        prompts: &Option<Arc<PromptLibrary>>,
+        audit: Option<(&AuditHandle, &str)>,
+    ) -> TextBrain {
         let mut textbrain = TextBrain::new(config.clone());
 
         // Always add dumb implementations (used as fallback in most modes)
         if config.mode.can_use_dumb() {
             textbrain = textbrain
                 .with_dumb_query_builder(Arc::new(DumbQueryBuilder::new()))
-                .with_dumb_matcher(Arc::new(DumbMatcher::new()));
+                .with_dumb_matcher(Arc::new(DumbMatcher::with_config(
+                    DumbMatcherConfig::default().with_weights(&config.scoring.dumb),
+                )));
         }
 
         // Add LLM implementations if configured and mode can use them
         if config.mode.can_use_llm() {
             if let Some(ref llm_config) = config.llm {
-                match llm_config.provider {
-                    LlmProvider::Anthropic => {
-                        if let Some(ref api_key) = llm_config.api_key {
-                            let mut client =
-                                AnthropicClient::new(api_key.clone(), llm_config.model.clone());
-                            if let Some(ref api_base) = llm_config.api_base {
-                                client = client.with_api_base(api_base.clone());
-                            }
-                            let client = Arc::new(client);
-                            textbrain = textbrain
-                                .with_llm_query_builder(Arc::new(LlmQueryBuilder::new(
-                                    client.clone(),
-                                )))
-                                .with_llm_matcher(Arc::new(LlmMatcher::new(client)));
-                            info!(
-                                "LLM integration enabled with Anthropic ({})",
-                                llm_config.model
-                            );
-                        } else {
-                            warn!("Anthropic provider configured but no API key provided");
-                        }
-                    }
-                    LlmProvider::Ollama => {
-                        let mut client = OllamaClient::new(llm_config.model.clone());
-                        if let Some(ref api_base) = llm_config.api_base {
-                            client = client.with_api_base(api_base.clone());
-                        }
-                        let client = Arc::new(client);
-                        textbrain = textbrain
-                            .with_llm_query_builder(Arc::new(LlmQueryBuilder::new(client.clone())))
-                            .with_llm_matcher(Arc::new(LlmMatcher::new(client)));
-                        info!("LLM integration enabled with Ollama ({})", llm_config.model);
-                    }
-                    LlmProvider::OpenAi | LlmProvider::Custom => {
-                        // TODO: Implement OpenAI and custom providers
+                // Completions are cached only when a cache store was provided
+                let cache = llm_cache
+                    .as_ref()
+                    .filter(|_| llm_config.cache.enabled)
+                    .map(|cache| {
+                        (
+                            Arc::clone(cache),
+                            Duration::from_secs(llm_config.cache.ttl_secs),
+                        )
+                    });
+
+                let health = llm_health.clone().unwrap_or_else(|| {
+                    Arc::new(LlmProviderHealth::new(llm_config.circuit_breaker.clone()))
+                });
+                let layers = LlmClientLayers {
+                    cache,
+                    budget: llm_budget.clone(),
+                    audit: audit.map(|(handle, ticket_id)| (handle.clone(), ticket_id.to_string())),
+                    prompts: prompts.clone().unwrap_or_default(),
+                };
+                let providers: Vec<FallbackProvider> = llm_config
+                    .providers()
+                    .iter()
+                    .filter_map(|provider| Self::build_llm_provider(provider, &layers))
+                    .collect();
+
+                if providers.is_empty() {
+                    warn!("No usable LLM provider configured, falling back to heuristics");
+                } else {
+                    info!(
+                        "LLM integration enabled with {} provider(s), primary {:?} ({})",
+                        providers.len(),
+                        llm_config.provider,
+                        llm_config.model
+                    );
+                    if let Err(e) = layers.prompts.reload_if_changed() {
                         warn!(
-                            "LLM provider {:?} is not yet implemented, falling back to heuristics",
-                            llm_config.provider
+                            "Failed to reload prompt templates, keeping previous ones: {}",
+                            e
                         );
                     }
+                    let client = FallbackLlmClient::new(providers, health);
+                    textbrain =
+                        Self::attach_llm_client(textbrain, Arc::new(client), layers.prompts);
                 }
             } else if config.mode.requires_llm() {
                 warn!("LLM mode requires LLM configuration but none provided");
@@ -1862,6 +3172,114 @@ where
         textbrain
     }
 
+    /// Build the client of one provider of the fallback chain, or `None` if
+    /// the provider cannot be used.
+    ///
+    /// Completions are cached, usage is charged to the budget and calls are
+    /// audited per provider, so all three are attributed to the provider
+    /// that actually answered. A fallback provider's answers are never
+    /// replayed for the primary once it recovers.
+    fn build_llm_provider(
+        provider: &LlmProviderConfig,
+        layers: &LlmClientLayers,
+    ) -> Option<FallbackProvider> {
+        let client: Arc<dyn LlmClient> = match provider.provider {
+            LlmProvider::Anthropic => {
+                let Some(ref api_key) = provider.api_key else {
+                    warn!(
+                        "Anthropic provider ({}) configured but no API key provided",
+                        provider.model
+                    );
+                    return None;
+                };
+                let mut client = AnthropicClient::new(api_key.clone(), provider.model.clone());
+                if let Some(ref api_base) = provider.api_base {
+                    client = client.with_api_base(api_base.clone());
+                }
+                Arc::new(client)
+            }
+            LlmProvider::Ollama => {
+                let mut client = OllamaClient::new(provider.model.clone());
+                if let Some(ref api_base) = provider.api_base {
+                    client = client.with_api_base(api_base.clone());
+                }
+                Arc::new(client)
+            }
+            LlmProvider::OpenAi => {
+                let Some(ref api_key) = provider.api_key else {
+                    warn!(
+                        "OpenAI provider ({}) configured but no API key provided",
+                        provider.model
+                    );
+                    return None;
+                };
+                let mut client = OpenAiClient::new(api_key.clone(), provider.model.clone());
+                if let Some(ref api_base) = provider.api_base {
+                    client = client.with_api_base(api_base.clone());
+                }
+                Arc::new(client)
+            }
+            LlmProvider::Custom => {
+                let Some(ref api_base) = provider.api_base else {
+                    warn!(
+                        "Custom LLM provider ({}) configured but no api_base provided",
+                        provider.model
+                    );
+                    return None;
+                };
+                let mut client = OpenAiClient::custom(api_base.clone(), provider.model.clone());
+                if let Some(ref api_key) = provider.api_key {
+                    client = client.with_api_key(api_key.clone());
+                }
+                Arc::new(client)
+            }
+        };
+        Some(FallbackProvider::new(
+            Self::layer_llm_provider(client, layers),
+            Duration::from_secs(provider.timeout_secs as u64),
+        ))
+    }
+
+    /// Wrap a provider's client with the budget, audit and cache layers.
+    ///
+    /// The cache sits in front of the budget, so cached answers are still
+    /// served once the budget is exhausted; only actual calls are audited.
+    fn layer_llm_provider(
+        mut client: Arc<dyn LlmClient>,
+        layers: &LlmClientLayers,
+    ) -> Arc<dyn LlmClient> {
+        if let Some(ref budget) = layers.budget {
+            client = Arc::new(BudgetedLlmClient::new(client, Arc::clone(budget)));
+        }
+        if let Some((ref audit, ref ticket_id)) = layers.audit {
+            client = Arc::new(AuditedLlmClient::new(
+                client,
+                audit.clone(),
+                Some(ticket_id.clone()),
+            ));
+        }
+        if let Some((ref cache, ttl)) = layers.cache {
+            client = Arc::new(CachedLlmClient::new(client, Arc::clone(cache), ttl));
+        }
+        client
+    }
+
+    /// Add the LLM query builder, matcher and file mapper.
+    fn attach_llm_client<L: LlmClient + 'static>(
+        textbrain: TextBrain,
+        client: Arc<L>,
+        prompts: Arc<PromptLibrary>,
+    ) -> TextBrain {
+        textbrain
+            .with_llm_query_builder(Arc::new(
+                LlmQueryBuilder::new(client.clone()).with_prompts(prompts.clone()),
+            ))
+            .with_llm_matcher(Arc::new(
+                LlmMatcher::new(client.clone()).with_prompts(prompts.clone()),
+            ))
+            .with_llm_file_mapper(Arc::new(LlmFileMapper::new(client).with_prompts(prompts)))
+    }
+
     /// Check if an error is retryable (transient) or permanent.
     ///
     /// Transient errors that can be retried:
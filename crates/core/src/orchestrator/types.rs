@@ -4,6 +4,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::ticket::FailoverRecord;
+
 /// Errors that can occur during orchestration.
 #[derive(Debug, Error)]
 pub enum OrchestratorError {
@@ -57,6 +59,16 @@ pub struct ActiveDownload {
     pub last_progress_pct: f32,
     /// When progress last changed (for stall detection).
     pub last_progress_at: DateTime<Utc>,
+    /// Priority of the owning ticket (for preemption).
+    #[serde(default)]
+    pub priority: u16,
+    /// Last failover decision (None until the first failover).
+    #[serde(default)]
+    pub failover: Option<FailoverRecord>,
+    /// Whether the finished download is being verified (skipped by the
+    /// monitor loop and preemption until verification is done).
+    #[serde(default)]
+    pub verifying: bool,
 }
 
 /// Current status of the orchestrator.
@@ -74,6 +86,8 @@ pub struct OrchestratorStatus {
     pub needs_approval_count: usize,
     /// Tickets currently downloading.
     pub downloading_count: usize,
+    /// Tickets whose download is paused by priority preemption.
+    pub preempted_count: usize,
 }
 
 #[cfg(test)]
@@ -91,6 +105,9 @@ mod tests {
             failover_round: 1,
             last_progress_pct: 0.0,
             last_progress_at: now,
+            priority: 100,
+            failover: None,
+            verifying: false,
         };
 
         let json = serde_json::to_string(&download).unwrap();
@@ -100,6 +117,7 @@ mod tests {
         assert_eq!(parsed.info_hash, "abc123def456");
         assert_eq!(parsed.candidate_idx, 0);
         assert_eq!(parsed.failover_round, 1);
+        assert_eq!(parsed.priority, 100);
     }
 
     #[test]
@@ -0,0 +1,858 @@
+//! Verification of completed downloads before conversion.
+//!
+//! Mapped files are probed with the converter and compared against what the
+//! ticket expects:
+//! - **Durations**: per-track durations, or the catalog runtime for movies
+//! - **Episode count**: probeable episode files against the expected episodes
+//! - **Resolution**: video frame size against the minimum resolution
+//! - **Lossless quality**: bit depth and sample rate against the audio
+//!   constraints, and a spectral check of decoded audio for lossy sources,
+//!   upsampling and bit-depth padding
+//!
+//! Checks without expectations to compare against are skipped, so tickets
+//! without catalog data pass as before. Lossless findings are returned as an
+//! [`AudioQualityReport`] to be recorded on the ticket.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::converter::{analyze_spectrum, Converter, MediaInfo, TimeSegment};
+use crate::textbrain::DumbFileMapperConfig;
+use crate::ticket::{
+    AudioQualityReport, CatalogReference, CompletedDownload, ExpectedContent, QueryContext,
+    TmdbMediaType,
+};
+
+use super::config::VerificationConfig;
+
+/// Why a completed download failed verification.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerificationFailure {
+    /// One entry per mismatch found.
+    pub issues: Vec<String>,
+}
+
+impl fmt::Display for VerificationFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.issues.join("; "))
+    }
+}
+
+/// Length of audio decoded from the middle of each lossless file.
+const ANALYSIS_SECS: f64 = 30.0;
+
+/// Verify a completed download against the ticket's query context.
+///
+/// Files that cannot be probed count as mismatches, since a truncated or
+/// corrupt file would fail conversion anyway. On success, returns the
+/// lossless quality report for music downloads with lossless files.
+pub async fn verify_download(
+    converter: &dyn Converter,
+    context: &QueryContext,
+    download: &CompletedDownload,
+    config: &VerificationConfig,
+) -> Result<Option<AudioQualityReport>, VerificationFailure> {
+    let mut probe = Prober::new(converter);
+    let mut issues = Vec::new();
+
+    let download_path = Path::new(&download.path);
+    let mapped: Vec<(&str, PathBuf)> = download
+        .file_mappings
+        .iter()
+        .map(|m| {
+            (
+                m.ticket_item_id.as_str(),
+                resolve_path(download_path, &m.torrent_file_path),
+            )
+        })
+        .collect();
+
+    // Track durations
+    if let Some(ExpectedContent::Album { tracks, .. }) = &context.expected {
+        // Several tracks mapped to one file (a CUE image) are checked as a whole
+        let mut expected_by_file: Vec<(PathBuf, Vec<u32>, f64)> = Vec::new();
+        for (item_id, path) in &mapped {
+            let Some(number) = item_id
+                .strip_prefix("track-")
+                .and_then(|n| n.parse::<u32>().ok())
+            else {
+                continue;
+            };
+            let Some(expected_secs) = tracks
+                .iter()
+                .find(|t| t.number == number)
+                .and_then(expected_track_secs)
+            else {
+                continue;
+            };
+            match expected_by_file.iter_mut().find(|(p, _, _)| p == path) {
+                Some((_, numbers, secs)) => {
+                    numbers.push(number);
+                    *secs += expected_secs;
+                }
+                None => expected_by_file.push((path.clone(), vec![number], expected_secs)),
+            }
+        }
+
+        for (path, numbers, expected_secs) in expected_by_file {
+            let label = if numbers.len() == 1 {
+                format!("track {}", numbers[0])
+            } else {
+                format!("tracks {}-{}", numbers[0], numbers[numbers.len() - 1])
+            };
+            match probe.info(&path).await {
+                Ok(info) => {
+                    if let Some(issue) =
+                        check_duration(&label, info.duration_secs, expected_secs, config)
+                    {
+                        issues.push(issue);
+                    }
+                }
+                Err(e) => issues.push(e),
+            }
+        }
+    }
+
+    // Movie runtime
+    if let Some(CatalogReference::Tmdb {
+        media_type: TmdbMediaType::Movie,
+        runtime_minutes: Some(runtime),
+        ..
+    }) = &context.catalog_reference
+    {
+        let files = video_files(download_path, &mapped);
+        if !files.is_empty() {
+            let mut total_secs = 0.0;
+            let mut probed = true;
+            for path in &files {
+                match probe.info(path).await {
+                    Ok(info) => total_secs += info.duration_secs,
+                    Err(e) => {
+                        issues.push(e);
+                        probed = false;
+                    }
+                }
+            }
+            if probed {
+                if let Some(issue) =
+                    check_duration("runtime", total_secs, *runtime as f64 * 60.0, config)
+                {
+                    issues.push(issue);
+                }
+            }
+        }
+    }
+
+    // Episode count
+    if let Some(expected_count) = expected_episode_count(context) {
+        let episodes: BTreeSet<&str> = mapped
+            .iter()
+            .filter(|(item_id, _)| is_episode_item(item_id))
+            .map(|(item_id, _)| *item_id)
+            .collect();
+        if !episodes.is_empty() {
+            let mut found = 0;
+            for episode in &episodes {
+                let mut playable = false;
+                for (_, path) in mapped.iter().filter(|(id, _)| id == episode) {
+                    match probe.info(path).await {
+                        Ok(_) => playable = true,
+                        Err(e) => issues.push(e),
+                    }
+                }
+                if playable {
+                    found += 1;
+                }
+            }
+            if found < expected_count {
+                issues.push(format!("{} of {} episodes present", found, expected_count));
+            }
+        }
+    }
+
+    // Minimum resolution
+    let min_resolution = context
+        .search_constraints
+        .as_ref()
+        .and_then(|sc| sc.video.as_ref())
+        .and_then(|v| v.min_resolution);
+    if let Some(min_resolution) = min_resolution {
+        let (min_width, min_height) = min_resolution.dimensions();
+        for path in video_files(download_path, &mapped) {
+            let info = match probe.info(&path).await {
+                Ok(info) => info,
+                Err(e) => {
+                    issues.push(e);
+                    continue;
+                }
+            };
+            let (Some(width), Some(height)) = (info.video_width, info.video_height) else {
+                continue;
+            };
+            // Cropped widescreen encodes keep the full width but not the height
+            if width < min_width && height < min_height {
+                issues.push(format!(
+                    "{} is {}x{}, below {}",
+                    file_name(&path),
+                    width,
+                    height,
+                    min_resolution.as_keyword()
+                ));
+            }
+        }
+    }
+
+    // Lossless quality
+    let mut audio_quality = None;
+    if matches!(
+        context.expected,
+        Some(ExpectedContent::Album { .. }) | Some(ExpectedContent::Track { .. })
+    ) {
+        let audio = context
+            .search_constraints
+            .as_ref()
+            .and_then(|sc| sc.audio.as_ref());
+        let min_bit_depth = audio.and_then(|a| a.min_bit_depth);
+        let min_sample_rate = audio.and_then(|a| a.min_sample_rate_hz);
+
+        let mut report = AudioQualityReport::default();
+        let mut files: Vec<&PathBuf> = Vec::new();
+        for (_, path) in &mapped {
+            if !files.contains(&path) {
+                files.push(path);
+            }
+        }
+        for path in files {
+            let info = match probe.info(path).await {
+                Ok(info) => info,
+                Err(e) => {
+                    issues.push(e);
+                    continue;
+                }
+            };
+            if !is_lossless(&info) {
+                continue;
+            }
+            let name = file_name(path);
+            let mut bit_depth = info.audio_bit_depth;
+            let mut suspect = Vec::new();
+
+            if config.lossless_analysis {
+                let start_secs = (info.duration_secs / 2.0 - ANALYSIS_SECS / 2.0).max(0.0);
+                let segment = TimeSegment {
+                    start_secs,
+                    end_secs: Some(start_secs + ANALYSIS_SECS),
+                };
+                // The FFT is CPU-bound, keep it off the async workers
+                let analysis = match converter.decode_pcm(path, Some(segment)).await {
+                    Ok(pcm) => tokio::task::spawn_blocking(move || analyze_spectrum(&pcm))
+                        .await
+                        .map_err(|e| e.to_string()),
+                    Err(e) => Err(e.to_string()),
+                };
+                match analysis {
+                    Ok(analysis) => {
+                        if let (Some(stored), Some(effective)) =
+                            (bit_depth, analysis.effective_bit_depth)
+                        {
+                            if effective < stored {
+                                suspect.push(format!(
+                                    "{}: {}-bit with {}-bit content, likely padded",
+                                    name, stored, effective
+                                ));
+                                bit_depth = Some(effective);
+                            }
+                        }
+                        let cutoff_khz = analysis.cutoff_hz.unwrap_or(0.0) / 1000.0;
+                        if analysis.likely_lossy_source() {
+                            suspect.push(format!(
+                                "{}: spectrum cut off at {:.1} kHz, likely lossy source",
+                                name, cutoff_khz
+                            ));
+                        } else if analysis.likely_upsampled() {
+                            suspect.push(format!(
+                                "{}: no content above {:.1} kHz at {} kHz, likely upsampled",
+                                name,
+                                cutoff_khz,
+                                analysis.sample_rate as f32 / 1000.0
+                            ));
+                        }
+                    }
+                    Err(e) => issues.push(format!("could not decode {}: {}", name, e)),
+                }
+            }
+
+            if let (Some(min), Some(depth)) = (min_bit_depth, bit_depth) {
+                if depth < min {
+                    issues.push(format!("{} is {}-bit, below {}-bit", name, depth, min));
+                }
+            }
+            if let (Some(min), Some(rate)) = (min_sample_rate, info.audio_sample_rate) {
+                if rate < min {
+                    issues.push(format!(
+                        "{} is {} kHz, below {} kHz",
+                        name,
+                        rate as f32 / 1000.0,
+                        min as f32 / 1000.0
+                    ));
+                }
+            }
+            if config.reject_suspect_lossless {
+                issues.extend(suspect.iter().cloned());
+            }
+
+            report.files_checked += 1;
+            report.bit_depth = min_some(report.bit_depth, bit_depth);
+            report.sample_rate_hz = min_some(report.sample_rate_hz, info.audio_sample_rate);
+            report.warnings.extend(suspect);
+        }
+        if report.files_checked > 0 {
+            audio_quality = Some(report);
+        }
+    }
+
+    // A file can fail several checks with the same probe error
+    let mut seen = BTreeSet::new();
+    issues.retain(|issue| seen.insert(issue.clone()));
+
+    if issues.is_empty() {
+        Ok(audio_quality)
+    } else {
+        Err(VerificationFailure { issues })
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Probes each file once, remembering results and errors.
+struct Prober<'a> {
+    converter: &'a dyn Converter,
+    results: HashMap<PathBuf, Result<MediaInfo, String>>,
+}
+
+impl<'a> Prober<'a> {
+    fn new(converter: &'a dyn Converter) -> Self {
+        Self {
+            converter,
+            results: HashMap::new(),
+        }
+    }
+
+    async fn info(&mut self, path: &Path) -> Result<MediaInfo, String> {
+        if let Some(result) = self.results.get(path) {
+            return result.clone();
+        }
+        let result = self
+            .converter
+            .probe(path)
+            .await
+            .map_err(|e| format!("could not probe {}: {}", file_name(path), e));
+        self.results.insert(path.to_path_buf(), result.clone());
+        result
+    }
+}
+
+/// Resolve a mapping path (relative to the torrent's save directory, or to
+/// the download itself).
+fn resolve_path(download_path: &Path, torrent_file_path: &str) -> PathBuf {
+    let save_dir = download_path.parent().unwrap_or(download_path);
+    let in_save_dir = save_dir.join(torrent_file_path);
+    if in_save_dir.exists() {
+        in_save_dir
+    } else {
+        download_path.join(torrent_file_path)
+    }
+}
+
+/// Mapped video files, or the download itself when it is a single video file.
+fn video_files(download_path: &Path, mapped: &[(&str, PathBuf)]) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = Vec::new();
+    for (_, path) in mapped {
+        if is_video_file(path) && !files.contains(path) {
+            files.push(path.clone());
+        }
+    }
+    if files.is_empty() && mapped.is_empty() && is_video_file(download_path) {
+        files.push(download_path.to_path_buf());
+    }
+    files
+}
+
+fn is_video_file(path: &Path) -> bool {
+    let extensions = DumbFileMapperConfig::default().video_extensions;
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| extensions.iter().any(|v| v.eq_ignore_ascii_case(ext)))
+}
+
+/// Whether a probed file holds lossless audio.
+fn is_lossless(info: &MediaInfo) -> bool {
+    info.audio_codec.as_deref().is_some_and(|codec| {
+        matches!(codec, "flac" | "alac" | "ape" | "wavpack" | "tta") || codec.starts_with("pcm_")
+    })
+}
+
+/// The lower of two optional values, ignoring missing ones.
+fn min_some<T: Ord>(a: Option<T>, b: Option<T>) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Whether a mapping item is an episode (`s01e02`).
+fn is_episode_item(item_id: &str) -> bool {
+    item_id
+        .strip_prefix('s')
+        .and_then(|rest| rest.split_once('e'))
+        .is_some_and(|(season, episode)| {
+            !season.is_empty()
+                && !episode.is_empty()
+                && season.chars().all(|c| c.is_ascii_digit())
+                && episode.chars().all(|c| c.is_ascii_digit())
+        })
+}
+
+/// Number of episodes the ticket expects, if known.
+fn expected_episode_count(context: &QueryContext) -> Option<usize> {
+    let catalog_count = match &context.catalog_reference {
+        Some(CatalogReference::Tmdb {
+            media_type: TmdbMediaType::Tv,
+            episode_count: Some(count),
+            ..
+        }) => Some(*count as usize),
+        _ => None,
+    };
+
+    let count = match context.expected.as_ref()? {
+        ExpectedContent::TvEpisode { episodes, .. } => episodes.len(),
+        ExpectedContent::TvSeason { episodes, .. } => {
+            catalog_count.unwrap_or(0).max(episodes.len())
+        }
+        ExpectedContent::TvSeries {
+            resolved_seasons, ..
+        } => resolved_seasons.iter().map(|s| s.episodes.len()).sum(),
+        _ => return None,
+    };
+    (count > 0).then_some(count)
+}
+
+fn expected_track_secs(track: &crate::ticket::ExpectedTrack) -> Option<f64> {
+    track
+        .duration_ms
+        .map(|ms| ms as f64 / 1000.0)
+        .or(track.duration_secs.map(f64::from))
+}
+
+/// Compare a probed duration against the expected one.
+fn check_duration(
+    label: &str,
+    actual_secs: f64,
+    expected_secs: f64,
+    config: &VerificationConfig,
+) -> Option<String> {
+    let tolerance = (expected_secs * config.duration_tolerance_pct as f64 / 100.0)
+        .max(config.duration_tolerance_secs as f64);
+    if (actual_secs - expected_secs).abs() <= tolerance {
+        return None;
+    }
+    Some(format!(
+        "{} is {}, expected {}",
+        label,
+        format_duration(actual_secs),
+        format_duration(expected_secs)
+    ))
+}
+
+/// Format seconds as `m:ss` (or `h:mm:ss`).
+fn format_duration(secs: f64) -> String {
+    let total = secs.round() as u64;
+    let (hours, minutes, seconds) = (total / 3600, (total % 3600) / 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::converter::{ConverterError, PcmAudio};
+    use crate::testing::MockConverter;
+    use crate::textbrain::FileMapping;
+    use crate::ticket::{
+        AudioSearchConstraints, ExpectedTrack, Resolution, SearchConstraints,
+        VideoSearchConstraints,
+    };
+
+    fn media_info(path: &str, duration_secs: f64, size: Option<(u32, u32)>) -> MediaInfo {
+        MediaInfo {
+            path: PathBuf::from(path),
+            size_bytes: 1024,
+            duration_secs,
+            format: "test".to_string(),
+            audio_codec: None,
+            audio_bitrate_kbps: None,
+            audio_sample_rate: None,
+            audio_channels: None,
+            audio_bit_depth: None,
+            video_codec: size.map(|_| "h264".to_string()),
+            video_width: size.map(|(w, _)| w),
+            video_height: size.map(|(_, h)| h),
+            video_fps: None,
+        }
+    }
+
+    fn download(mappings: &[(&str, &str)]) -> CompletedDownload {
+        CompletedDownload {
+            info_hash: "abc123".to_string(),
+            path: "/nonexistent/downloads/Release".to_string(),
+            file_mappings: mappings
+                .iter()
+                .map(|(file, item)| FileMapping {
+                    torrent_file_path: file.to_string(),
+                    ticket_item_id: item.to_string(),
+                    confidence: 0.9,
+                })
+                .collect(),
+            audio_quality: None,
+        }
+    }
+
+    fn album_context() -> QueryContext {
+        QueryContext::new(vec!["music".to_string()], "Test Album").with_expected(
+            ExpectedContent::Album {
+                artist: Some("Artist".to_string()),
+                title: "Test Album".to_string(),
+                tracks: vec![
+                    ExpectedTrack::new(1, "One").with_duration_ms(200_000),
+                    ExpectedTrack::new(2, "Two").with_duration(300),
+                ],
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_matching_track_durations_pass() {
+        let converter = MockConverter::new();
+        let path = "/nonexistent/downloads/Release";
+        converter
+            .set_probe_result(
+                format!("{}/01.flac", path),
+                media_info("01.flac", 201.0, None),
+            )
+            .await;
+        converter
+            .set_probe_result(
+                format!("{}/02.flac", path),
+                media_info("02.flac", 298.5, None),
+            )
+            .await;
+
+        let result = verify_download(
+            &converter,
+            &album_context(),
+            &download(&[("01.flac", "track-1"), ("02.flac", "track-2")]),
+            &VerificationConfig::default(),
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_truncated_track_fails() {
+        let converter = MockConverter::new();
+        converter
+            .set_probe_result(
+                "/nonexistent/downloads/Release/02.flac",
+                media_info("02.flac", 95.0, None),
+            )
+            .await;
+        converter
+            .set_default_media_info(media_info("", 200.0, None))
+            .await;
+
+        let failure = verify_download(
+            &converter,
+            &album_context(),
+            &download(&[("01.flac", "track-1"), ("02.flac", "track-2")]),
+            &VerificationConfig::default(),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(failure.issues, vec!["track 2 is 1:35, expected 5:00"]);
+    }
+
+    #[tokio::test]
+    async fn test_cue_image_checked_against_total() {
+        let converter = MockConverter::new();
+        converter
+            .set_default_media_info(media_info("", 500.0, None))
+            .await;
+
+        let result = verify_download(
+            &converter,
+            &album_context(),
+            &download(&[("image.flac", "track-1"), ("image.flac", "track-2")]),
+            &VerificationConfig::default(),
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_unprobeable_file_fails() {
+        let converter = MockConverter::new();
+        converter
+            .set_next_error(ConverterError::ProbeFailed {
+                reason: "moov atom not found".to_string(),
+            })
+            .await;
+
+        let failure = verify_download(
+            &converter,
+            &album_context(),
+            &download(&[("01.flac", "track-1")]),
+            &VerificationConfig::default(),
+        )
+        .await
+        .unwrap_err();
+        assert!(failure.issues[0].starts_with("could not probe 01.flac"));
+    }
+
+    #[tokio::test]
+    async fn test_movie_runtime_and_resolution() {
+        let converter = MockConverter::new();
+        converter
+            .set_default_media_info(media_info("", 5400.0, Some((1280, 536))))
+            .await;
+        let context = QueryContext::new(vec!["movie".to_string()], "Test Movie")
+            .with_catalog_reference(CatalogReference::Tmdb {
+                id: 1,
+                media_type: TmdbMediaType::Movie,
+                runtime_minutes: Some(130),
+                episode_count: None,
+                absolute_offset: None,
+            })
+            .with_search_constraints(SearchConstraints {
+                video: Some(VideoSearchConstraints {
+                    min_resolution: Some(Resolution::R1080p),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+
+        let failure = verify_download(
+            &converter,
+            &context,
+            &download(&[("Movie.mkv", "movie")]),
+            &VerificationConfig::default(),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            failure.issues,
+            vec![
+                "runtime is 1:30:00, expected 2:10:00",
+                "Movie.mkv is 1280x536, below 1080p",
+            ]
+        );
+
+        // Cropped 1080p passes on width
+        converter
+            .set_default_media_info(media_info("", 7800.0, Some((1920, 800))))
+            .await;
+        let result = verify_download(
+            &converter,
+            &context,
+            &download(&[("Movie.mkv", "movie")]),
+            &VerificationConfig::default(),
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_missing_episodes_fail() {
+        let converter = MockConverter::new();
+        let context = QueryContext::new(vec!["tv".to_string()], "Show S01")
+            .with_expected(ExpectedContent::TvSeason {
+                series: "Show".to_string(),
+                season: 1,
+                episodes: vec![],
+            })
+            .with_catalog_reference(CatalogReference::Tmdb {
+                id: 2,
+                media_type: TmdbMediaType::Tv,
+                runtime_minutes: None,
+                episode_count: Some(3),
+                absolute_offset: None,
+            });
+
+        let failure = verify_download(
+            &converter,
+            &context,
+            &download(&[("S01E01.mkv", "s01e01"), ("S01E02.mkv", "s01e02")]),
+            &VerificationConfig::default(),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(failure.issues, vec!["2 of 3 episodes present"]);
+    }
+
+    #[tokio::test]
+    async fn test_no_expectations_pass() {
+        let converter = MockConverter::new();
+        converter
+            .set_next_error(ConverterError::ProbeFailed {
+                reason: "unused".to_string(),
+            })
+            .await;
+        let context = QueryContext::new(vec!["music".to_string()], "Anything");
+
+        let result = verify_download(
+            &converter,
+            &context,
+            &download(&[("01.flac", "track-1")]),
+            &VerificationConfig::default(),
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    fn lossless_info(path: &str, duration_secs: f64, bits: u8, rate: u32) -> MediaInfo {
+        MediaInfo {
+            audio_codec: Some("flac".to_string()),
+            audio_sample_rate: Some(rate),
+            audio_channels: Some(1),
+            audio_bit_depth: Some(bits),
+            ..media_info(path, duration_secs, None)
+        }
+    }
+
+    /// One second of dithered 16-bit mono audio with content up to `max_hz`.
+    fn band_limited_pcm(max_hz: f64) -> PcmAudio {
+        let freqs: Vec<f64> = (1..)
+            .map(|i| i as f64 * 500.0)
+            .take_while(|f| *f <= max_hz)
+            .collect();
+        let mut state: u32 = 1;
+        let samples = (0..44100)
+            .map(|i| {
+                let t = i as f64 / 44100.0;
+                let value: f64 = freqs
+                    .iter()
+                    .map(|f| 0.5 / freqs.len() as f64 * (2.0 * std::f64::consts::PI * f * t).sin())
+                    .sum();
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                let dither = (state >> 8) as f64 / (1u32 << 24) as f64 - 0.5;
+                ((value * i16::MAX as f64 + dither).round() as i32) << 16
+            })
+            .collect();
+        PcmAudio {
+            sample_rate: 44100,
+            channels: 1,
+            samples,
+        }
+    }
+
+    async fn lossless_album(converter: &MockConverter) {
+        let path = "/nonexistent/downloads/Release";
+        converter
+            .set_probe_result(
+                format!("{}/01.flac", path),
+                lossless_info("01.flac", 200.0, 16, 44100),
+            )
+            .await;
+        converter
+            .set_probe_result(
+                format!("{}/02.flac", path),
+                lossless_info("02.flac", 300.0, 16, 44100),
+            )
+            .await;
+        converter
+            .set_pcm_result(format!("{}/02.flac", path), band_limited_pcm(16_000.0))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_lossless_quality_is_reported() {
+        let converter = MockConverter::new();
+        lossless_album(&converter).await;
+
+        let report = verify_download(
+            &converter,
+            &album_context(),
+            &download(&[("01.flac", "track-1"), ("02.flac", "track-2")]),
+            &VerificationConfig::default(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(report.files_checked, 2);
+        assert_eq!(report.bit_depth, Some(16));
+        assert_eq!(report.sample_rate_hz, Some(44100));
+        assert_eq!(report.warnings.len(), 1);
+        assert!(report.warnings[0].starts_with("02.flac: spectrum cut off at 16."));
+        assert!(report.warnings[0].ends_with("likely lossy source"));
+    }
+
+    #[tokio::test]
+    async fn test_suspect_lossless_can_fail() {
+        let converter = MockConverter::new();
+        lossless_album(&converter).await;
+        let config = VerificationConfig {
+            reject_suspect_lossless: true,
+            ..Default::default()
+        };
+
+        let failure = verify_download(
+            &converter,
+            &album_context(),
+            &download(&[("01.flac", "track-1"), ("02.flac", "track-2")]),
+            &config,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(failure.issues.len(), 1);
+        assert!(failure.issues[0].ends_with("likely lossy source"));
+    }
+
+    #[tokio::test]
+    async fn test_padded_bit_depth_below_minimum_fails() {
+        let converter = MockConverter::new();
+        // Decodes to the mock's 16-bit audio
+        converter
+            .set_default_media_info(lossless_info("", 200.0, 24, 96000))
+            .await;
+        let context = album_context().with_search_constraints(SearchConstraints {
+            audio: Some(AudioSearchConstraints {
+                min_bit_depth: Some(24),
+                min_sample_rate_hz: Some(96000),
+                ..Default::default()
+            }),
+            video: None,
+        });
+
+        let failure = verify_download(
+            &converter,
+            &context,
+            &download(&[("01.flac", "track-1")]),
+            &VerificationConfig::default(),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(failure.issues, vec!["01.flac is 16-bit, below 24-bit"]);
+    }
+}
@@ -8,7 +8,8 @@ use tokio::sync::{mpsc, RwLock, Semaphore};
 
 use crate::audit::{AuditEvent, AuditHandle};
 use crate::converter::{
-    ConversionConstraints, ConversionJob, ConversionProgress, Converter, EmbeddedMetadata,
+    ChapterInput, ChapterMergeJob, ConversionConstraints, ConversionJob, ConversionProgress,
+    Converter, EmbeddedMetadata,
 };
 use crate::metrics;
 use crate::placer::{FilePlacement, PlacementJob, Placer};
@@ -140,6 +141,11 @@ impl<C: Converter + 'static, P: Placer + 'static> PipelineProcessor<C, P> {
         }
     }
 
+    /// Returns the converter used for probing and conversion.
+    pub fn converter(&self) -> &C {
+        &self.converter
+    }
+
     /// Sets the audit handle for logging events.
     pub fn with_audit(mut self, audit: AuditHandle) -> Self {
         self.audit = Some(audit);
@@ -204,6 +210,19 @@ impl<C: Converter + 'static, P: Placer + 'static> PipelineProcessor<C, P> {
         }
     }
 
+    /// Removes any temp files left behind for a ticket.
+    ///
+    /// Used when resuming a job that was interrupted mid-conversion, so the
+    /// re-run does not pick up partially written outputs.
+    pub async fn cleanup_temp_files(&self, ticket_id: &str) -> std::io::Result<()> {
+        let temp_dir = self.config.temp_dir.join(ticket_id);
+        match tokio::fs::remove_dir_all(&temp_dir).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Processes a job through the pipeline.
     ///
     /// Returns immediately, processing happens in the background.
@@ -340,6 +359,7 @@ impl<C: Converter + 'static, P: Placer + 'static> PipelineProcessor<C, P> {
                     current_idx: 0,
                     total: total_files,
                     current_name: "Starting...".to_string(),
+                    download: job.download.clone(),
                 },
             );
 
@@ -423,7 +443,15 @@ impl<C: Converter + 'static, P: Placer + 'static> PipelineProcessor<C, P> {
             // Run conversion for each file
             let constraints = job.constraints.as_ref().unwrap();
 
-            for (idx, source_file) in job.source_files.iter().enumerate() {
+            // An audiobook's chapters are merged into one file in a single pass
+            let conversion_units = match constraints {
+                ConversionConstraints::Audiobook(_) => {
+                    &job.source_files[..job.source_files.len().min(1)]
+                }
+                _ => &job.source_files[..],
+            };
+
+            for (idx, source_file) in conversion_units.iter().enumerate() {
                 let current_file_name = source_file
                     .path
                     .file_name()
@@ -451,6 +479,7 @@ impl<C: Converter + 'static, P: Placer + 'static> PipelineProcessor<C, P> {
                             current_idx: idx,
                             total: total_files,
                             current_name: current_file_name.clone(),
+                            download: job.download.clone(),
                         },
                     );
                 }
@@ -481,28 +510,27 @@ impl<C: Converter + 'static, P: Placer + 'static> PipelineProcessor<C, P> {
                 }
 
                 // Build conversion job
-                let output_ext = match constraints {
-                    ConversionConstraints::Audio(a) => a.format.extension(),
-                    ConversionConstraints::Video(v) => {
-                        v.container.extension(v.audio.as_ref().map(|a| &a.format))
-                    }
-                };
+                let output_ext = constraints.output_extension();
                 let output_path = temp_dir.join(format!("{}.{}", source_file.item_id, output_ext));
 
-                let metadata = job.metadata.as_ref().map(|m| EmbeddedMetadata {
-                    title: m.title.clone(),
-                    artist: m.artist.clone(),
-                    album: m.album.clone(),
-                    album_artist: m.album_artist.clone(),
-                    year: m.year,
-                    track_number: m.track_number,
-                    track_total: m.track_total,
-                    disc_number: m.disc_number,
-                    disc_total: m.disc_total,
-                    genre: m.genre.clone(),
-                    comment: m.comment.clone(),
-                    extra: Default::default(),
-                });
+                let metadata = source_file
+                    .metadata
+                    .as_ref()
+                    .or(job.metadata.as_ref())
+                    .map(|m| EmbeddedMetadata {
+                        title: m.title.clone(),
+                        artist: m.artist.clone(),
+                        album: m.album.clone(),
+                        album_artist: m.album_artist.clone(),
+                        year: m.year,
+                        track_number: m.track_number,
+                        track_total: m.track_total,
+                        disc_number: m.disc_number,
+                        disc_total: m.disc_total,
+                        genre: m.genre.clone(),
+                        comment: m.comment.clone(),
+                        extra: Default::default(),
+                    });
 
                 let conv_job = ConversionJob {
                     job_id: format!("{}-{}", ticket_id, source_file.item_id),
@@ -511,6 +539,7 @@ impl<C: Converter + 'static, P: Placer + 'static> PipelineProcessor<C, P> {
                     constraints: constraints.clone(),
                     metadata,
                     cover_art_path: job.metadata.as_ref().and_then(|m| m.cover_art.clone()),
+                    segment: source_file.segment,
                 };
 
                 // Create channel for FFmpeg progress updates
@@ -539,9 +568,35 @@ impl<C: Converter + 'static, P: Placer + 'static> PipelineProcessor<C, P> {
                 });
 
                 // Run conversion with progress
-                let conv_result = converter
-                    .convert_with_progress(conv_job, conv_progress_tx)
-                    .await;
+                let conv_result = match constraints {
+                    ConversionConstraints::Audiobook(book) => {
+                        let merge_job = ChapterMergeJob {
+                            job_id: conv_job.job_id,
+                            chapters: job
+                                .source_files
+                                .iter()
+                                .map(|chapter| ChapterInput {
+                                    path: chapter.path.clone(),
+                                    title: chapter
+                                        .path
+                                        .file_stem()
+                                        .map(|s| s.to_string_lossy().to_string())
+                                        .unwrap_or_else(|| chapter.item_id.clone()),
+                                })
+                                .collect(),
+                            output_path: conv_job.output_path,
+                            constraints: book.clone(),
+                            metadata: conv_job.metadata,
+                            cover_art_path: conv_job.cover_art_path,
+                        };
+                        converter.merge_chapters(merge_job, conv_progress_tx).await
+                    }
+                    _ => {
+                        converter
+                            .convert_with_progress(conv_job, conv_progress_tx)
+                            .await
+                    }
+                };
 
                 // Wait for progress forwarder to complete
                 let _ = progress_forwarder.await;
@@ -739,6 +794,7 @@ impl<C: Converter + 'static, P: Placer + 'static> PipelineProcessor<C, P> {
                     started_at: chrono::Utc::now(),
                     files_placed: 0,
                     total_files: files_to_place,
+                    download: job.download.clone(),
                 },
             );
 
@@ -838,6 +894,10 @@ impl<C: Converter + 'static, P: Placer + 'static> PipelineProcessor<C, P> {
                                 conversion_duration_secs: conversion_duration.as_secs() as u32,
                                 final_size_bytes: result.total_bytes,
                                 files_placed: files_placed.len() as u32,
+                                audio_quality: job
+                                    .download
+                                    .as_ref()
+                                    .and_then(|d| d.audio_quality.clone()),
                             },
                         },
                     );
@@ -980,6 +1040,7 @@ mod tests {
             })),
             dest_dir: PathBuf::from("/tmp/test"),
             metadata: None,
+            download: None,
         };
 
         let result = processor.process(job, None).await;
@@ -3,8 +3,9 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
-use crate::converter::ConversionConstraints;
+use crate::converter::{ConversionConstraints, TimeSegment};
 use crate::textbrain::FileMapping;
+use crate::ticket::CompletedDownload;
 
 /// Status of a processing pool.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,6 +54,9 @@ pub struct PipelineJob {
     pub dest_dir: PathBuf,
     /// Metadata to embed (optional).
     pub metadata: Option<PipelineMetadata>,
+    /// Download the job was built from, persisted with the ticket state so
+    /// processing can be resumed after a restart (None for ad-hoc jobs).
+    pub download: Option<CompletedDownload>,
 }
 
 /// A source file to convert.
@@ -64,6 +68,10 @@ pub struct SourceFile {
     pub item_id: String,
     /// Destination filename (without directory).
     pub dest_filename: String,
+    /// Part of the source to extract, for tracks of a single-file album image.
+    pub segment: Option<TimeSegment>,
+    /// Metadata for this file, overriding the job metadata.
+    pub metadata: Option<PipelineMetadata>,
 }
 
 /// Metadata to embed during conversion.
@@ -95,7 +95,7 @@ pub fn deduplicate_results(raw: Vec<RawTorrentResult>) -> Vec<TorrentCandidate>
 
     let mut results: Vec<_> = by_hash.into_values().chain(no_hash).collect();
     // Sort by total seeders descending
-    results.sort_by(|a, b| b.seeders.cmp(&a.seeders));
+    results.sort_by_key(|r| std::cmp::Reverse(r.seeders));
     results
 }
 
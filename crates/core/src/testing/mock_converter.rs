@@ -8,8 +8,8 @@ use std::time::Duration;
 use tokio::sync::{mpsc, RwLock};
 
 use crate::converter::{
-    ConversionConstraints, ConversionJob, ConversionProgress, ConversionResult, Converter,
-    ConverterError, MediaInfo,
+    ChapterMergeJob, ConversionJob, ConversionProgress, ConversionResult, Converter,
+    ConverterError, MediaInfo, PcmAudio, TimeSegment,
 };
 
 /// A recorded conversion job for test assertions.
@@ -27,6 +27,7 @@ pub struct RecordedConversion {
 /// - Track conversion jobs for assertions
 /// - Simulate success/failure
 /// - Control probe results
+/// - Control decoded PCM audio
 /// - Simulate progress updates
 ///
 /// # Example
@@ -54,12 +55,18 @@ pub struct RecordedConversion {
 pub struct MockConverter {
     /// Recorded conversions.
     conversions: Arc<RwLock<Vec<RecordedConversion>>>,
+    /// Recorded chapter merges.
+    merges: Arc<RwLock<Vec<ChapterMergeJob>>>,
     /// Pre-configured probe results by path.
     probe_results: Arc<RwLock<HashMap<PathBuf, MediaInfo>>>,
+    /// Pre-configured decoded audio by path.
+    pcm_results: Arc<RwLock<HashMap<PathBuf, PcmAudio>>>,
     /// If set, the next operation will fail with this error.
     next_error: Arc<RwLock<Option<ConverterError>>>,
     /// Simulated conversion duration in milliseconds.
     conversion_duration_ms: Arc<RwLock<u64>>,
+    /// Simulated probe duration in milliseconds.
+    probe_duration_ms: Arc<RwLock<u64>>,
     /// Whether to send progress updates during conversion.
     send_progress: Arc<RwLock<bool>>,
     /// Default media info for probing unknown files.
@@ -77,9 +84,12 @@ impl MockConverter {
     pub fn new() -> Self {
         Self {
             conversions: Arc::new(RwLock::new(Vec::new())),
+            merges: Arc::new(RwLock::new(Vec::new())),
             probe_results: Arc::new(RwLock::new(HashMap::new())),
+            pcm_results: Arc::new(RwLock::new(HashMap::new())),
             next_error: Arc::new(RwLock::new(None)),
             conversion_duration_ms: Arc::new(RwLock::new(100)),
+            probe_duration_ms: Arc::new(RwLock::new(0)),
             send_progress: Arc::new(RwLock::new(true)),
             default_media_info: Arc::new(RwLock::new(None)),
         }
@@ -90,6 +100,11 @@ impl MockConverter {
         self.conversions.read().await.clone()
     }
 
+    /// Get all recorded chapter merges.
+    pub async fn recorded_merges(&self) -> Vec<ChapterMergeJob> {
+        self.merges.read().await.clone()
+    }
+
     /// Clear recorded conversions.
     pub async fn clear_recorded(&self) {
         self.conversions.write().await.clear();
@@ -108,6 +123,14 @@ impl MockConverter {
             .insert(path.as_ref().to_path_buf(), info);
     }
 
+    /// Set the decoded audio returned for a specific path.
+    pub async fn set_pcm_result(&self, path: impl AsRef<Path>, pcm: PcmAudio) {
+        self.pcm_results
+            .write()
+            .await
+            .insert(path.as_ref().to_path_buf(), pcm);
+    }
+
     /// Set the default media info for probing unknown files.
     pub async fn set_default_media_info(&self, info: MediaInfo) {
         *self.default_media_info.write().await = Some(info);
@@ -133,6 +156,11 @@ impl MockConverter {
         *self.conversion_duration_ms.write().await = duration.as_millis() as u64;
     }
 
+    /// Set the simulated probe duration.
+    pub async fn set_probe_duration(&self, duration: Duration) {
+        *self.probe_duration_ms.write().await = duration.as_millis() as u64;
+    }
+
     /// Enable or disable progress updates during conversion.
     pub async fn set_send_progress(&self, send: bool) {
         *self.send_progress.write().await = send;
@@ -161,6 +189,7 @@ impl MockConverter {
             audio_bitrate_kbps: Some(320),
             audio_sample_rate: Some(48000),
             audio_channels: Some(2),
+            audio_bit_depth: None,
             video_codec: if is_video {
                 Some("h264".to_string())
             } else {
@@ -171,6 +200,23 @@ impl MockConverter {
             video_fps: if is_video { Some(24.0) } else { None },
         }
     }
+
+    /// Create default decoded audio for testing: two seconds of full-band
+    /// 16-bit stereo noise, which passes spectral checks.
+    fn create_default_pcm() -> PcmAudio {
+        let mut state: u32 = 0x1234_5678;
+        let samples = (0..44100 * 2 * 2)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                ((state >> 16) as i16 as i32) << 16
+            })
+            .collect();
+        PcmAudio {
+            sample_rate: 44100,
+            channels: 2,
+            samples,
+        }
+    }
 }
 
 #[async_trait]
@@ -184,6 +230,12 @@ impl Converter for MockConverter {
             return Err(err);
         }
 
+        // Simulate probe time
+        let duration_ms = *self.probe_duration_ms.read().await;
+        if duration_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(duration_ms)).await;
+        }
+
         // Check for pre-configured result
         if let Some(info) = self.probe_results.read().await.get(path) {
             return Ok(info.clone());
@@ -200,6 +252,22 @@ impl Converter for MockConverter {
         Ok(Self::create_default_info(path))
     }
 
+    async fn decode_pcm(
+        &self,
+        path: &Path,
+        _segment: Option<TimeSegment>,
+    ) -> Result<PcmAudio, ConverterError> {
+        if let Some(err) = self.take_error().await {
+            return Err(err);
+        }
+
+        if let Some(pcm) = self.pcm_results.read().await.get(path) {
+            return Ok(pcm.clone());
+        }
+
+        Ok(Self::create_default_pcm())
+    }
+
     async fn convert(&self, job: ConversionJob) -> Result<ConversionResult, ConverterError> {
         if let Some(err) = self.take_error().await {
             self.conversions.write().await.push(RecordedConversion {
@@ -222,10 +290,7 @@ impl Converter for MockConverter {
         }
 
         // Determine output format
-        let output_format = match &job.constraints {
-            ConversionConstraints::Audio(audio) => audio.format.extension().to_string(),
-            ConversionConstraints::Video(video) => video.container.extension(None).to_string(),
-        };
+        let output_format = job.constraints.output_extension().to_string();
 
         Ok(ConversionResult {
             job_id: job.job_id,
@@ -276,6 +341,38 @@ impl Converter for MockConverter {
         self.convert(job).await
     }
 
+    async fn merge_chapters(
+        &self,
+        job: ChapterMergeJob,
+        _progress_tx: mpsc::Sender<ConversionProgress>,
+    ) -> Result<ConversionResult, ConverterError> {
+        if let Some(err) = self.take_error().await {
+            return Err(err);
+        }
+
+        self.merges.write().await.push(job.clone());
+
+        let duration_ms = *self.conversion_duration_ms.read().await;
+        if duration_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(duration_ms)).await;
+        }
+
+        Ok(ConversionResult {
+            job_id: job.job_id,
+            output_path: job.output_path,
+            output_size_bytes: 50 * 1024 * 1024,
+            duration_ms,
+            input_format: job
+                .chapters
+                .first()
+                .and_then(|c| c.path.extension())
+                .and_then(|e| e.to_str())
+                .unwrap_or("unknown")
+                .to_string(),
+            output_format: "m4b".to_string(),
+        })
+    }
+
     async fn validate(&self) -> Result<(), ConverterError> {
         if let Some(err) = self.take_error().await {
             return Err(err);
@@ -299,7 +396,7 @@ impl Converter for MockConverter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::converter::{AudioConstraints, AudioFormat};
+    use crate::converter::{AudioConstraints, AudioFormat, ConversionConstraints};
 
     fn create_test_job(id: &str) -> ConversionJob {
         ConversionJob {
@@ -315,6 +412,7 @@ mod tests {
             }),
             metadata: None,
             cover_art_path: None,
+            segment: None,
         }
     }
 
@@ -353,6 +451,7 @@ mod tests {
             audio_bitrate_kbps: Some(192),
             audio_sample_rate: Some(44100),
             audio_channels: Some(2),
+            audio_bit_depth: None,
             video_codec: None,
             video_width: None,
             video_height: None,
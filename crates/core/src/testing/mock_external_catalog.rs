@@ -6,7 +6,8 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use crate::external_catalog::{
-    ExternalCatalog, ExternalCatalogError, MusicBrainzRelease, TmdbMovie, TmdbSeason, TmdbSeries,
+    ExternalCatalog, ExternalCatalogError, MusicBrainzRelease, MusicBrainzReleaseGroup, TmdbMovie,
+    TmdbSeason, TmdbSeries,
 };
 
 /// A recorded catalog query for test assertions.
@@ -14,6 +15,7 @@ use crate::external_catalog::{
 pub enum RecordedCatalogQuery {
     SearchReleases { query: String, limit: u32 },
     GetRelease { mbid: String },
+    SearchReleaseGroups { artist: String, limit: u32 },
     SearchMovies { query: String, year: Option<u32> },
     GetMovie { tmdb_id: u32 },
     SearchTv { query: String },
@@ -46,6 +48,8 @@ pub enum RecordedCatalogQuery {
 pub struct MockExternalCatalog {
     /// MusicBrainz releases by MBID.
     releases: Arc<RwLock<HashMap<String, MusicBrainzRelease>>>,
+    /// MusicBrainz release groups, in insertion order.
+    release_groups: Arc<RwLock<Vec<MusicBrainzReleaseGroup>>>,
     /// TMDB movies by ID.
     movies: Arc<RwLock<HashMap<u32, TmdbMovie>>>,
     /// TMDB series by ID.
@@ -69,6 +73,7 @@ impl MockExternalCatalog {
     pub fn new() -> Self {
         Self {
             releases: Arc::new(RwLock::new(HashMap::new())),
+            release_groups: Arc::new(RwLock::new(Vec::new())),
             movies: Arc::new(RwLock::new(HashMap::new())),
             series: Arc::new(RwLock::new(HashMap::new())),
             seasons: Arc::new(RwLock::new(HashMap::new())),
@@ -103,6 +108,11 @@ impl MockExternalCatalog {
         self.releases.write().await.clear();
     }
 
+    /// Add a MusicBrainz release group.
+    pub async fn add_release_group(&self, release_group: MusicBrainzReleaseGroup) {
+        self.release_groups.write().await.push(release_group);
+    }
+
     // =========================================================================
     // TMDB Movies Configuration
     // =========================================================================
@@ -257,6 +267,33 @@ impl ExternalCatalog for MockExternalCatalog {
             .ok_or_else(|| ExternalCatalogError::NotFound(format!("Release {} not found", mbid)))
     }
 
+    async fn search_release_groups(
+        &self,
+        artist: &str,
+        limit: u32,
+    ) -> Result<Vec<MusicBrainzReleaseGroup>, ExternalCatalogError> {
+        if let Some(err) = self.take_error().await {
+            return Err(err);
+        }
+
+        self.record(RecordedCatalogQuery::SearchReleaseGroups {
+            artist: artist.to_string(),
+            limit,
+        })
+        .await;
+
+        let artist_lower = artist.to_lowercase();
+        Ok(self
+            .release_groups
+            .read()
+            .await
+            .iter()
+            .filter(|rg| rg.artist_credit.to_lowercase().contains(&artist_lower))
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
     async fn search_movies(
         &self,
         query: &str,
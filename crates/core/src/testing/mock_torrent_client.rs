@@ -126,8 +126,8 @@ impl MockTorrentClient {
                 };
                 // Estimate ETA based on simulated speed
                 let remaining_bytes = torrent.info.size_bytes - torrent.info.downloaded_bytes;
-                if torrent.info.download_speed > 0 {
-                    torrent.info.eta_secs = Some(remaining_bytes / torrent.info.download_speed);
+                if let Some(eta) = remaining_bytes.checked_div(torrent.info.download_speed) {
+                    torrent.info.eta_secs = Some(eta);
                 }
             }
         }
@@ -339,7 +339,7 @@ impl TorrentClient for MockTorrentClient {
             .collect();
 
         // Sort by added_at descending
-        result.sort_by(|a, b| b.added_at.cmp(&a.added_at));
+        result.sort_by_key(|t| std::cmp::Reverse(t.added_at));
 
         Ok(result)
     }
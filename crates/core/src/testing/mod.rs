@@ -34,7 +34,8 @@ pub use mock_torrent_client::MockTorrentClient;
 /// Test fixtures and helper functions.
 pub mod fixtures {
     use crate::external_catalog::{
-        MusicBrainzRelease, MusicBrainzTrack, TmdbEpisode, TmdbMovie, TmdbSeason, TmdbSeries,
+        MusicBrainzRelease, MusicBrainzReleaseGroup, MusicBrainzTrack, TmdbEpisode, TmdbMovie,
+        TmdbSeason, TmdbSeries,
     };
     use crate::searcher::{TorrentCandidate, TorrentSource};
 
@@ -97,6 +98,22 @@ pub mod fixtures {
         }
     }
 
+    /// Create a test MusicBrainz release group for a studio album.
+    pub fn musicbrainz_release_group(
+        artist: &str,
+        title: &str,
+        year: u32,
+    ) -> MusicBrainzReleaseGroup {
+        MusicBrainzReleaseGroup {
+            mbid: format!("rg-{}", title.to_lowercase().replace(' ', "-")),
+            title: title.to_string(),
+            artist_credit: artist.to_string(),
+            primary_type: Some("Album".to_string()),
+            secondary_types: vec![],
+            first_release_date: Some(format!("{}-01-01", year)),
+        }
+    }
+
     /// Create a test TMDB movie.
     pub fn tmdb_movie(title: &str, year: u32) -> TmdbMovie {
         TmdbMovie {
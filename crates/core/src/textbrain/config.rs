@@ -1,8 +1,14 @@
 //! TextBrain configuration types.
 
+use std::collections::HashMap;
+use std::path::PathBuf;
+
 use serde::{Deserialize, Serialize};
 
 use crate::searcher::FileEnricherConfig;
+use crate::textbrain::llm_budget::{LlmBudgetConfig, ModelPricing};
+use crate::textbrain::llm_cache::LlmCacheConfig;
+use crate::textbrain::llm_fallback::LlmCircuitBreakerConfig;
 
 /// TextBrain coordination mode.
 ///
@@ -78,6 +84,76 @@ pub struct LlmConfig {
     /// Maximum tokens for completions.
     #[serde(default = "default_max_tokens")]
     pub max_tokens: u32,
+    /// Completion cache configuration.
+    #[serde(default)]
+    pub cache: LlmCacheConfig,
+    /// Daily and monthly usage limits.
+    /// Once a limit is reached, TextBrain behaves as in `dumb_only` mode.
+    #[serde(default)]
+    pub budget: LlmBudgetConfig,
+    /// Token prices per model name, used for cost accounting.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub pricing: HashMap<String, ModelPricing>,
+    /// Providers tried in order when the primary provider fails or is
+    /// skipped by its circuit breaker.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallbacks: Vec<LlmProviderConfig>,
+    /// Circuit breaker applied to every provider.
+    #[serde(default)]
+    pub circuit_breaker: LlmCircuitBreakerConfig,
+}
+
+impl LlmConfig {
+    /// The primary provider followed by the fallbacks, in the order they are tried.
+    pub fn providers(&self) -> Vec<LlmProviderConfig> {
+        let primary = LlmProviderConfig {
+            provider: self.provider.clone(),
+            model: self.model.clone(),
+            api_key: self.api_key.clone(),
+            api_base: self.api_base.clone(),
+            timeout_secs: self.timeout_secs,
+        };
+        std::iter::once(primary)
+            .chain(self.fallbacks.iter().cloned())
+            .collect()
+    }
+}
+
+/// A fallback LLM provider.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LlmProviderConfig {
+    /// LLM provider.
+    pub provider: LlmProvider,
+    /// Model name/identifier.
+    pub model: String,
+    /// API key (can reference env var with ${VAR_NAME}).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// Custom API base URL (for proxies or self-hosted).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_base: Option<String>,
+    /// Request timeout in seconds.
+    #[serde(default = "default_timeout")]
+    pub timeout_secs: u32,
+}
+
+impl LlmProviderConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.model.is_empty() {
+            return Err("LLM model name cannot be empty".to_string());
+        }
+        // API key is optional for some providers (e.g., local Ollama)
+        if self.provider != LlmProvider::Ollama && self.api_key.is_none() {
+            // Check if api_base is set (might be using a proxy that doesn't need key)
+            if self.api_base.is_none() {
+                return Err(format!(
+                    "LLM provider {:?} requires api_key or api_base",
+                    self.provider
+                ));
+            }
+        }
+        Ok(())
+    }
 }
 
 fn default_timeout() -> u32 {
@@ -102,6 +178,11 @@ pub struct TextBrainConfig {
     /// If dumb confidence < threshold, try LLM.
     #[serde(default = "default_confidence_threshold")]
     pub confidence_threshold: f32,
+    /// File mapping quality threshold for the LLM file mapper.
+    /// In modes that can use the LLM, candidates whose heuristic file mapping
+    /// quality is below this are re-mapped by the LLM.
+    #[serde(default = "default_file_mapping_threshold")]
+    pub file_mapping_threshold: f32,
     /// Maximum queries to try before giving up.
     #[serde(default = "default_max_queries")]
     pub max_queries: u32,
@@ -112,6 +193,177 @@ pub struct TextBrainConfig {
     /// Controls fetching and caching of torrent file listings.
     #[serde(default)]
     pub file_enrichment: FileEnricherConfig,
+    /// Directory of prompt template overrides (`{task}.toml` or
+    /// `{task}.{content_type}.toml`). Built-in templates are used for the rest.
+    /// Changes are picked up at the next acquisition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompts_dir: Option<PathBuf>,
+    /// Weights of the heuristic scorers.
+    /// Overridden at runtime by the active calibrated scoring profile, if any.
+    #[serde(default)]
+    pub scoring: ScoringWeights,
+}
+
+/// Weights of the heuristic scorers' components.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoringWeights {
+    /// Generic matcher (`DumbMatcher`) weights.
+    #[serde(default)]
+    pub dumb: DumbScoringWeights,
+    /// Music scorer weights.
+    #[serde(default)]
+    pub music: MusicScoringWeights,
+    /// Video scorer weights.
+    #[serde(default)]
+    pub video: VideoScoringWeights,
+}
+
+impl ScoringWeights {
+    /// Validate that all weights are finite and non-negative.
+    pub fn validate(&self) -> Result<(), String> {
+        let all = self
+            .dumb
+            .to_vec()
+            .into_iter()
+            .chain(self.music.to_vec())
+            .chain(self.video.to_vec());
+        for weight in all {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(format!(
+                    "scoring weights must be non-negative, got {}",
+                    weight
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Weights of the generic matcher.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DumbScoringWeights {
+    pub title: f32,
+    pub quality: f32,
+    pub health: f32,
+    pub size: f32,
+}
+
+impl Default for DumbScoringWeights {
+    fn default() -> Self {
+        Self {
+            // Title match is the primary factor - content relevance matters most
+            title: 0.75,
+            quality: 0.15,
+            // Health (seeders) as a tiebreaker
+            health: 0.10,
+            // Size weight disabled - too crude without category-aware thresholds
+            // (50GB is small for a TV series collection, huge for a single album).
+            // The music and video scorers check size against runtime instead.
+            size: 0.0,
+        }
+    }
+}
+
+/// Weights of the music scorer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MusicScoringWeights {
+    pub title: f32,
+    pub format: f32,
+    pub health: f32,
+}
+
+impl Default for MusicScoringWeights {
+    fn default() -> Self {
+        Self {
+            title: 0.40,
+            format: 0.15,
+            health: 0.10,
+        }
+    }
+}
+
+/// Weights of the video scorer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VideoScoringWeights {
+    pub title: f32,
+    pub resolution: f32,
+    pub source: f32,
+    pub codec: f32,
+    pub health: f32,
+}
+
+impl Default for VideoScoringWeights {
+    fn default() -> Self {
+        Self {
+            title: 0.30,
+            resolution: 0.15,
+            source: 0.12,
+            codec: 0.05,
+            health: 0.08,
+        }
+    }
+}
+
+// Flat weight vectors (field order) are what calibration searches over.
+
+impl DumbScoringWeights {
+    /// Weights as a flat vector (field order).
+    pub fn to_vec(&self) -> Vec<f32> {
+        vec![self.title, self.quality, self.health, self.size]
+    }
+
+    /// Build from a flat vector in field order.
+    pub fn from_slice(values: &[f32]) -> Self {
+        Self {
+            title: values[0],
+            quality: values[1],
+            health: values[2],
+            size: values[3],
+        }
+    }
+}
+
+impl MusicScoringWeights {
+    /// Weights as a flat vector (field order).
+    pub fn to_vec(&self) -> Vec<f32> {
+        vec![self.title, self.format, self.health]
+    }
+
+    /// Build from a flat vector in field order.
+    pub fn from_slice(values: &[f32]) -> Self {
+        Self {
+            title: values[0],
+            format: values[1],
+            health: values[2],
+        }
+    }
+}
+
+impl VideoScoringWeights {
+    /// Weights as a flat vector (field order).
+    pub fn to_vec(&self) -> Vec<f32> {
+        vec![
+            self.title,
+            self.resolution,
+            self.source,
+            self.codec,
+            self.health,
+        ]
+    }
+
+    /// Build from a flat vector in field order.
+    pub fn from_slice(values: &[f32]) -> Self {
+        Self {
+            title: values[0],
+            resolution: values[1],
+            source: values[2],
+            codec: values[3],
+            health: values[4],
+        }
+    }
 }
 
 fn default_auto_approve_threshold() -> f32 {
@@ -122,6 +374,10 @@ fn default_confidence_threshold() -> f32 {
     0.7
 }
 
+fn default_file_mapping_threshold() -> f32 {
+    0.6
+}
+
 fn default_max_queries() -> u32 {
     5
 }
@@ -132,9 +388,12 @@ impl Default for TextBrainConfig {
             mode: TextBrainMode::default(),
             auto_approve_threshold: default_auto_approve_threshold(),
             confidence_threshold: default_confidence_threshold(),
+            file_mapping_threshold: default_file_mapping_threshold(),
             max_queries: default_max_queries(),
             llm: None,
             file_enrichment: FileEnricherConfig::default(),
+            prompts_dir: None,
+            scoring: ScoringWeights::default(),
         }
     }
 }
@@ -155,6 +414,13 @@ impl TextBrainConfig {
                 self.confidence_threshold
             ));
         }
+        if !(0.0..=1.0).contains(&self.file_mapping_threshold) {
+            return Err(format!(
+                "file_mapping_threshold must be between 0.0 and 1.0, got {}",
+                self.file_mapping_threshold
+            ));
+        }
+        self.scoring.validate()?;
 
         // Check LLM requirement
         if self.mode.requires_llm() && self.llm.is_none() {
@@ -166,17 +432,21 @@ impl TextBrainConfig {
 
         // Validate LLM config if present
         if let Some(llm) = &self.llm {
-            if llm.model.is_empty() {
-                return Err("LLM model name cannot be empty".to_string());
+            for provider in llm.providers() {
+                provider.validate()?;
+            }
+            if llm.circuit_breaker.failure_threshold == 0 {
+                return Err("llm.circuit_breaker.failure_threshold must be at least 1".to_string());
             }
-            // API key is optional for some providers (e.g., local Ollama)
-            if llm.provider != LlmProvider::Ollama && llm.api_key.is_none() {
-                // Check if api_base is set (might be using a proxy that doesn't need key)
-                if llm.api_base.is_none() {
-                    return Err(format!(
-                        "LLM provider {:?} requires api_key or api_base",
-                        llm.provider
-                    ));
+            // A cost limit never trips for models that cost nothing
+            if llm.budget.daily_cost.is_some() || llm.budget.monthly_cost.is_some() {
+                for provider in llm.providers() {
+                    if !llm.pricing.contains_key(&provider.model) {
+                        return Err(format!(
+                            "llm.budget sets a cost limit, but model {} has no llm.pricing entry",
+                            provider.model
+                        ));
+                    }
                 }
             }
         }
@@ -193,6 +463,73 @@ impl TextBrainConfig {
     }
 }
 
+/// Per-ticket overrides of the global TextBrain settings.
+///
+/// Unset fields keep the global value. Useful to force the LLM and manual
+/// review for rare content, or heuristics with aggressive auto-approval
+/// for bulk imports.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TextBrainOverrides {
+    /// Coordination mode for this ticket.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<TextBrainMode>,
+    /// Score threshold for auto-approval (0.0-1.0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_approve_threshold: Option<f32>,
+    /// Maximum queries to try before giving up.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_queries: Option<u32>,
+    /// Never auto-approve; the best candidates always go to manual review.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub require_approval: bool,
+}
+
+impl TextBrainOverrides {
+    /// Returns true if no setting is overridden.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Validate the overrides against the global configuration.
+    pub fn validate(&self, config: &TextBrainConfig) -> Result<(), String> {
+        if let Some(threshold) = self.auto_approve_threshold {
+            if !(0.0..=1.0).contains(&threshold) {
+                return Err(format!(
+                    "auto_approve_threshold must be between 0.0 and 1.0, got {}",
+                    threshold
+                ));
+            }
+        }
+        if self.max_queries == Some(0) {
+            return Err("max_queries must be at least 1".to_string());
+        }
+        if let Some(mode) = self.mode {
+            if mode.requires_llm() && config.llm.is_none() {
+                return Err(format!(
+                    "Mode {:?} requires LLM configuration, but none provided",
+                    mode
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Return a copy of `config` with the overridden settings applied.
+    pub fn apply(&self, config: &TextBrainConfig) -> TextBrainConfig {
+        let mut config = config.clone();
+        if let Some(mode) = self.mode {
+            config.mode = mode;
+        }
+        if let Some(threshold) = self.auto_approve_threshold {
+            config.auto_approve_threshold = threshold;
+        }
+        if let Some(max_queries) = self.max_queries {
+            config.max_queries = max_queries;
+        }
+        config
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,6 +600,11 @@ mod tests {
                 api_base: Some("http://localhost:5000".to_string()),
                 timeout_secs: 30,
                 max_tokens: 1024,
+                cache: LlmCacheConfig::default(),
+                budget: LlmBudgetConfig::default(),
+                pricing: HashMap::new(),
+                fallbacks: Vec::new(),
+                circuit_breaker: LlmCircuitBreakerConfig::default(),
             }),
             ..Default::default()
         };
@@ -288,6 +630,117 @@ api_key = "sk-test"
         let llm = config.llm.unwrap();
         assert_eq!(llm.provider, LlmProvider::Anthropic);
         assert_eq!(llm.model, "claude-3-haiku-20240307");
+        assert!(llm.cache.enabled);
+        assert_eq!(llm.budget, LlmBudgetConfig::default());
+        assert!(llm.pricing.is_empty());
+    }
+
+    #[test]
+    fn test_llm_budget_and_pricing() {
+        let toml = r#"
+[llm]
+provider = "anthropic"
+model = "claude-3-haiku-20240307"
+
+[llm.budget]
+daily_tokens = 200000
+monthly_cost = 5.0
+
+[llm.pricing."claude-3-haiku-20240307"]
+input_per_mtok = 0.25
+output_per_mtok = 1.25
+"#;
+        let config: TextBrainConfig = toml::from_str(toml).unwrap();
+        let llm = config.llm.unwrap();
+        assert_eq!(llm.budget.daily_tokens, Some(200_000));
+        assert_eq!(llm.budget.monthly_tokens, None);
+        assert_eq!(llm.budget.monthly_cost, Some(5.0));
+        assert_eq!(llm.pricing["claude-3-haiku-20240307"].output_per_mtok, 1.25);
+    }
+
+    #[test]
+    fn test_cost_budget_requires_pricing() {
+        let toml = r#"
+[llm]
+provider = "anthropic"
+model = "claude-3-5-sonnet-latest"
+api_key = "sk-test"
+
+[llm.budget]
+monthly_cost = 5.0
+"#;
+        let mut config: TextBrainConfig = toml::from_str(toml).unwrap();
+        assert!(config.validate().is_err());
+
+        let llm = config.llm.as_mut().unwrap();
+        llm.pricing.insert(
+            "claude-3-5-sonnet-latest".to_string(),
+            ModelPricing {
+                input_per_mtok: 3.0,
+                output_per_mtok: 15.0,
+            },
+        );
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_llm_fallbacks() {
+        let toml = r#"
+[llm]
+provider = "anthropic"
+model = "claude-3-haiku-20240307"
+api_key = "sk-test"
+
+[llm.circuit_breaker]
+failure_threshold = 5
+
+[[llm.fallbacks]]
+provider = "ollama"
+model = "llama3"
+timeout_secs = 120
+"#;
+        let config: TextBrainConfig = toml::from_str(toml).unwrap();
+        assert!(config.validate().is_ok());
+        let llm = config.llm.unwrap();
+        assert_eq!(llm.circuit_breaker.failure_threshold, 5);
+        assert_eq!(llm.circuit_breaker.cooldown_secs, 60);
+
+        let providers = llm.providers();
+        assert_eq!(providers.len(), 2);
+        assert_eq!(providers[0].provider, LlmProvider::Anthropic);
+        assert_eq!(providers[0].timeout_secs, 30);
+        assert_eq!(providers[1].provider, LlmProvider::Ollama);
+        assert_eq!(providers[1].timeout_secs, 120);
+    }
+
+    #[test]
+    fn test_fallback_requires_api_key() {
+        let toml = r#"
+[llm]
+provider = "ollama"
+model = "llama3"
+
+[[llm.fallbacks]]
+provider = "anthropic"
+model = "claude-3-haiku-20240307"
+"#;
+        let config: TextBrainConfig = toml::from_str(toml).unwrap();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_partial_scoring_weights() {
+        let toml = r#"
+[scoring.music]
+format = 0.3
+"#;
+        let config: TextBrainConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.scoring.music.format, 0.3);
+        assert_eq!(
+            config.scoring.music.title,
+            MusicScoringWeights::default().title
+        );
+        assert_eq!(config.scoring.dumb, DumbScoringWeights::default());
     }
 
     #[test]
@@ -301,9 +754,58 @@ api_key = "sk-test"
                 api_base: None,
                 timeout_secs: 60,
                 max_tokens: 2048,
+                cache: LlmCacheConfig::default(),
+                budget: LlmBudgetConfig::default(),
+                pricing: HashMap::new(),
+                fallbacks: Vec::new(),
+                circuit_breaker: LlmCircuitBreakerConfig::default(),
             }),
             ..Default::default()
         };
         assert!(config.validate().is_ok());
     }
+
+    #[test]
+    fn test_overrides_apply() {
+        let overrides = TextBrainOverrides {
+            mode: Some(TextBrainMode::DumbFirst),
+            auto_approve_threshold: Some(0.5),
+            ..Default::default()
+        };
+        let config = overrides.apply(&TextBrainConfig::default());
+        assert_eq!(config.mode, TextBrainMode::DumbFirst);
+        assert_eq!(config.auto_approve_threshold, 0.5);
+        assert_eq!(config.max_queries, default_max_queries());
+        assert!(TextBrainOverrides::default().is_empty());
+        assert!(!overrides.is_empty());
+    }
+
+    #[test]
+    fn test_overrides_validate() {
+        let config = TextBrainConfig::default();
+        let invalid = [
+            TextBrainOverrides {
+                auto_approve_threshold: Some(1.5),
+                ..Default::default()
+            },
+            TextBrainOverrides {
+                max_queries: Some(0),
+                ..Default::default()
+            },
+            TextBrainOverrides {
+                mode: Some(TextBrainMode::LlmOnly),
+                ..Default::default()
+            },
+        ];
+        for overrides in invalid {
+            assert!(overrides.validate(&config).is_err(), "{:?}", overrides);
+        }
+
+        let overrides = TextBrainOverrides {
+            mode: Some(TextBrainMode::LlmFirst),
+            require_approval: true,
+            ..Default::default()
+        };
+        assert!(overrides.validate(&config).is_ok());
+    }
 }
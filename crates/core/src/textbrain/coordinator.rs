@@ -7,12 +7,14 @@ use chrono::{DateTime, Utc};
 use tracing::{debug, info};
 
 use crate::audit::{AuditEvent, AuditHandle};
+use crate::blacklist::Blacklist;
 use crate::content;
 use crate::searcher::{FileEnricher, SearchQuery, Searcher, TorrentCandidate};
 use crate::textbrain::{
     config::{TextBrainConfig, TextBrainMode},
+    file_mapper::calculate_mapping_quality,
     llm::LlmUsage,
-    traits::{CandidateMatcher, QueryBuilder, TextBrainError},
+    traits::{CandidateMatcher, FileMapper, QueryBuilder, TextBrainError},
     types::{AcquisitionResult, MatchResult, QueryBuildResult, ScoredCandidate},
 };
 use crate::ticket::{AcquisitionPhase, QueryContext};
@@ -59,9 +61,14 @@ pub struct TextBrain {
     llm_query_builder: Option<Arc<dyn QueryBuilder>>,
     dumb_matcher: Option<Arc<dyn CandidateMatcher>>,
     llm_matcher: Option<Arc<dyn CandidateMatcher>>,
+    llm_file_mapper: Option<Arc<dyn FileMapper>>,
     file_enricher: Option<Arc<FileEnricher>>,
+    blacklist: Blacklist,
 }
 
+/// Maximum candidates (best first) re-mapped by the LLM file mapper per scoring pass.
+const LLM_FILE_MAPPING_MAX_CANDIDATES: usize = 3;
+
 impl TextBrain {
     /// Create a new TextBrain with the given configuration.
     ///
@@ -73,7 +80,9 @@ impl TextBrain {
             llm_query_builder: None,
             dumb_matcher: None,
             llm_matcher: None,
+            llm_file_mapper: None,
             file_enricher: None,
+            blacklist: Blacklist::default(),
         }
     }
 
@@ -101,12 +110,30 @@ impl TextBrain {
         self
     }
 
+    /// Set the LLM-powered file mapper, used when heuristic file mapping is poor.
+    pub fn with_llm_file_mapper(mut self, mapper: Arc<dyn FileMapper>) -> Self {
+        self.llm_file_mapper = Some(mapper);
+        self
+    }
+
     /// Set the file enricher for fetching torrent file listings.
     pub fn with_file_enricher(mut self, enricher: Arc<FileEnricher>) -> Self {
         self.file_enricher = Some(enricher);
         self
     }
 
+    /// Set the blacklist of candidates that must never be selected
+    /// (rejected or stalled releases, bad release groups, ...).
+    pub fn with_blacklist(mut self, blacklist: Blacklist) -> Self {
+        self.blacklist = blacklist;
+        self
+    }
+
+    /// Check whether a candidate is blacklisted.
+    fn is_blacklisted(&self, candidate: &TorrentCandidate) -> bool {
+        self.blacklist.check(candidate).is_some()
+    }
+
     /// Get the configuration.
     pub fn config(&self) -> &TextBrainConfig {
         &self.config
@@ -130,11 +157,26 @@ impl TextBrain {
     /// Score candidates against the ticket context.
     ///
     /// Uses the configured mode to determine which matchers to use.
+    /// Blacklisted candidates are dropped before scoring. In modes that can
+    /// use the LLM, poorly mapped file listings of the best candidates are
+    /// re-mapped by the LLM file mapper.
     pub async fn score_candidates(
         &self,
         context: &QueryContext,
         candidates: &[TorrentCandidate],
     ) -> Result<MatchResult, TextBrainError> {
+        let filtered: Vec<TorrentCandidate>;
+        let candidates = if self.blacklist.is_empty() {
+            candidates
+        } else {
+            filtered = candidates
+                .iter()
+                .filter(|c| !self.is_blacklisted(c))
+                .cloned()
+                .collect();
+            &filtered
+        };
+
         if candidates.is_empty() {
             return Ok(MatchResult {
                 candidates: vec![],
@@ -143,11 +185,66 @@ impl TextBrain {
             });
         }
 
-        match self.config.mode {
+        let mut result = match self.config.mode {
             TextBrainMode::DumbOnly => self.score_dumb(context, candidates).await,
             TextBrainMode::DumbFirst => self.score_dumb_first(context, candidates).await,
             TextBrainMode::LlmFirst => self.score_llm_first(context, candidates).await,
             TextBrainMode::LlmOnly => self.score_llm(context, candidates).await,
+        }?;
+
+        self.remap_files(context, &mut result).await;
+        Ok(result)
+    }
+
+    /// Re-map the files of the best candidates with the LLM file mapper when
+    /// their mapping quality is below `file_mapping_threshold`.
+    ///
+    /// The LLM mapping replaces the existing one only if it is of higher
+    /// quality; LLM failures keep the existing mapping.
+    async fn remap_files(&self, context: &QueryContext, result: &mut MatchResult) {
+        let (Some(mapper), Some(expected)) = (&self.llm_file_mapper, &context.expected) else {
+            return;
+        };
+        if !self.config.mode.can_use_llm() {
+            return;
+        }
+
+        for scored in result
+            .candidates
+            .iter_mut()
+            .take(LLM_FILE_MAPPING_MAX_CANDIDATES)
+        {
+            let Some(files) = scored.candidate.files.as_ref().filter(|f| !f.is_empty()) else {
+                continue;
+            };
+            let quality = calculate_mapping_quality(&scored.file_mappings, expected);
+            if quality >= self.config.file_mapping_threshold {
+                continue;
+            }
+
+            match mapper.map_files(files, expected).await {
+                Ok(mapped) => {
+                    result.llm_usage = merge_llm_usage(result.llm_usage.take(), mapped.llm_usage);
+                    let mapped_quality = calculate_mapping_quality(&mapped.mappings, expected);
+                    if mapped_quality > quality {
+                        debug!(
+                            info_hash = %scored.candidate.info_hash,
+                            quality,
+                            mapped_quality,
+                            "Using {} file mapping",
+                            mapper.name()
+                        );
+                        scored.file_mappings = mapped.mappings;
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "LLM file mapping failed for {}: {}",
+                        scored.candidate.info_hash,
+                        e
+                    );
+                }
+            }
         }
     }
 
@@ -192,7 +289,7 @@ impl TextBrain {
             let search_query = SearchQuery {
                 query: query_str.clone(),
                 indexers: None,
-                categories: None,
+                categories: content::search_categories(context),
                 limit: Some(50),
             };
 
@@ -423,9 +520,10 @@ impl TextBrain {
             // Score using discography-aware scoring
             for candidate in &search_result.candidates {
                 // Skip if we already have this candidate from primary search
-                if all_scored
-                    .iter()
-                    .any(|c| c.candidate.info_hash == candidate.info_hash)
+                if self.is_blacklisted(candidate)
+                    || all_scored
+                        .iter()
+                        .any(|c| c.candidate.info_hash == candidate.info_hash)
                 {
                     continue;
                 }
@@ -585,9 +683,10 @@ impl TextBrain {
 
             // Score each candidate with discography-aware scoring
             for candidate in &search_result.candidates {
-                if all_scored
-                    .iter()
-                    .any(|c| c.candidate.info_hash == candidate.info_hash)
+                if self.is_blacklisted(candidate)
+                    || all_scored
+                        .iter()
+                        .any(|c| c.candidate.info_hash == candidate.info_hash)
                 {
                     continue;
                 }
@@ -857,7 +956,7 @@ impl TextBrain {
             let search_query = SearchQuery {
                 query: query_str.clone(),
                 indexers: None,
-                categories: None,
+                categories: content::search_categories(context),
                 limit: Some(50),
             };
 
@@ -1350,6 +1449,38 @@ mod tests {
         }
     }
 
+    // Mock file mapper that maps every movie to the first file
+    struct MockFileMapper {
+        calls: std::sync::atomic::AtomicU32,
+    }
+
+    #[async_trait::async_trait]
+    impl FileMapper for MockFileMapper {
+        fn name(&self) -> &str {
+            "mock"
+        }
+
+        async fn map_files(
+            &self,
+            files: &[crate::searcher::TorrentFile],
+            _expected: &crate::ticket::ExpectedContent,
+        ) -> Result<crate::textbrain::types::FileMappingResult, TextBrainError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(crate::textbrain::types::FileMappingResult {
+                mappings: vec![crate::textbrain::types::FileMapping {
+                    torrent_file_path: files[0].path.clone(),
+                    ticket_item_id: "movie".to_string(),
+                    confidence: 0.9,
+                }],
+                method: "mock".to_string(),
+                llm_usage: Some(LlmUsage {
+                    input_tokens: 50,
+                    output_tokens: 10,
+                }),
+            })
+        }
+    }
+
     // Mock searcher for testing
     struct MockSearcher {
         candidates: Vec<TorrentCandidate>,
@@ -1402,6 +1533,48 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_llm_file_mapper_remaps_poor_mappings() {
+        let mut candidate = make_candidate("Heat.1995.1080p");
+        candidate.files = Some(vec![crate::searcher::TorrentFile {
+            path: "Heat.1995.1080p/heat.mkv".to_string(),
+            size_bytes: 8_000_000_000,
+        }]);
+        let context = QueryContext::new(vec!["movie".to_string()], "Heat 1995")
+            .with_expected(crate::ticket::ExpectedContent::movie("Heat"));
+
+        for (mode, expected_calls) in [(TextBrainMode::DumbOnly, 0), (TextBrainMode::DumbFirst, 1)]
+        {
+            let mapper = Arc::new(MockFileMapper {
+                calls: std::sync::atomic::AtomicU32::new(0),
+            });
+            let brain = TextBrain::new(TextBrainConfig {
+                mode,
+                ..Default::default()
+            })
+            .with_dumb_matcher(Arc::new(MockMatcher { score: 0.9 }))
+            .with_llm_file_mapper(mapper.clone());
+
+            let result = brain
+                .score_candidates(&context, &[candidate.clone()])
+                .await
+                .unwrap();
+
+            assert_eq!(
+                mapper.calls.load(std::sync::atomic::Ordering::SeqCst),
+                expected_calls
+            );
+            let mappings = &result.candidates[0].file_mappings;
+            if expected_calls == 0 {
+                assert!(mappings.is_empty());
+                assert!(result.llm_usage.is_none());
+            } else {
+                assert_eq!(mappings[0].torrent_file_path, "Heat.1995.1080p/heat.mkv");
+                assert_eq!(result.llm_usage.unwrap().input_tokens, 50);
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_build_queries_dumb_only() {
         let config = TextBrainConfig {
@@ -1485,4 +1658,34 @@ mod tests {
         assert!(!result.auto_approved);
         assert!(result.best_candidate.is_some());
     }
+
+    #[tokio::test]
+    async fn test_acquire_skips_blacklisted_candidates() {
+        let config = TextBrainConfig {
+            mode: TextBrainMode::DumbOnly,
+            auto_approve_threshold: 0.8,
+            ..Default::default()
+        };
+
+        let brain = TextBrain::new(config)
+            .with_dumb_query_builder(Arc::new(MockQueryBuilder {
+                queries: vec!["test query".to_string()],
+                confidence: 0.9,
+            }))
+            .with_dumb_matcher(Arc::new(MockMatcher { score: 0.95 }))
+            .with_blacklist(Blacklist::default().with_info_hashes(["HASH_Stalled"]));
+
+        let searcher = MockSearcher {
+            candidates: vec![make_candidate("Stalled"), make_candidate("Fresh")],
+        };
+
+        let context = QueryContext::new(vec!["music".to_string()], "Test description");
+        let result = brain.acquire(&context, &searcher).await.unwrap();
+
+        assert_eq!(result.all_candidates.len(), 1);
+        assert_eq!(
+            result.best_candidate.unwrap().candidate.info_hash,
+            "hash_Fresh"
+        );
+    }
 }
@@ -7,6 +7,7 @@ use async_trait::async_trait;
 use std::collections::HashSet;
 
 use crate::searcher::TorrentCandidate;
+use crate::textbrain::config::DumbScoringWeights;
 use crate::textbrain::file_mapper::{calculate_mapping_quality, DumbFileMapper};
 use crate::textbrain::traits::{CandidateMatcher, TextBrainError};
 use crate::textbrain::types::{FileMapping, MatchResult, ScoredCandidate};
@@ -35,15 +36,12 @@ pub struct DumbMatcherConfig {
 
 impl Default for DumbMatcherConfig {
     fn default() -> Self {
+        let weights = DumbScoringWeights::default();
         Self {
-            // Title match is the primary factor - content relevance matters most
-            title_weight: 0.75,
-            quality_weight: 0.15,
-            // Health (seeders) as a tiebreaker
-            health_weight: 0.10,
-            // Size weight disabled - too crude without category-aware thresholds
-            // (50GB is small for a TV series collection, huge for a single album)
-            size_weight: 0.0,
+            title_weight: weights.title,
+            quality_weight: weights.quality,
+            health_weight: weights.health,
+            size_weight: weights.size,
             min_seeders: 1,
             ideal_seeders: 20,
             min_size_bytes: 1024 * 1024, // 1 MB (unused when weight is 0)
@@ -52,6 +50,17 @@ impl Default for DumbMatcherConfig {
     }
 }
 
+impl DumbMatcherConfig {
+    /// Use the given component weights.
+    pub fn with_weights(mut self, weights: &DumbScoringWeights) -> Self {
+        self.title_weight = weights.title;
+        self.quality_weight = weights.quality;
+        self.health_weight = weights.health;
+        self.size_weight = weights.size;
+        self
+    }
+}
+
 /// Heuristic-based candidate matcher.
 ///
 /// Scores candidates by:
@@ -504,6 +513,18 @@ impl DumbMatcher {
         }
     }
 
+    /// Score each candidate, in input order.
+    pub(crate) fn score_each(
+        &self,
+        context: &QueryContext,
+        candidates: &[TorrentCandidate],
+    ) -> Vec<ScoredCandidate> {
+        candidates
+            .iter()
+            .map(|c| self.score_candidate(c, context))
+            .collect()
+    }
+
     /// Calculate file mappings for a candidate.
     ///
     /// Returns (mappings, quality_score).
@@ -547,10 +568,7 @@ impl CandidateMatcher for DumbMatcher {
         context: &QueryContext,
         candidates: &[TorrentCandidate],
     ) -> Result<MatchResult, TextBrainError> {
-        let mut scored: Vec<ScoredCandidate> = candidates
-            .iter()
-            .map(|c| self.score_candidate(c, context))
-            .collect();
+        let mut scored = self.score_each(context, candidates);
 
         // Sort by score descending
         scored.sort_by(|a, b| {
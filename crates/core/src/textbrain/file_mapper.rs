@@ -5,7 +5,7 @@
 
 use crate::searcher::TorrentFile;
 use crate::textbrain::types::FileMapping;
-use crate::ticket::{ExpectedContent, ExpectedTrack};
+use crate::ticket::{BookFormat, ExpectedAlbum, ExpectedChapter, ExpectedContent, ExpectedTrack};
 
 /// Configuration for the dumb file mapper.
 #[derive(Debug, Clone)]
@@ -29,7 +29,7 @@ impl Default for DumbFileMapperConfig {
             track_number_weight: 0.4,
             title_weight: 0.6,
             audio_extensions: vec![
-                "flac", "mp3", "m4a", "aac", "ogg", "opus", "wav", "ape", "wv", "alac",
+                "flac", "mp3", "m4a", "m4b", "aac", "ogg", "opus", "wav", "ape", "wv", "alac",
             ]
             .into_iter()
             .map(String::from)
@@ -73,6 +73,9 @@ impl DumbFileMapper {
     pub fn map_files(&self, files: &[TorrentFile], expected: &ExpectedContent) -> Vec<FileMapping> {
         match expected {
             ExpectedContent::Album { tracks, .. } => self.map_album_files(files, tracks),
+            ExpectedContent::Discography { albums, .. } => {
+                self.map_discography_files(files, albums)
+            }
             ExpectedContent::Track { title, artist } => {
                 self.map_single_track(files, title, artist.as_deref())
             }
@@ -82,6 +85,42 @@ impl DumbFileMapper {
                 season,
                 episodes,
             } => self.map_tv_episodes(files, series, *season, episodes),
+            ExpectedContent::TvSeason {
+                series,
+                season,
+                episodes,
+            } => {
+                if episodes.is_empty() {
+                    self.map_detected_episodes(files, series, Some(&[*season]))
+                } else {
+                    self.map_tv_episodes(files, series, *season, episodes)
+                }
+            }
+            ExpectedContent::TvSeries {
+                series,
+                seasons,
+                resolved_seasons,
+            } => {
+                if resolved_seasons.is_empty() {
+                    self.map_detected_episodes(files, series, seasons.as_deref())
+                } else {
+                    resolved_seasons
+                        .iter()
+                        .flat_map(|s| self.map_tv_episodes(files, series, s.season, &s.episodes))
+                        .collect()
+                }
+            }
+            ExpectedContent::Audiobook { chapters, .. } => {
+                self.map_audiobook_files(files, chapters)
+            }
+            ExpectedContent::Book { title, formats, .. } => {
+                self.map_book_file(files, title, formats)
+            }
+            ExpectedContent::Comic {
+                series,
+                issue,
+                volume,
+            } => self.map_comic_file(files, series, *issue, *volume),
         }
     }
 
@@ -115,6 +154,80 @@ impl DumbFileMapper {
         mappings
     }
 
+    /// Map files for a discography.
+    ///
+    /// Each album is matched to the folder whose name best matches its title
+    /// (disc subfolders belong to their album). Items are
+    /// `album-{a}-track-{n}`, with `a` the 1-based album position.
+    fn map_discography_files(
+        &self,
+        files: &[TorrentFile],
+        albums: &[ExpectedAlbum],
+    ) -> Vec<FileMapping> {
+        // Audio files grouped by album folder
+        let mut folders: Vec<(&str, Vec<TorrentFile>)> = Vec::new();
+        for file in self.filter_audio_files(files) {
+            let folder = album_folder(&file.path);
+            match folders.iter_mut().find(|(f, _)| *f == folder) {
+                Some((_, folder_files)) => folder_files.push(file.clone()),
+                None => folders.push((folder, vec![file.clone()])),
+            }
+        }
+
+        let mut used = vec![false; folders.len()];
+        let mut mappings = Vec::new();
+
+        for (album_idx, album) in albums.iter().enumerate() {
+            let best = folders
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| !used[*idx])
+                .map(|(idx, (folder, _))| {
+                    let name = folder.rsplit('/').next().unwrap_or(folder);
+                    let mut score = self.title_similarity(name, &album.title);
+                    if album.year.is_some_and(|y| name.contains(&y.to_string())) {
+                        score += 0.1;
+                    }
+                    (idx, score)
+                })
+                .filter(|(_, score)| *score >= 0.5)
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+            let Some((folder_idx, folder_score)) = best else {
+                continue;
+            };
+            used[folder_idx] = true;
+            let folder_files = &folders[folder_idx].1;
+            let prefix = format!("album-{}-", album_idx + 1);
+
+            if album.tracks.is_empty() {
+                let mut sorted: Vec<&TorrentFile> = folder_files.iter().collect();
+                sorted.sort_by(|a, b| natural_cmp(&a.path, &b.path));
+                mappings.extend(
+                    sorted
+                        .into_iter()
+                        .enumerate()
+                        .map(|(idx, file)| FileMapping {
+                            torrent_file_path: file.path.clone(),
+                            ticket_item_id: format!("{}track-{}", prefix, idx + 1),
+                            confidence: folder_score.min(1.0),
+                        }),
+                );
+            } else {
+                mappings.extend(
+                    self.map_album_files(folder_files, &album.tracks)
+                        .into_iter()
+                        .map(|m| FileMapping {
+                            ticket_item_id: format!("{}{}", prefix, m.ticket_item_id),
+                            ..m
+                        }),
+                );
+            }
+        }
+
+        mappings
+    }
+
     /// Map files for a single track.
     fn map_single_track(
         &self,
@@ -253,6 +366,204 @@ impl DumbFileMapper {
         mappings
     }
 
+    /// Map files for a season pack whose episode list is unknown.
+    ///
+    /// Every video file carrying an episode marker becomes its own
+    /// `sNNeNN` item, restricted to `seasons` when given.
+    fn map_detected_episodes(
+        &self,
+        files: &[TorrentFile],
+        series: &str,
+        seasons: Option<&[u32]>,
+    ) -> Vec<FileMapping> {
+        let mut best: Vec<((u32, u32), &TorrentFile, f32)> = Vec::new();
+
+        for file in self.filter_video_files(files) {
+            let Some((season, episode)) = self.extract_episode_marker(&file.path) else {
+                continue;
+            };
+            if seasons.is_some_and(|s| !s.contains(&season)) {
+                continue;
+            }
+
+            let confidence = (self.title_similarity(&file.path, series) * 0.5 + 0.5).min(1.0);
+            if confidence < self.config.min_confidence {
+                continue;
+            }
+
+            match best
+                .iter_mut()
+                .find(|(key, _, _)| *key == (season, episode))
+            {
+                Some(entry) if confidence > entry.2 => {
+                    *entry = ((season, episode), file, confidence)
+                }
+                Some(_) => {}
+                None => best.push(((season, episode), file, confidence)),
+            }
+        }
+
+        best.sort_by_key(|(key, _, _)| *key);
+        best.into_iter()
+            .map(|((season, episode), file, confidence)| FileMapping {
+                torrent_file_path: file.path.clone(),
+                ticket_item_id: format!("s{:02}e{:02}", season, episode),
+                confidence,
+            })
+            .collect()
+    }
+
+    /// Map files for an audiobook.
+    ///
+    /// A single audio file is taken to be the whole book. Otherwise files are
+    /// assigned to chapters in natural path order, since audiobook releases
+    /// rarely carry chapter titles in their filenames.
+    fn map_audiobook_files(
+        &self,
+        files: &[TorrentFile],
+        chapters: &[ExpectedChapter],
+    ) -> Vec<FileMapping> {
+        let mut audio_files = self.filter_audio_files(files);
+
+        if audio_files.is_empty() {
+            return Vec::new();
+        }
+
+        if audio_files.len() == 1 {
+            let file = audio_files[0];
+            let confidence = if self.get_extension(&file.path) == "m4b" {
+                0.95
+            } else {
+                0.8
+            };
+            return vec![FileMapping {
+                torrent_file_path: file.path.clone(),
+                ticket_item_id: "book".to_string(),
+                confidence,
+            }];
+        }
+
+        audio_files.sort_by(|a, b| natural_cmp(&a.path, &b.path));
+
+        // Agreement between file count and chapter count is the only signal
+        let confidence = if chapters.is_empty() {
+            0.7
+        } else if chapters.len() == audio_files.len() {
+            0.9
+        } else {
+            0.5
+        };
+
+        audio_files
+            .iter()
+            .enumerate()
+            .map(|(idx, file)| {
+                let number = chapters
+                    .get(idx)
+                    .map(|c| c.number)
+                    .unwrap_or(idx as u32 + 1);
+                FileMapping {
+                    torrent_file_path: file.path.clone(),
+                    ticket_item_id: format!("chapter-{}", number),
+                    confidence,
+                }
+            })
+            .collect()
+    }
+
+    /// Map the target ebook out of a single book or a pack.
+    ///
+    /// Only files in an acceptable format are considered; earlier entries in
+    /// `formats` are preferred.
+    fn map_book_file(
+        &self,
+        files: &[TorrentFile],
+        title: &str,
+        formats: &[BookFormat],
+    ) -> Vec<FileMapping> {
+        let book_files = self.filter_book_files(files, formats, false);
+
+        let scored = book_files.iter().map(|(file, format)| {
+            let filename = self.extract_filename(&file.path);
+            let title_score = self.title_similarity(&filename, title);
+            let score = title_score * 0.7 + format_preference(*format, formats) * 0.3;
+            (*file, score)
+        });
+
+        self.best_single_mapping(scored.collect(), book_files.len(), "book")
+    }
+
+    /// Map the target comic issue or volume out of a single file or a pack.
+    fn map_comic_file(
+        &self,
+        files: &[TorrentFile],
+        series: &str,
+        issue: Option<u32>,
+        volume: Option<u32>,
+    ) -> Vec<FileMapping> {
+        let comic_files = self.filter_book_files(files, &[], true);
+
+        let scored = comic_files.iter().map(|(file, _)| {
+            let filename = self.extract_filename(&file.path).to_lowercase();
+            let series_score = self.title_similarity(&filename, series);
+
+            let mut checks = 0;
+            let mut matched = 0;
+            if let Some(issue) = issue {
+                checks += 1;
+                if number_runs(&filename).contains(&issue) {
+                    matched += 1;
+                }
+            }
+            if let Some(volume) = volume {
+                checks += 1;
+                if has_volume_marker(&filename, volume) {
+                    matched += 1;
+                }
+            }
+
+            let score = if checks == 0 {
+                series_score
+            } else {
+                series_score * 0.4 + (matched as f32 / checks as f32) * 0.6
+            };
+            (*file, score)
+        });
+
+        self.best_single_mapping(scored.collect(), comic_files.len(), "comic")
+    }
+
+    /// Pick the best scored file as the single mapping for `item_id`.
+    ///
+    /// A lone candidate is trusted even with a poor filename match, since
+    /// single-book torrents are often named after the release, not the title.
+    fn best_single_mapping(
+        &self,
+        mut scored: Vec<(&TorrentFile, f32)>,
+        candidate_count: usize,
+        item_id: &str,
+    ) -> Vec<FileMapping> {
+        if candidate_count == 1 {
+            if let Some(entry) = scored.first_mut() {
+                entry.1 = entry.1.max(0.8);
+            }
+        }
+
+        scored.retain(|(_, score)| *score >= self.config.min_confidence);
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        scored
+            .first()
+            .map(|(file, confidence)| {
+                vec![FileMapping {
+                    torrent_file_path: file.path.clone(),
+                    ticket_item_id: item_id.to_string(),
+                    confidence: confidence.min(1.0),
+                }]
+            })
+            .unwrap_or_default()
+    }
+
     /// Find best matching file for a track.
     fn find_best_track_match<'a>(
         &self,
@@ -383,6 +694,32 @@ impl DumbFileMapper {
             .collect()
     }
 
+    /// Filter files to ebook or comic files in an acceptable format.
+    ///
+    /// An empty `formats` list accepts any ebook format, or any comic format
+    /// (plus PDF) when `comics` is set.
+    fn filter_book_files<'a>(
+        &self,
+        files: &'a [TorrentFile],
+        formats: &[BookFormat],
+        comics: bool,
+    ) -> Vec<(&'a TorrentFile, BookFormat)> {
+        files
+            .iter()
+            .filter_map(|f| {
+                let format = BookFormat::from_extension(&self.get_extension(&f.path))?;
+                let accepted = if !formats.is_empty() {
+                    formats.contains(&format)
+                } else if comics {
+                    format.is_comic() || format == BookFormat::Pdf
+                } else {
+                    !format.is_comic()
+                };
+                accepted.then_some((f, format))
+            })
+            .collect()
+    }
+
     /// Get file extension from path.
     fn get_extension(&self, path: &str) -> String {
         path.rsplit('.').next().unwrap_or("").to_lowercase()
@@ -458,6 +795,22 @@ impl DumbFileMapper {
         None
     }
 
+    /// Extract a (season, episode) pair from an `S01E02` or `1x02` marker.
+    fn extract_episode_marker(&self, path: &str) -> Option<(u32, u32)> {
+        let filename = path.rsplit(['/', '\\']).next().unwrap_or(path);
+        let patterns = [
+            r"(?i)s(\d{1,2})[ ._-]?e(\d{1,3})",
+            r"(?i)\b(\d{1,2})x(\d{2,3})\b",
+        ];
+
+        patterns.iter().find_map(|pattern| {
+            let caps = regex_lite::Regex::new(pattern).ok()?.captures(filename)?;
+            let season = caps.get(1)?.as_str().parse().ok()?;
+            let episode = caps.get(2)?.as_str().parse().ok()?;
+            Some((season, episode))
+        })
+    }
+
     /// Extract leading number from filename.
     fn extract_leading_number(&self, filename: &str) -> Option<u32> {
         let re = regex_lite::Regex::new(r"^(\d{1,3})[\s.\-_]").ok()?;
@@ -588,8 +941,13 @@ pub fn calculate_mapping_quality(mappings: &[FileMapping], expected: &ExpectedCo
         return 0.0;
     }
 
-    // Coverage score: how many expected items have mappings
-    let coverage = mappings.len() as f32 / expected_count as f32;
+    // Coverage score: how many expected items have mappings. A single
+    // audiobook file covers every chapter.
+    let coverage = if mappings.iter().any(|m| m.ticket_item_id == "book") {
+        1.0
+    } else {
+        mappings.len() as f32 / expected_count as f32
+    };
 
     // Average confidence of mappings
     let avg_confidence: f32 =
@@ -599,6 +957,87 @@ pub fn calculate_mapping_quality(mappings: &[FileMapping], expected: &ExpectedCo
     (coverage * 0.6 + avg_confidence * 0.4).min(1.0)
 }
 
+/// The album folder of a file: its parent directory, or the grandparent for
+/// files in disc subfolders ("Album/CD1/01.flac").
+fn album_folder(path: &str) -> &str {
+    let parent = path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("");
+    let (grandparent, name) = parent.rsplit_once('/').unwrap_or(("", parent));
+    let is_disc_folder = regex_lite::Regex::new(r"(?i)^(cd|disc|disk)\s*\d+$")
+        .map(|re| re.is_match(name))
+        .unwrap_or(false);
+    if is_disc_folder {
+        grandparent
+    } else {
+        parent
+    }
+}
+
+/// Preference for a format given the requested order (1.0 for the first).
+fn format_preference(format: BookFormat, formats: &[BookFormat]) -> f32 {
+    match formats.iter().position(|f| *f == format) {
+        Some(idx) => (1.0 - idx as f32 * 0.15).max(0.5),
+        None => 0.7,
+    }
+}
+
+/// All runs of digits in a string, as numbers ("#012" yields 12).
+fn number_runs(text: &str) -> Vec<u32> {
+    text.split(|c: char| !c.is_ascii_digit())
+        .filter_map(|run| run.parse().ok())
+        .collect()
+}
+
+/// Whether a (lowercased) filename names the given volume, e.g. "v03",
+/// "vol. 3" or "volume 3".
+fn has_volume_marker(filename: &str, volume: u32) -> bool {
+    ["volume", "vol.", "vol", "v"].iter().any(|marker| {
+        filename.match_indices(marker).any(|(idx, _)| {
+            let preceded_by_letter = filename[..idx]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_alphabetic());
+            let rest = filename[idx + marker.len()..].trim_start_matches([' ', '.', '_']);
+            let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+            !preceded_by_letter && digits.parse::<u32>().ok() == Some(volume)
+        })
+    })
+}
+
+/// Compare paths so that embedded numbers sort numerically ("2" before "10").
+pub(crate) fn natural_cmp(a: &str, b: &str) -> std::cmp::Ordering {
+    natural_key(a).cmp(&natural_key(b))
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum NaturalChunk {
+    Number(u64),
+    Text(String),
+}
+
+fn natural_key(s: &str) -> Vec<NaturalChunk> {
+    let mut chunks = Vec::new();
+    let mut chars = s.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        let is_digit = c.is_ascii_digit();
+        let mut chunk = String::new();
+        while let Some(&next) = chars.peek() {
+            if next.is_ascii_digit() != is_digit {
+                break;
+            }
+            chunk.extend(next.to_lowercase());
+            chars.next();
+        }
+        chunks.push(if is_digit {
+            NaturalChunk::Number(chunk.parse().unwrap_or(u64::MAX))
+        } else {
+            NaturalChunk::Text(chunk)
+        });
+    }
+
+    chunks
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -866,4 +1305,244 @@ mod tests {
             Some(1)
         );
     }
+
+    #[test]
+    fn test_audiobook_chapters_natural_order() {
+        let mapper = DumbFileMapper::new();
+        let files = vec![
+            make_file("Book/Part 10.mp3", 20_000_000),
+            make_file("Book/Part 2.mp3", 20_000_000),
+            make_file("Book/Part 1.mp3", 20_000_000),
+            make_file("Book/cover.jpg", 100_000),
+        ];
+        let expected = ExpectedContent::Audiobook {
+            author: Some("Frank Herbert".to_string()),
+            title: "Dune".to_string(),
+            narrator: None,
+            series: None,
+            chapters: vec![
+                ExpectedChapter::new(1, "One"),
+                ExpectedChapter::new(2, "Two"),
+                ExpectedChapter::new(3, "Three"),
+            ],
+        };
+
+        let mappings = mapper.map_files(&files, &expected);
+        let paths: Vec<_> = mappings
+            .iter()
+            .map(|m| (m.ticket_item_id.as_str(), m.torrent_file_path.as_str()))
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                ("chapter-1", "Book/Part 1.mp3"),
+                ("chapter-2", "Book/Part 2.mp3"),
+                ("chapter-3", "Book/Part 10.mp3"),
+            ]
+        );
+        assert!(mappings.iter().all(|m| m.confidence > 0.8));
+    }
+
+    #[test]
+    fn test_audiobook_single_file_covers_book() {
+        let mapper = DumbFileMapper::new();
+        let files = vec![make_file("Dune.m4b", 900_000_000)];
+        let expected = ExpectedContent::Audiobook {
+            author: None,
+            title: "Dune".to_string(),
+            narrator: None,
+            series: None,
+            chapters: vec![
+                ExpectedChapter::new(1, "One"),
+                ExpectedChapter::new(2, "Two"),
+            ],
+        };
+
+        let mappings = mapper.map_files(&files, &expected);
+        assert_eq!(mappings.len(), 1);
+        assert_eq!(mappings[0].ticket_item_id, "book");
+        assert!(calculate_mapping_quality(&mappings, &expected) > 0.9);
+    }
+
+    #[test]
+    fn test_book_mapped_out_of_pack_with_preferred_format() {
+        let mapper = DumbFileMapper::new();
+        let files = vec![
+            make_file("Herbert Collection/Frank Herbert - Dune.pdf", 9_000_000),
+            make_file("Herbert Collection/Frank Herbert - Dune.epub", 2_000_000),
+            make_file(
+                "Herbert Collection/Frank Herbert - Dune Messiah.epub",
+                1_500_000,
+            ),
+            make_file("Herbert Collection/The Dosadi Experiment.epub", 1_200_000),
+        ];
+        let expected = ExpectedContent::Book {
+            author: Some("Frank Herbert".to_string()),
+            title: "Dune".to_string(),
+            isbn: None,
+            formats: vec![BookFormat::Epub, BookFormat::Pdf],
+        };
+
+        let mappings = mapper.map_files(&files, &expected);
+        assert_eq!(mappings.len(), 1);
+        assert_eq!(mappings[0].ticket_item_id, "book");
+        assert!(mappings[0].torrent_file_path.ends_with("Dune.epub"));
+        assert!(!mappings[0].torrent_file_path.contains("Messiah"));
+    }
+
+    #[test]
+    fn test_book_ignores_unrequested_formats() {
+        let mapper = DumbFileMapper::new();
+        let files = vec![make_file("Dune.mobi", 2_000_000)];
+        let expected = ExpectedContent::Book {
+            author: None,
+            title: "Dune".to_string(),
+            isbn: None,
+            formats: vec![BookFormat::Epub],
+        };
+
+        assert!(mapper.map_files(&files, &expected).is_empty());
+    }
+
+    #[test]
+    fn test_comic_issue_mapped_out_of_pack() {
+        let mapper = DumbFileMapper::new();
+        let files = vec![
+            make_file("Saga/Saga 011 (2013).cbz", 40_000_000),
+            make_file("Saga/Saga 012 (2013).cbz", 40_000_000),
+            make_file("Saga/Saga 013 (2013).cbz", 40_000_000),
+        ];
+
+        let mappings = mapper.map_files(&files, &ExpectedContent::comic_issue("Saga", 12));
+        assert_eq!(mappings.len(), 1);
+        assert_eq!(mappings[0].ticket_item_id, "comic");
+        assert_eq!(mappings[0].torrent_file_path, "Saga/Saga 012 (2013).cbz");
+    }
+
+    #[test]
+    fn test_comic_volume_marker() {
+        assert!(has_volume_marker("saga v03 (2014).cbr", 3));
+        assert!(has_volume_marker("saga vol. 3.cbr", 3));
+        assert!(!has_volume_marker("saga v30.cbr", 3));
+        assert!(!has_volume_marker("saga reloaded 3.cbr", 3));
+    }
+
+    #[test]
+    fn test_season_pack_one_item_per_episode() {
+        let mapper = DumbFileMapper::new();
+        let files = vec![
+            make_file("Breaking.Bad.S02/Breaking.Bad.S02E01.mkv", 500_000_000),
+            make_file("Breaking.Bad.S02/Breaking.Bad.S02E02.mkv", 500_000_000),
+            make_file("Breaking.Bad.S02/Breaking.Bad.S02E03.mkv", 500_000_000),
+            make_file("Breaking.Bad.S02/Sample/sample.mkv", 10_000_000),
+        ];
+
+        let resolved = ExpectedContent::TvSeason {
+            series: "Breaking Bad".to_string(),
+            season: 2,
+            episodes: vec![1, 2, 3],
+        };
+        let mappings = mapper.map_files(&files, &resolved);
+        let ids: Vec<_> = mappings.iter().map(|m| m.ticket_item_id.as_str()).collect();
+        assert_eq!(ids, ["s02e01", "s02e02", "s02e03"]);
+
+        // Without a resolved episode list, episodes are detected from filenames.
+        let unresolved = ExpectedContent::tv_season("Breaking Bad", 2);
+        let mappings = mapper.map_files(&files, &unresolved);
+        let ids: Vec<_> = mappings.iter().map(|m| m.ticket_item_id.as_str()).collect();
+        assert_eq!(ids, ["s02e01", "s02e02", "s02e03"]);
+    }
+
+    #[test]
+    fn test_series_pack_filters_requested_seasons() {
+        let mapper = DumbFileMapper::new();
+        let files = vec![
+            make_file("Firefly/Season 1/Firefly.S01E01.mkv", 500_000_000),
+            make_file("Firefly/Season 1/Firefly.S01E02.mkv", 500_000_000),
+            make_file("Firefly/Season 2/Firefly.2x01.mkv", 500_000_000),
+            make_file("Firefly/Extras/Firefly.Gag.Reel.1920x1080.mkv", 50_000_000),
+        ];
+
+        let mappings = mapper.map_files(&files, &ExpectedContent::tv_series("Firefly"));
+        let ids: Vec<_> = mappings.iter().map(|m| m.ticket_item_id.as_str()).collect();
+        assert_eq!(ids, ["s01e01", "s01e02", "s02e01"]);
+
+        let only_second = ExpectedContent::TvSeries {
+            series: "Firefly".to_string(),
+            seasons: Some(vec![2]),
+            resolved_seasons: vec![],
+        };
+        let mappings = mapper.map_files(&files, &only_second);
+        assert_eq!(mappings.len(), 1);
+        assert_eq!(mappings[0].ticket_item_id, "s02e01");
+    }
+
+    #[test]
+    fn test_discography_split_by_album_folder() {
+        let mapper = DumbFileMapper::new();
+        let files = vec![
+            make_file(
+                "Pink Floyd/1977 - Animals/01 Pigs on the Wing.flac",
+                30_000_000,
+            ),
+            make_file("Pink Floyd/1977 - Animals/02 Dogs.flac", 90_000_000),
+            make_file(
+                "Pink Floyd/1979 - The Wall/CD1/01 In the Flesh.flac",
+                30_000_000,
+            ),
+            make_file("Pink Floyd/1979 - The Wall/CD2/01 Hey You.flac", 30_000_000),
+            make_file("Pink Floyd/1979 - The Wall/cover.jpg", 500_000),
+        ];
+        let albums = vec![
+            ExpectedAlbum {
+                title: "The Wall".to_string(),
+                year: Some(1979),
+                mbid: None,
+                tracks: vec![],
+            },
+            ExpectedAlbum {
+                title: "Animals".to_string(),
+                year: Some(1977),
+                mbid: None,
+                tracks: vec![],
+            },
+            ExpectedAlbum {
+                title: "Meddle".to_string(),
+                year: Some(1971),
+                mbid: None,
+                tracks: vec![],
+            },
+        ];
+
+        let mappings = mapper.map_files(
+            &files,
+            &ExpectedContent::Discography {
+                artist: "Pink Floyd".to_string(),
+                albums,
+            },
+        );
+
+        let ids: Vec<_> = mappings
+            .iter()
+            .map(|m| (m.ticket_item_id.as_str(), m.torrent_file_path.as_str()))
+            .collect();
+        assert_eq!(
+            ids,
+            [
+                (
+                    "album-1-track-1",
+                    "Pink Floyd/1979 - The Wall/CD1/01 In the Flesh.flac"
+                ),
+                (
+                    "album-1-track-2",
+                    "Pink Floyd/1979 - The Wall/CD2/01 Hey You.flac"
+                ),
+                (
+                    "album-2-track-1",
+                    "Pink Floyd/1977 - Animals/01 Pigs on the Wing.flac"
+                ),
+                ("album-2-track-2", "Pink Floyd/1977 - Animals/02 Dogs.flac"),
+            ]
+        );
+    }
 }
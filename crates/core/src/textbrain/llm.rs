@@ -3,6 +3,11 @@
 use async_trait::async_trait;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::time::Duration;
+use tracing::warn;
+
+/// Times a completion that does not parse as the expected JSON is sent back
+/// to the model for repair before giving up.
+pub const JSON_REPAIR_ATTEMPTS: u32 = 2;
 
 /// Error type for LLM operations.
 #[derive(Debug, thiserror::Error)]
@@ -21,6 +26,21 @@ pub enum LlmError {
 
     #[error("Not configured")]
     NotConfigured,
+
+    #[error("Cache error: {0}")]
+    Cache(String),
+
+    #[error("Database error: {0}")]
+    Database(String),
+
+    #[error("LLM budget exhausted")]
+    BudgetExhausted,
+
+    #[error("Unavailable: {0}")]
+    Unavailable(String),
+
+    #[error("Invalid structured output: {message} - Response: {raw}")]
+    SchemaViolation { message: String, raw: String },
 }
 
 /// Token usage statistics.
@@ -41,6 +61,42 @@ pub struct CompletionRequest {
     pub max_tokens: u32,
     /// Temperature (0.0 = deterministic, 1.0 = creative)
     pub temperature: f32,
+    /// JSON schema the response must follow, enforced with the provider's
+    /// native structured-output mechanism where there is one.
+    pub response_schema: Option<ResponseSchema>,
+    /// What the completion is for (e.g., "scoring"), reported in audit events.
+    pub purpose: Option<String>,
+    /// Version of the prompt template the request was rendered from.
+    pub prompt_version: Option<String>,
+}
+
+/// Named JSON schema for structured completions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseSchema {
+    /// Schema name (used as the tool name for Anthropic).
+    pub name: String,
+    /// JSON schema of the response object.
+    pub schema: serde_json::Value,
+}
+
+impl ResponseSchema {
+    /// Whether a completion is a JSON object with the schema's required
+    /// top-level fields.
+    pub fn accepts(&self, text: &str) -> bool {
+        let Ok(serde_json::Value::Object(object)) = parse_json_output::<serde_json::Value>(text)
+        else {
+            return false;
+        };
+        self.schema
+            .get("required")
+            .and_then(|required| required.as_array())
+            .is_none_or(|required| {
+                required
+                    .iter()
+                    .filter_map(|field| field.as_str())
+                    .all(|field| object.contains_key(field))
+            })
+    }
 }
 
 impl CompletionRequest {
@@ -50,6 +106,9 @@ impl CompletionRequest {
             prompt: prompt.into(),
             max_tokens: 1024,
             temperature: 0.0, // Deterministic by default for matching tasks
+            response_schema: None,
+            purpose: None,
+            prompt_version: None,
         }
     }
 
@@ -67,6 +126,51 @@ impl CompletionRequest {
         self.temperature = temperature;
         self
     }
+
+    pub fn with_response_schema(
+        mut self,
+        name: impl Into<String>,
+        schema: serde_json::Value,
+    ) -> Self {
+        self.response_schema = Some(ResponseSchema {
+            name: name.into(),
+            schema,
+        });
+        self
+    }
+
+    pub fn with_purpose(mut self, purpose: impl Into<String>) -> Self {
+        self.purpose = Some(purpose.into());
+        self
+    }
+
+    pub fn with_prompt_version(mut self, version: impl Into<String>) -> Self {
+        self.prompt_version = Some(version.into());
+        self
+    }
+
+    /// Follow-up request asking the model to fix a response that failed to parse.
+    fn repair(&self, raw: &str, problem: &str) -> Self {
+        let mut request = self.clone();
+        request.prompt = format!(
+            "{}\n\nYour previous response could not be used: {}\n\nPrevious response:\n{}\n\nRespond again with only the corrected JSON.",
+            self.prompt, problem, raw
+        );
+        request
+    }
+}
+
+/// Parse a completion as JSON, tolerating surrounding prose or code fences.
+pub fn parse_json_output<T: DeserializeOwned>(text: &str) -> Result<T, LlmError> {
+    let json_str = match (text.find('{'), text.rfind('}')) {
+        (Some(start), Some(end)) if start < end => &text[start..=end],
+        _ => text,
+    };
+
+    serde_json::from_str(json_str).map_err(|e| LlmError::SchemaViolation {
+        message: e.to_string(),
+        raw: text.to_string(),
+    })
 }
 
 /// Response from a completion.
@@ -93,14 +197,40 @@ pub trait LlmClient: Send + Sync {
     async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, LlmError>;
 
     /// Send a completion request and parse the response as JSON.
+    ///
+    /// Responses that do not parse are sent back for repair up to
+    /// [`JSON_REPAIR_ATTEMPTS`] times; the returned usage covers all attempts.
     async fn complete_json<T: DeserializeOwned>(
         &self,
         request: CompletionRequest,
-    ) -> Result<(T, LlmUsage), LlmError> {
-        let response = self.complete(request).await?;
-        let parsed: T = serde_json::from_str(&response.text)
-            .map_err(|e| LlmError::Json(format!("{}: {}", e, response.text)))?;
-        Ok((parsed, response.usage))
+    ) -> Result<(T, LlmUsage), LlmError>
+    where
+        Self: Sized,
+    {
+        let mut usage = LlmUsage::default();
+        let mut attempt_request = request.clone();
+        let mut repairs = 0;
+
+        loop {
+            let response = self.complete(attempt_request).await?;
+            usage.input_tokens += response.usage.input_tokens;
+            usage.output_tokens += response.usage.output_tokens;
+
+            match parse_json_output(&response.text) {
+                Ok(parsed) => return Ok((parsed, usage)),
+                Err(LlmError::SchemaViolation { message, raw })
+                    if repairs < JSON_REPAIR_ATTEMPTS =>
+                {
+                    repairs += 1;
+                    warn!(
+                        "LLM response did not match the expected JSON ({}), asking for a repair ({}/{})",
+                        message, repairs, JSON_REPAIR_ATTEMPTS
+                    );
+                    attempt_request = request.repair(&raw, &message);
+                }
+                Err(e) => return Err(e),
+            }
+        }
     }
 }
 
@@ -141,6 +271,25 @@ struct AnthropicRequest {
     messages: Vec<AnthropicMessage>,
     #[serde(skip_serializing_if = "Option::is_none")]
     temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<AnthropicTool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<AnthropicToolChoice>,
+}
+
+/// Tool whose input schema is the requested response schema.
+#[derive(Debug, Serialize)]
+struct AnthropicTool {
+    name: String,
+    description: String,
+    input_schema: serde_json::Value,
+}
+
+#[derive(Debug, Serialize)]
+struct AnthropicToolChoice {
+    #[serde(rename = "type")]
+    choice_type: String,
+    name: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -160,7 +309,11 @@ struct AnthropicResponse {
 struct AnthropicContent {
     #[serde(rename = "type")]
     content_type: String,
+    #[serde(default)]
     text: String,
+    /// Tool input (for "tool_use" blocks).
+    #[serde(default)]
+    input: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -190,6 +343,23 @@ impl LlmClient for AnthropicClient {
     }
 
     async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, LlmError> {
+        // Structured output is requested by forcing a tool call whose input
+        // schema is the response schema
+        let (tools, tool_choice) = match request.response_schema {
+            Some(schema) => (
+                Some(vec![AnthropicTool {
+                    name: schema.name.clone(),
+                    description: "Record the response".to_string(),
+                    input_schema: schema.schema,
+                }]),
+                Some(AnthropicToolChoice {
+                    choice_type: "tool".to_string(),
+                    name: schema.name,
+                }),
+            ),
+            None => (None, None),
+        };
+
         let anthropic_request = AnthropicRequest {
             model: self.model.clone(),
             max_tokens: request.max_tokens,
@@ -203,6 +373,8 @@ impl LlmClient for AnthropicClient {
             } else {
                 Some(request.temperature)
             },
+            tools,
+            tool_choice,
         };
 
         let response = self
@@ -231,13 +403,7 @@ impl LlmClient for AnthropicClient {
             .await
             .map_err(|e| LlmError::Json(e.to_string()))?;
 
-        let text = anthropic_response
-            .content
-            .into_iter()
-            .filter(|c| c.content_type == "text")
-            .map(|c| c.text)
-            .collect::<Vec<_>>()
-            .join("");
+        let text = anthropic_text(anthropic_response.content);
 
         Ok(CompletionResponse {
             text,
@@ -250,6 +416,24 @@ impl LlmClient for AnthropicClient {
     }
 }
 
+/// Response text: the forced tool call's input if there is one, else the text blocks.
+fn anthropic_text(content: Vec<AnthropicContent>) -> String {
+    if let Some(input) = content
+        .iter()
+        .find(|c| c.content_type == "tool_use")
+        .and_then(|c| c.input.as_ref())
+    {
+        return input.to_string();
+    }
+
+    content
+        .into_iter()
+        .filter(|c| c.content_type == "text")
+        .map(|c| c.text)
+        .collect::<Vec<_>>()
+        .join("")
+}
+
 // ============================================================================
 // Ollama Implementation
 // ============================================================================
@@ -291,6 +475,9 @@ struct OllamaRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     system: Option<String>,
     stream: bool,
+    /// JSON schema the response must follow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<OllamaOptions>,
 }
@@ -339,6 +526,7 @@ impl LlmClient for OllamaClient {
             prompt: request.prompt,
             system: request.system,
             stream: false,
+            format: request.response_schema.map(|s| s.schema),
             options: Some(OllamaOptions {
                 temperature: if request.temperature == 0.0 {
                     Some(0.0) // Ollama needs explicit 0 for deterministic
@@ -384,6 +572,240 @@ impl LlmClient for OllamaClient {
     }
 }
 
+// ============================================================================
+// OpenAI Implementation
+// ============================================================================
+
+/// OpenAI chat completions client.
+///
+/// Also serves custom OpenAI-compatible endpoints (vLLM, llama.cpp server,
+/// LiteLLM and the like), which may not need an API key.
+pub struct OpenAiClient {
+    client: reqwest::Client,
+    provider: &'static str,
+    api_key: Option<String>,
+    model: String,
+    api_base: String,
+}
+
+impl OpenAiClient {
+    /// Create a client for the OpenAI API.
+    pub fn new(api_key: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            provider: "openai",
+            api_key: Some(api_key.into()),
+            model: model.into(),
+            api_base: "https://api.openai.com/v1".to_string(),
+        }
+    }
+
+    /// Create a client for a custom OpenAI-compatible endpoint.
+    ///
+    /// # Arguments
+    /// * `api_base` - Base URL including the version prefix (e.g., "http://localhost:8000/v1")
+    /// * `model` - Model name as known to the endpoint
+    pub fn custom(api_base: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            provider: "custom",
+            api_key: None,
+            model: model.into(),
+            api_base: api_base.into(),
+        }
+    }
+
+    /// Set the API key sent as a bearer token.
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Set a custom API base URL.
+    pub fn with_api_base(mut self, api_base: impl Into<String>) -> Self {
+        self.api_base = api_base.into();
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct OpenAiRequest {
+    model: String,
+    messages: Vec<OpenAiMessage>,
+    max_tokens: u32,
+    temperature: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<OpenAiResponseFormat>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct OpenAiMessage {
+    role: String,
+    #[serde(default)]
+    content: Option<String>,
+    /// Refusal message (structured output requests only).
+    #[serde(default, skip_serializing)]
+    refusal: Option<String>,
+}
+
+impl OpenAiMessage {
+    fn new(role: &str, content: String) -> Self {
+        Self {
+            role: role.to_string(),
+            content: Some(content),
+            refusal: None,
+        }
+    }
+}
+
+/// Structured output: the response must be JSON following the schema.
+#[derive(Debug, Serialize)]
+struct OpenAiResponseFormat {
+    #[serde(rename = "type")]
+    format_type: String,
+    json_schema: OpenAiJsonSchema,
+}
+
+#[derive(Debug, Serialize)]
+struct OpenAiJsonSchema {
+    name: String,
+    schema: serde_json::Value,
+    /// Strict mode needs every property required and no additional
+    /// properties, which our schemas don't guarantee.
+    strict: bool,
+}
+
+impl From<ResponseSchema> for OpenAiResponseFormat {
+    fn from(schema: ResponseSchema) -> Self {
+        Self {
+            format_type: "json_schema".to_string(),
+            json_schema: OpenAiJsonSchema {
+                name: schema.name,
+                schema: schema.schema,
+                strict: false,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAiResponse {
+    model: String,
+    choices: Vec<OpenAiChoice>,
+    #[serde(default)]
+    usage: Option<OpenAiUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAiChoice {
+    message: OpenAiMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAiUsage {
+    prompt_tokens: u32,
+    completion_tokens: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAiError {
+    error: OpenAiErrorDetail,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAiErrorDetail {
+    message: String,
+}
+
+#[async_trait]
+impl LlmClient for OpenAiClient {
+    fn provider(&self) -> &str {
+        self.provider
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, LlmError> {
+        let mut messages = Vec::with_capacity(2);
+        if let Some(system) = request.system {
+            messages.push(OpenAiMessage::new("system", system));
+        }
+        messages.push(OpenAiMessage::new("user", request.prompt));
+
+        let openai_request = OpenAiRequest {
+            model: self.model.clone(),
+            messages,
+            max_tokens: request.max_tokens,
+            temperature: request.temperature,
+            response_format: request.response_schema.map(OpenAiResponseFormat::from),
+        };
+
+        let mut http_request = self
+            .client
+            .post(format!("{}/chat/completions", self.api_base))
+            .header("content-type", "application/json")
+            .json(&openai_request);
+        if let Some(ref api_key) = self.api_key {
+            http_request = http_request.bearer_auth(api_key);
+        }
+
+        let response = http_request
+            .send()
+            .await
+            .map_err(|e| LlmError::Http(e.to_string()))?;
+
+        let status = response.status().as_u16();
+
+        if status != 200 {
+            let error_text = response.text().await.unwrap_or_default();
+            let message = serde_json::from_str::<OpenAiError>(&error_text)
+                .map(|e| e.error.message)
+                .unwrap_or(error_text);
+            return Err(LlmError::Api { status, message });
+        }
+
+        let openai_response: OpenAiResponse = response
+            .json()
+            .await
+            .map_err(|e| LlmError::Json(e.to_string()))?;
+
+        completion_from_openai(openai_response, status)
+    }
+}
+
+/// Convert a chat completion, treating a refusal as an API error.
+fn completion_from_openai(
+    response: OpenAiResponse,
+    status: u16,
+) -> Result<CompletionResponse, LlmError> {
+    let message = response
+        .choices
+        .into_iter()
+        .next()
+        .map(|choice| choice.message)
+        .ok_or_else(|| LlmError::Json("response has no choices".to_string()))?;
+
+    if let Some(refusal) = message.refusal {
+        return Err(LlmError::Api {
+            status,
+            message: format!("model refused: {}", refusal),
+        });
+    }
+
+    let usage = response.usage.map_or_else(LlmUsage::default, |u| LlmUsage {
+        input_tokens: u.prompt_tokens,
+        output_tokens: u.completion_tokens,
+    });
+
+    Ok(CompletionResponse {
+        text: message.content.unwrap_or_default(),
+        usage,
+        model: response.model,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -421,6 +843,7 @@ mod tests {
             prompt: "Hello".to_string(),
             system: Some("Be helpful".to_string()),
             stream: false,
+            format: None,
             options: Some(OllamaOptions {
                 temperature: Some(0.7),
                 num_predict: Some(100),
@@ -431,5 +854,182 @@ mod tests {
         assert!(json.contains("\"model\":\"llama3\""));
         assert!(json.contains("\"stream\":false"));
         assert!(json.contains("\"temperature\":0.7"));
+        assert!(!json.contains("format"));
+    }
+
+    #[test]
+    fn test_openai_clients() {
+        let client = OpenAiClient::new("sk-test", "gpt-4o-mini");
+        assert_eq!(client.provider(), "openai");
+        assert_eq!(client.api_base, "https://api.openai.com/v1");
+
+        let client = OpenAiClient::custom("http://localhost:8000/v1", "qwen2.5");
+        assert_eq!(client.provider(), "custom");
+        assert_eq!(client.model(), "qwen2.5");
+        assert!(client.api_key.is_none());
+    }
+
+    #[test]
+    fn test_openai_request_maps_response_schema() {
+        let request = OpenAiRequest {
+            model: "gpt-4o-mini".to_string(),
+            messages: vec![OpenAiMessage::new("user", "Hello".to_string())],
+            max_tokens: 100,
+            temperature: 0.0,
+            response_format: Some(
+                ResponseSchema {
+                    name: "scores".to_string(),
+                    schema: serde_json::json!({"type": "object"}),
+                }
+                .into(),
+            ),
+        };
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["messages"][0]["content"], "Hello");
+        assert!(json["messages"][0].get("refusal").is_none());
+        assert_eq!(json["response_format"]["type"], "json_schema");
+        assert_eq!(json["response_format"]["json_schema"]["name"], "scores");
+        assert_eq!(
+            json["response_format"]["json_schema"]["schema"]["type"],
+            "object"
+        );
+    }
+
+    #[test]
+    fn test_openai_response_conversion() {
+        let response: OpenAiResponse = serde_json::from_str(
+            r#"{
+                "model": "gpt-4o-mini-2024-07-18",
+                "choices": [{"message": {"role": "assistant", "content": "{\"scores\": []}"}}],
+                "usage": {"prompt_tokens": 12, "completion_tokens": 4}
+            }"#,
+        )
+        .unwrap();
+        let completion = completion_from_openai(response, 200).unwrap();
+        assert_eq!(completion.text, r#"{"scores": []}"#);
+        assert_eq!(completion.usage.input_tokens, 12);
+        assert_eq!(completion.model, "gpt-4o-mini-2024-07-18");
+
+        let refused: OpenAiResponse = serde_json::from_str(
+            r#"{
+                "model": "gpt-4o-mini",
+                "choices": [{"message": {"role": "assistant", "content": null, "refusal": "no"}}]
+            }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            completion_from_openai(refused, 200),
+            Err(LlmError::Api { .. })
+        ));
+    }
+
+    #[test]
+    fn test_anthropic_tool_use_response_text() {
+        let response: AnthropicResponse = serde_json::from_str(
+            r#"{
+                "model": "claude-3-haiku-20240307",
+                "content": [
+                    {"type": "text", "text": "Scoring now."},
+                    {"type": "tool_use", "id": "t1", "name": "scores", "input": {"scores": []}}
+                ],
+                "usage": {"input_tokens": 10, "output_tokens": 5}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(anthropic_text(response.content), r#"{"scores":[]}"#);
+    }
+
+    #[test]
+    fn test_parse_json_output() {
+        #[derive(Debug, Deserialize)]
+        struct Out {
+            value: u32,
+        }
+
+        let parsed: Out = parse_json_output("```json\n{\"value\": 3}\n```").unwrap();
+        assert_eq!(parsed.value, 3);
+
+        match parse_json_output::<Out>("{\"value\": \"three\"}") {
+            Err(LlmError::SchemaViolation { raw, .. }) => {
+                assert_eq!(raw, "{\"value\": \"three\"}")
+            }
+            other => panic!("expected schema violation, got {:?}", other),
+        }
+    }
+
+    /// Client that answers with each scripted response in turn.
+    struct ScriptedClient {
+        responses: std::sync::Mutex<Vec<String>>,
+        prompts: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl LlmClient for ScriptedClient {
+        fn provider(&self) -> &str {
+            "mock"
+        }
+
+        fn model(&self) -> &str {
+            "mock-model"
+        }
+
+        async fn complete(
+            &self,
+            request: CompletionRequest,
+        ) -> Result<CompletionResponse, LlmError> {
+            self.prompts.lock().unwrap().push(request.prompt);
+            Ok(CompletionResponse {
+                text: self.responses.lock().unwrap().remove(0),
+                usage: LlmUsage {
+                    input_tokens: 10,
+                    output_tokens: 5,
+                },
+                model: "mock-model".to_string(),
+            })
+        }
+    }
+
+    fn scripted(responses: &[&str]) -> ScriptedClient {
+        ScriptedClient {
+            responses: std::sync::Mutex::new(responses.iter().map(|r| r.to_string()).collect()),
+            prompts: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_complete_json_repairs_invalid_output() {
+        let client = scripted(&["{\"queries\": [\"a\",", "{\"queries\": [\"a\"]}"]);
+
+        let (parsed, usage): (serde_json::Value, LlmUsage) = client
+            .complete_json(CompletionRequest::new("make queries"))
+            .await
+            .unwrap();
+
+        assert_eq!(parsed["queries"][0], "a");
+        assert_eq!(usage.input_tokens, 20);
+        let prompts = client.prompts.lock().unwrap();
+        assert_eq!(prompts.len(), 2);
+        assert!(prompts[1].starts_with("make queries"));
+        assert!(prompts[1].contains("{\"queries\": [\"a\","));
+    }
+
+    #[tokio::test]
+    async fn test_complete_json_gives_up_after_repairs() {
+        let client = scripted(&["nope", "still nope", "never"]);
+
+        let result = client
+            .complete_json::<serde_json::Value>(CompletionRequest::new("x"))
+            .await;
+
+        match result {
+            Err(LlmError::SchemaViolation { raw, .. }) => assert_eq!(raw, "never"),
+            other => panic!("expected schema violation, got {:?}", other),
+        }
+        assert_eq!(
+            client.prompts.lock().unwrap().len() as u32,
+            JSON_REPAIR_ATTEMPTS + 1
+        );
     }
 }
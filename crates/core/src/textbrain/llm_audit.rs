@@ -0,0 +1,173 @@
+//! Audit events for LLM calls.
+//!
+//! [`AuditedLlmClient`] wraps any [`LlmClient`] and emits `LlmCallStarted`,
+//! `LlmCallCompleted` and `LlmCallFailed` events for every completion,
+//! tagged with the request's purpose and prompt template version.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+
+use crate::audit::{AuditEvent, AuditHandle};
+use crate::textbrain::llm::{CompletionRequest, CompletionResponse, LlmClient, LlmError};
+
+/// LLM client that records its calls in the audit log.
+pub struct AuditedLlmClient<C: LlmClient + ?Sized> {
+    inner: Arc<C>,
+    audit: AuditHandle,
+    ticket_id: Option<String>,
+}
+
+impl<C: LlmClient + ?Sized> AuditedLlmClient<C> {
+    /// Wrap a client, attributing its calls to a ticket if given.
+    pub fn new(inner: Arc<C>, audit: AuditHandle, ticket_id: Option<String>) -> Self {
+        Self {
+            inner,
+            audit,
+            ticket_id,
+        }
+    }
+}
+
+#[async_trait]
+impl<C: LlmClient + ?Sized + 'static> LlmClient for AuditedLlmClient<C> {
+    fn provider(&self) -> &str {
+        self.inner.provider()
+    }
+
+    fn model(&self) -> &str {
+        self.inner.model()
+    }
+
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, LlmError> {
+        let purpose = request
+            .purpose
+            .clone()
+            .unwrap_or_else(|| "completion".to_string());
+        let prompt_version = request.prompt_version.clone();
+
+        self.audit
+            .emit(AuditEvent::LlmCallStarted {
+                ticket_id: self.ticket_id.clone(),
+                purpose: purpose.clone(),
+                provider: self.provider().to_string(),
+                model: self.model().to_string(),
+            })
+            .await;
+
+        let start = Instant::now();
+        let result = self.inner.complete(request).await;
+        let duration_ms = start.elapsed().as_millis() as u64;
+
+        let event = match &result {
+            Ok(response) => AuditEvent::LlmCallCompleted {
+                ticket_id: self.ticket_id.clone(),
+                purpose,
+                input_tokens: response.usage.input_tokens,
+                output_tokens: response.usage.output_tokens,
+                duration_ms,
+                prompt_version,
+            },
+            Err(e) => AuditEvent::LlmCallFailed {
+                ticket_id: self.ticket_id.clone(),
+                purpose,
+                error: e.to_string(),
+                duration_ms,
+                is_timeout: matches!(e, LlmError::Timeout(_)),
+            },
+        };
+        self.audit.emit(event).await;
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::textbrain::llm::LlmUsage;
+    use tokio::sync::mpsc;
+
+    struct MockClient;
+
+    #[async_trait]
+    impl LlmClient for MockClient {
+        fn provider(&self) -> &str {
+            "mock"
+        }
+
+        fn model(&self) -> &str {
+            "mock-model"
+        }
+
+        async fn complete(
+            &self,
+            request: CompletionRequest,
+        ) -> Result<CompletionResponse, LlmError> {
+            if request.prompt == "fail" {
+                return Err(LlmError::Http("down".to_string()));
+            }
+            Ok(CompletionResponse {
+                text: "{}".to_string(),
+                usage: LlmUsage {
+                    input_tokens: 10,
+                    output_tokens: 5,
+                },
+                model: "mock-model".to_string(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_emits_completed_event_with_prompt_version() {
+        let (tx, mut rx) = mpsc::channel(10);
+        let client = AuditedLlmClient::new(
+            Arc::new(MockClient),
+            AuditHandle::new(tx),
+            Some("ticket-1".to_string()),
+        );
+
+        let request = CompletionRequest::new("hi")
+            .with_purpose("scoring")
+            .with_prompt_version("v2");
+        client.complete(request).await.unwrap();
+
+        let started = rx.recv().await.unwrap().event;
+        assert!(matches!(
+            started,
+            AuditEvent::LlmCallStarted { ref purpose, ref model, .. }
+                if purpose == "scoring" && model == "mock-model"
+        ));
+        match rx.recv().await.unwrap().event {
+            AuditEvent::LlmCallCompleted {
+                ticket_id,
+                input_tokens,
+                prompt_version,
+                ..
+            } => {
+                assert_eq!(ticket_id.as_deref(), Some("ticket-1"));
+                assert_eq!(input_tokens, 10);
+                assert_eq!(prompt_version.as_deref(), Some("v2"));
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_emits_failed_event() {
+        let (tx, mut rx) = mpsc::channel(10);
+        let client = AuditedLlmClient::new(Arc::new(MockClient), AuditHandle::new(tx), None);
+
+        assert!(client
+            .complete(CompletionRequest::new("fail"))
+            .await
+            .is_err());
+
+        rx.recv().await.unwrap();
+        assert!(matches!(
+            rx.recv().await.unwrap().event,
+            AuditEvent::LlmCallFailed { is_timeout: false, ref purpose, .. } if purpose == "completion"
+        ));
+    }
+}
@@ -0,0 +1,584 @@
+//! LLM usage accounting and budget limits.
+//!
+//! Every completion's token usage is recorded in a ledger, priced with the
+//! per-model pricing table. [`LlmBudget`] sums the ledger over the current
+//! UTC day and month and reports whether any configured limit is exhausted;
+//! [`BudgetedLlmClient`] refuses requests once it is.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::metrics;
+use crate::textbrain::config::LlmConfig;
+use crate::textbrain::llm::{CompletionRequest, CompletionResponse, LlmClient, LlmError, LlmUsage};
+
+/// LLM budget limits. Unset limits are not enforced.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LlmBudgetConfig {
+    /// Maximum tokens (input + output) per UTC day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_tokens: Option<u64>,
+    /// Maximum tokens (input + output) per UTC month.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monthly_tokens: Option<u64>,
+    /// Maximum cost per UTC day, in the currency of the pricing table.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_cost: Option<f64>,
+    /// Maximum cost per UTC month, in the currency of the pricing table.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monthly_cost: Option<f64>,
+}
+
+/// Price of a model's tokens, per million tokens.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelPricing {
+    /// Price per million input tokens.
+    #[serde(default)]
+    pub input_per_mtok: f64,
+    /// Price per million output tokens.
+    #[serde(default)]
+    pub output_per_mtok: f64,
+}
+
+impl ModelPricing {
+    /// Cost of the given usage.
+    pub fn cost(&self, usage: &LlmUsage) -> f64 {
+        (usage.input_tokens as f64 * self.input_per_mtok
+            + usage.output_tokens as f64 * self.output_per_mtok)
+            / 1_000_000.0
+    }
+}
+
+/// Summed usage over a period.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LlmUsageTotals {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost: f64,
+}
+
+/// Trait for the LLM usage ledger.
+pub trait LlmUsageStore: Send + Sync {
+    /// Record the usage and cost of one completion.
+    fn record(
+        &self,
+        provider: &str,
+        model: &str,
+        usage: &LlmUsage,
+        cost: f64,
+    ) -> Result<(), LlmError>;
+
+    /// Sum the usage recorded at or after `since`.
+    fn totals_since(&self, since: DateTime<Utc>) -> Result<LlmUsageTotals, LlmError>;
+}
+
+/// Usage and limits for one budget period.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LlmBudgetPeriod {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_limit: Option<f64>,
+    /// Tokens left before the token limit (None = no token limit).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tokens_remaining: Option<u64>,
+    /// Cost left before the cost limit (None = no cost limit).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_remaining: Option<f64>,
+}
+
+impl LlmBudgetPeriod {
+    fn new(totals: LlmUsageTotals, token_limit: Option<u64>, cost_limit: Option<f64>) -> Self {
+        let tokens = totals.input_tokens + totals.output_tokens;
+        Self {
+            input_tokens: totals.input_tokens,
+            output_tokens: totals.output_tokens,
+            cost: totals.cost,
+            token_limit,
+            cost_limit,
+            tokens_remaining: token_limit.map(|limit| limit.saturating_sub(tokens)),
+            cost_remaining: cost_limit.map(|limit| (limit - totals.cost).max(0.0)),
+        }
+    }
+
+    /// Whether a limit of this period has been reached.
+    pub fn exhausted(&self) -> bool {
+        self.tokens_remaining == Some(0) || self.cost_remaining.is_some_and(|c| c <= 0.0)
+    }
+}
+
+/// Current usage against the configured budget.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LlmBudgetStatus {
+    /// Usage in the current UTC day.
+    pub daily: LlmBudgetPeriod,
+    /// Usage in the current UTC month.
+    pub monthly: LlmBudgetPeriod,
+    /// Whether any limit has been reached.
+    pub exhausted: bool,
+}
+
+/// Budget limits applied to a usage ledger.
+pub struct LlmBudget {
+    store: Arc<dyn LlmUsageStore>,
+    limits: LlmBudgetConfig,
+    pricing: HashMap<String, ModelPricing>,
+    /// Models already warned about for having no pricing.
+    unpriced: Mutex<HashSet<String>>,
+}
+
+impl LlmBudget {
+    pub fn new(
+        store: Arc<dyn LlmUsageStore>,
+        limits: LlmBudgetConfig,
+        pricing: HashMap<String, ModelPricing>,
+    ) -> Self {
+        Self {
+            store,
+            limits,
+            pricing,
+            unpriced: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Create a budget from the LLM configuration (no limits if there is none).
+    pub fn from_config(store: Arc<dyn LlmUsageStore>, config: Option<&LlmConfig>) -> Self {
+        match config {
+            Some(config) => Self::new(store, config.budget.clone(), config.pricing.clone()),
+            None => Self::new(store, LlmBudgetConfig::default(), HashMap::new()),
+        }
+    }
+
+    /// Cost of the given usage of a model.
+    ///
+    /// Prices are looked up by the configured model, then by the model the
+    /// provider reported (which may be a dated name for an alias). Models
+    /// without pricing cost nothing and are warned about once.
+    pub fn cost(&self, model: &str, response_model: &str, usage: &LlmUsage) -> f64 {
+        match self
+            .pricing
+            .get(model)
+            .or_else(|| self.pricing.get(response_model))
+        {
+            Some(pricing) => pricing.cost(usage),
+            None => {
+                if self.unpriced.lock().unwrap().insert(model.to_string()) {
+                    warn!(
+                        "No pricing for LLM model {} (reported as {}), its usage costs nothing",
+                        model, response_model
+                    );
+                }
+                0.0
+            }
+        }
+    }
+
+    /// Record the usage of one completion of the configured `model`, answered
+    /// by `response_model`.
+    pub fn record(
+        &self,
+        provider: &str,
+        model: &str,
+        response_model: &str,
+        usage: &LlmUsage,
+    ) -> Result<(), LlmError> {
+        let cost = self.cost(model, response_model, usage);
+
+        metrics::LLM_TOKENS
+            .with_label_values(&[provider, "input"])
+            .inc_by(usage.input_tokens as u64);
+        metrics::LLM_TOKENS
+            .with_label_values(&[provider, "output"])
+            .inc_by(usage.output_tokens as u64);
+        metrics::LLM_COST
+            .with_label_values(&[provider])
+            .inc_by(cost);
+
+        self.store.record(provider, response_model, usage, cost)
+    }
+
+    /// Current usage against the limits. Also updates the remaining-budget metrics.
+    pub fn status(&self) -> Result<LlmBudgetStatus, LlmError> {
+        let now = Utc::now();
+        let day_start = Utc
+            .with_ymd_and_hms(now.year(), now.month(), now.day(), 0, 0, 0)
+            .single()
+            .unwrap_or(now);
+        let month_start = Utc
+            .with_ymd_and_hms(now.year(), now.month(), 1, 0, 0, 0)
+            .single()
+            .unwrap_or(now);
+
+        let daily = LlmBudgetPeriod::new(
+            self.store.totals_since(day_start)?,
+            self.limits.daily_tokens,
+            self.limits.daily_cost,
+        );
+        let monthly = LlmBudgetPeriod::new(
+            self.store.totals_since(month_start)?,
+            self.limits.monthly_tokens,
+            self.limits.monthly_cost,
+        );
+
+        for (period, status) in [("daily", &daily), ("monthly", &monthly)] {
+            if let Some(tokens) = status.tokens_remaining {
+                metrics::LLM_BUDGET_REMAINING
+                    .with_label_values(&[period, "tokens"])
+                    .set(tokens as f64);
+            }
+            if let Some(cost) = status.cost_remaining {
+                metrics::LLM_BUDGET_REMAINING
+                    .with_label_values(&[period, "cost"])
+                    .set(cost);
+            }
+        }
+
+        let exhausted = daily.exhausted() || monthly.exhausted();
+        Ok(LlmBudgetStatus {
+            daily,
+            monthly,
+            exhausted,
+        })
+    }
+
+    /// Whether any limit has been reached. Ledger errors count as not exhausted.
+    pub fn is_exhausted(&self) -> bool {
+        match self.status() {
+            Ok(status) => status.exhausted,
+            Err(e) => {
+                warn!("Failed to read LLM budget: {}", e);
+                false
+            }
+        }
+    }
+}
+
+/// LLM client that records usage against a budget and refuses requests once
+/// the budget is exhausted.
+pub struct BudgetedLlmClient<C: LlmClient + ?Sized> {
+    inner: Arc<C>,
+    budget: Arc<LlmBudget>,
+}
+
+impl<C: LlmClient + ?Sized> BudgetedLlmClient<C> {
+    /// Wrap a client with a budget.
+    pub fn new(inner: Arc<C>, budget: Arc<LlmBudget>) -> Self {
+        Self { inner, budget }
+    }
+}
+
+#[async_trait]
+impl<C: LlmClient + ?Sized> LlmClient for BudgetedLlmClient<C> {
+    fn provider(&self) -> &str {
+        self.inner.provider()
+    }
+
+    fn model(&self) -> &str {
+        self.inner.model()
+    }
+
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, LlmError> {
+        if self.budget.is_exhausted() {
+            return Err(LlmError::BudgetExhausted);
+        }
+
+        let response = self.inner.complete(request).await?;
+        if let Err(e) = self.budget.record(
+            self.provider(),
+            self.model(),
+            &response.model,
+            &response.usage,
+        ) {
+            warn!("Failed to record LLM usage: {}", e);
+        }
+        Ok(response)
+    }
+}
+
+/// SQLite-backed LLM usage ledger.
+pub struct SqliteLlmUsageStore {
+    conn: Mutex<Connection>,
+}
+
+impl SqliteLlmUsageStore {
+    /// Create a new SQLite usage ledger, creating the database file and tables if needed.
+    pub fn new(path: &Path) -> Result<Self, LlmError> {
+        let conn = Connection::open(path).map_err(|e| LlmError::Database(e.to_string()))?;
+        Self::initialize_schema(&conn)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Create an in-memory SQLite usage ledger (useful for testing).
+    pub fn in_memory() -> Result<Self, LlmError> {
+        let conn = Connection::open_in_memory().map_err(|e| LlmError::Database(e.to_string()))?;
+        Self::initialize_schema(&conn)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    fn initialize_schema(conn: &Connection) -> Result<(), LlmError> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS llm_usage (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                recorded_at INTEGER NOT NULL,
+                provider TEXT NOT NULL,
+                model TEXT NOT NULL,
+                input_tokens INTEGER NOT NULL,
+                output_tokens INTEGER NOT NULL,
+                cost REAL NOT NULL
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_llm_usage_recorded_at ON llm_usage(recorded_at);
+            "#,
+        )
+        .map_err(|e| LlmError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+impl LlmUsageStore for SqliteLlmUsageStore {
+    fn record(
+        &self,
+        provider: &str,
+        model: &str,
+        usage: &LlmUsage,
+        cost: f64,
+    ) -> Result<(), LlmError> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO llm_usage (recorded_at, provider, model, input_tokens, output_tokens, cost) VALUES (?, ?, ?, ?, ?, ?)",
+            params![
+                Utc::now().timestamp(),
+                provider,
+                model,
+                usage.input_tokens,
+                usage.output_tokens,
+                cost
+            ],
+        )
+        .map_err(|e| LlmError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    fn totals_since(&self, since: DateTime<Utc>) -> Result<LlmUsageTotals, LlmError> {
+        let conn = self.conn.lock().unwrap();
+        conn.query_row(
+            "SELECT COALESCE(SUM(input_tokens), 0), COALESCE(SUM(output_tokens), 0), COALESCE(SUM(cost), 0.0) FROM llm_usage WHERE recorded_at >= ?",
+            params![since.timestamp()],
+            |row| {
+                Ok(LlmUsageTotals {
+                    input_tokens: row.get::<_, i64>(0)? as u64,
+                    output_tokens: row.get::<_, i64>(1)? as u64,
+                    cost: row.get(2)?,
+                })
+            },
+        )
+        .map_err(|e| LlmError::Database(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    struct FixedUsageClient {
+        calls: AtomicU32,
+    }
+
+    #[async_trait]
+    impl LlmClient for FixedUsageClient {
+        fn provider(&self) -> &str {
+            "mock"
+        }
+
+        fn model(&self) -> &str {
+            "mock-model"
+        }
+
+        async fn complete(
+            &self,
+            _request: CompletionRequest,
+        ) -> Result<CompletionResponse, LlmError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(CompletionResponse {
+                text: "ok".to_string(),
+                usage: LlmUsage {
+                    input_tokens: 600,
+                    output_tokens: 400,
+                },
+                model: "mock-model".to_string(),
+            })
+        }
+    }
+
+    fn budget(limits: LlmBudgetConfig) -> Arc<LlmBudget> {
+        let pricing = HashMap::from([(
+            "mock-model".to_string(),
+            ModelPricing {
+                input_per_mtok: 1.0,
+                output_per_mtok: 5.0,
+            },
+        )]);
+        Arc::new(LlmBudget::new(
+            Arc::new(SqliteLlmUsageStore::in_memory().unwrap()),
+            limits,
+            pricing,
+        ))
+    }
+
+    #[test]
+    fn test_model_pricing_cost() {
+        let pricing = ModelPricing {
+            input_per_mtok: 3.0,
+            output_per_mtok: 15.0,
+        };
+        let usage = LlmUsage {
+            input_tokens: 1_000_000,
+            output_tokens: 200_000,
+        };
+        assert!((pricing.cost(&usage) - 6.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_token_budget_blocks_requests() {
+        let budget = budget(LlmBudgetConfig {
+            daily_tokens: Some(1500),
+            ..Default::default()
+        });
+        let inner = Arc::new(FixedUsageClient {
+            calls: AtomicU32::new(0),
+        });
+        let client = BudgetedLlmClient::new(inner.clone(), budget.clone());
+
+        client.complete(CompletionRequest::new("a")).await.unwrap();
+        let status = budget.status().unwrap();
+        assert_eq!(status.daily.tokens_remaining, Some(500));
+        assert!(!status.exhausted);
+
+        // The second call overshoots the limit; the third is refused
+        client.complete(CompletionRequest::new("b")).await.unwrap();
+        let result = client.complete(CompletionRequest::new("c")).await;
+        assert!(matches!(result, Err(LlmError::BudgetExhausted)));
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+
+        let status = budget.status().unwrap();
+        assert!(status.exhausted);
+        assert_eq!(status.daily.tokens_remaining, Some(0));
+        assert_eq!(status.monthly.input_tokens, 1200);
+        assert_eq!(status.monthly.tokens_remaining, None);
+    }
+
+    #[tokio::test]
+    async fn test_cost_budget() {
+        let budget = budget(LlmBudgetConfig {
+            monthly_cost: Some(0.005),
+            ..Default::default()
+        });
+        let client = BudgetedLlmClient::new(
+            Arc::new(FixedUsageClient {
+                calls: AtomicU32::new(0),
+            }),
+            budget.clone(),
+        );
+
+        // 600 input at 1.0/Mtok + 400 output at 5.0/Mtok = 0.0026 per call
+        client.complete(CompletionRequest::new("a")).await.unwrap();
+        let status = budget.status().unwrap();
+        assert!((status.monthly.cost - 0.0026).abs() < 1e-9);
+        assert!(!status.exhausted);
+
+        client.complete(CompletionRequest::new("b")).await.unwrap();
+        let status = budget.status().unwrap();
+        assert!(status.exhausted);
+        assert_eq!(status.monthly.cost_remaining, Some(0.0));
+    }
+
+    #[tokio::test]
+    async fn test_cost_priced_by_configured_model() {
+        struct AliasClient;
+
+        #[async_trait]
+        impl LlmClient for AliasClient {
+            fn provider(&self) -> &str {
+                "mock"
+            }
+
+            fn model(&self) -> &str {
+                "mock-model"
+            }
+
+            async fn complete(
+                &self,
+                _request: CompletionRequest,
+            ) -> Result<CompletionResponse, LlmError> {
+                Ok(CompletionResponse {
+                    text: "ok".to_string(),
+                    usage: LlmUsage {
+                        input_tokens: 600,
+                        output_tokens: 400,
+                    },
+                    model: "mock-model-20241022".to_string(),
+                })
+            }
+        }
+
+        let budget = budget(LlmBudgetConfig {
+            monthly_cost: Some(0.005),
+            ..Default::default()
+        });
+        let client = BudgetedLlmClient::new(Arc::new(AliasClient), budget.clone());
+
+        client.complete(CompletionRequest::new("a")).await.unwrap();
+        client.complete(CompletionRequest::new("b")).await.unwrap();
+
+        let status = budget.status().unwrap();
+        assert!((status.monthly.cost - 0.0052).abs() < 1e-9);
+        assert!(status.exhausted);
+    }
+
+    #[test]
+    fn test_cost_falls_back_to_response_model() {
+        let budget = budget(LlmBudgetConfig::default());
+        let usage = LlmUsage {
+            input_tokens: 1_000_000,
+            output_tokens: 0,
+        };
+
+        assert_eq!(budget.cost("mock-alias", "mock-model", &usage), 1.0);
+        assert_eq!(budget.cost("unpriced", "unpriced-20241022", &usage), 0.0);
+    }
+
+    #[test]
+    fn test_unlimited_budget_is_never_exhausted() {
+        let budget = budget(LlmBudgetConfig::default());
+        let usage = LlmUsage {
+            input_tokens: 10_000_000,
+            output_tokens: 0,
+        };
+        budget
+            .record("mock", "unpriced-model", "unpriced-model", &usage)
+            .unwrap();
+
+        let status = budget.status().unwrap();
+        assert!(!status.exhausted);
+        assert_eq!(status.daily.input_tokens, 10_000_000);
+        assert_eq!(status.daily.cost, 0.0);
+    }
+}
@@ -0,0 +1,379 @@
+//! Persistent LLM completion cache.
+//!
+//! [`CachedLlmClient`] wraps any [`LlmClient`] and answers repeated requests
+//! (same model, system prompt, prompt, temperature and response schema) from a SQLite cache
+//! until the entry's TTL expires. Cached answers report zero token usage.
+//! Structured responses that do not match their schema are not cached, so a
+//! malformed answer is not replayed into the JSON repair loop.
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+use crate::metrics;
+use crate::textbrain::llm::{CompletionRequest, CompletionResponse, LlmClient, LlmError, LlmUsage};
+
+/// LLM cache configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LlmCacheConfig {
+    /// Enable the completion cache (default: true).
+    #[serde(default = "default_cache_enabled")]
+    pub enabled: bool,
+    /// How long a cached completion stays valid, in seconds (default: 7 days).
+    #[serde(default = "default_cache_ttl_secs")]
+    pub ttl_secs: u64,
+}
+
+fn default_cache_enabled() -> bool {
+    true
+}
+
+fn default_cache_ttl_secs() -> u64 {
+    7 * 24 * 60 * 60
+}
+
+impl Default for LlmCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_cache_enabled(),
+            ttl_secs: default_cache_ttl_secs(),
+        }
+    }
+}
+
+/// Trait for LLM completion cache storage.
+pub trait LlmCache: Send + Sync {
+    /// Get an unexpired completion by key.
+    fn get(&self, key: &str) -> Result<Option<CompletionResponse>, LlmError>;
+
+    /// Store a completion under a key for the given TTL, replacing any existing entry.
+    fn put(&self, key: &str, response: &CompletionResponse, ttl: Duration) -> Result<(), LlmError>;
+
+    /// Remove expired entries, returning how many were removed.
+    fn purge_expired(&self) -> Result<usize, LlmError>;
+}
+
+/// Cache key for a request: a hash of the model, system prompt, prompt,
+/// temperature and response schema.
+pub fn cache_key(model: &str, request: &CompletionRequest) -> String {
+    let mut hasher = Sha256::new();
+    for part in [
+        model,
+        request.system.as_deref().unwrap_or(""),
+        request.prompt.as_str(),
+    ] {
+        hasher.update(part.as_bytes());
+        hasher.update([0u8]);
+    }
+    hasher.update(request.temperature.to_bits().to_le_bytes());
+    if let Some(ref schema) = request.response_schema {
+        hasher.update(schema.name.as_bytes());
+        hasher.update([0u8]);
+        hasher.update(schema.schema.to_string().as_bytes());
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// LLM client that serves repeated requests from a cache.
+pub struct CachedLlmClient<C: LlmClient + ?Sized> {
+    inner: Arc<C>,
+    cache: Arc<dyn LlmCache>,
+    ttl: Duration,
+}
+
+impl<C: LlmClient + ?Sized> CachedLlmClient<C> {
+    /// Wrap a client with a cache.
+    pub fn new(inner: Arc<C>, cache: Arc<dyn LlmCache>, ttl: Duration) -> Self {
+        Self { inner, cache, ttl }
+    }
+}
+
+#[async_trait]
+impl<C: LlmClient + ?Sized> LlmClient for CachedLlmClient<C> {
+    fn provider(&self) -> &str {
+        self.inner.provider()
+    }
+
+    fn model(&self) -> &str {
+        self.inner.model()
+    }
+
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, LlmError> {
+        let key = cache_key(self.inner.model(), &request);
+
+        match self.cache.get(&key) {
+            Ok(Some(cached)) => {
+                metrics::LLM_CACHE_REQUESTS
+                    .with_label_values(&[self.provider(), "hit"])
+                    .inc();
+                return Ok(cached);
+            }
+            Ok(None) => {}
+            Err(e) => warn!("LLM cache lookup failed: {}", e),
+        }
+
+        metrics::LLM_CACHE_REQUESTS
+            .with_label_values(&[self.provider(), "miss"])
+            .inc();
+
+        let schema = request.response_schema.clone();
+        let response = self.inner.complete(request).await?;
+        if schema.is_some_and(|schema| !schema.accepts(&response.text)) {
+            return Ok(response);
+        }
+        if let Err(e) = self.cache.put(&key, &response, self.ttl) {
+            warn!("Failed to cache LLM completion: {}", e);
+        }
+        Ok(response)
+    }
+}
+
+/// SQLite-backed LLM completion cache.
+pub struct SqliteLlmCache {
+    conn: Mutex<Connection>,
+}
+
+impl SqliteLlmCache {
+    /// Create a new SQLite LLM cache, creating the database file and tables if needed.
+    pub fn new(path: &Path) -> Result<Self, LlmError> {
+        let conn = Connection::open(path).map_err(|e| LlmError::Cache(e.to_string()))?;
+        Self::initialize_schema(&conn)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Create an in-memory SQLite LLM cache (useful for testing).
+    pub fn in_memory() -> Result<Self, LlmError> {
+        let conn = Connection::open_in_memory().map_err(|e| LlmError::Cache(e.to_string()))?;
+        Self::initialize_schema(&conn)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    fn initialize_schema(conn: &Connection) -> Result<(), LlmError> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS llm_cache (
+                key TEXT PRIMARY KEY,
+                model TEXT NOT NULL,
+                text TEXT NOT NULL,
+                created_at INTEGER NOT NULL,
+                expires_at INTEGER NOT NULL
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_llm_cache_expires_at ON llm_cache(expires_at);
+            "#,
+        )
+        .map_err(|e| LlmError::Cache(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+impl LlmCache for SqliteLlmCache {
+    fn get(&self, key: &str) -> Result<Option<CompletionResponse>, LlmError> {
+        let conn = self.conn.lock().unwrap();
+        conn.query_row(
+            "SELECT text, model FROM llm_cache WHERE key = ? AND expires_at > ?",
+            params![key, Utc::now().timestamp()],
+            |row| {
+                Ok(CompletionResponse {
+                    text: row.get(0)?,
+                    usage: LlmUsage::default(),
+                    model: row.get(1)?,
+                })
+            },
+        )
+        .optional()
+        .map_err(|e| LlmError::Cache(e.to_string()))
+    }
+
+    fn put(&self, key: &str, response: &CompletionResponse, ttl: Duration) -> Result<(), LlmError> {
+        let now = Utc::now().timestamp();
+        let expires_at = now.saturating_add(ttl.as_secs().min(i64::MAX as u64) as i64);
+
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT OR REPLACE INTO llm_cache (key, model, text, created_at, expires_at) VALUES (?, ?, ?, ?, ?)",
+            params![key, response.model, response.text, now, expires_at],
+        )
+        .map_err(|e| LlmError::Cache(e.to_string()))?;
+
+        Ok(())
+    }
+
+    fn purge_expired(&self) -> Result<usize, LlmError> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "DELETE FROM llm_cache WHERE expires_at <= ?",
+            params![Utc::now().timestamp()],
+        )
+        .map_err(|e| LlmError::Cache(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    struct CountingClient {
+        calls: AtomicU32,
+    }
+
+    #[async_trait]
+    impl LlmClient for CountingClient {
+        fn provider(&self) -> &str {
+            "mock"
+        }
+
+        fn model(&self) -> &str {
+            "mock-model"
+        }
+
+        async fn complete(
+            &self,
+            request: CompletionRequest,
+        ) -> Result<CompletionResponse, LlmError> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            // JSON prompts are echoed back as the answer
+            let text = if request.prompt.starts_with('{') {
+                request.prompt
+            } else {
+                format!("{} #{}", request.prompt, call)
+            };
+            Ok(CompletionResponse {
+                text,
+                usage: LlmUsage {
+                    input_tokens: 10,
+                    output_tokens: 5,
+                },
+                model: "mock-model".to_string(),
+            })
+        }
+    }
+
+    fn cached_client(ttl: Duration) -> (Arc<CountingClient>, CachedLlmClient<CountingClient>) {
+        let inner = Arc::new(CountingClient {
+            calls: AtomicU32::new(0),
+        });
+        let cache = Arc::new(SqliteLlmCache::in_memory().unwrap());
+        (inner.clone(), CachedLlmClient::new(inner, cache, ttl))
+    }
+
+    #[tokio::test]
+    async fn test_repeated_request_served_from_cache() {
+        let (inner, client) = cached_client(Duration::from_secs(60));
+        let request = CompletionRequest::new("score these").with_system("You rank torrents");
+
+        let first = client.complete(request.clone()).await.unwrap();
+        let second = client.complete(request).await.unwrap();
+
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(first.text, second.text);
+        assert_eq!(first.usage.input_tokens, 10);
+        assert_eq!(second.usage.input_tokens, 0);
+        assert_eq!(second.model, "mock-model");
+    }
+
+    #[tokio::test]
+    async fn test_key_covers_prompt_system_and_temperature() {
+        let (inner, client) = cached_client(Duration::from_secs(60));
+
+        client.complete(CompletionRequest::new("a")).await.unwrap();
+        client.complete(CompletionRequest::new("b")).await.unwrap();
+        client
+            .complete(CompletionRequest::new("a").with_system("other"))
+            .await
+            .unwrap();
+        client
+            .complete(CompletionRequest::new("a").with_temperature(0.5))
+            .await
+            .unwrap();
+        client
+            .complete(
+                CompletionRequest::new("a")
+                    .with_response_schema("out", serde_json::json!({"type": "object"})),
+            )
+            .await
+            .unwrap();
+        // max_tokens is not part of the key
+        client
+            .complete(CompletionRequest::new("a").with_max_tokens(10))
+            .await
+            .unwrap();
+
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test]
+    async fn test_malformed_structured_responses_are_not_cached() {
+        let (inner, client) = cached_client(Duration::from_secs(60));
+        let schema = serde_json::json!({"type": "object", "required": ["queries"]});
+
+        for _ in 0..2 {
+            client
+                .complete(
+                    CompletionRequest::new("not json").with_response_schema("q", schema.clone()),
+                )
+                .await
+                .unwrap();
+            client
+                .complete(
+                    CompletionRequest::new(r#"{"other": 1}"#)
+                        .with_response_schema("q", schema.clone()),
+                )
+                .await
+                .unwrap();
+        }
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 4);
+
+        for _ in 0..2 {
+            client
+                .complete(
+                    CompletionRequest::new(r#"{"queries": []}"#)
+                        .with_response_schema("q", schema.clone()),
+                )
+                .await
+                .unwrap();
+        }
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_are_not_served() {
+        let (inner, client) = cached_client(Duration::ZERO);
+
+        client.complete(CompletionRequest::new("a")).await.unwrap();
+        client.complete(CompletionRequest::new("a")).await.unwrap();
+
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_purge_expired() {
+        let cache = SqliteLlmCache::in_memory().unwrap();
+        let response = CompletionResponse {
+            text: "x".to_string(),
+            usage: LlmUsage::default(),
+            model: "m".to_string(),
+        };
+        cache.put("old", &response, Duration::ZERO).unwrap();
+        cache
+            .put("fresh", &response, Duration::from_secs(60))
+            .unwrap();
+
+        assert_eq!(cache.purge_expired().unwrap(), 1);
+        assert!(cache.get("fresh").unwrap().is_some());
+        assert!(cache.get("old").unwrap().is_none());
+    }
+}
@@ -0,0 +1,532 @@
+//! LLM provider fallback chain.
+//!
+//! [`FallbackLlmClient`] tries an ordered list of providers, each with its own
+//! request timeout, and moves on to the next one when a provider fails.
+//! Every provider is guarded by a circuit breaker: after
+//! `failure_threshold` consecutive failures it is skipped until
+//! `cooldown_secs` have passed, after which a single trial request decides
+//! whether it is closed again. Breaker state lives in [`LlmProviderHealth`],
+//! which outlives individual clients and backs the health endpoint.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::textbrain::llm::{CompletionRequest, CompletionResponse, LlmClient, LlmError};
+
+/// Circuit breaker configuration for LLM providers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LlmCircuitBreakerConfig {
+    /// Consecutive failures after which a provider is skipped (default: 3).
+    #[serde(default = "default_failure_threshold")]
+    pub failure_threshold: u32,
+    /// How long an open circuit skips the provider, in seconds (default: 60).
+    #[serde(default = "default_cooldown_secs")]
+    pub cooldown_secs: u64,
+}
+
+fn default_failure_threshold() -> u32 {
+    3
+}
+
+fn default_cooldown_secs() -> u64 {
+    60
+}
+
+impl Default for LlmCircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: default_failure_threshold(),
+            cooldown_secs: default_cooldown_secs(),
+        }
+    }
+}
+
+/// State of a provider's circuit breaker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Requests go through.
+    Closed,
+    /// Too many consecutive failures, the provider is skipped.
+    Open,
+    /// Cooldown elapsed, the next request is a trial.
+    HalfOpen,
+}
+
+/// Health of one LLM provider, as reported by the health endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LlmProviderStatus {
+    /// Provider name (e.g., "anthropic", "ollama").
+    pub provider: String,
+    /// Model name.
+    pub model: String,
+    /// Circuit breaker state.
+    pub state: CircuitState,
+    /// Failures since the last success.
+    pub consecutive_failures: u32,
+    /// Requests sent to this provider since startup.
+    pub total_requests: u64,
+    /// Failed requests since startup.
+    pub total_failures: u64,
+    /// Message of the most recent failure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    /// When the provider last answered successfully.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_success_at: Option<DateTime<Utc>>,
+    /// When the provider last failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_failure_at: Option<DateTime<Utc>>,
+    /// Latency of the last successful request in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_latency_ms: Option<u64>,
+}
+
+#[derive(Debug, Default)]
+struct ProviderHealth {
+    consecutive_failures: u32,
+    total_requests: u64,
+    total_failures: u64,
+    opened_at: Option<Instant>,
+    last_error: Option<String>,
+    last_success_at: Option<DateTime<Utc>>,
+    last_failure_at: Option<DateTime<Utc>>,
+    last_latency_ms: Option<u64>,
+}
+
+/// Circuit breakers and request statistics of all LLM providers.
+///
+/// Shared between the clients built for each acquisition so breaker state
+/// survives across tickets.
+#[derive(Debug, Default)]
+pub struct LlmProviderHealth {
+    config: LlmCircuitBreakerConfig,
+    providers: Mutex<HashMap<(String, String), ProviderHealth>>,
+}
+
+impl LlmProviderHealth {
+    pub fn new(config: LlmCircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            providers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn state_of(&self, health: &ProviderHealth) -> CircuitState {
+        match health.opened_at {
+            Some(opened_at)
+                if opened_at.elapsed() < Duration::from_secs(self.config.cooldown_secs) =>
+            {
+                CircuitState::Open
+            }
+            Some(_) => CircuitState::HalfOpen,
+            None => CircuitState::Closed,
+        }
+    }
+
+    /// Current circuit state of a provider.
+    pub fn state(&self, provider: &str, model: &str) -> CircuitState {
+        let providers = self.providers.lock().unwrap();
+        providers
+            .get(&(provider.to_string(), model.to_string()))
+            .map(|health| self.state_of(health))
+            .unwrap_or(CircuitState::Closed)
+    }
+
+    /// Record a successful request, closing the provider's circuit.
+    pub fn record_success(&self, provider: &str, model: &str, latency: Duration) {
+        let mut providers = self.providers.lock().unwrap();
+        let health = providers
+            .entry((provider.to_string(), model.to_string()))
+            .or_default();
+        health.total_requests += 1;
+        health.consecutive_failures = 0;
+        health.opened_at = None;
+        health.last_success_at = Some(Utc::now());
+        health.last_latency_ms = Some(latency.as_millis() as u64);
+    }
+
+    /// Record a failed request, opening the provider's circuit once the
+    /// failure threshold is reached or when a trial request fails.
+    pub fn record_failure(&self, provider: &str, model: &str, error: &LlmError) {
+        let mut providers = self.providers.lock().unwrap();
+        let health = providers
+            .entry((provider.to_string(), model.to_string()))
+            .or_default();
+        health.total_requests += 1;
+        health.total_failures += 1;
+        health.consecutive_failures += 1;
+        health.last_error = Some(error.to_string());
+        health.last_failure_at = Some(Utc::now());
+        if health.opened_at.is_some()
+            || health.consecutive_failures >= self.config.failure_threshold
+        {
+            health.opened_at = Some(Instant::now());
+        }
+    }
+
+    /// Status of a provider. Providers that were never used are reported closed.
+    pub fn status(&self, provider: &str, model: &str) -> LlmProviderStatus {
+        let providers = self.providers.lock().unwrap();
+        let health = providers.get(&(provider.to_string(), model.to_string()));
+        LlmProviderStatus {
+            provider: provider.to_string(),
+            model: model.to_string(),
+            state: health
+                .map(|h| self.state_of(h))
+                .unwrap_or(CircuitState::Closed),
+            consecutive_failures: health.map(|h| h.consecutive_failures).unwrap_or(0),
+            total_requests: health.map(|h| h.total_requests).unwrap_or(0),
+            total_failures: health.map(|h| h.total_failures).unwrap_or(0),
+            last_error: health.and_then(|h| h.last_error.clone()),
+            last_success_at: health.and_then(|h| h.last_success_at),
+            last_failure_at: health.and_then(|h| h.last_failure_at),
+            last_latency_ms: health.and_then(|h| h.last_latency_ms),
+        }
+    }
+}
+
+/// One provider of a fallback chain.
+pub struct FallbackProvider {
+    client: Arc<dyn LlmClient>,
+    timeout: Duration,
+}
+
+impl FallbackProvider {
+    pub fn new(client: Arc<dyn LlmClient>, timeout: Duration) -> Self {
+        Self { client, timeout }
+    }
+}
+
+/// LLM client trying an ordered list of providers until one answers.
+///
+/// Reports the first provider's name and model; responses carry the model
+/// that actually answered. An exhausted budget ends the chain without
+/// counting as a provider failure, since the budget is shared.
+pub struct FallbackLlmClient {
+    providers: Vec<FallbackProvider>,
+    health: Arc<LlmProviderHealth>,
+}
+
+impl FallbackLlmClient {
+    /// Create a fallback chain. `providers` must not be empty.
+    pub fn new(providers: Vec<FallbackProvider>, health: Arc<LlmProviderHealth>) -> Self {
+        assert!(
+            !providers.is_empty(),
+            "fallback chain needs at least one provider"
+        );
+        Self { providers, health }
+    }
+}
+
+#[async_trait]
+impl LlmClient for FallbackLlmClient {
+    fn provider(&self) -> &str {
+        self.providers[0].client.provider()
+    }
+
+    fn model(&self) -> &str {
+        self.providers[0].client.model()
+    }
+
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, LlmError> {
+        let mut last_error = None;
+
+        for entry in &self.providers {
+            let (provider, model) = (entry.client.provider(), entry.client.model());
+            if self.health.state(provider, model) == CircuitState::Open {
+                continue;
+            }
+
+            let started = Instant::now();
+            let result =
+                match tokio::time::timeout(entry.timeout, entry.client.complete(request.clone()))
+                    .await
+                {
+                    Ok(result) => result,
+                    Err(_) => Err(LlmError::Timeout(entry.timeout)),
+                };
+
+            match result {
+                Ok(response) => {
+                    self.health
+                        .record_success(provider, model, started.elapsed());
+                    return Ok(response);
+                }
+                Err(LlmError::BudgetExhausted) => return Err(LlmError::BudgetExhausted),
+                Err(e) => {
+                    warn!("LLM provider {} ({}) failed: {}", provider, model, e);
+                    self.health.record_failure(provider, model, &e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or(LlmError::Unavailable(
+            "all LLM providers have open circuits".to_string(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::textbrain::llm::LlmUsage;
+    use crate::textbrain::llm_budget::{
+        BudgetedLlmClient, LlmBudget, LlmBudgetConfig, LlmUsageStore, LlmUsageTotals,
+    };
+    use crate::textbrain::llm_cache::{CachedLlmClient, SqliteLlmCache};
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+    struct MockClient {
+        name: &'static str,
+        fail: AtomicBool,
+        calls: AtomicU32,
+    }
+
+    impl MockClient {
+        fn new(name: &'static str, fail: bool) -> Arc<Self> {
+            Arc::new(Self {
+                name,
+                fail: AtomicBool::new(fail),
+                calls: AtomicU32::new(0),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl LlmClient for MockClient {
+        fn provider(&self) -> &str {
+            self.name
+        }
+
+        fn model(&self) -> &str {
+            "model"
+        }
+
+        async fn complete(
+            &self,
+            _request: CompletionRequest,
+        ) -> Result<CompletionResponse, LlmError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.fail.load(Ordering::SeqCst) {
+                return Err(LlmError::Http("connection refused".to_string()));
+            }
+            Ok(CompletionResponse {
+                text: self.name.to_string(),
+                usage: LlmUsage::default(),
+                model: "model".to_string(),
+            })
+        }
+    }
+
+    fn chain(clients: &[Arc<MockClient>], health: &Arc<LlmProviderHealth>) -> FallbackLlmClient {
+        FallbackLlmClient::new(
+            clients
+                .iter()
+                .map(|c| FallbackProvider::new(c.clone(), Duration::from_secs(5)))
+                .collect(),
+            Arc::clone(health),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_next_provider() {
+        let health = Arc::new(LlmProviderHealth::default());
+        let primary = MockClient::new("anthropic", true);
+        let local = MockClient::new("ollama", false);
+        let client = chain(&[primary.clone(), local.clone()], &health);
+
+        let response = client.complete(CompletionRequest::new("hi")).await.unwrap();
+        assert_eq!(response.text, "ollama");
+        assert_eq!(client.provider(), "anthropic");
+
+        let status = health.status("anthropic", "model");
+        assert_eq!(status.consecutive_failures, 1);
+        assert_eq!(status.state, CircuitState::Closed);
+        assert!(status.last_error.unwrap().contains("connection refused"));
+        assert_eq!(health.status("ollama", "model").total_requests, 1);
+    }
+
+    #[tokio::test]
+    async fn test_open_circuit_skips_provider() {
+        let health = Arc::new(LlmProviderHealth::new(LlmCircuitBreakerConfig {
+            failure_threshold: 2,
+            cooldown_secs: 60,
+        }));
+        let primary = MockClient::new("anthropic", true);
+        let local = MockClient::new("ollama", false);
+        let client = chain(&[primary.clone(), local.clone()], &health);
+
+        for _ in 0..4 {
+            client.complete(CompletionRequest::new("hi")).await.unwrap();
+        }
+
+        assert_eq!(primary.calls.load(Ordering::SeqCst), 2);
+        assert_eq!(local.calls.load(Ordering::SeqCst), 4);
+        assert_eq!(health.state("anthropic", "model"), CircuitState::Open);
+    }
+
+    #[tokio::test]
+    async fn test_half_open_trial_closes_or_reopens() {
+        let health = Arc::new(LlmProviderHealth::new(LlmCircuitBreakerConfig {
+            failure_threshold: 1,
+            cooldown_secs: 0,
+        }));
+        let error = LlmError::Http("down".to_string());
+
+        health.record_failure("ollama", "model", &error);
+        assert_eq!(health.state("ollama", "model"), CircuitState::HalfOpen);
+
+        health.record_success("ollama", "model", Duration::from_millis(12));
+        let status = health.status("ollama", "model");
+        assert_eq!(status.state, CircuitState::Closed);
+        assert_eq!(status.last_latency_ms, Some(12));
+        assert_eq!(status.total_failures, 1);
+    }
+
+    #[tokio::test]
+    async fn test_all_providers_failing_returns_last_error() {
+        let health = Arc::new(LlmProviderHealth::default());
+        let client = chain(
+            &[
+                MockClient::new("anthropic", true),
+                MockClient::new("ollama", true),
+            ],
+            &health,
+        );
+
+        let err = client
+            .complete(CompletionRequest::new("hi"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LlmError::Http(_)));
+    }
+
+    /// Usage ledger remembering which provider each completion was charged to.
+    #[derive(Default)]
+    struct RecordingStore {
+        providers: Mutex<Vec<String>>,
+    }
+
+    impl LlmUsageStore for RecordingStore {
+        fn record(
+            &self,
+            provider: &str,
+            _model: &str,
+            _usage: &LlmUsage,
+            _cost: f64,
+        ) -> Result<(), LlmError> {
+            self.providers.lock().unwrap().push(provider.to_string());
+            Ok(())
+        }
+
+        fn totals_since(&self, _since: DateTime<Utc>) -> Result<LlmUsageTotals, LlmError> {
+            Ok(LlmUsageTotals::default())
+        }
+    }
+
+    fn budgeted_chain(
+        clients: &[Arc<MockClient>],
+        budget: &Arc<LlmBudget>,
+        health: &Arc<LlmProviderHealth>,
+    ) -> FallbackLlmClient {
+        FallbackLlmClient::new(
+            clients
+                .iter()
+                .map(|c| {
+                    let client: Arc<dyn LlmClient> =
+                        Arc::new(BudgetedLlmClient::new(c.clone(), Arc::clone(budget)));
+                    FallbackProvider::new(client, Duration::from_secs(5))
+                })
+                .collect(),
+            Arc::clone(health),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_usage_charged_to_answering_provider() {
+        let store = Arc::new(RecordingStore::default());
+        let budget = Arc::new(LlmBudget::new(
+            store.clone(),
+            LlmBudgetConfig::default(),
+            HashMap::new(),
+        ));
+        let health = Arc::new(LlmProviderHealth::default());
+        let client = budgeted_chain(
+            &[
+                MockClient::new("anthropic", true),
+                MockClient::new("ollama", false),
+            ],
+            &budget,
+            &health,
+        );
+
+        client.complete(CompletionRequest::new("hi")).await.unwrap();
+
+        assert_eq!(*store.providers.lock().unwrap(), vec!["ollama".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_budget_does_not_trip_breakers() {
+        let budget = Arc::new(LlmBudget::new(
+            Arc::new(RecordingStore::default()),
+            LlmBudgetConfig {
+                daily_tokens: Some(0),
+                ..Default::default()
+            },
+            HashMap::new(),
+        ));
+        let health = Arc::new(LlmProviderHealth::new(LlmCircuitBreakerConfig {
+            failure_threshold: 1,
+            cooldown_secs: 60,
+        }));
+        let local = MockClient::new("ollama", false);
+        let client = budgeted_chain(
+            &[MockClient::new("anthropic", false), local.clone()],
+            &budget,
+            &health,
+        );
+
+        let err = client
+            .complete(CompletionRequest::new("hi"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LlmError::BudgetExhausted));
+        assert_eq!(local.calls.load(Ordering::SeqCst), 0);
+        assert_eq!(health.state("anthropic", "model"), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_fallback_answers_not_replayed_for_recovered_primary() {
+        let health = Arc::new(LlmProviderHealth::default());
+        let primary = MockClient::new("anthropic", true);
+        let local = MockClient::new("ollama", false);
+        let client = FallbackLlmClient::new(
+            [primary.clone(), local.clone()]
+                .into_iter()
+                .map(|c| {
+                    let cache = Arc::new(SqliteLlmCache::in_memory().unwrap());
+                    let client: Arc<dyn LlmClient> =
+                        Arc::new(CachedLlmClient::new(c, cache, Duration::from_secs(60)));
+                    FallbackProvider::new(client, Duration::from_secs(5))
+                })
+                .collect(),
+            health,
+        );
+
+        let response = client.complete(CompletionRequest::new("hi")).await.unwrap();
+        assert_eq!(response.text, "ollama");
+
+        primary.fail.store(false, Ordering::SeqCst);
+        let response = client.complete(CompletionRequest::new("hi")).await.unwrap();
+        assert_eq!(response.text, "anthropic");
+        assert_eq!(local.calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,464 @@
+//! LLM-powered file mapper implementation.
+//!
+//! Used for torrents whose layout defeats the heuristic mapper (CUE sheets
+//! with a single image file, mixed disc folders, foreign-language titles).
+//! The returned mapping is validated against the real file listing and the
+//! expected items before it is accepted.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::searcher::TorrentFile;
+use crate::textbrain::llm::LlmClient;
+use crate::textbrain::prompts::{PromptLibrary, PromptTask, RenderedPrompt};
+use crate::textbrain::traits::{FileMapper, TextBrainError};
+use crate::textbrain::types::{FileMapping, FileMappingResult};
+use crate::ticket::ExpectedContent;
+
+/// Configuration for the LLM file mapper.
+#[derive(Debug, Clone)]
+pub struct LlmFileMapperConfig {
+    /// Maximum files listed in the prompt (to limit token usage).
+    pub max_files: usize,
+    /// Maximum tokens for the LLM response.
+    pub max_tokens: u32,
+    /// Temperature for generation.
+    pub temperature: f32,
+}
+
+impl Default for LlmFileMapperConfig {
+    fn default() -> Self {
+        Self {
+            max_files: 200,
+            max_tokens: 2048,
+            temperature: 0.0, // Deterministic mapping
+        }
+    }
+}
+
+/// LLM-powered file mapper.
+///
+/// Generic over the LLM client type to support different backends
+/// (Anthropic, Ollama, etc.).
+pub struct LlmFileMapper<C: LlmClient> {
+    client: Arc<C>,
+    config: LlmFileMapperConfig,
+    prompts: Arc<PromptLibrary>,
+}
+
+impl<C: LlmClient> LlmFileMapper<C> {
+    /// Create a new LLM file mapper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self::with_config(client, LlmFileMapperConfig::default())
+    }
+
+    /// Create with custom configuration.
+    pub fn with_config(client: Arc<C>, config: LlmFileMapperConfig) -> Self {
+        Self {
+            client,
+            config,
+            prompts: Arc::new(PromptLibrary::builtin()),
+        }
+    }
+
+    /// Use prompt templates from the given library instead of the built-in ones.
+    pub fn with_prompts(mut self, prompts: Arc<PromptLibrary>) -> Self {
+        self.prompts = prompts;
+        self
+    }
+
+    /// Render the file mapping prompts for the expected items and the file listing.
+    ///
+    /// Template variables: `{{expected}}` (item IDs) and `{{files}}`.
+    fn render_prompt(&self, files: &[TorrentFile], expected: &ExpectedContent) -> RenderedPrompt {
+        let mut items = String::new();
+        for (id, description) in expected_items(expected) {
+            items.push_str(&format!("- {}: {}\n", id, description));
+        }
+
+        let mut listing = String::new();
+        for file in files.iter().take(self.config.max_files) {
+            listing.push_str(&format!(
+                "- {} ({:.1} MB)\n",
+                file.path,
+                file.size_bytes as f64 / 1_048_576.0
+            ));
+        }
+        if files.len() > self.config.max_files {
+            listing.push_str(&format!(
+                "... and {} more files\n",
+                files.len() - self.config.max_files
+            ));
+        }
+
+        self.prompts
+            .template(PromptTask::FileMapping, Some(expected.type_name()))
+            .render(
+                PromptTask::FileMapping,
+                &[("expected", items), ("files", listing)],
+            )
+    }
+
+    /// JSON schema of [`LlmFileMappingResponse`].
+    fn response_schema() -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "mappings": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "item_id": {"type": "string"},
+                            "file_path": {"type": "string"},
+                            "confidence": {"type": "number", "minimum": 0, "maximum": 1}
+                        },
+                        "required": ["item_id", "file_path"]
+                    }
+                }
+            },
+            "required": ["mappings"]
+        })
+    }
+
+    /// Keep only mappings to real files and expected items, one per item.
+    fn validate(
+        &self,
+        parsed: LlmFileMappingResponse,
+        files: &[TorrentFile],
+        expected: &ExpectedContent,
+    ) -> Vec<FileMapping> {
+        let paths: HashSet<&str> = files.iter().map(|f| f.path.as_str()).collect();
+        let item_ids: HashSet<String> = expected_items(expected)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+
+        let mut mapped_items = HashSet::new();
+        let mut mappings = Vec::new();
+        for item in parsed.mappings {
+            if !paths.contains(item.file_path.as_str()) {
+                tracing::debug!(path = %item.file_path, "LLM mapped a file not in the torrent");
+                continue;
+            }
+            if !item_ids.contains(&item.item_id) || !mapped_items.insert(item.item_id.clone()) {
+                continue;
+            }
+            mappings.push(FileMapping {
+                torrent_file_path: item.file_path,
+                ticket_item_id: item.item_id,
+                confidence: item.confidence.unwrap_or(0.8).clamp(0.0, 1.0),
+            });
+        }
+
+        mappings.sort_by(|a, b| {
+            b.confidence
+                .partial_cmp(&a.confidence)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        mappings
+    }
+}
+
+/// Expected JSON response from the LLM for file mapping.
+#[derive(Debug, Deserialize, Serialize)]
+struct LlmFileMappingResponse {
+    mappings: Vec<LlmFileMappingItem>,
+}
+
+/// Individual mapping from the LLM.
+#[derive(Debug, Deserialize, Serialize)]
+struct LlmFileMappingItem {
+    item_id: String,
+    file_path: String,
+    confidence: Option<f32>,
+}
+
+/// Item IDs (as used by [`DumbFileMapper`](super::DumbFileMapper)) and
+/// descriptions of the expected content.
+fn expected_items(expected: &ExpectedContent) -> Vec<(String, String)> {
+    match expected {
+        ExpectedContent::Album { tracks, .. } => tracks
+            .iter()
+            .map(|track| {
+                let description = match track.disc_number {
+                    Some(disc) => format!("disc {} track {}: {}", disc, track.number, track.title),
+                    None => format!("track {}: {}", track.number, track.title),
+                };
+                (format!("track-{}", track.number), description)
+            })
+            .collect(),
+        // Albums whose tracks are unknown cannot be itemised
+        ExpectedContent::Discography { albums, .. } => albums
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, album)| {
+                album.tracks.iter().map(move |track| {
+                    (
+                        format!("album-{}-track-{}", idx + 1, track.number),
+                        format!("{} track {}: {}", album.title, track.number, track.title),
+                    )
+                })
+            })
+            .collect(),
+        ExpectedContent::Track { title, artist } => {
+            let description = match artist {
+                Some(artist) => format!("{} - {}", artist, title),
+                None => title.clone(),
+            };
+            vec![("track-1".to_string(), description)]
+        }
+        ExpectedContent::Movie { title, year } => {
+            let description = match year {
+                Some(year) => format!("{} ({}), main feature", title, year),
+                None => format!("{}, main feature", title),
+            };
+            vec![("movie".to_string(), description)]
+        }
+        ExpectedContent::TvEpisode {
+            series,
+            season,
+            episodes,
+        } => episode_items(series, *season, episodes),
+        ExpectedContent::TvSeason {
+            series,
+            season,
+            episodes,
+        } => episode_items(series, *season, episodes),
+        ExpectedContent::TvSeries {
+            series,
+            resolved_seasons,
+            ..
+        } => resolved_seasons
+            .iter()
+            .flat_map(|s| episode_items(series, s.season, &s.episodes))
+            .collect(),
+        ExpectedContent::Audiobook {
+            title, chapters, ..
+        } => {
+            if chapters.is_empty() {
+                vec![("book".to_string(), format!("{}, complete audiobook", title))]
+            } else {
+                chapters
+                    .iter()
+                    .map(|chapter| {
+                        (
+                            format!("chapter-{}", chapter.number),
+                            format!("chapter {}: {}", chapter.number, chapter.title),
+                        )
+                    })
+                    .collect()
+            }
+        }
+        ExpectedContent::Book { author, title, .. } => {
+            let description = match author {
+                Some(author) => format!("{} - {}, ebook", author, title),
+                None => format!("{}, ebook", title),
+            };
+            vec![("book".to_string(), description)]
+        }
+        ExpectedContent::Comic {
+            series,
+            issue,
+            volume,
+        } => {
+            let mut description = series.clone();
+            if let Some(volume) = volume {
+                description.push_str(&format!(" vol. {}", volume));
+            }
+            if let Some(issue) = issue {
+                description.push_str(&format!(" #{}", issue));
+            }
+            vec![("comic".to_string(), description)]
+        }
+    }
+}
+
+/// One `sNNeNN` item per episode of a season.
+fn episode_items(series: &str, season: u32, episodes: &[u32]) -> Vec<(String, String)> {
+    episodes
+        .iter()
+        .map(|episode| {
+            (
+                format!("s{:02}e{:02}", season, episode),
+                format!("{} season {} episode {}", series, season, episode),
+            )
+        })
+        .collect()
+}
+
+#[async_trait]
+impl<C: LlmClient + 'static> FileMapper for LlmFileMapper<C> {
+    fn name(&self) -> &str {
+        "llm"
+    }
+
+    async fn map_files(
+        &self,
+        files: &[TorrentFile],
+        expected: &ExpectedContent,
+    ) -> Result<FileMappingResult, TextBrainError> {
+        if files.is_empty() {
+            return Ok(FileMappingResult {
+                mappings: Vec::new(),
+                method: "llm".to_string(),
+                llm_usage: None,
+            });
+        }
+
+        let request = self
+            .render_prompt(files, expected)
+            .into_request()
+            .with_max_tokens(self.config.max_tokens)
+            .with_temperature(self.config.temperature)
+            .with_response_schema("file_mappings", Self::response_schema());
+
+        let (parsed, usage) = self
+            .client
+            .complete_json::<LlmFileMappingResponse>(request)
+            .await
+            .map_err(|e| TextBrainError::LlmError(e.to_string()))?;
+
+        Ok(FileMappingResult {
+            mappings: self.validate(parsed, files, expected),
+            method: "llm".to_string(),
+            llm_usage: Some(usage),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::textbrain::llm::{CompletionRequest, CompletionResponse, LlmError, LlmUsage};
+    use crate::ticket::ExpectedTrack;
+
+    /// Mock LLM client for testing.
+    struct MockLlmClient {
+        response: String,
+    }
+
+    #[async_trait]
+    impl LlmClient for MockLlmClient {
+        fn provider(&self) -> &str {
+            "mock"
+        }
+
+        fn model(&self) -> &str {
+            "mock-model"
+        }
+
+        async fn complete(
+            &self,
+            _request: CompletionRequest,
+        ) -> Result<CompletionResponse, LlmError> {
+            Ok(CompletionResponse {
+                text: self.response.clone(),
+                usage: LlmUsage {
+                    input_tokens: 300,
+                    output_tokens: 80,
+                },
+                model: "mock-model".to_string(),
+            })
+        }
+    }
+
+    fn mapper(response: &str) -> LlmFileMapper<MockLlmClient> {
+        LlmFileMapper::new(Arc::new(MockLlmClient {
+            response: response.to_string(),
+        }))
+    }
+
+    fn file(path: &str, size_bytes: u64) -> TorrentFile {
+        TorrentFile {
+            path: path.to_string(),
+            size_bytes,
+        }
+    }
+
+    fn album() -> ExpectedContent {
+        ExpectedContent::album(
+            "Kind of Blue",
+            vec![
+                ExpectedTrack::new(1, "So What"),
+                ExpectedTrack::new(2, "Freddie Freeloader"),
+                ExpectedTrack::new(3, "Blue in Green"),
+            ],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_cue_image_maps_all_tracks() {
+        let files = vec![
+            file("Miles Davis - Kind of Blue/CDImage.flac", 300_000_000),
+            file("Miles Davis - Kind of Blue/CDImage.cue", 2_000),
+        ];
+        let mapper = mapper(
+            r#"{"mappings": [
+                {"item_id": "track-1", "file_path": "Miles Davis - Kind of Blue/CDImage.flac", "confidence": 0.9},
+                {"item_id": "track-2", "file_path": "Miles Davis - Kind of Blue/CDImage.flac", "confidence": 0.9},
+                {"item_id": "track-3", "file_path": "Miles Davis - Kind of Blue/CDImage.flac", "confidence": 0.9}
+            ]}"#,
+        );
+
+        let result = mapper.map_files(&files, &album()).await.unwrap();
+
+        assert_eq!(result.mappings.len(), 3);
+        assert_eq!(result.method, "llm");
+        assert_eq!(result.llm_usage.unwrap().input_tokens, 300);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_unknown_paths_items_and_duplicates() {
+        let files = vec![
+            file("Disc 1/01.flac", 30_000_000),
+            file("Disc 1/02.flac", 30_000_000),
+        ];
+        let mapper = mapper(
+            r#"{"mappings": [
+                {"item_id": "track-1", "file_path": "Disc 1/01.flac", "confidence": 0.95},
+                {"item_id": "track-1", "file_path": "Disc 1/02.flac", "confidence": 0.5},
+                {"item_id": "track-2", "file_path": "Disc 1/02 - Freddie.flac", "confidence": 0.9},
+                {"item_id": "track-9", "file_path": "Disc 1/02.flac", "confidence": 0.9},
+                {"item_id": "track-3", "file_path": "Disc 1/02.flac", "confidence": 4.0}
+            ]}"#,
+        );
+
+        let result = mapper.map_files(&files, &album()).await.unwrap();
+
+        assert_eq!(result.mappings.len(), 2);
+        assert_eq!(result.mappings[0].ticket_item_id, "track-3");
+        assert_eq!(result.mappings[0].confidence, 1.0);
+        assert_eq!(result.mappings[1].ticket_item_id, "track-1");
+        assert_eq!(result.mappings[1].torrent_file_path, "Disc 1/01.flac");
+    }
+
+    #[test]
+    fn test_expected_item_ids_match_dumb_mapper() {
+        let tv = ExpectedContent::TvEpisode {
+            series: "Dark".to_string(),
+            season: 1,
+            episodes: vec![1, 2],
+        };
+        let ids: Vec<String> = expected_items(&tv).into_iter().map(|(id, _)| id).collect();
+        assert_eq!(ids, vec!["s01e01", "s01e02"]);
+
+        let movie = ExpectedContent::movie("Heat");
+        assert_eq!(expected_items(&movie)[0].0, "movie");
+    }
+
+    #[test]
+    fn test_build_user_prompt_lists_items_and_files() {
+        let mapper = mapper("{}");
+        let prompt = mapper
+            .render_prompt(&[file("CD1/01 - So What.flac", 52_428_800)], &album())
+            .user;
+
+        assert!(prompt.contains("- track-1: track 1: So What"));
+        assert!(prompt.contains("- CD1/01 - So What.flac (50.0 MB)"));
+    }
+}
@@ -5,10 +5,12 @@
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::sync::Arc;
 
 use crate::searcher::TorrentCandidate;
-use crate::textbrain::llm::{CompletionRequest, LlmClient, LlmUsage};
+use crate::textbrain::llm::{LlmClient, LlmUsage};
+use crate::textbrain::prompts::{PromptLibrary, PromptTask, RenderedPrompt};
 use crate::textbrain::traits::{CandidateMatcher, TextBrainError};
 use crate::textbrain::types::{MatchResult, ScoredCandidate};
 use crate::ticket::{ExpectedContent, QueryContext};
@@ -44,73 +46,49 @@ impl Default for LlmMatcherConfig {
 pub struct LlmMatcher<C: LlmClient> {
     client: Arc<C>,
     config: LlmMatcherConfig,
+    prompts: Arc<PromptLibrary>,
 }
 
 impl<C: LlmClient> LlmMatcher<C> {
     /// Create a new LLM matcher.
     pub fn new(client: Arc<C>) -> Self {
-        Self {
-            client,
-            config: LlmMatcherConfig::default(),
-        }
+        Self::with_config(client, LlmMatcherConfig::default())
     }
 
     /// Create with custom configuration.
     pub fn with_config(client: Arc<C>, config: LlmMatcherConfig) -> Self {
-        Self { client, config }
+        Self {
+            client,
+            config,
+            prompts: Arc::new(PromptLibrary::builtin()),
+        }
     }
 
-    /// Build the system prompt for candidate scoring.
-    fn build_system_prompt(&self) -> String {
-        r#"You are a torrent quality evaluator. Your task is to score torrent candidates based on how well they match the user's request.
-
-SCORING GUIDELINES (0.0 to 1.0):
-- 0.95-1.0: Perfect match - exact content, preferred quality, healthy seeders
-- 0.85-0.94: Excellent match - correct content, good quality
-- 0.70-0.84: Good match - likely correct, acceptable quality
-- 0.50-0.69: Partial match - might be correct, some concerns
-- 0.30-0.49: Poor match - probably wrong content or bad quality
-- 0.00-0.29: No match - wrong content, fake, or unusable
-
-RED FLAGS (reduce score significantly):
-- CAM, HDCAM, TS, TELESYNC = low quality bootleg
-- SCREENER, SCR = early leak, often poor quality
-- Wrong year for movies
-- Wrong season for TV
-- Very few seeders (< 2) = may be dead
-- Suspiciously small file size
-- MP3 when FLAC requested (or similar quality mismatch)
-
-GREEN FLAGS (increase score):
-- REMUX, PROPER, REPACK = high quality
-- Multiple release groups = well-seeded
-- File list matches expected tracks/episodes
-- Matches requested codec/resolution exactly
-
-Respond with JSON only:
-{
-  "scores": [
-    {"index": 0, "score": 0.95, "reasoning": "Brief explanation"},
-    {"index": 1, "score": 0.72, "reasoning": "Brief explanation"}
-  ]
-}"#.to_string()
+    /// Use prompt templates from the given library instead of the built-in ones.
+    pub fn with_prompts(mut self, prompts: Arc<PromptLibrary>) -> Self {
+        self.prompts = prompts;
+        self
     }
 
-    /// Build the user prompt with context and candidates.
-    fn build_user_prompt(&self, context: &QueryContext, candidates: &[TorrentCandidate]) -> String {
-        let mut prompt = String::new();
-
-        // Describe what we're looking for
-        prompt.push_str("LOOKING FOR:\n");
-        prompt.push_str(&format!("Description: {}\n", context.description));
-
+    /// Render the scoring prompts for a context and its candidates.
+    ///
+    /// Template variables: `{{description}}`, `{{constraints}}` (quality
+    /// requirements), `{{expected}}` and `{{candidates}}`.
+    fn render_prompt(
+        &self,
+        context: &QueryContext,
+        candidates: &[TorrentCandidate],
+    ) -> RenderedPrompt {
+        let mut constraints = String::new();
         if !context.tags.is_empty() {
-            prompt.push_str(&format!(
+            constraints.push_str(&format!(
                 "Quality requirements: {}\n",
                 context.tags.join(", ")
             ));
         }
 
+        let mut expected_block = String::new();
+
         if let Some(expected) = &context.expected {
             match expected {
                 ExpectedContent::Album {
@@ -118,27 +96,35 @@ Respond with JSON only:
                     title,
                     tracks,
                 } => {
-                    prompt.push_str("Type: Music Album\n");
+                    expected_block.push_str("Type: Music Album\n");
                     if let Some(artist) = artist {
-                        prompt.push_str(&format!("Artist: {}\n", artist));
+                        expected_block.push_str(&format!("Artist: {}\n", artist));
                     }
-                    prompt.push_str(&format!("Album: {}\n", title));
+                    expected_block.push_str(&format!("Album: {}\n", title));
                     if !tracks.is_empty() {
-                        prompt.push_str(&format!("Expected tracks: {}\n", tracks.len()));
+                        expected_block.push_str(&format!("Expected tracks: {}\n", tracks.len()));
+                    }
+                }
+                ExpectedContent::Discography { artist, albums } => {
+                    expected_block.push_str("Type: Artist Discography\n");
+                    expected_block.push_str(&format!("Artist: {}\n", artist));
+                    if !albums.is_empty() {
+                        let titles: Vec<&str> = albums.iter().map(|a| a.title.as_str()).collect();
+                        expected_block.push_str(&format!("Wanted albums: {}\n", titles.join(", ")));
                     }
                 }
                 ExpectedContent::Track { artist, title } => {
-                    prompt.push_str("Type: Single Track\n");
+                    expected_block.push_str("Type: Single Track\n");
                     if let Some(artist) = artist {
-                        prompt.push_str(&format!("Artist: {}\n", artist));
+                        expected_block.push_str(&format!("Artist: {}\n", artist));
                     }
-                    prompt.push_str(&format!("Track: {}\n", title));
+                    expected_block.push_str(&format!("Track: {}\n", title));
                 }
                 ExpectedContent::Movie { title, year } => {
-                    prompt.push_str("Type: Movie\n");
-                    prompt.push_str(&format!("Title: {}\n", title));
+                    expected_block.push_str("Type: Movie\n");
+                    expected_block.push_str(&format!("Title: {}\n", title));
                     if let Some(year) = year {
-                        prompt.push_str(&format!("Year: {}\n", year));
+                        expected_block.push_str(&format!("Year: {}\n", year));
                     }
                 }
                 ExpectedContent::TvEpisode {
@@ -146,18 +132,105 @@ Respond with JSON only:
                     season,
                     episodes,
                 } => {
-                    prompt.push_str("Type: TV Episode\n");
-                    prompt.push_str(&format!("Series: {}\n", series));
-                    prompt.push_str(&format!("Season: {}\n", season));
-                    prompt.push_str(&format!("Episodes: {:?}\n", episodes));
+                    expected_block.push_str("Type: TV Episode\n");
+                    expected_block.push_str(&format!("Series: {}\n", series));
+                    expected_block.push_str(&format!("Season: {}\n", season));
+                    expected_block.push_str(&format!("Episodes: {:?}\n", episodes));
+                }
+                ExpectedContent::TvSeason {
+                    series,
+                    season,
+                    episodes,
+                } => {
+                    expected_block.push_str("Type: TV Season (season pack preferred)\n");
+                    expected_block.push_str(&format!("Series: {}\n", series));
+                    expected_block.push_str(&format!("Season: {}\n", season));
+                    if !episodes.is_empty() {
+                        expected_block
+                            .push_str(&format!("Expected episodes: {}\n", episodes.len()));
+                    }
+                }
+                ExpectedContent::TvSeries {
+                    series,
+                    seasons,
+                    resolved_seasons,
+                } => {
+                    expected_block.push_str("Type: TV Series (series or season packs preferred)\n");
+                    expected_block.push_str(&format!("Series: {}\n", series));
+                    match seasons {
+                        Some(seasons) => {
+                            expected_block.push_str(&format!("Seasons: {:?}\n", seasons))
+                        }
+                        None => expected_block.push_str("Seasons: all\n"),
+                    }
+                    if !resolved_seasons.is_empty() {
+                        let total: usize = resolved_seasons.iter().map(|s| s.episodes.len()).sum();
+                        expected_block.push_str(&format!("Expected episodes: {}\n", total));
+                    }
+                }
+                ExpectedContent::Audiobook {
+                    author,
+                    title,
+                    narrator,
+                    series,
+                    chapters,
+                } => {
+                    expected_block.push_str("Type: Audiobook\n");
+                    if let Some(author) = author {
+                        expected_block.push_str(&format!("Author: {}\n", author));
+                    }
+                    expected_block.push_str(&format!("Title: {}\n", title));
+                    if let Some(narrator) = narrator {
+                        expected_block.push_str(&format!("Narrator: {}\n", narrator));
+                    }
+                    if let Some(series) = series {
+                        expected_block.push_str(&format!("Series: {}\n", series));
+                    }
+                    if !chapters.is_empty() {
+                        expected_block
+                            .push_str(&format!("Expected chapters: {}\n", chapters.len()));
+                    }
+                }
+                ExpectedContent::Book {
+                    author,
+                    title,
+                    isbn,
+                    formats,
+                } => {
+                    expected_block.push_str("Type: Ebook\n");
+                    if let Some(author) = author {
+                        expected_block.push_str(&format!("Author: {}\n", author));
+                    }
+                    expected_block.push_str(&format!("Title: {}\n", title));
+                    if let Some(isbn) = isbn {
+                        expected_block.push_str(&format!("ISBN: {}\n", isbn));
+                    }
+                    if !formats.is_empty() {
+                        let names: Vec<_> = formats.iter().map(|f| f.extension()).collect();
+                        expected_block
+                            .push_str(&format!("Accepted formats: {}\n", names.join(", ")));
+                    }
+                }
+                ExpectedContent::Comic {
+                    series,
+                    issue,
+                    volume,
+                } => {
+                    expected_block.push_str("Type: Comic\n");
+                    expected_block.push_str(&format!("Series: {}\n", series));
+                    if let Some(volume) = volume {
+                        expected_block.push_str(&format!("Volume: {}\n", volume));
+                    }
+                    if let Some(issue) = issue {
+                        expected_block.push_str(&format!("Issue: {}\n", issue));
+                    }
                 }
             }
         }
 
-        prompt.push_str("\nCANDIDATES TO SCORE:\n");
-
+        let mut candidates_block = String::new();
         for (i, candidate) in candidates.iter().enumerate() {
-            prompt.push_str(&format!(
+            candidates_block.push_str(&format!(
                 "\n[{}] Title: {}\n    Size: {} MB, Seeders: {}\n",
                 i,
                 candidate.title,
@@ -173,43 +246,58 @@ Respond with JSON only:
                     .map(|f| f.path.as_str())
                     .collect();
                 if !file_names.is_empty() {
-                    prompt.push_str(&format!("    Files: {}\n", file_names.join(", ")));
+                    candidates_block.push_str(&format!("    Files: {}\n", file_names.join(", ")));
                 }
                 if files.len() > 10 {
-                    prompt.push_str(&format!("    ... and {} more files\n", files.len() - 10));
+                    candidates_block
+                        .push_str(&format!("    ... and {} more files\n", files.len() - 10));
                 }
             }
         }
 
-        prompt.push_str("\nScore each candidate from 0.0 to 1.0 based on match quality.");
-        prompt
+        let content_type = context.expected.as_ref().map(|e| e.type_name());
+        self.prompts
+            .template(PromptTask::Scoring, content_type)
+            .render(
+                PromptTask::Scoring,
+                &[
+                    ("description", context.description.clone()),
+                    ("constraints", constraints),
+                    ("expected", expected_block),
+                    ("candidates", candidates_block),
+                ],
+            )
+    }
+
+    /// JSON schema of [`LlmScoreResponse`].
+    fn response_schema() -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "scores": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "index": {"type": "integer", "minimum": 0},
+                            "score": {"type": "number", "minimum": 0, "maximum": 1},
+                            "reasoning": {"type": "string"}
+                        },
+                        "required": ["index", "score"]
+                    }
+                }
+            },
+            "required": ["scores"]
+        })
     }
 
-    /// Parse the LLM response into scored candidates.
-    fn parse_response(
+    /// Turn the parsed LLM response into scored candidates.
+    fn build_result(
         &self,
-        text: &str,
+        parsed: LlmScoreResponse,
         candidates: &[TorrentCandidate],
         usage: LlmUsage,
     ) -> Result<MatchResult, TextBrainError> {
-        // Extract JSON from response
-        let json_str = if let Some(start) = text.find('{') {
-            if let Some(end) = text.rfind('}') {
-                &text[start..=end]
-            } else {
-                text
-            }
-        } else {
-            text
-        };
-
-        let parsed: LlmScoreResponse = serde_json::from_str(json_str).map_err(|e| {
-            TextBrainError::LlmError(format!(
-                "Failed to parse LLM response: {} - Response: {}",
-                e, text
-            ))
-        })?;
-
         // Build scored candidates
         let mut scored: Vec<ScoredCandidate> = Vec::new();
 
@@ -298,22 +386,21 @@ impl<C: LlmClient + 'static> CandidateMatcher for LlmMatcher<C> {
         let candidates_slice: Vec<TorrentCandidate> =
             candidates_to_score.iter().map(|c| (*c).clone()).collect();
 
-        let system_prompt = self.build_system_prompt();
-        let user_prompt = self.build_user_prompt(context, &candidates_slice);
-
-        let request = CompletionRequest::new(user_prompt)
-            .with_system(system_prompt)
+        let request = self
+            .render_prompt(context, &candidates_slice)
+            .into_request()
             .with_max_tokens(self.config.max_tokens)
-            .with_temperature(self.config.temperature);
+            .with_temperature(self.config.temperature)
+            .with_response_schema("candidate_scores", Self::response_schema());
 
-        let response = self
+        let (parsed, usage) = self
             .client
-            .complete(request)
+            .complete_json::<LlmScoreResponse>(request)
             .await
             .map_err(|e| TextBrainError::LlmError(e.to_string()))?;
 
-        // Parse scores for the candidates we sent
-        let mut result = self.parse_response(&response.text, &candidates_slice, response.usage)?;
+        // Scores for the candidates we sent
+        let mut result = self.build_result(parsed, &candidates_slice, usage)?;
 
         // Add remaining candidates that weren't sent to LLM
         if candidates.len() > self.config.max_candidates {
@@ -335,7 +422,7 @@ impl<C: LlmClient + 'static> CandidateMatcher for LlmMatcher<C> {
 mod tests {
     use super::*;
     use crate::searcher::TorrentSource;
-    use crate::textbrain::llm::{CompletionResponse, LlmError};
+    use crate::textbrain::llm::{CompletionRequest, CompletionResponse, LlmError};
     use crate::ticket::ExpectedTrack;
     use std::sync::Mutex;
 
@@ -527,7 +614,7 @@ mod tests {
         });
 
         let candidates = vec![make_candidate("Pink Floyd - Dark Side FLAC", 50, 500)];
-        let prompt = matcher.build_user_prompt(&context, &candidates);
+        let prompt = matcher.render_prompt(&context, &candidates).user;
 
         assert!(prompt.contains("Pink Floyd"));
         assert!(prompt.contains("Music Album"));
@@ -546,7 +633,7 @@ mod tests {
         });
 
         let candidates = vec![make_candidate("Inception.2010.1080p.BluRay", 100, 5000)];
-        let prompt = matcher.build_user_prompt(&context, &candidates);
+        let prompt = matcher.render_prompt(&context, &candidates).user;
 
         assert!(prompt.contains("Movie"));
         assert!(prompt.contains("Inception"));
@@ -571,7 +658,7 @@ mod tests {
             },
         ]);
 
-        let prompt = matcher.build_user_prompt(&context, &[candidate]);
+        let prompt = matcher.render_prompt(&context, &[candidate]).user;
 
         assert!(prompt.contains("Track One"));
         assert!(prompt.contains("Track Two"));
@@ -617,6 +704,36 @@ mod tests {
         assert!(overflow_candidate.reasoning.contains("overflow"));
     }
 
+    #[test]
+    fn test_render_prompt_uses_content_type_template() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("scoring.movie.toml"),
+            "version = \"movie-v3\"\nuser = \"Find {{expected}}\\n{{constraints}}\\n{{candidates}}\"\n",
+        )
+        .unwrap();
+        let prompts = Arc::new(PromptLibrary::from_dir(dir.path()).unwrap());
+        let matcher = LlmMatcher::new(Arc::new(MockLlmClient::new("{}"))).with_prompts(prompts);
+
+        let mut context = make_context("Inception", &["1080p"]);
+        context.expected = Some(ExpectedContent::Movie {
+            title: "Inception".to_string(),
+            year: Some(2010),
+        });
+        let rendered =
+            matcher.render_prompt(&context, &[make_candidate("Inception 2010", 10, 8000)]);
+
+        assert_eq!(rendered.version, "movie-v3");
+        assert!(rendered.user.starts_with("Find Type: Movie"));
+        assert!(rendered.user.contains("Quality requirements: 1080p"));
+        assert!(rendered.user.contains("[0] Title: Inception 2010"));
+
+        // Other content types keep the built-in template
+        context.expected = Some(ExpectedContent::track("Song"));
+        let rendered = matcher.render_prompt(&context, &[]);
+        assert!(rendered.version.starts_with("builtin-"));
+    }
+
     #[test]
     fn test_matcher_name() {
         let client = Arc::new(MockLlmClient::new("{}"));
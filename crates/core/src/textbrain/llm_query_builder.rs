@@ -5,9 +5,11 @@
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::sync::Arc;
 
-use crate::textbrain::llm::{CompletionRequest, LlmClient, LlmUsage};
+use crate::textbrain::llm::{LlmClient, LlmUsage};
+use crate::textbrain::prompts::{PromptLibrary, PromptTask, RenderedPrompt};
 use crate::textbrain::traits::{QueryBuilder, TextBrainError};
 use crate::textbrain::types::QueryBuildResult;
 use crate::ticket::{ExpectedContent, QueryContext};
@@ -43,60 +45,45 @@ impl Default for LlmQueryBuilderConfig {
 pub struct LlmQueryBuilder<C: LlmClient> {
     client: Arc<C>,
     config: LlmQueryBuilderConfig,
+    prompts: Arc<PromptLibrary>,
 }
 
 impl<C: LlmClient> LlmQueryBuilder<C> {
     /// Create a new LLM query builder.
     pub fn new(client: Arc<C>) -> Self {
-        Self {
-            client,
-            config: LlmQueryBuilderConfig::default(),
-        }
+        Self::with_config(client, LlmQueryBuilderConfig::default())
     }
 
     /// Create with custom configuration.
     pub fn with_config(client: Arc<C>, config: LlmQueryBuilderConfig) -> Self {
-        Self { client, config }
+        Self {
+            client,
+            config,
+            prompts: Arc::new(PromptLibrary::builtin()),
+        }
     }
 
-    /// Build the system prompt for query generation.
-    fn build_system_prompt(&self) -> String {
-        r#"You are a torrent search query optimizer. Your task is to generate effective search queries for finding specific media content on torrent sites.
-
-IMPORTANT RULES:
-1. Generate 3-5 search queries, ordered from most specific to most general
-2. Use common torrent naming conventions (e.g., "Artist - Album [FLAC]", "Movie.2023.1080p.BluRay")
-3. Include quality indicators when specified (FLAC, 1080p, x265, etc.)
-4. For albums: try "Artist - Album" and "Artist Album" formats
-5. For movies: include year, try with and without resolution
-6. For TV: use "Series S01E01" format, also try season packs
-7. Remove request phrases like "looking for", "please", "I want"
-8. Keep queries concise - torrent search works best with key terms
-9. When required audio languages are specified, include the 3-letter language code in queries (e.g., "ita" for Italian, "eng" for English, "ger" for German)
-
-Respond with JSON only, no other text:
-{
-  "queries": ["query1", "query2", "query3"],
-  "confidence": 0.85,
-  "reasoning": "Brief explanation of query strategy"
-}"#.to_string()
+    /// Use prompt templates from the given library instead of the built-in ones.
+    pub fn with_prompts(mut self, prompts: Arc<PromptLibrary>) -> Self {
+        self.prompts = prompts;
+        self
     }
 
-    /// Build the user prompt from context.
-    fn build_user_prompt(&self, context: &QueryContext) -> String {
+    /// Render the query building prompts for a context.
+    ///
+    /// Template variables: `{{description}}`, `{{constraints}}` (tags and
+    /// required languages), `{{expected}}` and `{{max_queries}}`.
+    fn render_prompt(&self, context: &QueryContext) -> RenderedPrompt {
         use crate::ticket::LanguagePriority;
 
-        let mut prompt = format!(
-            "Generate search queries for:\n\nDescription: {}",
-            context.description
-        );
-
+        let mut constraints = String::new();
         if !context.tags.is_empty() {
-            prompt.push_str(&format!("\nTags: {}", context.tags.join(", ")));
+            constraints.push_str(&format!("Tags: {}\n", context.tags.join(", ")));
         }
 
+        let mut expected_block = String::new();
         if let Some(expected) = &context.expected {
-            prompt.push_str("\n\nExpected content:\n");
+            expected_block.push_str("Expected content:\n");
             match expected {
                 ExpectedContent::Album {
                     artist,
@@ -104,26 +91,32 @@ Respond with JSON only, no other text:
                     tracks,
                 } => {
                     if let Some(artist) = artist {
-                        prompt.push_str(&format!("Album: {} - {}\n", artist, title));
+                        expected_block.push_str(&format!("Album: {} - {}\n", artist, title));
                     } else {
-                        prompt.push_str(&format!("Album: {}\n", title));
+                        expected_block.push_str(&format!("Album: {}\n", title));
                     }
                     if !tracks.is_empty() {
-                        prompt.push_str(&format!("Tracks: {} tracks\n", tracks.len()));
+                        expected_block.push_str(&format!("Tracks: {} tracks\n", tracks.len()));
+                    }
+                }
+                ExpectedContent::Discography { artist, albums } => {
+                    expected_block.push_str(&format!("Discography: {}\n", artist));
+                    if !albums.is_empty() {
+                        expected_block.push_str(&format!("Albums: {} albums\n", albums.len()));
                     }
                 }
                 ExpectedContent::Track { artist, title } => {
                     if let Some(artist) = artist {
-                        prompt.push_str(&format!("Single track: {} - {}\n", artist, title));
+                        expected_block.push_str(&format!("Single track: {} - {}\n", artist, title));
                     } else {
-                        prompt.push_str(&format!("Single track: {}\n", title));
+                        expected_block.push_str(&format!("Single track: {}\n", title));
                     }
                 }
                 ExpectedContent::Movie { title, year } => {
                     if let Some(year) = year {
-                        prompt.push_str(&format!("Movie: {} ({})\n", title, year));
+                        expected_block.push_str(&format!("Movie: {} ({})\n", title, year));
                     } else {
-                        prompt.push_str(&format!("Movie: {}\n", title));
+                        expected_block.push_str(&format!("Movie: {}\n", title));
                     }
                 }
                 ExpectedContent::TvEpisode {
@@ -136,17 +129,95 @@ Respond with JSON only, no other text:
                     } else {
                         format!("E{:02}-E{:02}", episodes[0], episodes[episodes.len() - 1])
                     };
-                    prompt.push_str(&format!(
+                    expected_block.push_str(&format!(
                         "TV Episode: {} S{:02}{}\n",
                         series, season, ep_str
                     ));
                 }
+                ExpectedContent::TvSeason { series, season, .. } => {
+                    expected_block.push_str(&format!(
+                        "TV Season: {} S{:02} (complete season pack)\n",
+                        series, season
+                    ));
+                }
+                ExpectedContent::TvSeries {
+                    series, seasons, ..
+                } => match seasons {
+                    Some(seasons) => {
+                        let list: Vec<String> =
+                            seasons.iter().map(|s| format!("S{:02}", s)).collect();
+                        expected_block.push_str(&format!(
+                            "TV Series: {} seasons {}\n",
+                            series,
+                            list.join(", ")
+                        ));
+                    }
+                    None => {
+                        expected_block
+                            .push_str(&format!("TV Series: {} (complete series)\n", series));
+                    }
+                },
+                ExpectedContent::Audiobook {
+                    author,
+                    title,
+                    narrator,
+                    series,
+                    chapters,
+                } => {
+                    if let Some(author) = author {
+                        expected_block.push_str(&format!("Audiobook: {} - {}\n", author, title));
+                    } else {
+                        expected_block.push_str(&format!("Audiobook: {}\n", title));
+                    }
+                    if let Some(narrator) = narrator {
+                        expected_block.push_str(&format!("Narrated by: {}\n", narrator));
+                    }
+                    if let Some(series) = series {
+                        expected_block.push_str(&format!("Series: {}\n", series));
+                    }
+                    if !chapters.is_empty() {
+                        expected_block
+                            .push_str(&format!("Chapters: {} chapters\n", chapters.len()));
+                    }
+                }
+                ExpectedContent::Book {
+                    author,
+                    title,
+                    isbn,
+                    formats,
+                } => {
+                    if let Some(author) = author {
+                        expected_block.push_str(&format!("Ebook: {} - {}\n", author, title));
+                    } else {
+                        expected_block.push_str(&format!("Ebook: {}\n", title));
+                    }
+                    if let Some(isbn) = isbn {
+                        expected_block.push_str(&format!("ISBN: {}\n", isbn));
+                    }
+                    if !formats.is_empty() {
+                        let names: Vec<_> = formats.iter().map(|f| f.extension()).collect();
+                        expected_block.push_str(&format!("Formats: {}\n", names.join(", ")));
+                    }
+                }
+                ExpectedContent::Comic {
+                    series,
+                    issue,
+                    volume,
+                } => {
+                    expected_block.push_str(&format!("Comic: {}\n", series));
+                    if let Some(volume) = volume {
+                        expected_block.push_str(&format!("Volume: {}\n", volume));
+                    }
+                    if let Some(issue) = issue {
+                        expected_block.push_str(&format!("Issue: #{}\n", issue));
+                    }
+                }
             }
         }
 
         // Add required language constraints
-        if let Some(ref constraints) = context.search_constraints {
-            if let Some(ref video) = constraints.video {
+        if let Some(ref search_constraints) = context.search_constraints {
+            if let Some(ref video) = search_constraints.video {
                 let required_audio: Vec<_> = video
                     .audio_languages
                     .iter()
@@ -155,19 +226,26 @@ Respond with JSON only, no other text:
                     .collect();
 
                 if !required_audio.is_empty() {
-                    prompt.push_str(&format!(
-                        "\n\nREQUIRED audio language(s): {}. Include language codes (e.g., ita, eng) in the queries.",
+                    constraints.push_str(&format!(
+                        "\nREQUIRED audio language(s): {}. Include language codes (e.g., ita, eng) in the queries.\n",
                         required_audio.join(", ")
                     ));
                 }
             }
         }
 
-        prompt.push_str(&format!(
-            "\n\nGenerate up to {} search queries.",
-            self.config.max_queries
-        ));
-        prompt
+        let content_type = context.expected.as_ref().map(|e| e.type_name());
+        self.prompts
+            .template(PromptTask::QueryBuilding, content_type)
+            .render(
+                PromptTask::QueryBuilding,
+                &[
+                    ("description", context.description.clone()),
+                    ("constraints", constraints),
+                    ("expected", expected_block),
+                    ("max_queries", self.config.max_queries.to_string()),
+                ],
+            )
     }
 
     /// Convert ISO 639-1 language code to human-readable name.
@@ -197,30 +275,25 @@ Respond with JSON only, no other text:
         }
     }
 
-    /// Parse the LLM response into a QueryBuildResult.
-    fn parse_response(
+    /// JSON schema of [`LlmQueryResponse`].
+    fn response_schema() -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "queries": {"type": "array", "items": {"type": "string"}},
+                "confidence": {"type": "number", "minimum": 0, "maximum": 1},
+                "reasoning": {"type": "string"}
+            },
+            "required": ["queries"]
+        })
+    }
+
+    /// Turn the parsed LLM response into a QueryBuildResult.
+    fn build_result(
         &self,
-        text: &str,
+        parsed: LlmQueryResponse,
         usage: LlmUsage,
     ) -> Result<QueryBuildResult, TextBrainError> {
-        // Try to extract JSON from the response (handle markdown code blocks)
-        let json_str = if let Some(start) = text.find('{') {
-            if let Some(end) = text.rfind('}') {
-                &text[start..=end]
-            } else {
-                text
-            }
-        } else {
-            text
-        };
-
-        let parsed: LlmQueryResponse = serde_json::from_str(json_str).map_err(|e| {
-            TextBrainError::LlmError(format!(
-                "Failed to parse LLM response: {} - Response: {}",
-                e, text
-            ))
-        })?;
-
         if parsed.queries.is_empty() {
             return Err(TextBrainError::NoQueriesGenerated);
         }
@@ -260,28 +333,29 @@ impl<C: LlmClient + 'static> QueryBuilder for LlmQueryBuilder<C> {
         &self,
         context: &QueryContext,
     ) -> Result<QueryBuildResult, TextBrainError> {
-        let system_prompt = self.build_system_prompt();
-        let user_prompt = self.build_user_prompt(context);
-
-        let request = CompletionRequest::new(user_prompt)
-            .with_system(system_prompt)
+        let request = self
+            .render_prompt(context)
+            .into_request()
             .with_max_tokens(self.config.max_tokens)
-            .with_temperature(self.config.temperature);
+            .with_temperature(self.config.temperature)
+            .with_response_schema("search_queries", Self::response_schema());
 
-        let response = self
+        let (parsed, usage) = self
             .client
-            .complete(request)
+            .complete_json::<LlmQueryResponse>(request)
             .await
             .map_err(|e| TextBrainError::LlmError(e.to_string()))?;
 
-        self.parse_response(&response.text, response.usage)
+        self.build_result(parsed, usage)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::textbrain::llm::{CompletionResponse, LlmError};
+    use crate::textbrain::llm::{
+        parse_json_output, CompletionRequest, CompletionResponse, LlmError,
+    };
     use crate::ticket::ExpectedTrack;
     use std::sync::Mutex;
 
@@ -412,7 +486,7 @@ mod tests {
             }],
         });
 
-        let prompt = builder.build_user_prompt(&context);
+        let prompt = builder.render_prompt(&context).user;
         assert!(prompt.contains("Pink Floyd"));
         assert!(prompt.contains("The Dark Side of the Moon"));
         assert!(prompt.contains("Album:"));
@@ -429,7 +503,7 @@ mod tests {
             year: Some(2010),
         });
 
-        let prompt = builder.build_user_prompt(&context);
+        let prompt = builder.render_prompt(&context).user;
         assert!(prompt.contains("Inception"));
         assert!(prompt.contains("2010"));
         assert!(prompt.contains("Movie:"));
@@ -447,7 +521,7 @@ mod tests {
             episodes: vec![1],
         });
 
-        let prompt = builder.build_user_prompt(&context);
+        let prompt = builder.render_prompt(&context).user;
         assert!(prompt.contains("Breaking Bad"));
         assert!(prompt.contains("S01E01"));
         assert!(prompt.contains("TV Episode:"));
@@ -464,7 +538,9 @@ mod tests {
             output_tokens: 5,
         };
 
-        let result = builder.parse_response(text, usage).unwrap();
+        let result = builder
+            .build_result(parse_json_output(text).unwrap(), usage)
+            .unwrap();
         assert_eq!(result.queries.len(), 1);
         assert_eq!(result.confidence, 0.8); // Default when not specified
     }
@@ -484,7 +560,9 @@ mod tests {
             output_tokens: 5,
         };
 
-        let result = builder.parse_response(text, usage).unwrap();
+        let result = builder
+            .build_result(parse_json_output(text).unwrap(), usage)
+            .unwrap();
         assert_eq!(result.queries.len(), 2);
     }
 
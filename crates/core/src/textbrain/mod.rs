@@ -67,37 +67,71 @@ mod dumb_matcher;
 mod dumb_query_builder;
 mod file_mapper;
 mod llm;
+mod llm_audit;
+mod llm_budget;
+mod llm_cache;
+mod llm_fallback;
+mod llm_file_mapper;
 mod llm_matcher;
 mod llm_query_builder;
+mod prompts;
 pub mod training;
+mod training_export;
 mod traits;
 mod types;
 
 // LLM client types
 pub use llm::{
-    AnthropicClient, CompletionRequest, CompletionResponse, LlmClient, LlmError, LlmUsage,
-    OllamaClient,
+    parse_json_output, AnthropicClient, CompletionRequest, CompletionResponse, LlmClient, LlmError,
+    LlmUsage, OllamaClient, OpenAiClient, ResponseSchema, JSON_REPAIR_ATTEMPTS,
+};
+pub use llm_audit::AuditedLlmClient;
+pub use llm_budget::{
+    BudgetedLlmClient, LlmBudget, LlmBudgetConfig, LlmBudgetPeriod, LlmBudgetStatus, LlmUsageStore,
+    LlmUsageTotals, ModelPricing, SqliteLlmUsageStore,
+};
+pub use llm_cache::{cache_key, CachedLlmClient, LlmCache, LlmCacheConfig, SqliteLlmCache};
+pub use llm_fallback::{
+    CircuitState, FallbackLlmClient, FallbackProvider, LlmCircuitBreakerConfig, LlmProviderHealth,
+    LlmProviderStatus,
+};
+
+// Prompt templates
+pub use prompts::{
+    builtin_template, PromptError, PromptLibrary, PromptTask, PromptTemplate, PromptTemplateInfo,
+    RenderedPrompt,
 };
 
 // Configuration types
-pub use config::{LlmConfig, LlmProvider, TextBrainConfig, TextBrainMode};
+pub use config::{
+    DumbScoringWeights, LlmConfig, LlmProvider, LlmProviderConfig, MusicScoringWeights,
+    ScoringWeights, TextBrainConfig, TextBrainMode, TextBrainOverrides, VideoScoringWeights,
+};
 
 // Core traits
-pub use traits::{CandidateMatcher, QueryBuilder, TextBrainError};
+pub use traits::{CandidateMatcher, FileMapper, QueryBuilder, TextBrainError};
 
 // Dumb implementations
 pub use dumb_matcher::{DumbMatcher, DumbMatcherConfig};
 pub use dumb_query_builder::{DumbQueryBuilder, DumbQueryBuilderConfig};
+pub(crate) use file_mapper::natural_cmp;
 pub use file_mapper::{calculate_mapping_quality, DumbFileMapper, DumbFileMapperConfig};
 
 // LLM implementations
+pub use llm_file_mapper::{LlmFileMapper, LlmFileMapperConfig};
 pub use llm_matcher::{LlmMatcher, LlmMatcherConfig};
 pub use llm_query_builder::{LlmQueryBuilder, LlmQueryBuilderConfig};
 
+// Training dataset export
+pub use training_export::{
+    export_training_data, to_jsonl, TrainingExport, TrainingExportFormat, TrainingExportOptions,
+    TrainingTask,
+};
+
 // Result types
 pub use types::{
-    AcquisitionResult, FileMapping, MatchResult, QueryBuildResult, ScoredCandidate,
-    ScoredCandidateSummary,
+    AcquisitionResult, FileMapping, FileMappingResult, MatchResult, QueryBuildResult,
+    ScoredCandidate, ScoredCandidateSummary,
 };
 
 // The coordinator
@@ -0,0 +1,565 @@
+//! Prompt templates for the LLM-powered components.
+//!
+//! Each task (query building, scoring, file mapping) has a built-in template
+//! that can be overridden from a directory of TOML files:
+//!
+//! ```text
+//! prompts/
+//!   scoring.toml             # all content types
+//!   scoring.movie.toml       # movies only
+//!   query_building.album.toml
+//! ```
+//!
+//! A template file has optional `version`, `system` and `user` keys; missing
+//! prompts are taken from the built-in template. Templates reference
+//! variables with `{{name}}`, e.g. `{{expected}}`, `{{candidates}}` and
+//! `{{constraints}}`. Without an explicit version, a template is versioned by
+//! a hash of its prompts, so every change is visible in `LlmCallCompleted`
+//! audit events.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::{info, warn};
+
+use crate::textbrain::llm::CompletionRequest;
+
+/// Error type for prompt template loading.
+#[derive(Debug, thiserror::Error)]
+pub enum PromptError {
+    #[error("Failed to read prompt templates from {path:?}: {message}")]
+    Io { path: PathBuf, message: String },
+
+    #[error("Invalid prompt template {path:?}: {message}")]
+    Parse { path: PathBuf, message: String },
+}
+
+/// Task a prompt template is used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PromptTask {
+    /// Search query generation (`LlmQueryBuilder`).
+    QueryBuilding,
+    /// Candidate scoring (`LlmMatcher`).
+    Scoring,
+    /// File mapping (`LlmFileMapper`).
+    FileMapping,
+}
+
+impl PromptTask {
+    pub const ALL: [PromptTask; 3] = [
+        PromptTask::QueryBuilding,
+        PromptTask::Scoring,
+        PromptTask::FileMapping,
+    ];
+
+    /// Name used in template file names and audit events.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PromptTask::QueryBuilding => "query_building",
+            PromptTask::Scoring => "scoring",
+            PromptTask::FileMapping => "file_mapping",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|task| task.as_str() == name)
+    }
+}
+
+impl fmt::Display for PromptTask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A versioned system and user prompt pair.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromptTemplate {
+    /// Template version, recorded with every LLM call.
+    pub version: String,
+    /// System prompt.
+    pub system: String,
+    /// User prompt.
+    pub user: String,
+}
+
+impl PromptTemplate {
+    /// Render the system and user prompts, replacing `{{name}}` with the
+    /// value of each variable. Runs of blank lines left by empty variables
+    /// are collapsed.
+    pub fn render(&self, task: PromptTask, vars: &[(&str, String)]) -> RenderedPrompt {
+        RenderedPrompt {
+            task,
+            version: self.version.clone(),
+            system: render(&self.system, vars),
+            user: render(&self.user, vars),
+        }
+    }
+}
+
+/// Prompts rendered from a template, ready to be sent.
+#[derive(Debug, Clone)]
+pub struct RenderedPrompt {
+    pub task: PromptTask,
+    pub version: String,
+    pub system: String,
+    pub user: String,
+}
+
+impl RenderedPrompt {
+    /// Completion request for the rendered prompts, tagged with the task and
+    /// template version.
+    pub fn into_request(self) -> CompletionRequest {
+        CompletionRequest::new(self.user)
+            .with_system(self.system)
+            .with_purpose(self.task.as_str())
+            .with_prompt_version(self.version)
+    }
+}
+
+fn render(template: &str, vars: &[(&str, String)]) -> String {
+    let mut text = template.to_string();
+    for (name, value) in vars {
+        text = text.replace(&format!("{{{{{}}}}}", name), value.trim_end());
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut blank_run = 0;
+    for line in text.trim().lines() {
+        if line.trim().is_empty() {
+            blank_run += 1;
+            if blank_run > 1 {
+                continue;
+            }
+        } else {
+            blank_run = 0;
+        }
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(line.trim_end());
+    }
+    out
+}
+
+/// Short content hash used as the version of unversioned templates.
+fn content_version(system: &str, user: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(system.as_bytes());
+    hasher.update([0]);
+    hasher.update(user.as_bytes());
+    format!("{:x}", hasher.finalize())[..8].to_string()
+}
+
+/// Summary of a loaded template, for listing.
+#[derive(Debug, Clone, Serialize)]
+pub struct PromptTemplateInfo {
+    pub task: PromptTask,
+    /// Content type the template is specific to, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    pub version: String,
+    /// File the template was loaded from (`None` for built-in templates).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
+/// Contents of a template file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TemplateFile {
+    version: Option<String>,
+    system: Option<String>,
+    user: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct LoadedTemplate {
+    template: PromptTemplate,
+    path: PathBuf,
+}
+
+#[derive(Debug, Default)]
+struct LoadedTemplates {
+    /// Keyed by task and optional content type.
+    templates: HashMap<(PromptTask, Option<String>), LoadedTemplate>,
+    /// Names, sizes and modification times of the files they came from.
+    fingerprint: Vec<(String, u64, Option<SystemTime>)>,
+}
+
+/// Built-in templates, optionally overridden by files from a directory.
+#[derive(Debug, Default)]
+pub struct PromptLibrary {
+    dir: Option<PathBuf>,
+    loaded: RwLock<LoadedTemplates>,
+}
+
+impl PromptLibrary {
+    /// Library with the built-in templates only.
+    pub fn builtin() -> Self {
+        Self::default()
+    }
+
+    /// Load template overrides from a directory.
+    pub fn from_dir(dir: impl Into<PathBuf>) -> Result<Self, PromptError> {
+        let library = Self {
+            dir: Some(dir.into()),
+            loaded: RwLock::new(LoadedTemplates::default()),
+        };
+        library.reload()?;
+        Ok(library)
+    }
+
+    /// Directory templates are loaded from, if any.
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+
+    /// Re-read the template directory, returning how many templates were
+    /// loaded. On error the previously loaded templates are kept.
+    pub fn reload(&self) -> Result<usize, PromptError> {
+        let Some(dir) = &self.dir else {
+            return Ok(0);
+        };
+
+        let fingerprint = fingerprint(dir)?;
+        let mut templates = HashMap::new();
+        for (name, _, _) in &fingerprint {
+            let path = dir.join(name);
+            let stem = name.trim_end_matches(".toml");
+            let (task_name, content_type) = match stem.split_once('.') {
+                Some((task, content_type)) => (task, Some(content_type.to_string())),
+                None => (stem, None),
+            };
+            let Some(task) = PromptTask::from_name(task_name) else {
+                warn!("Ignoring prompt template {:?}: unknown task", path);
+                continue;
+            };
+
+            let contents = std::fs::read_to_string(&path).map_err(|e| PromptError::Io {
+                path: path.clone(),
+                message: e.to_string(),
+            })?;
+            let file: TemplateFile = toml::from_str(&contents).map_err(|e| PromptError::Parse {
+                path: path.clone(),
+                message: e.to_string(),
+            })?;
+
+            let builtin = builtin_template(task);
+            let system = file.system.unwrap_or(builtin.system);
+            let user = file.user.unwrap_or(builtin.user);
+            let version = file
+                .version
+                .unwrap_or_else(|| content_version(&system, &user));
+            templates.insert(
+                (task, content_type),
+                LoadedTemplate {
+                    template: PromptTemplate {
+                        version,
+                        system,
+                        user,
+                    },
+                    path,
+                },
+            );
+        }
+
+        let count = templates.len();
+        *self.loaded.write().unwrap() = LoadedTemplates {
+            templates,
+            fingerprint,
+        };
+        info!("Loaded {} prompt template(s) from {:?}", count, dir);
+        Ok(count)
+    }
+
+    /// Reload the templates if any file in the directory was added, removed
+    /// or modified since the last load. Returns whether a reload happened.
+    pub fn reload_if_changed(&self) -> Result<bool, PromptError> {
+        let Some(dir) = &self.dir else {
+            return Ok(false);
+        };
+        if fingerprint(dir)? == self.loaded.read().unwrap().fingerprint {
+            return Ok(false);
+        }
+        self.reload().map(|_| true)
+    }
+
+    /// Template for a task and content type.
+    ///
+    /// Resolution order: `{task}.{content_type}.toml`, `{task}.toml`, built-in.
+    pub fn template(&self, task: PromptTask, content_type: Option<&str>) -> PromptTemplate {
+        let loaded = self.loaded.read().unwrap();
+        content_type
+            .and_then(|ct| loaded.templates.get(&(task, Some(ct.to_string()))))
+            .or_else(|| loaded.templates.get(&(task, None)))
+            .map(|t| t.template.clone())
+            .unwrap_or_else(|| builtin_template(task))
+    }
+
+    /// All templates in use: loaded overrides and the built-in templates of
+    /// tasks without a generic override.
+    pub fn list(&self) -> Vec<PromptTemplateInfo> {
+        let loaded = self.loaded.read().unwrap();
+        let mut infos: Vec<PromptTemplateInfo> = PromptTask::ALL
+            .into_iter()
+            .filter(|task| !loaded.templates.contains_key(&(*task, None)))
+            .map(|task| PromptTemplateInfo {
+                task,
+                content_type: None,
+                version: builtin_template(task).version,
+                path: None,
+            })
+            .collect();
+        infos.extend(
+            loaded
+                .templates
+                .iter()
+                .map(|((task, content_type), t)| PromptTemplateInfo {
+                    task: *task,
+                    content_type: content_type.clone(),
+                    version: t.template.version.clone(),
+                    path: Some(t.path.clone()),
+                }),
+        );
+        infos.sort_by(|a, b| {
+            (a.task.as_str(), &a.content_type).cmp(&(b.task.as_str(), &b.content_type))
+        });
+        infos
+    }
+}
+
+/// `.toml` files of the template directory with their sizes and modification times.
+fn fingerprint(dir: &Path) -> Result<Vec<(String, u64, Option<SystemTime>)>, PromptError> {
+    let io_error = |e: std::io::Error| PromptError::Io {
+        path: dir.to_path_buf(),
+        message: e.to_string(),
+    };
+
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir).map_err(io_error)? {
+        let entry = entry.map_err(io_error)?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !name.ends_with(".toml") {
+            continue;
+        }
+        let metadata = entry.metadata().map_err(io_error)?;
+        if metadata.is_file() {
+            files.push((name, metadata.len(), metadata.modified().ok()));
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Built-in template of a task, versioned `builtin-{hash}`.
+pub fn builtin_template(task: PromptTask) -> PromptTemplate {
+    let (system, user) = match task {
+        PromptTask::QueryBuilding => (QUERY_BUILDING_SYSTEM, QUERY_BUILDING_USER),
+        PromptTask::Scoring => (SCORING_SYSTEM, SCORING_USER),
+        PromptTask::FileMapping => (FILE_MAPPING_SYSTEM, FILE_MAPPING_USER),
+    };
+    PromptTemplate {
+        version: format!("builtin-{}", content_version(system, user)),
+        system: system.to_string(),
+        user: user.to_string(),
+    }
+}
+
+const QUERY_BUILDING_SYSTEM: &str = r#"You are a torrent search query optimizer. Your task is to generate effective search queries for finding specific media content on torrent sites.
+
+IMPORTANT RULES:
+1. Generate 3-5 search queries, ordered from most specific to most general
+2. Use common torrent naming conventions (e.g., "Artist - Album [FLAC]", "Movie.2023.1080p.BluRay")
+3. Include quality indicators when specified (FLAC, 1080p, x265, etc.)
+4. For albums: try "Artist - Album" and "Artist Album" formats
+5. For movies: include year, try with and without resolution
+6. For TV: use "Series S01E01" format, also try season packs
+7. Remove request phrases like "looking for", "please", "I want"
+8. Keep queries concise - torrent search works best with key terms
+9. When required audio languages are specified, include the 3-letter language code in queries (e.g., "ita" for Italian, "eng" for English, "ger" for German)
+
+Respond with JSON only, no other text:
+{
+  "queries": ["query1", "query2", "query3"],
+  "confidence": 0.85,
+  "reasoning": "Brief explanation of query strategy"
+}"#;
+
+const QUERY_BUILDING_USER: &str = r#"Generate search queries for:
+
+Description: {{description}}
+{{constraints}}
+
+{{expected}}
+
+Generate up to {{max_queries}} search queries."#;
+
+const SCORING_SYSTEM: &str = r#"You are a torrent quality evaluator. Your task is to score torrent candidates based on how well they match the user's request.
+
+SCORING GUIDELINES (0.0 to 1.0):
+- 0.95-1.0: Perfect match - exact content, preferred quality, healthy seeders
+- 0.85-0.94: Excellent match - correct content, good quality
+- 0.70-0.84: Good match - likely correct, acceptable quality
+- 0.50-0.69: Partial match - might be correct, some concerns
+- 0.30-0.49: Poor match - probably wrong content or bad quality
+- 0.00-0.29: No match - wrong content, fake, or unusable
+
+RED FLAGS (reduce score significantly):
+- CAM, HDCAM, TS, TELESYNC = low quality bootleg
+- SCREENER, SCR = early leak, often poor quality
+- Wrong year for movies
+- Wrong season for TV
+- Very few seeders (< 2) = may be dead
+- Suspiciously small file size
+- MP3 when FLAC requested (or similar quality mismatch)
+
+GREEN FLAGS (increase score):
+- REMUX, PROPER, REPACK = high quality
+- Multiple release groups = well-seeded
+- File list matches expected tracks/episodes
+- Matches requested codec/resolution exactly
+
+Respond with JSON only:
+{
+  "scores": [
+    {"index": 0, "score": 0.95, "reasoning": "Brief explanation"},
+    {"index": 1, "score": 0.72, "reasoning": "Brief explanation"}
+  ]
+}"#;
+
+const SCORING_USER: &str = r#"LOOKING FOR:
+Description: {{description}}
+{{constraints}}
+{{expected}}
+
+CANDIDATES TO SCORE:
+{{candidates}}
+
+Score each candidate from 0.0 to 1.0 based on match quality."#;
+
+const FILE_MAPPING_SYSTEM: &str = r#"You map the files of a torrent to the items a user asked for.
+
+RULES:
+- Use file paths exactly as listed; never invent paths
+- Use item IDs exactly as listed
+- Map each item to at most one file
+- A single audio image with a .cue sheet holds every track: map all tracks to the image file
+- Ignore samples, extras, artwork, logs and subtitles
+- Leave out items you cannot find
+- Confidence is 0.0 to 1.0
+
+Respond with JSON only:
+{
+  "mappings": [
+    {"item_id": "track-1", "file_path": "CD1/01 - Intro.flac", "confidence": 0.9}
+  ]
+}"#;
+
+const FILE_MAPPING_USER: &str = r#"ITEMS:
+{{expected}}
+
+FILES:
+{{files}}"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_render_replaces_variables_and_collapses_blank_lines() {
+        let template = PromptTemplate {
+            version: "1".to_string(),
+            system: "Score {{what}}".to_string(),
+            user: "A: {{a}}\n{{empty}}\n\n\nB: {{b}}\n".to_string(),
+        };
+        let rendered = template.render(
+            PromptTask::Scoring,
+            &[
+                ("what", "torrents".to_string()),
+                ("a", "one\n".to_string()),
+                ("empty", String::new()),
+                ("b", "two".to_string()),
+            ],
+        );
+        assert_eq!(rendered.system, "Score torrents");
+        assert_eq!(rendered.user, "A: one\n\nB: two");
+
+        let request = rendered.into_request();
+        assert_eq!(request.purpose.as_deref(), Some("scoring"));
+        assert_eq!(request.prompt_version.as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn test_builtin_templates_are_versioned() {
+        let library = PromptLibrary::builtin();
+        let template = library.template(PromptTask::Scoring, Some("movie"));
+        assert!(template.version.starts_with("builtin-"));
+        assert!(template.user.contains("{{candidates}}"));
+        assert_eq!(library.list().len(), 3);
+    }
+
+    #[test]
+    fn test_directory_overrides_by_task_and_content_type() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("scoring.toml"),
+            "version = \"v2\"\nuser = \"Score {{candidates}}\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("scoring.movie.toml"),
+            "user = \"Movies: {{candidates}}\"\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("notes.toml"), "").unwrap();
+
+        let library = PromptLibrary::from_dir(dir.path()).unwrap();
+
+        let generic = library.template(PromptTask::Scoring, Some("album"));
+        assert_eq!(generic.version, "v2");
+        assert_eq!(generic.user, "Score {{candidates}}");
+        // Missing system prompt comes from the built-in template
+        assert_eq!(generic.system, builtin_template(PromptTask::Scoring).system);
+
+        let movie = library.template(PromptTask::Scoring, Some("movie"));
+        assert_eq!(movie.user, "Movies: {{candidates}}");
+        assert_eq!(movie.version.len(), 8);
+
+        assert_eq!(
+            library.template(PromptTask::FileMapping, None),
+            builtin_template(PromptTask::FileMapping)
+        );
+    }
+
+    #[test]
+    fn test_reload_if_changed_keeps_templates_on_error() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("query_building.toml");
+        std::fs::write(&path, "version = \"a\"\n").unwrap();
+
+        let library = PromptLibrary::from_dir(dir.path()).unwrap();
+        assert!(!library.reload_if_changed().unwrap());
+
+        std::fs::write(&path, "version = \"bb\"\n").unwrap();
+        assert!(library.reload_if_changed().unwrap());
+        assert_eq!(
+            library.template(PromptTask::QueryBuilding, None).version,
+            "bb"
+        );
+
+        std::fs::write(&path, "version = ").unwrap();
+        assert!(library.reload_if_changed().is_err());
+        assert_eq!(
+            library.template(PromptTask::QueryBuilding, None).version,
+            "bb"
+        );
+    }
+}
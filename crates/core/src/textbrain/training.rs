@@ -183,12 +183,12 @@ pub fn create_acquisition_training_events(
             .and_then(|e| serde_json::to_string(e).ok()),
         output_queries: result.queries_tried.clone(),
         method: result.query_method.clone(),
-        confidence: if result.best_candidate.is_some() {
-            result.best_candidate.as_ref().unwrap().score
-        } else {
-            0.0
-        },
-        success: Some(result.best_candidate.is_some() && result.auto_approved),
+        confidence: result
+            .best_candidate
+            .as_ref()
+            .map(|c| c.score)
+            .unwrap_or(0.0),
+        success: None, // Back-filled when the ticket completes or fails
     });
 
     // Scoring context event (if we have candidates)
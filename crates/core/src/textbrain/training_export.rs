@@ -0,0 +1,518 @@
+//! Training dataset export.
+//!
+//! Turns the training events recorded in the audit log (see
+//! [`super::training`]) into JSONL datasets for fine-tuning. Each task gets
+//! its own dataset; samples are deduplicated and split deterministically into
+//! train and validation sets.
+//!
+//! Samples only contain what the model sees and should produce - ticket IDs,
+//! user IDs, info hashes and timestamps are never exported.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::audit::{AuditError, AuditEvent, AuditStore, TrainingCandidate};
+
+/// Which model task a dataset is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrainingTask {
+    /// Context -> search queries.
+    QueryGeneration,
+    /// Context + candidates -> scores.
+    CandidateScoring,
+    /// Expected content + torrent files -> file mappings.
+    FileMapping,
+}
+
+impl TrainingTask {
+    /// Audit event types that feed this task.
+    fn event_types(&self) -> &'static [&'static str] {
+        match self {
+            TrainingTask::QueryGeneration => &["training_query_context"],
+            TrainingTask::CandidateScoring => &["training_scoring_context", "user_correction"],
+            TrainingTask::FileMapping => &["training_file_mapping_context"],
+        }
+    }
+
+    /// System prompt / instruction for this task.
+    fn instruction(&self) -> &'static str {
+        match self {
+            TrainingTask::QueryGeneration => {
+                "Generate torrent search queries for the requested content, ordered from most specific to most general. Respond with JSON only."
+            }
+            TrainingTask::CandidateScoring => {
+                "Score each torrent candidate from 0.0 to 1.0 based on how well it matches the request. Respond with JSON only."
+            }
+            TrainingTask::FileMapping => {
+                "Map each torrent file to the expected content item it contains. Respond with JSON only."
+            }
+        }
+    }
+}
+
+/// Output format of exported samples.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrainingExportFormat {
+    /// `{"messages": [{"role": "system", ...}, {"role": "user", ...}, {"role": "assistant", ...}]}`
+    #[default]
+    Chat,
+    /// `{"instruction": ..., "input": ..., "output": ...}`
+    Instruction,
+}
+
+/// Options for exporting a training dataset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingExportOptions {
+    /// Task to export.
+    pub task: TrainingTask,
+    /// Sample format.
+    #[serde(default)]
+    pub format: TrainingExportFormat,
+    /// Fraction of samples held out for validation (0.0-1.0).
+    #[serde(default = "default_validation_fraction")]
+    pub validation_fraction: f32,
+    /// Include query samples whose ticket failed or has no outcome yet.
+    #[serde(default)]
+    pub include_unsuccessful: bool,
+    /// Minimum mapping quality for file mapping samples.
+    #[serde(default = "default_min_mapping_quality")]
+    pub min_mapping_quality: f32,
+}
+
+fn default_validation_fraction() -> f32 {
+    0.1
+}
+
+fn default_min_mapping_quality() -> f32 {
+    0.8
+}
+
+impl TrainingExportOptions {
+    /// Default options for a task.
+    pub fn new(task: TrainingTask) -> Self {
+        Self {
+            task,
+            format: TrainingExportFormat::default(),
+            validation_fraction: default_validation_fraction(),
+            include_unsuccessful: false,
+            min_mapping_quality: default_min_mapping_quality(),
+        }
+    }
+}
+
+/// An exported dataset.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TrainingExport {
+    /// Training samples.
+    pub train: Vec<Value>,
+    /// Held-out validation samples.
+    pub validation: Vec<Value>,
+    /// Duplicate samples dropped.
+    pub duplicates_removed: usize,
+}
+
+/// A task-independent sample before formatting.
+struct Sample {
+    input: String,
+    output: String,
+}
+
+/// Export a training dataset from the audit store.
+pub fn export_training_data(
+    store: &dyn AuditStore,
+    options: &TrainingExportOptions,
+) -> Result<TrainingExport, AuditError> {
+    let mut events = Vec::new();
+    for event_type in options.task.event_types() {
+        events.extend(store.query_all(event_type)?.into_iter().map(|r| r.data));
+    }
+
+    // Scoring samples for tickets the user corrected taught the wrong ranking
+    let corrected_tickets: HashSet<String> = events
+        .iter()
+        .filter_map(|e| match e {
+            AuditEvent::UserCorrection { ticket_id, .. } => Some(ticket_id.clone()),
+            _ => None,
+        })
+        .collect();
+
+    let mut export = TrainingExport::default();
+    let mut seen = HashSet::new();
+
+    for event in &events {
+        let Some(sample) = build_sample(event, options, &corrected_tickets) else {
+            continue;
+        };
+
+        if !seen.insert(digest(&format!("{}\n\0{}", sample.input, sample.output))) {
+            export.duplicates_removed += 1;
+            continue;
+        }
+
+        let formatted = format_sample(options.task, options.format, &sample);
+        // Split on the input so the same prompt never lands in both sets
+        if in_validation_split(&sample.input, options.validation_fraction) {
+            export.validation.push(formatted);
+        } else {
+            export.train.push(formatted);
+        }
+    }
+
+    Ok(export)
+}
+
+/// Serialize samples as JSON Lines.
+pub fn to_jsonl(samples: &[Value]) -> String {
+    let mut out = String::new();
+    for sample in samples {
+        out.push_str(&sample.to_string());
+        out.push('\n');
+    }
+    out
+}
+
+/// Build a sample from a training event, or None if it should be skipped.
+fn build_sample(
+    event: &AuditEvent,
+    options: &TrainingExportOptions,
+    corrected_tickets: &HashSet<String>,
+) -> Option<Sample> {
+    match event {
+        AuditEvent::TrainingQueryContext {
+            input_tags,
+            input_description,
+            input_expected,
+            output_queries,
+            confidence,
+            success,
+            ..
+        } => {
+            if output_queries.is_empty()
+                || (!options.include_unsuccessful && *success != Some(true))
+            {
+                return None;
+            }
+            let mut input = describe_request(input_description, input_expected.as_deref());
+            if !input_tags.is_empty() {
+                input.push_str(&format!("\nTags: {}", input_tags.join(", ")));
+            }
+            Some(Sample {
+                input,
+                output: json!({ "queries": output_queries, "confidence": round(*confidence) })
+                    .to_string(),
+            })
+        }
+
+        AuditEvent::TrainingScoringContext {
+            ticket_id,
+            input_description,
+            input_expected,
+            input_candidates,
+            output_scores,
+            ..
+        } => {
+            if input_candidates.is_empty() || corrected_tickets.contains(ticket_id) {
+                return None;
+            }
+            let scores: Vec<Value> = output_scores
+                .iter()
+                .enumerate()
+                .map(|(index, score)| json!({ "index": index, "score": round(*score) }))
+                .collect();
+            Some(Sample {
+                input: describe_candidates(
+                    input_description,
+                    input_expected.as_deref(),
+                    input_candidates,
+                ),
+                output: json!({ "scores": scores }).to_string(),
+            })
+        }
+
+        // The user's pick is the only match; everything else shown scores zero
+        AuditEvent::UserCorrection {
+            selected_idx,
+            context_description,
+            expected_content,
+            candidates,
+            ..
+        } => {
+            if *selected_idx >= candidates.len() {
+                return None;
+            }
+            let scores: Vec<Value> = (0..candidates.len())
+                .map(|index| {
+                    let score = if index == *selected_idx { 1.0 } else { 0.0 };
+                    json!({ "index": index, "score": score })
+                })
+                .collect();
+            Some(Sample {
+                input: describe_candidates(
+                    context_description,
+                    expected_content.as_deref(),
+                    candidates,
+                ),
+                output: json!({ "scores": scores }).to_string(),
+            })
+        }
+
+        AuditEvent::TrainingFileMappingContext {
+            input_expected,
+            input_files,
+            output_mappings,
+            quality,
+            ..
+        } => {
+            if output_mappings.is_empty() || *quality < options.min_mapping_quality {
+                return None;
+            }
+            let mut input = format!("Expected content: {}\n\nFiles:", input_expected);
+            for file in input_files {
+                input.push_str(&format!("\n{} ({} bytes)", file.path, file.size_bytes));
+            }
+            let mappings: Vec<Value> = output_mappings
+                .iter()
+                .map(|m| json!({ "file": m.file_path, "item_id": m.item_id }))
+                .collect();
+            Some(Sample {
+                input,
+                output: json!({ "mappings": mappings }).to_string(),
+            })
+        }
+
+        _ => None,
+    }
+}
+
+/// Describe what was requested.
+fn describe_request(description: &str, expected: Option<&str>) -> String {
+    let mut input = format!("Description: {}", description);
+    if let Some(expected) = expected {
+        input.push_str(&format!("\nExpected content: {}", expected));
+    }
+    input
+}
+
+/// Describe a request and the candidates to score.
+fn describe_candidates(
+    description: &str,
+    expected: Option<&str>,
+    candidates: &[TrainingCandidate],
+) -> String {
+    let mut input = describe_request(description, expected);
+    input.push_str("\n\nCandidates:");
+    for (i, candidate) in candidates.iter().enumerate() {
+        input.push_str(&format!(
+            "\n[{}] {} ({} MB, {} seeders",
+            i,
+            candidate.title,
+            candidate.size_bytes / (1024 * 1024),
+            candidate.seeders
+        ));
+        if let Some(ref category) = candidate.category {
+            input.push_str(&format!(", {}", category));
+        }
+        input.push(')');
+    }
+    input
+}
+
+/// Render a sample in the requested format.
+fn format_sample(task: TrainingTask, format: TrainingExportFormat, sample: &Sample) -> Value {
+    match format {
+        TrainingExportFormat::Chat => json!({
+            "messages": [
+                { "role": "system", "content": task.instruction() },
+                { "role": "user", "content": sample.input },
+                { "role": "assistant", "content": sample.output },
+            ]
+        }),
+        TrainingExportFormat::Instruction => json!({
+            "instruction": task.instruction(),
+            "input": sample.input,
+            "output": sample.output,
+        }),
+    }
+}
+
+/// Deterministically assign an input to the validation split.
+fn in_validation_split(input: &str, fraction: f32) -> bool {
+    let hash = Sha256::digest(input.as_bytes());
+    let bucket = u16::from_be_bytes([hash[0], hash[1]]) as f32 / u16::MAX as f32;
+    bucket < fraction.clamp(0.0, 1.0)
+}
+
+fn digest(text: &str) -> String {
+    format!("{:x}", Sha256::digest(text.as_bytes()))
+}
+
+/// Round scores so float noise doesn't defeat deduplication.
+fn round(value: f32) -> f64 {
+    (value as f64 * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::{AuditRecord, SqliteAuditStore, TrainingFile, TrainingFileMapping};
+    use chrono::Utc;
+
+    fn insert(store: &SqliteAuditStore, event: AuditEvent) {
+        store
+            .insert(&AuditRecord {
+                id: 0,
+                timestamp: Utc::now(),
+                event_type: event.event_type().to_string(),
+                ticket_id: event.ticket_id().map(String::from),
+                user_id: event.user_id().map(String::from),
+                data: event,
+            })
+            .unwrap();
+    }
+
+    fn query_event(ticket_id: &str, description: &str, success: Option<bool>) -> AuditEvent {
+        AuditEvent::TrainingQueryContext {
+            sample_id: uuid::Uuid::new_v4().to_string(),
+            ticket_id: ticket_id.to_string(),
+            input_tags: vec!["flac".to_string()],
+            input_description: description.to_string(),
+            input_expected: None,
+            output_queries: vec!["Beatles Abbey Road FLAC".to_string()],
+            method: "dumb".to_string(),
+            confidence: 0.8,
+            success,
+        }
+    }
+
+    fn candidate(title: &str) -> TrainingCandidate {
+        TrainingCandidate {
+            title: title.to_string(),
+            hash: format!("hash-{}", title),
+            size_bytes: 500 * 1024 * 1024,
+            seeders: 10,
+            category: None,
+        }
+    }
+
+    #[test]
+    fn test_query_export_filters_dedups_and_strips_ids() {
+        let store = SqliteAuditStore::in_memory().unwrap();
+        insert(&store, query_event("ticket-1", "Abbey Road", Some(true)));
+        insert(&store, query_event("ticket-2", "Abbey Road", Some(true)));
+        insert(&store, query_event("ticket-3", "Let It Be", Some(false)));
+        insert(&store, query_event("ticket-4", "Help!", None));
+
+        let export = export_training_data(
+            &store,
+            &TrainingExportOptions::new(TrainingTask::QueryGeneration),
+        )
+        .unwrap();
+
+        assert_eq!(export.train.len() + export.validation.len(), 1);
+        assert_eq!(export.duplicates_removed, 1);
+
+        let jsonl = to_jsonl(&export.train) + &to_jsonl(&export.validation);
+        assert!(jsonl.contains("Abbey Road"));
+        assert!(!jsonl.contains("ticket-"));
+        assert_eq!(jsonl.lines().count(), 1);
+
+        let mut options = TrainingExportOptions::new(TrainingTask::QueryGeneration);
+        options.include_unsuccessful = true;
+        let export = export_training_data(&store, &options).unwrap();
+        assert_eq!(export.train.len() + export.validation.len(), 3);
+    }
+
+    #[test]
+    fn test_scoring_export_prefers_user_corrections() {
+        let store = SqliteAuditStore::in_memory().unwrap();
+        insert(
+            &store,
+            AuditEvent::TrainingScoringContext {
+                sample_id: "s1".to_string(),
+                ticket_id: "ticket-1".to_string(),
+                input_description: "Abbey Road".to_string(),
+                input_expected: None,
+                input_candidates: vec![candidate("Wrong"), candidate("Right")],
+                output_recommended_idx: 0,
+                output_scores: vec![0.9, 0.8],
+                method: "dumb".to_string(),
+            },
+        );
+        insert(
+            &store,
+            AuditEvent::UserCorrection {
+                ticket_id: "ticket-1".to_string(),
+                recommended_idx: 0,
+                selected_idx: 1,
+                context_description: "Abbey Road".to_string(),
+                expected_content: None,
+                candidates: vec![candidate("Wrong"), candidate("Right")],
+                user_id: "alice".to_string(),
+            },
+        );
+
+        let mut options = TrainingExportOptions::new(TrainingTask::CandidateScoring);
+        options.format = TrainingExportFormat::Instruction;
+        options.validation_fraction = 0.0;
+        let export = export_training_data(&store, &options).unwrap();
+
+        assert_eq!(export.train.len(), 1);
+        let sample = &export.train[0];
+        let output: Value = serde_json::from_str(sample["output"].as_str().unwrap()).unwrap();
+        assert_eq!(output["scores"][1]["score"], 1.0);
+        assert_eq!(output["scores"][0]["score"], 0.0);
+        let text = sample.to_string();
+        assert!(!text.contains("alice"));
+        assert!(!text.contains("hash-"));
+    }
+
+    #[test]
+    fn test_file_mapping_export_respects_quality_and_format() {
+        let store = SqliteAuditStore::in_memory().unwrap();
+        for (ticket_id, quality) in [("ticket-1", 0.95), ("ticket-2", 0.3)] {
+            insert(
+                &store,
+                AuditEvent::TrainingFileMappingContext {
+                    sample_id: ticket_id.to_string(),
+                    ticket_id: ticket_id.to_string(),
+                    input_expected: format!("{{\"album\":\"{}\"}}", ticket_id),
+                    input_files: vec![TrainingFile {
+                        path: "01 - Come Together.flac".to_string(),
+                        size_bytes: 30_000_000,
+                    }],
+                    output_mappings: vec![TrainingFileMapping {
+                        file_path: "01 - Come Together.flac".to_string(),
+                        item_id: "track-1".to_string(),
+                        confidence: 0.9,
+                    }],
+                    quality,
+                },
+            );
+        }
+
+        let mut options = TrainingExportOptions::new(TrainingTask::FileMapping);
+        options.validation_fraction = 0.0;
+        let export = export_training_data(&store, &options).unwrap();
+
+        assert_eq!(export.train.len(), 1);
+        let messages = export.train[0]["messages"].as_array().unwrap();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[0]["role"], "system");
+        assert!(messages[2]["content"].as_str().unwrap().contains("track-1"));
+    }
+
+    #[test]
+    fn test_validation_split_is_deterministic() {
+        assert!(!in_validation_split("anything", 0.0));
+        assert!(in_validation_split("anything", 1.0));
+        assert_eq!(
+            in_validation_split("Abbey Road", 0.5),
+            in_validation_split("Abbey Road", 0.5)
+        );
+    }
+}
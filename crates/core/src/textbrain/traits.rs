@@ -3,9 +3,9 @@
 use async_trait::async_trait;
 use thiserror::Error;
 
-use crate::searcher::TorrentCandidate;
-use crate::textbrain::types::{MatchResult, QueryBuildResult};
-use crate::ticket::QueryContext;
+use crate::searcher::{TorrentCandidate, TorrentFile};
+use crate::textbrain::types::{FileMappingResult, MatchResult, QueryBuildResult};
+use crate::ticket::{ExpectedContent, QueryContext};
 
 /// Errors that can occur during TextBrain operations.
 #[derive(Debug, Error)]
@@ -72,6 +72,22 @@ pub trait CandidateMatcher: Send + Sync {
     ) -> Result<MatchResult, TextBrainError>;
 }
 
+/// Trait for mapping a torrent's files to the expected ticket items.
+#[async_trait]
+pub trait FileMapper: Send + Sync {
+    /// Name of this file mapper for logging/audit.
+    fn name(&self) -> &str;
+
+    /// Map files to expected items.
+    ///
+    /// Every returned mapping refers to a path from `files`.
+    async fn map_files(
+        &self,
+        files: &[TorrentFile],
+        expected: &ExpectedContent,
+    ) -> Result<FileMappingResult, TextBrainError>;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
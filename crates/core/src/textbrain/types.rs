@@ -47,6 +47,18 @@ pub struct FileMapping {
     pub confidence: f32,
 }
 
+/// Result of mapping torrent files to ticket items.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileMappingResult {
+    /// Mappings, sorted by confidence descending.
+    pub mappings: Vec<FileMapping>,
+    /// Method used: "dumb" or "llm".
+    pub method: String,
+    /// LLM token usage (if LLM was used).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llm_usage: Option<LlmUsage>,
+}
+
 /// Result of candidate matching/scoring.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchResult {
@@ -7,8 +7,10 @@ mod types;
 pub use sqlite_store::SqliteTicketStore;
 pub use store::{CreateTicketRequest, TicketError, TicketFilter, TicketStore};
 pub use types::{
-    AcquisitionPhase, AudioSearchConstraints, CatalogReference, CompletionStats, ExpectedContent,
-    ExpectedTrack, LanguagePreference, LanguagePriority, OutputConstraints, QueryContext,
-    Resolution, RetryPhase, SearchConstraints, SelectedCandidate, Ticket, TicketState,
-    TmdbMediaType, VideoCodec, VideoSearchConstraints, VideoSource,
+    AcquisitionPhase, AnimeConstraints, AudioQualityReport, AudioSearchConstraints, BookFormat,
+    CatalogReference, CompletedDownload, CompletionStats, Edition, ExpectedAlbum, ExpectedChapter,
+    ExpectedContent, ExpectedSeason, ExpectedTrack, FailoverRecord, HdrPreference,
+    LanguagePreference, LanguagePriority, OutputConstraints, QueryContext, RaceEntrant, Resolution,
+    RetryPhase, SearchConstraints, SelectedCandidate, Ticket, TicketState, TmdbMediaType,
+    VideoCodec, VideoSearchConstraints, VideoSource,
 };
@@ -6,6 +6,8 @@ use std::sync::Mutex;
 use chrono::{DateTime, Utc};
 use rusqlite::{params, Connection};
 
+use crate::textbrain::TextBrainOverrides;
+
 use super::{
     CreateTicketRequest, OutputConstraints, QueryContext, Ticket, TicketError, TicketFilter,
     TicketState, TicketStore,
@@ -67,6 +69,18 @@ impl SqliteTicketStore {
             [],
         );
 
+        // Migration: add reacquire_count column if it doesn't exist
+        let _ = conn.execute(
+            "ALTER TABLE tickets ADD COLUMN reacquire_count INTEGER NOT NULL DEFAULT 0",
+            [],
+        );
+
+        // Migration: add textbrain_overrides column if it doesn't exist
+        let _ = conn.execute(
+            "ALTER TABLE tickets ADD COLUMN textbrain_overrides TEXT",
+            [],
+        );
+
         Ok(())
     }
 
@@ -106,6 +120,8 @@ impl SqliteTicketStore {
         let output_constraints_json: Option<String> = row.get(7)?;
         let updated_at_str: String = row.get(8)?;
         let retry_count: u32 = row.get::<_, Option<u32>>(9)?.unwrap_or(0);
+        let reacquire_count: u32 = row.get::<_, Option<u32>>(10)?.unwrap_or(0);
+        let textbrain_overrides_json: Option<String> = row.get(11)?;
 
         // Parse timestamps - use default if parsing fails (shouldn't happen with valid data)
         let created_at = DateTime::parse_from_rfc3339(&created_at_str)
@@ -125,6 +141,9 @@ impl SqliteTicketStore {
         let output_constraints: Option<OutputConstraints> =
             output_constraints_json.and_then(|json| serde_json::from_str(&json).ok());
 
+        let textbrain_overrides: Option<TextBrainOverrides> =
+            textbrain_overrides_json.and_then(|json| serde_json::from_str(&json).ok());
+
         Ok(Ticket {
             id,
             created_at,
@@ -134,7 +153,9 @@ impl SqliteTicketStore {
             query_context,
             dest_path,
             output_constraints,
+            textbrain_overrides,
             retry_count,
+            reacquire_count,
             updated_at,
         })
     }
@@ -161,8 +182,15 @@ impl TicketStore for SqliteTicketStore {
             .transpose()
             .map_err(|e| TicketError::Database(e.to_string()))?;
 
+        let textbrain_overrides_json = request
+            .textbrain_overrides
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| TicketError::Database(e.to_string()))?;
+
         conn.execute(
-            "INSERT INTO tickets (id, created_at, created_by, state, priority, query_context, dest_path, output_constraints, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO tickets (id, created_at, created_by, state, priority, query_context, dest_path, output_constraints, textbrain_overrides, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             params![
                 id,
                 now.to_rfc3339(),
@@ -172,6 +200,7 @@ impl TicketStore for SqliteTicketStore {
                 query_context_json,
                 request.dest_path,
                 output_constraints_json,
+                textbrain_overrides_json,
                 now.to_rfc3339(),
             ],
         )
@@ -186,7 +215,9 @@ impl TicketStore for SqliteTicketStore {
             query_context: request.query_context,
             dest_path: request.dest_path,
             output_constraints: request.output_constraints,
+            textbrain_overrides: request.textbrain_overrides,
             retry_count: 0,
+            reacquire_count: 0,
             updated_at: now,
         })
     }
@@ -195,7 +226,7 @@ impl TicketStore for SqliteTicketStore {
         let conn = self.conn.lock().unwrap();
 
         let result = conn.query_row(
-            "SELECT id, created_at, created_by, state, priority, query_context, dest_path, output_constraints, updated_at, retry_count FROM tickets WHERE id = ?",
+            "SELECT id, created_at, created_by, state, priority, query_context, dest_path, output_constraints, updated_at, retry_count, reacquire_count, textbrain_overrides FROM tickets WHERE id = ?",
             params![id],
             Self::row_to_ticket,
         );
@@ -213,7 +244,7 @@ impl TicketStore for SqliteTicketStore {
         let (where_clause, params) = Self::build_where_clause(filter);
 
         let sql = format!(
-            "SELECT id, created_at, created_by, state, priority, query_context, dest_path, output_constraints, updated_at, retry_count FROM tickets {} ORDER BY priority DESC, created_at ASC LIMIT ? OFFSET ?",
+            "SELECT id, created_at, created_by, state, priority, query_context, dest_path, output_constraints, updated_at, retry_count, reacquire_count, textbrain_overrides FROM tickets {} ORDER BY priority DESC, created_at ASC LIMIT ? OFFSET ?",
             where_clause
         );
 
@@ -262,7 +293,7 @@ impl TicketStore for SqliteTicketStore {
 
         // First, get the current ticket to check state
         let current = conn.query_row(
-            "SELECT id, created_at, created_by, state, priority, query_context, dest_path, output_constraints, updated_at, retry_count FROM tickets WHERE id = ?",
+            "SELECT id, created_at, created_by, state, priority, query_context, dest_path, output_constraints, updated_at, retry_count, reacquire_count, textbrain_overrides FROM tickets WHERE id = ?",
             params![id],
             Self::row_to_ticket,
         );
@@ -304,7 +335,9 @@ impl TicketStore for SqliteTicketStore {
             query_context: current_ticket.query_context,
             dest_path: current_ticket.dest_path,
             output_constraints: current_ticket.output_constraints,
+            textbrain_overrides: current_ticket.textbrain_overrides,
             retry_count: current_ticket.retry_count,
+            reacquire_count: current_ticket.reacquire_count,
             updated_at: now,
         })
     }
@@ -314,7 +347,7 @@ impl TicketStore for SqliteTicketStore {
 
         // Get current ticket
         let current = conn.query_row(
-            "SELECT id, created_at, created_by, state, priority, query_context, dest_path, output_constraints, updated_at, retry_count FROM tickets WHERE id = ?",
+            "SELECT id, created_at, created_by, state, priority, query_context, dest_path, output_constraints, updated_at, retry_count, reacquire_count, textbrain_overrides FROM tickets WHERE id = ?",
             params![id],
             Self::row_to_ticket,
         );
@@ -343,12 +376,46 @@ impl TicketStore for SqliteTicketStore {
         })
     }
 
+    fn increment_reacquire_count(&self, id: &str) -> Result<Ticket, TicketError> {
+        let conn = self.conn.lock().unwrap();
+
+        // Get current ticket
+        let current = conn.query_row(
+            "SELECT id, created_at, created_by, state, priority, query_context, dest_path, output_constraints, updated_at, retry_count, reacquire_count, textbrain_overrides FROM tickets WHERE id = ?",
+            params![id],
+            Self::row_to_ticket,
+        );
+
+        let current_ticket = match current {
+            Ok(ticket) => ticket,
+            Err(rusqlite::Error::QueryReturnedNoRows) => {
+                return Err(TicketError::NotFound(id.to_string()));
+            }
+            Err(e) => return Err(TicketError::Database(e.to_string())),
+        };
+
+        let new_reacquire_count = current_ticket.reacquire_count + 1;
+        let now = Utc::now();
+
+        conn.execute(
+            "UPDATE tickets SET reacquire_count = ?, updated_at = ? WHERE id = ?",
+            params![new_reacquire_count, now.to_rfc3339(), id],
+        )
+        .map_err(|e| TicketError::Database(e.to_string()))?;
+
+        Ok(Ticket {
+            reacquire_count: new_reacquire_count,
+            updated_at: now,
+            ..current_ticket
+        })
+    }
+
     fn delete(&self, id: &str) -> Result<Ticket, TicketError> {
         let conn = self.conn.lock().unwrap();
 
         // First, get the ticket to return it
         let ticket = conn.query_row(
-            "SELECT id, created_at, created_by, state, priority, query_context, dest_path, output_constraints, updated_at, retry_count FROM tickets WHERE id = ?",
+            "SELECT id, created_at, created_by, state, priority, query_context, dest_path, output_constraints, updated_at, retry_count, reacquire_count, textbrain_overrides FROM tickets WHERE id = ?",
             params![id],
             Self::row_to_ticket,
         );
@@ -387,6 +454,7 @@ mod tests {
             ),
             dest_path: "/media/music/beatles".to_string(),
             output_constraints: None, // Keep original format
+            textbrain_overrides: None,
         }
     }
 
@@ -419,6 +487,28 @@ mod tests {
         assert_eq!(fetched.created_by, created.created_by);
     }
 
+    #[test]
+    fn test_textbrain_overrides_roundtrip() {
+        let store = create_test_store();
+        let overrides = TextBrainOverrides {
+            mode: Some(crate::textbrain::TextBrainMode::LlmFirst),
+            require_approval: true,
+            ..Default::default()
+        };
+        let request = CreateTicketRequest {
+            textbrain_overrides: Some(overrides.clone()),
+            ..create_test_request()
+        };
+
+        let created = store.create(request).unwrap();
+        let fetched = store.get(&created.id).unwrap().unwrap();
+        assert_eq!(fetched.textbrain_overrides, Some(overrides));
+
+        let plain = store.create(create_test_request()).unwrap();
+        let fetched = store.get(&plain.id).unwrap().unwrap();
+        assert!(fetched.textbrain_overrides.is_none());
+    }
+
     #[test]
     fn test_get_nonexistent_ticket() {
         let store = create_test_store();
@@ -659,6 +749,7 @@ mod tests {
                         conversion_duration_secs: 30,
                         final_size_bytes: 500_000,
                         files_placed: 5,
+                        audio_quality: None,
                     },
                 },
             )
@@ -687,6 +778,23 @@ mod tests {
         assert!(matches!(updated.state, TicketState::Failed { .. }));
     }
 
+    #[test]
+    fn test_increment_reacquire_count() {
+        let store = create_test_store();
+        let ticket = store.create(create_test_request()).unwrap();
+        assert_eq!(ticket.reacquire_count, 0);
+
+        let updated = store.increment_reacquire_count(&ticket.id).unwrap();
+        assert_eq!(updated.reacquire_count, 1);
+
+        // Survives state updates
+        store
+            .update_state(&ticket.id, TicketState::Pending)
+            .unwrap();
+        let fetched = store.get(&ticket.id).unwrap().unwrap();
+        assert_eq!(fetched.reacquire_count, 1);
+    }
+
     #[test]
     fn test_file_based_store() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -4,6 +4,7 @@
 
 use std::fmt;
 
+use crate::textbrain::TextBrainOverrides;
 use crate::ticket::{OutputConstraints, QueryContext, Ticket, TicketState};
 
 /// Error type for ticket operations.
@@ -54,6 +55,8 @@ pub struct CreateTicketRequest {
     pub dest_path: String,
     /// Output format constraints (None = keep original, no conversion).
     pub output_constraints: Option<OutputConstraints>,
+    /// Per-ticket TextBrain overrides (None = use the global settings).
+    pub textbrain_overrides: Option<TextBrainOverrides>,
 }
 
 /// Filter for querying tickets.
@@ -126,6 +129,10 @@ pub trait TicketStore: Send + Sync {
     /// Returns the updated ticket.
     fn increment_retry_count(&self, id: &str) -> Result<Ticket, TicketError>;
 
+    /// Increment the number of times a ticket was sent back to acquisition.
+    /// Returns the updated ticket.
+    fn increment_reacquire_count(&self, id: &str) -> Result<Ticket, TicketError>;
+
     /// Permanently delete a ticket and all associated data.
     /// Returns the deleted ticket if found.
     fn delete(&self, id: &str) -> Result<Ticket, TicketError>;
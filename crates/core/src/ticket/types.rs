@@ -3,8 +3,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::converter::{AudioConstraints, AudioFormat, VideoConstraints};
-use crate::textbrain::{FileMapping, ScoredCandidateSummary};
+use crate::converter::{AudioConstraints, AudioFormat, AudiobookConstraints, VideoConstraints};
+use crate::textbrain::{FileMapping, ScoredCandidateSummary, TextBrainOverrides};
 
 // ============================================================================
 // Catalog Reference Types
@@ -35,12 +35,16 @@ pub enum CatalogReference {
         id: u32,
         /// Media type (movie or TV).
         media_type: TmdbMediaType,
-        /// Runtime in minutes (for movies).
+        /// Runtime in minutes (for movies), or of one episode (for TV).
         #[serde(default, skip_serializing_if = "Option::is_none")]
         runtime_minutes: Option<u32>,
         /// Episode count (for TV seasons).
         #[serde(default, skip_serializing_if = "Option::is_none")]
         episode_count: Option<u32>,
+        /// Episodes aired before the expected season, for converting to
+        /// absolute episode numbers (anime).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        absolute_offset: Option<u32>,
     },
 }
 
@@ -84,6 +88,17 @@ pub struct AudioSearchConstraints {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub min_bitrate_kbps: Option<u32>,
 
+    /// Minimum bit depth for lossless formats (16 or 24).
+    /// Candidates tagged below this are penalized, and downloaded files
+    /// below it fail verification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_bit_depth: Option<u8>,
+
+    /// Minimum sample rate in Hz for lossless formats (e.g. 44100, 96000).
+    /// Applied like `min_bit_depth`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_sample_rate_hz: Option<u32>,
+
     /// Avoid compilation/various artists releases.
     #[serde(default)]
     pub avoid_compilations: bool,
@@ -125,6 +140,34 @@ pub struct VideoSearchConstraints {
     /// Exclude releases with hardcoded subtitles.
     #[serde(default)]
     pub exclude_hardcoded_subs: bool,
+
+    /// HDR preference (prefer HDR or Dolby Vision, or exclude HDR releases).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hdr: Option<HdrPreference>,
+
+    /// Required edition (hard filter - reject other editions).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_edition: Option<Edition>,
+
+    /// Prefer REPACK/PROPER releases, which fix problems in the original.
+    #[serde(default)]
+    pub prefer_proper: bool,
+
+    /// Anime release preferences (fansub groups, dual audio).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anime: Option<AnimeConstraints>,
+}
+
+/// Anime-specific release preferences.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AnimeConstraints {
+    /// Preferred release groups, e.g. ["SubsPlease", "Erai-raws"] (boost score for matches).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub preferred_groups: Vec<String>,
+
+    /// Prefer releases with both original and dubbed audio.
+    #[serde(default)]
+    pub prefer_dual_audio: bool,
 }
 
 /// Video resolution for search constraints.
@@ -148,6 +191,15 @@ impl Resolution {
             Resolution::R2160p => "2160p",
         }
     }
+
+    /// Returns the nominal frame size as (width, height).
+    pub fn dimensions(&self) -> (u32, u32) {
+        match self {
+            Resolution::R720p => (1280, 720),
+            Resolution::R1080p => (1920, 1080),
+            Resolution::R2160p => (3840, 2160),
+        }
+    }
 }
 
 /// Video source quality for search constraints.
@@ -179,6 +231,58 @@ impl VideoSource {
     }
 }
 
+/// HDR preference for video search constraints.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HdrPreference {
+    /// Prefer HDR releases (HDR10, HDR10+ or Dolby Vision).
+    Prefer,
+    /// Prefer Dolby Vision, then other HDR formats.
+    PreferDolbyVision,
+    /// Avoid HDR releases (for SDR-only displays).
+    Exclude,
+}
+
+impl HdrPreference {
+    /// Returns the preferred format as a search keyword (None when excluding).
+    pub fn as_keyword(&self) -> Option<&'static str> {
+        match self {
+            HdrPreference::Prefer => Some("HDR"),
+            HdrPreference::PreferDolbyVision => Some("DV"),
+            HdrPreference::Exclude => None,
+        }
+    }
+}
+
+/// Movie edition for search constraints.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Edition {
+    /// Theatrical cut (also matched by releases without an edition tag)
+    Theatrical,
+    /// Director's Cut
+    DirectorsCut,
+    /// Extended edition
+    Extended,
+    /// Unrated cut
+    Unrated,
+    /// IMAX (expanded aspect ratio)
+    Imax,
+}
+
+impl Edition {
+    /// Returns the edition as a search keyword.
+    pub fn as_keyword(&self) -> &'static str {
+        match self {
+            Edition::Theatrical => "Theatrical",
+            Edition::DirectorsCut => "Directors Cut",
+            Edition::Extended => "Extended",
+            Edition::Unrated => "Unrated",
+            Edition::Imax => "IMAX",
+        }
+    }
+}
+
 /// Video codec for search constraints.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -262,6 +366,9 @@ pub enum OutputConstraints {
 
     /// Convert video files to specified format.
     Video(VideoConstraints),
+
+    /// Merge audiobook chapters into a single chaptered M4B.
+    Audiobook(AudiobookConstraints),
 }
 
 impl OutputConstraints {
@@ -292,6 +399,9 @@ impl OutputConstraints {
             Self::Original => None,
             Self::Audio(a) => Some(crate::converter::ConversionConstraints::Audio(a.clone())),
             Self::Video(v) => Some(crate::converter::ConversionConstraints::Video(v.clone())),
+            Self::Audiobook(a) => Some(crate::converter::ConversionConstraints::Audiobook(
+                a.clone(),
+            )),
         }
     }
 }
@@ -359,6 +469,30 @@ impl QueryContext {
         self.search_constraints = Some(constraints);
         self
     }
+
+    /// Whether this is an anime request (tagged "anime" or with anime constraints).
+    pub fn is_anime(&self) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case("anime"))
+            || self.anime_constraints().is_some()
+    }
+
+    /// Anime release preferences, if any.
+    pub fn anime_constraints(&self) -> Option<&AnimeConstraints> {
+        self.search_constraints
+            .as_ref()
+            .and_then(|sc| sc.video.as_ref())
+            .and_then(|v| v.anime.as_ref())
+    }
+
+    /// Absolute episode offset from the TMDB catalog reference, if known.
+    pub fn absolute_offset(&self) -> Option<u32> {
+        match &self.catalog_reference {
+            Some(CatalogReference::Tmdb {
+                absolute_offset, ..
+            }) => *absolute_offset,
+            _ => None,
+        }
+    }
 }
 
 /// Expected content structure for file validation.
@@ -379,6 +513,16 @@ pub enum ExpectedContent {
         tracks: Vec<ExpectedTrack>,
     },
 
+    /// An artist's discography.
+    Discography {
+        /// Artist name.
+        artist: String,
+        /// Albums to collect, resolved from MusicBrainz release groups when
+        /// empty.
+        #[serde(default)]
+        albums: Vec<ExpectedAlbum>,
+    },
+
     /// Single music track.
     Track {
         /// Artist name (optional).
@@ -406,6 +550,170 @@ pub enum ExpectedContent {
         /// Episode numbers (e.g., [1, 2, 3] for S01E01-03).
         episodes: Vec<u32>,
     },
+
+    /// A whole TV season.
+    TvSeason {
+        /// Series name.
+        series: String,
+        /// Season number.
+        season: u32,
+        /// Episode numbers, resolved from the external catalog (empty if unresolved).
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        episodes: Vec<u32>,
+    },
+
+    /// Several seasons or a complete series.
+    TvSeries {
+        /// Series name.
+        series: String,
+        /// Season numbers (None for every regular season).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        seasons: Option<Vec<u32>>,
+        /// Episodes per season, resolved from the external catalog (empty if unresolved).
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        resolved_seasons: Vec<ExpectedSeason>,
+    },
+
+    /// Audiobook.
+    Audiobook {
+        /// Author name (optional, for matching).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        author: Option<String>,
+        /// Book title.
+        title: String,
+        /// Narrator (optional, for matching).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        narrator: Option<String>,
+        /// Series name (optional).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        series: Option<String>,
+        /// Expected chapters in order (empty if unknown).
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        chapters: Vec<ExpectedChapter>,
+    },
+
+    /// Ebook.
+    Book {
+        /// Author name (optional, for matching).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        author: Option<String>,
+        /// Book title.
+        title: String,
+        /// ISBN (optional, for matching).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        isbn: Option<String>,
+        /// Acceptable formats in order of preference (empty = any).
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        formats: Vec<BookFormat>,
+    },
+
+    /// Comic issue or collected volume.
+    Comic {
+        /// Series name.
+        series: String,
+        /// Issue number (optional).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        issue: Option<u32>,
+        /// Volume number (optional, for collected editions).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        volume: Option<u32>,
+    },
+}
+
+/// Ebook and comic file formats.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum BookFormat {
+    Epub,
+    Azw3,
+    Mobi,
+    Pdf,
+    Cbz,
+    Cbr,
+}
+
+impl BookFormat {
+    /// All formats, ebook formats first.
+    pub const ALL: [BookFormat; 6] = [
+        Self::Epub,
+        Self::Azw3,
+        Self::Mobi,
+        Self::Pdf,
+        Self::Cbz,
+        Self::Cbr,
+    ];
+
+    /// File extension for this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Epub => "epub",
+            Self::Azw3 => "azw3",
+            Self::Mobi => "mobi",
+            Self::Pdf => "pdf",
+            Self::Cbz => "cbz",
+            Self::Cbr => "cbr",
+        }
+    }
+
+    /// Format for a file extension (case-insensitive).
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|f| f.extension().eq_ignore_ascii_case(ext))
+    }
+
+    /// Whether this is a comic archive format.
+    pub fn is_comic(&self) -> bool {
+        matches!(self, Self::Cbz | Self::Cbr)
+    }
+}
+
+/// Album within an [`ExpectedContent::Discography`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExpectedAlbum {
+    /// Album title.
+    pub title: String,
+    /// Release year.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub year: Option<u32>,
+    /// MusicBrainz release group ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mbid: Option<String>,
+    /// Expected tracks in order (empty if unknown).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tracks: Vec<ExpectedTrack>,
+}
+
+/// Episodes of one season of a [`ExpectedContent::TvSeries`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExpectedSeason {
+    /// Season number.
+    pub season: u32,
+    /// Episode numbers.
+    pub episodes: Vec<u32>,
+}
+
+/// Expected chapter in an audiobook.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExpectedChapter {
+    /// Chapter number (1-indexed).
+    pub number: u32,
+    /// Chapter title.
+    pub title: String,
+    /// Expected duration in seconds (optional, for validation).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<u32>,
+}
+
+impl ExpectedChapter {
+    /// Create a new expected chapter.
+    pub fn new(number: u32, title: impl Into<String>) -> Self {
+        Self {
+            number,
+            title: title.into(),
+            duration_secs: None,
+        }
+    }
 }
 
 /// Expected track in an album.
@@ -466,6 +774,22 @@ impl ExpectedTrack {
 }
 
 impl ExpectedContent {
+    /// Name of the content type, as used in serialized tickets.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Album { .. } => "album",
+            Self::Discography { .. } => "discography",
+            Self::Track { .. } => "track",
+            Self::Movie { .. } => "movie",
+            Self::TvEpisode { .. } => "tv_episode",
+            Self::TvSeason { .. } => "tv_season",
+            Self::TvSeries { .. } => "tv_series",
+            Self::Audiobook { .. } => "audiobook",
+            Self::Book { .. } => "book",
+            Self::Comic { .. } => "comic",
+        }
+    }
+
     /// Create an album expectation.
     pub fn album(title: impl Into<String>, tracks: Vec<ExpectedTrack>) -> Self {
         Self::Album {
@@ -538,13 +862,98 @@ impl ExpectedContent {
         }
     }
 
+    /// Create a whole-season expectation (episodes resolved later).
+    pub fn tv_season(series: impl Into<String>, season: u32) -> Self {
+        Self::TvSeason {
+            series: series.into(),
+            season,
+            episodes: vec![],
+        }
+    }
+
+    /// Create a complete-series expectation (episodes resolved later).
+    pub fn tv_series(series: impl Into<String>) -> Self {
+        Self::TvSeries {
+            series: series.into(),
+            seasons: None,
+            resolved_seasons: vec![],
+        }
+    }
+
+    /// Whether this is a season or series expectation whose episode list
+    /// has not been resolved from the external catalog yet.
+    pub fn needs_episode_resolution(&self) -> bool {
+        match self {
+            Self::TvSeason { episodes, .. } => episodes.is_empty(),
+            Self::TvSeries {
+                resolved_seasons, ..
+            } => resolved_seasons.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Create a discography expectation (albums resolved later).
+    pub fn discography(artist: impl Into<String>) -> Self {
+        Self::Discography {
+            artist: artist.into(),
+            albums: vec![],
+        }
+    }
+
+    /// Whether this is a discography whose album list has not been
+    /// resolved from the external catalog yet.
+    pub fn needs_album_resolution(&self) -> bool {
+        matches!(self, Self::Discography { albums, .. } if albums.is_empty())
+    }
+
+    /// Create an audiobook expectation with author.
+    pub fn audiobook_by(author: impl Into<String>, title: impl Into<String>) -> Self {
+        Self::Audiobook {
+            author: Some(author.into()),
+            title: title.into(),
+            narrator: None,
+            series: None,
+            chapters: vec![],
+        }
+    }
+
+    /// Create an ebook expectation with author.
+    pub fn book_by(author: impl Into<String>, title: impl Into<String>) -> Self {
+        Self::Book {
+            author: Some(author.into()),
+            title: title.into(),
+            isbn: None,
+            formats: vec![],
+        }
+    }
+
+    /// Create a comic issue expectation.
+    pub fn comic_issue(series: impl Into<String>, issue: u32) -> Self {
+        Self::Comic {
+            series: series.into(),
+            issue: Some(issue),
+            volume: None,
+        }
+    }
+
     /// Get the expected file count.
     pub fn expected_file_count(&self) -> usize {
         match self {
             ExpectedContent::Album { tracks, .. } => tracks.len(),
+            // An album with unknown tracks counts as one item
+            ExpectedContent::Discography { albums, .. } => {
+                albums.iter().map(|a| a.tracks.len().max(1)).sum()
+            }
             ExpectedContent::Track { .. } => 1,
             ExpectedContent::Movie { .. } => 1,
             ExpectedContent::TvEpisode { episodes, .. } => episodes.len(),
+            ExpectedContent::TvSeason { episodes, .. } => episodes.len(),
+            ExpectedContent::TvSeries {
+                resolved_seasons, ..
+            } => resolved_seasons.iter().map(|s| s.episodes.len()).sum(),
+            ExpectedContent::Audiobook { chapters, .. } => chapters.len().max(1),
+            ExpectedContent::Book { .. } => 1,
+            ExpectedContent::Comic { .. } => 1,
         }
     }
 }
@@ -577,11 +986,81 @@ pub struct SelectedCandidate {
     pub size_bytes: u64,
     /// Match score (0.0-1.0).
     pub score: f32,
+    /// Seeder count at acquisition time.
+    #[serde(default)]
+    pub seeders: u32,
     /// File mappings from acquisition (which torrent files match which ticket items).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub file_mappings: Vec<FileMapping>,
 }
 
+/// Failover bookkeeping for a download, recorded by the failover strategy.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FailoverRecord {
+    /// Strategy that made the last failover decision.
+    pub strategy: String,
+    /// Why the current candidate was chosen.
+    pub reason: String,
+    /// Candidates already tried in the current round (indices into `candidates`).
+    #[serde(default)]
+    pub tried: Vec<usize>,
+    /// Torrents racing each other, including the current one (race strategy only).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub racing: Vec<RaceEntrant>,
+}
+
+/// A torrent taking part in a failover race.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RaceEntrant {
+    /// Index of the candidate (into `candidates`).
+    pub candidate_idx: usize,
+    /// Info hash of the started torrent.
+    pub info_hash: String,
+}
+
+/// A finished download handed to the pipeline.
+///
+/// Persisted in the Converting and Placing states so the pipeline can be
+/// re-run from the downloaded content after a restart.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompletedDownload {
+    /// Info hash of the downloaded torrent.
+    pub info_hash: String,
+    /// Path to the downloaded content (file or directory).
+    pub path: String,
+    /// File mappings from acquisition.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_mappings: Vec<FileMapping>,
+    /// Lossless quality findings from verification (music only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_quality: Option<AudioQualityReport>,
+}
+
+/// Quality of the lossless files in a downloaded music release, measured
+/// from their decoded audio.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AudioQualityReport {
+    /// Number of lossless files analyzed.
+    pub files_checked: u32,
+    /// Lowest effective bit depth found.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bit_depth: Option<u8>,
+    /// Lowest sample rate found, in Hz.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sample_rate_hz: Option<u32>,
+    /// Files that look transcoded from a lossy source, upsampled or padded,
+    /// one finding per entry.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl AudioQualityReport {
+    /// Whether any file looks like it was not made from a lossless source.
+    pub fn is_suspect(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+}
+
 /// Statistics for a completed ticket.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CompletionStats {
@@ -595,6 +1074,9 @@ pub struct CompletionStats {
     pub final_size_bytes: u64,
     /// Number of files placed.
     pub files_placed: u32,
+    /// Lossless quality findings recorded during verification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_quality: Option<AudioQualityReport>,
 }
 
 /// Phase at which a retryable failure occurred.
@@ -634,6 +1116,9 @@ impl std::fmt::Display for RetryPhase {
 ///         AcquisitionFailed                            Rejected
 ///
 /// Downloading -> Converting -> Placing -> Completed
+///      ^    |
+///      |    v
+///    Preempted (paused for a higher-priority ticket)
 ///
 /// Any non-terminal state can transition to Failed or Cancelled.
 /// ```
@@ -742,6 +1227,34 @@ pub enum TicketState {
         /// All candidates for failover.
         #[serde(default)]
         candidates: Vec<SelectedCandidate>,
+        /// Last failover decision and its reasoning (None until the first failover).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        failover: Option<FailoverRecord>,
+    },
+
+    /// Download paused to free a slot for a higher-priority ticket.
+    /// The orchestrator resumes it once a download slot becomes available.
+    Preempted {
+        /// Info hash of the paused torrent.
+        info_hash: String,
+        /// Download progress when the torrent was paused (0.0-100.0).
+        progress_pct: f32,
+        started_at: DateTime<Utc>,
+        /// Index of the candidate that was downloading (0-based).
+        #[serde(default)]
+        candidate_idx: usize,
+        /// Failover round the download was in.
+        #[serde(default = "default_failover_round")]
+        failover_round: u8,
+        /// All candidates for failover.
+        #[serde(default)]
+        candidates: Vec<SelectedCandidate>,
+        /// ID of the higher-priority ticket that took the slot.
+        preempted_by: String,
+        preempted_at: DateTime<Utc>,
+        /// Last failover decision, restored on resume.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        failover: Option<FailoverRecord>,
     },
 
     /// Converting downloaded files (transcoding, metadata embedding).
@@ -753,6 +1266,9 @@ pub enum TicketState {
         /// Name of the current item.
         current_name: String,
         started_at: DateTime<Utc>,
+        /// Download being processed (for resuming after a restart).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        download: Option<CompletedDownload>,
     },
 
     /// Placing converted files to their final destinations.
@@ -762,6 +1278,9 @@ pub enum TicketState {
         /// Total files to place.
         total_files: usize,
         started_at: DateTime<Utc>,
+        /// Download being processed (for resuming after a restart).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        download: Option<CompletedDownload>,
     },
 
     // ========================================================================
@@ -839,6 +1358,7 @@ impl TicketState {
             self,
             TicketState::Acquiring { .. }
                 | TicketState::Downloading { .. }
+                | TicketState::Preempted { .. }
                 | TicketState::Converting { .. }
                 | TicketState::Placing { .. }
                 | TicketState::PendingRetry { .. }
@@ -864,6 +1384,7 @@ impl TicketState {
             TicketState::Approved { .. } => "approved",
             TicketState::Rejected { .. } => "rejected",
             TicketState::Downloading { .. } => "downloading",
+            TicketState::Preempted { .. } => "preempted",
             TicketState::Converting { .. } => "converting",
             TicketState::Placing { .. } => "placing",
             TicketState::Completed { .. } => "completed",
@@ -915,11 +1436,20 @@ pub struct Ticket {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub output_constraints: Option<OutputConstraints>,
 
+    /// Per-ticket overrides of the TextBrain mode and thresholds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub textbrain_overrides: Option<TextBrainOverrides>,
+
     /// Number of retry attempts made for this ticket.
     /// Persists across state transitions to track retry history.
     #[serde(default)]
     pub retry_count: u32,
 
+    /// Number of times the ticket was sent back to acquisition after its
+    /// failover candidates ran out.
+    #[serde(default)]
+    pub reacquire_count: u32,
+
     /// Last update timestamp.
     pub updated_at: DateTime<Utc>,
 }
@@ -995,12 +1525,64 @@ mod tests {
             last_progress_pct: 45.5,
             last_progress_at: now,
             candidates: vec![],
+            failover: None,
         };
         assert!(!state.is_terminal());
         assert!(state.is_active());
         assert_eq!(state.state_type(), "downloading");
     }
 
+    #[test]
+    fn test_preempted_state() {
+        let now = Utc::now();
+        let state = TicketState::Preempted {
+            info_hash: "abc123".to_string(),
+            progress_pct: 30.0,
+            started_at: now,
+            candidate_idx: 0,
+            failover_round: 1,
+            candidates: vec![],
+            preempted_by: "ticket-urgent".to_string(),
+            preempted_at: now,
+            failover: None,
+        };
+        assert!(!state.is_terminal());
+        assert!(state.can_cancel());
+        assert!(state.is_active());
+        assert_eq!(state.state_type(), "preempted");
+
+        let json = serde_json::to_string(&state).unwrap();
+        assert!(json.contains(r#""type":"preempted""#));
+        let parsed: TicketState = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, state);
+    }
+
+    #[test]
+    fn test_placing_state_persists_download() {
+        let state = TicketState::Placing {
+            files_placed: 0,
+            total_files: 1,
+            started_at: Utc::now(),
+            download: Some(CompletedDownload {
+                info_hash: "abc123".to_string(),
+                path: "/downloads/Album".to_string(),
+                file_mappings: vec![],
+                audio_quality: None,
+            }),
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        let parsed: TicketState = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, state);
+
+        // States written before downloads were persisted still load
+        let legacy = r#"{"type":"converting","current_idx":0,"total":1,"current_name":"x","started_at":"2024-01-01T00:00:00Z"}"#;
+        let parsed: TicketState = serde_json::from_str(legacy).unwrap();
+        assert!(matches!(
+            parsed,
+            TicketState::Converting { download: None, .. }
+        ));
+    }
+
     #[test]
     fn test_completed_state_is_terminal() {
         let state = TicketState::Completed {
@@ -1011,6 +1593,7 @@ mod tests {
                 conversion_duration_secs: 30,
                 final_size_bytes: 500_000,
                 files_placed: 10,
+                audio_quality: None,
             },
         };
         assert!(state.is_terminal());
@@ -3,6 +3,7 @@
 //! These tests verify the complete ticket lifecycle through the orchestrator:
 //! pending -> acquiring -> downloading -> converting -> placing -> completed
 
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -10,20 +11,40 @@ use tempfile::TempDir;
 
 use torrentino_core::{
     testing::{fixtures, MockConverter, MockPlacer, MockSearcher, MockTorrentClient},
-    ticket::{CreateTicketRequest, QueryContext, TicketState},
-    OrchestratorConfig, PipelineProcessor, ProcessorConfig, SqliteCatalog, SqliteTicketStore,
-    TextBrainConfig, TicketOrchestrator, TicketStore,
+    ticket::{
+        AcquisitionPhase, CompletedDownload, CreateTicketRequest, ExpectedContent, ExpectedTrack,
+        FailoverRecord, QueryContext, SelectedCandidate, TicketState,
+    },
+    BlacklistFilter, BlacklistKind, BlacklistStore, FailoverConfig, FailoverStrategyKind,
+    FileMapping, OrchestratorConfig, PipelineProcessor, ProcessorConfig, SqliteBlacklistStore,
+    SqliteCatalog, SqliteTicketStore, TextBrainConfig, TextBrainOverrides, TicketOrchestrator,
+    TicketStore, TorrentClient, TorrentState, VerificationAction, VerificationConfig,
 };
 
 /// Test helper to create all dependencies for orchestrator testing.
 struct TestHarness {
     ticket_store: Arc<SqliteTicketStore>,
+    blacklist_store: Arc<SqliteBlacklistStore>,
     searcher: Arc<MockSearcher>,
     torrent_client: Arc<MockTorrentClient>,
     converter: MockConverter,
     placer: MockPlacer,
     catalog: Arc<SqliteCatalog>,
-    _temp_dir: TempDir,
+    temp_dir: TempDir,
+}
+
+/// Build a failover candidate whose magnet resolves to `hash` in the mock client.
+fn candidate(title: &str, hash: &str, score: f32, seeders: u32) -> SelectedCandidate {
+    SelectedCandidate {
+        title: title.to_string(),
+        info_hash: hash.to_string(),
+        magnet_uri: format!("magnet:?xt=urn:btih:{}", hash),
+        torrent_url: None,
+        size_bytes: 100 * 1024 * 1024,
+        score,
+        seeders,
+        file_mappings: vec![],
+    }
 }
 
 impl TestHarness {
@@ -34,6 +55,9 @@ impl TestHarness {
         let ticket_store =
             Arc::new(SqliteTicketStore::new(&db_path).expect("Failed to create ticket store"));
         let catalog = Arc::new(SqliteCatalog::new(&db_path).expect("Failed to create catalog"));
+        let blacklist_store = Arc::new(
+            SqliteBlacklistStore::new(&db_path).expect("Failed to create blacklist store"),
+        );
         let searcher = Arc::new(MockSearcher::new());
         let torrent_client = Arc::new(MockTorrentClient::new());
         let converter = MockConverter::new();
@@ -49,40 +73,85 @@ impl TestHarness {
 
         Self {
             ticket_store,
+            blacklist_store,
             searcher,
             torrent_client,
             converter,
             placer,
             catalog,
-            _temp_dir: temp_dir,
+            temp_dir,
         }
     }
 
     fn create_orchestrator(&self) -> TicketOrchestrator<MockConverter, MockPlacer> {
-        let config = OrchestratorConfig {
+        self.create_orchestrator_with_config(OrchestratorConfig {
             enabled: true,
             acquisition_poll_interval_ms: 50,
             download_poll_interval_ms: 50,
             auto_approve_threshold: 0.0, // Auto-approve everything
             max_concurrent_downloads: 3,
             ..Default::default()
-        };
+        })
+    }
+
+    fn create_orchestrator_with_config(
+        &self,
+        config: OrchestratorConfig,
+    ) -> TicketOrchestrator<MockConverter, MockPlacer> {
+        self.create_orchestrator_with_pipeline(config, Arc::new(self.create_pipeline()))
+    }
+
+    /// Create an orchestrator backed by a running pipeline, so completed
+    /// downloads are carried through conversion and placement.
+    async fn create_orchestrator_with_running_pipeline(
+        &self,
+    ) -> TicketOrchestrator<MockConverter, MockPlacer> {
+        let pipeline = self.create_pipeline();
+        pipeline.start().await;
+
+        self.create_orchestrator_with_pipeline(
+            OrchestratorConfig {
+                enabled: true,
+                acquisition_poll_interval_ms: 50,
+                download_poll_interval_ms: 50,
+                auto_approve_threshold: 0.0,
+                max_concurrent_downloads: 3,
+                ..Default::default()
+            },
+            Arc::new(pipeline),
+        )
+    }
 
+    fn create_pipeline(&self) -> PipelineProcessor<MockConverter, MockPlacer> {
         let processor_config = ProcessorConfig {
             max_parallel_conversions: 2,
             max_parallel_placements: 2,
+            temp_dir: self.processing_dir(),
             ..Default::default()
         };
 
-        let pipeline = PipelineProcessor::new(
+        PipelineProcessor::new(
             processor_config,
             self.converter.clone(),
             self.placer.clone(),
         )
-        .with_ticket_store(Arc::clone(&self.ticket_store) as Arc<dyn TicketStore>);
+        .with_ticket_store(Arc::clone(&self.ticket_store) as Arc<dyn TicketStore>)
+    }
 
-        let pipeline = Arc::new(pipeline);
+    fn create_orchestrator_with_pipeline(
+        &self,
+        config: OrchestratorConfig,
+        pipeline: Arc<PipelineProcessor<MockConverter, MockPlacer>>,
+    ) -> TicketOrchestrator<MockConverter, MockPlacer> {
+        self.create_orchestrator_without_blacklist(config, pipeline)
+            .with_blacklist_store(Arc::clone(&self.blacklist_store) as Arc<dyn BlacklistStore>)
+    }
 
+    fn create_orchestrator_without_blacklist(
+        &self,
+        config: OrchestratorConfig,
+        pipeline: Arc<PipelineProcessor<MockConverter, MockPlacer>>,
+    ) -> TicketOrchestrator<MockConverter, MockPlacer> {
         TicketOrchestrator::new(
             config,
             Arc::clone(&self.ticket_store) as Arc<dyn TicketStore>,
@@ -95,13 +164,27 @@ impl TestHarness {
         )
     }
 
+    /// Temp directory used by the pipeline for intermediate files.
+    fn processing_dir(&self) -> PathBuf {
+        self.temp_dir.path().join("processing")
+    }
+
     fn create_ticket(&self, description: &str) -> String {
+        self.create_ticket_with_overrides(description, None)
+    }
+
+    fn create_ticket_with_overrides(
+        &self,
+        description: &str,
+        textbrain_overrides: Option<TextBrainOverrides>,
+    ) -> String {
         let request = CreateTicketRequest {
             created_by: "test".to_string(),
             priority: 100,
             query_context: QueryContext::new(vec!["test".to_string()], description),
             dest_path: "/media/test".into(),
             output_constraints: None,
+            textbrain_overrides,
         };
 
         self.ticket_store
@@ -110,6 +193,91 @@ impl TestHarness {
             .id
     }
 
+    /// Create a ticket and move it straight to Approved with a single candidate.
+    fn create_approved_ticket(&self, description: &str, priority: u16, hash: &str) -> String {
+        self.create_approved_ticket_with_candidates(
+            description,
+            priority,
+            vec![candidate(description, hash, 0.9, 10)],
+        )
+    }
+
+    /// Create a ticket and move it straight to Approved, selecting the first candidate.
+    fn create_approved_ticket_with_candidates(
+        &self,
+        description: &str,
+        priority: u16,
+        candidates: Vec<SelectedCandidate>,
+    ) -> String {
+        self.create_approved_ticket_with_context(
+            QueryContext::new(vec!["test".to_string()], description),
+            priority,
+            candidates,
+        )
+    }
+
+    /// Create an approved ticket for `query_context` with failover candidates.
+    fn create_approved_ticket_with_context(
+        &self,
+        query_context: QueryContext,
+        priority: u16,
+        candidates: Vec<SelectedCandidate>,
+    ) -> String {
+        let request = CreateTicketRequest {
+            created_by: "test".to_string(),
+            priority,
+            query_context,
+            dest_path: "/media/test".into(),
+            output_constraints: None,
+            textbrain_overrides: None,
+        };
+        let ticket_id = self
+            .ticket_store
+            .create(request)
+            .expect("Failed to create ticket")
+            .id;
+
+        self.ticket_store
+            .update_state(
+                &ticket_id,
+                TicketState::Approved {
+                    selected: candidates[0].clone(),
+                    candidates,
+                    approved_by: "test".to_string(),
+                    approved_at: chrono::Utc::now(),
+                },
+            )
+            .expect("Failed to approve ticket");
+
+        ticket_id
+    }
+
+    /// Wait until a downloading ticket's failover record satisfies `predicate`.
+    async fn wait_for_failover(
+        &self,
+        ticket_id: &str,
+        timeout: Duration,
+        predicate: impl Fn(usize, &FailoverRecord) -> bool,
+    ) -> Option<(usize, FailoverRecord)> {
+        let start = std::time::Instant::now();
+        while start.elapsed() < timeout {
+            if let Ok(Some(ticket)) = self.ticket_store.get(ticket_id) {
+                if let TicketState::Downloading {
+                    candidate_idx,
+                    failover: Some(failover),
+                    ..
+                } = ticket.state
+                {
+                    if predicate(candidate_idx, &failover) {
+                        return Some((candidate_idx, failover));
+                    }
+                }
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        None
+    }
+
     async fn wait_for_state(
         &self,
         ticket_id: &str,
@@ -159,6 +327,7 @@ impl TestHarness {
             TicketState::Approved { .. } => "approved",
             TicketState::Rejected { .. } => "rejected",
             TicketState::Downloading { .. } => "downloading",
+            TicketState::Preempted { .. } => "preempted",
             TicketState::Converting { .. } => "converting",
             TicketState::Placing { .. } => "placing",
             TicketState::Completed { .. } => "completed",
@@ -260,6 +429,43 @@ async fn test_ticket_reaches_auto_approved_with_search_results() {
     );
 }
 
+#[tokio::test]
+async fn test_require_approval_override_skips_auto_approval() {
+    let harness = TestHarness::new().await;
+
+    harness
+        .searcher
+        .set_results(vec![fixtures::audio_candidate(
+            "Test Artist",
+            "Test Album",
+            "hash123",
+        )])
+        .await;
+
+    // The harness threshold of 0.0 would auto-approve any candidate
+    let ticket_id = harness.create_ticket_with_overrides(
+        "Test album",
+        Some(TextBrainOverrides {
+            require_approval: true,
+            ..Default::default()
+        }),
+    );
+
+    let orchestrator = harness.create_orchestrator();
+    orchestrator.start().await;
+
+    let reached = harness
+        .wait_for_state(&ticket_id, "needs_approval", Duration::from_secs(5))
+        .await;
+
+    orchestrator.stop().await;
+
+    assert!(
+        reached,
+        "Ticket requiring approval should wait for manual approval"
+    );
+}
+
 #[tokio::test]
 async fn test_no_search_results_transitions_to_acquisition_failed() {
     let harness = TestHarness::new().await;
@@ -421,6 +627,706 @@ async fn test_orchestrator_status_reflects_running_state() {
     );
 }
 
+// =============================================================================
+// Priority Preemption Tests
+// =============================================================================
+
+fn preemption_config() -> OrchestratorConfig {
+    OrchestratorConfig {
+        enabled: true,
+        acquisition_poll_interval_ms: 50,
+        download_poll_interval_ms: 50,
+        max_concurrent_downloads: 1,
+        preemption_enabled: true,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_higher_priority_ticket_preempts_and_low_priority_resumes() {
+    let harness = TestHarness::new().await;
+    let orchestrator = harness.create_orchestrator_with_config(preemption_config());
+
+    let low = harness.create_approved_ticket("Low priority album", 10, "lowhash");
+    orchestrator.start().await;
+
+    assert!(
+        harness
+            .wait_for_state(&low, "downloading", Duration::from_secs(5))
+            .await,
+        "Low priority ticket should start downloading"
+    );
+
+    let high = harness.create_approved_ticket("Urgent album", 200, "highhash");
+
+    let preempted = harness
+        .wait_for_state(&low, "preempted", Duration::from_secs(5))
+        .await;
+    let high_downloading = harness
+        .wait_for_state(&high, "downloading", Duration::from_secs(5))
+        .await;
+
+    let low_ticket = harness.ticket_store.get(&low).unwrap().unwrap();
+    let low_torrent = harness.torrent_client.get_torrent("lowhash").await.unwrap();
+
+    // Finish the urgent download to free the slot
+    harness.torrent_client.set_progress("highhash", 1.0).await;
+    let resumed = harness
+        .wait_for_state(&low, "downloading", Duration::from_secs(5))
+        .await;
+    let low_torrent_after = harness.torrent_client.get_torrent("lowhash").await.unwrap();
+
+    orchestrator.stop().await;
+
+    assert!(preempted, "Low priority download should be preempted");
+    assert!(
+        high_downloading,
+        "High priority ticket should start downloading"
+    );
+    match low_ticket.state {
+        TicketState::Preempted { preempted_by, .. } => assert_eq!(preempted_by, high),
+        other => panic!("Expected preempted state, got {:?}", other),
+    }
+    assert_eq!(low_torrent.state, TorrentState::Paused);
+
+    assert!(
+        resumed,
+        "Low priority download should resume once a slot frees"
+    );
+    assert_eq!(low_torrent_after.state, TorrentState::Downloading);
+}
+
+#[tokio::test]
+async fn test_preemption_disabled_keeps_hard_cap() {
+    let harness = TestHarness::new().await;
+    let orchestrator = harness.create_orchestrator_with_config(OrchestratorConfig {
+        preemption_enabled: false,
+        ..preemption_config()
+    });
+
+    let low = harness.create_approved_ticket("Low priority album", 10, "lowhash");
+    orchestrator.start().await;
+
+    assert!(
+        harness
+            .wait_for_state(&low, "downloading", Duration::from_secs(5))
+            .await
+    );
+
+    let high = harness.create_approved_ticket("Urgent album", 200, "highhash");
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let low_state = harness.get_ticket_state(&low);
+    let high_state = harness.get_ticket_state(&high);
+
+    orchestrator.stop().await;
+
+    assert_eq!(low_state.as_deref(), Some("downloading"));
+    assert_eq!(high_state.as_deref(), Some("approved"));
+}
+
+#[tokio::test]
+async fn test_equal_priority_does_not_preempt() {
+    let harness = TestHarness::new().await;
+    let orchestrator = harness.create_orchestrator_with_config(preemption_config());
+
+    let first = harness.create_approved_ticket("First album", 100, "firsthash");
+    orchestrator.start().await;
+
+    assert!(
+        harness
+            .wait_for_state(&first, "downloading", Duration::from_secs(5))
+            .await
+    );
+
+    let second = harness.create_approved_ticket("Second album", 100, "secondhash");
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let first_state = harness.get_ticket_state(&first);
+    let second_state = harness.get_ticket_state(&second);
+
+    orchestrator.stop().await;
+
+    assert_eq!(first_state.as_deref(), Some("downloading"));
+    assert_eq!(second_state.as_deref(), Some("approved"));
+}
+
+// =============================================================================
+// Failover Strategy Tests
+// =============================================================================
+
+/// Config that declares a download stalled after one second without progress.
+fn failover_config(failover: FailoverConfig) -> OrchestratorConfig {
+    OrchestratorConfig {
+        enabled: true,
+        acquisition_poll_interval_ms: 50,
+        download_poll_interval_ms: 50,
+        auto_approve_threshold: 0.0,
+        max_concurrent_downloads: 3,
+        stall_timeout_round1_secs: 1,
+        failover,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_by_seeders_failover_picks_most_seeded_candidate() {
+    let harness = TestHarness::new().await;
+    let orchestrator = harness.create_orchestrator_with_config(failover_config(FailoverConfig {
+        strategy: FailoverStrategyKind::BySeeders,
+        ..Default::default()
+    }));
+
+    let ticket_id = harness.create_approved_ticket_with_candidates(
+        "Test album",
+        100,
+        vec![
+            candidate("Best match", "firsthash", 0.9, 2),
+            candidate("Few seeders", "fewhash", 0.8, 5),
+            candidate("Many seeders", "manyhash", 0.7, 80),
+        ],
+    );
+    orchestrator.start().await;
+
+    let result = harness
+        .wait_for_failover(&ticket_id, Duration::from_secs(5), |_, _| true)
+        .await;
+
+    orchestrator.stop().await;
+
+    let (candidate_idx, failover) = result.expect("Stalled download should fail over");
+    assert_eq!(candidate_idx, 2);
+    assert_eq!(failover.strategy, "by_seeders");
+    assert!(failover.reason.contains("most seeders (80)"));
+    assert!(!harness.torrent_client.has_torrent("firsthash").await);
+}
+
+#[tokio::test]
+async fn test_race_failover_keeps_first_entrant_to_progress() {
+    let harness = TestHarness::new().await;
+    let orchestrator = harness.create_orchestrator_with_config(failover_config(FailoverConfig {
+        strategy: FailoverStrategyKind::Race,
+        race_size: 2,
+        race_win_pct: 5.0,
+        ..Default::default()
+    }));
+
+    let ticket_id = harness.create_approved_ticket_with_candidates(
+        "Test album",
+        100,
+        vec![
+            candidate("Stalls", "stallhash", 0.9, 10),
+            candidate("Slow", "slowhash", 0.8, 10),
+            candidate("Fast", "fasthash", 0.7, 10),
+        ],
+    );
+    orchestrator.start().await;
+
+    let racing = harness
+        .wait_for_failover(&ticket_id, Duration::from_secs(5), |_, f| {
+            f.racing.len() == 2
+        })
+        .await;
+    assert!(racing.is_some(), "Stall should start a race");
+
+    harness.torrent_client.set_progress("fasthash", 0.1).await;
+
+    let result = harness
+        .wait_for_failover(&ticket_id, Duration::from_secs(5), |_, f| {
+            f.racing.is_empty()
+        })
+        .await;
+
+    orchestrator.stop().await;
+
+    let (candidate_idx, failover) = result.expect("Race should have a winner");
+    assert_eq!(candidate_idx, 2);
+    assert!(failover.reason.contains("candidate 3 won the race"));
+    assert!(harness.torrent_client.has_torrent("fasthash").await);
+    assert!(!harness.torrent_client.has_torrent("slowhash").await);
+}
+
+// =============================================================================
+// Download Verification Tests
+// =============================================================================
+
+/// Album context expecting a five-minute track, with a candidate mapping it.
+fn verification_ticket(harness: &TestHarness, hash: &str) -> String {
+    let query_context = QueryContext::new(vec!["music".to_string()], "Test Album").with_expected(
+        ExpectedContent::Album {
+            artist: Some("Test Artist".to_string()),
+            title: "Test Album".to_string(),
+            tracks: vec![ExpectedTrack::new(1, "Only Track").with_duration(300)],
+        },
+    );
+    let mut truncated = candidate("Truncated", hash, 0.9, 10);
+    truncated.file_mappings = vec![FileMapping {
+        torrent_file_path: "01 - Only Track.flac".to_string(),
+        ticket_item_id: "track-1".to_string(),
+        confidence: 0.9,
+    }];
+
+    harness.create_approved_ticket_with_context(
+        query_context,
+        100,
+        vec![truncated, candidate("Fallback", "fallbackhash", 0.8, 10)],
+    )
+}
+
+fn verification_config(verification: VerificationConfig) -> OrchestratorConfig {
+    OrchestratorConfig {
+        enabled: true,
+        acquisition_poll_interval_ms: 50,
+        download_poll_interval_ms: 50,
+        auto_approve_threshold: 0.0,
+        max_concurrent_downloads: 3,
+        verification,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_failed_verification_fails_over_to_next_candidate() {
+    let harness = TestHarness::new().await;
+    // The mock probes audio files as three minutes long
+    let orchestrator =
+        harness.create_orchestrator_with_config(verification_config(VerificationConfig::default()));
+    let ticket_id = verification_ticket(&harness, "truncatedhash");
+    orchestrator.start().await;
+
+    let downloading = harness
+        .wait_for_state(&ticket_id, "downloading", Duration::from_secs(5))
+        .await;
+    assert!(downloading);
+    harness
+        .torrent_client
+        .set_progress("truncatedhash", 1.0)
+        .await;
+
+    let result = harness
+        .wait_for_failover(&ticket_id, Duration::from_secs(5), |_, _| true)
+        .await;
+
+    orchestrator.stop().await;
+
+    let (candidate_idx, _) = result.expect("Failed verification should fail over");
+    assert_eq!(candidate_idx, 1);
+    assert!(!harness.torrent_client.has_torrent("truncatedhash").await);
+    assert!(harness.torrent_client.has_torrent("fallbackhash").await);
+    assert!(harness.converter.recorded_conversions().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_failed_verification_can_fail_ticket() {
+    let harness = TestHarness::new().await;
+    let orchestrator =
+        harness.create_orchestrator_with_config(verification_config(VerificationConfig {
+            on_mismatch: VerificationAction::Fail,
+            ..Default::default()
+        }));
+    let ticket_id = verification_ticket(&harness, "truncatedhash");
+    orchestrator.start().await;
+
+    let downloading = harness
+        .wait_for_state(&ticket_id, "downloading", Duration::from_secs(5))
+        .await;
+    assert!(downloading);
+    harness
+        .torrent_client
+        .set_progress("truncatedhash", 1.0)
+        .await;
+
+    let failed = harness
+        .wait_for_state(&ticket_id, "failed", Duration::from_secs(5))
+        .await;
+
+    orchestrator.stop().await;
+
+    assert!(failed, "Failed verification should fail the ticket");
+    let ticket = harness.ticket_store.get(&ticket_id).unwrap().unwrap();
+    match ticket.state {
+        TicketState::Failed { error, .. } => {
+            assert_eq!(
+                error,
+                "Download failed verification: track 1 is 3:00, expected 5:00"
+            );
+        }
+        other => panic!("Expected failed state, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_slow_verification_does_not_block_download_monitor() {
+    let harness = TestHarness::new().await;
+    harness
+        .converter
+        .set_probe_duration(Duration::from_secs(2))
+        .await;
+    let orchestrator =
+        harness.create_orchestrator_with_config(verification_config(VerificationConfig::default()));
+    let verifying_id = verification_ticket(&harness, "truncatedhash");
+    let other_id = harness.create_approved_ticket("Other album", 100, "otherhash");
+    orchestrator.start().await;
+
+    for id in [&verifying_id, &other_id] {
+        assert!(
+            harness
+                .wait_for_state(id, "downloading", Duration::from_secs(5))
+                .await
+        );
+    }
+    harness
+        .torrent_client
+        .set_progress("truncatedhash", 1.0)
+        .await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    harness.torrent_client.set_progress("otherhash", 0.5).await;
+
+    // The other download keeps being tracked while the first is probed
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(1);
+    let mut progress = 0.0;
+    while tokio::time::Instant::now() < deadline {
+        if let Some(TicketState::Downloading { progress_pct, .. }) = harness
+            .ticket_store
+            .get(&other_id)
+            .unwrap()
+            .map(|t| t.state)
+        {
+            progress = progress_pct;
+            if progress >= 50.0 {
+                break;
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    let verifying = harness.ticket_store.get(&verifying_id).unwrap().unwrap();
+    orchestrator.stop().await;
+
+    assert!(progress >= 50.0, "Progress stuck at {}", progress);
+    assert_eq!(verifying.state.state_type(), "downloading");
+}
+
+#[tokio::test]
+async fn test_research_failover_sends_ticket_back_to_acquisition() {
+    let harness = TestHarness::new().await;
+    harness.searcher.set_results(vec![]).await;
+    let orchestrator = harness.create_orchestrator_with_config(failover_config(FailoverConfig {
+        strategy: FailoverStrategyKind::Research,
+        ..Default::default()
+    }));
+
+    let ticket_id = harness.create_approved_ticket("Test album", 100, "onlyhash");
+    orchestrator.start().await;
+
+    // With nothing new to find, the fresh search fails acquisition
+    let reached = harness
+        .wait_for_state(&ticket_id, "acquisition_failed", Duration::from_secs(5))
+        .await;
+
+    orchestrator.stop().await;
+
+    assert!(
+        reached,
+        "Exhausted candidates should trigger a fresh search"
+    );
+    assert!(!harness.searcher.recorded_searches().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_research_failover_without_blacklist_store_fails_ticket() {
+    let harness = TestHarness::new().await;
+    harness.searcher.set_results(vec![]).await;
+    let orchestrator = harness.create_orchestrator_without_blacklist(
+        failover_config(FailoverConfig {
+            strategy: FailoverStrategyKind::Research,
+            ..Default::default()
+        }),
+        Arc::new(harness.create_pipeline()),
+    );
+
+    let ticket_id = harness.create_approved_ticket("Test album", 100, "onlyhash");
+    orchestrator.start().await;
+
+    // Without a blacklist a fresh search could pick the stalled torrent again
+    let reached = harness
+        .wait_for_state(&ticket_id, "failed", Duration::from_secs(5))
+        .await;
+
+    orchestrator.stop().await;
+
+    assert!(reached, "Ticket should fail instead of searching again");
+    assert!(harness.searcher.recorded_searches().await.is_empty());
+    let ticket = harness.ticket_store.get(&ticket_id).unwrap().unwrap();
+    assert_eq!(ticket.reacquire_count, 0);
+}
+
+#[tokio::test]
+async fn test_reacquire_on_exhaustion_skips_stalled_hashes() {
+    let harness = TestHarness::new().await;
+    harness
+        .searcher
+        .set_results(vec![
+            fixtures::audio_candidate("Test Artist", "Test Album", "stalledhash"),
+            fixtures::audio_candidate("Test Artist", "Test Album", "freshhash"),
+        ])
+        .await;
+    let orchestrator = harness.create_orchestrator_with_config(OrchestratorConfig {
+        stall_timeout_round2_secs: 1,
+        stall_timeout_round3_secs: 1,
+        ..failover_config(FailoverConfig {
+            reacquire_on_exhaustion: true,
+            ..Default::default()
+        })
+    });
+
+    let ticket_id = harness.create_approved_ticket("Test album", 100, "stalledhash");
+    orchestrator.start().await;
+
+    // After three stalled rounds the fresh search must pick the other release
+    let start = std::time::Instant::now();
+    let mut reacquired = false;
+    while start.elapsed() < Duration::from_secs(10) {
+        if let Some(ticket) = harness.ticket_store.get(&ticket_id).unwrap() {
+            if let TicketState::Downloading { ref info_hash, .. } = ticket.state {
+                if info_hash == "freshhash" {
+                    reacquired = true;
+                    break;
+                }
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    orchestrator.stop().await;
+
+    assert!(reacquired, "Exhausted candidates should be re-acquired");
+    let ticket = harness.ticket_store.get(&ticket_id).unwrap().unwrap();
+    assert_eq!(ticket.reacquire_count, 1);
+    let blacklisted = harness
+        .blacklist_store
+        .list(&BlacklistFilter {
+            ticket_id: Some(ticket_id.clone()),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(blacklisted.len(), 1);
+    assert_eq!(blacklisted[0].kind, BlacklistKind::InfoHash);
+    assert_eq!(blacklisted[0].value, "stalledhash");
+}
+
+// =============================================================================
+// Restart Recovery Tests
+// =============================================================================
+
+/// Write a fake completed download to disk and describe it.
+fn completed_download(harness: &TestHarness, name: &str) -> CompletedDownload {
+    let downloads = harness.temp_dir.path().join("downloads");
+    std::fs::create_dir_all(&downloads).expect("Failed to create downloads dir");
+    let path = downloads.join(name);
+    std::fs::write(&path, b"downloaded content").expect("Failed to write download");
+
+    CompletedDownload {
+        info_hash: "donehash".to_string(),
+        path: path.to_string_lossy().to_string(),
+        file_mappings: vec![],
+        audio_quality: None,
+    }
+}
+
+/// Leave a partially written file in the pipeline temp dir for a ticket.
+fn write_partial_temp_file(harness: &TestHarness, ticket_id: &str) -> PathBuf {
+    let dir = harness.processing_dir().join(ticket_id);
+    std::fs::create_dir_all(&dir).expect("Failed to create temp dir");
+    let path = dir.join("main.partial");
+    std::fs::write(&path, b"half a file").expect("Failed to write partial file");
+    path
+}
+
+#[tokio::test]
+async fn test_restart_during_acquisition_requeues_ticket() {
+    let harness = TestHarness::new().await;
+    harness
+        .searcher
+        .set_results(vec![fixtures::audio_candidate(
+            "Test Artist",
+            "Test Album",
+            "acqhash",
+        )])
+        .await;
+
+    // Simulate a crash mid-acquisition
+    let ticket_id = harness.create_ticket("Test album");
+    harness
+        .ticket_store
+        .update_state(
+            &ticket_id,
+            TicketState::Acquiring {
+                started_at: chrono::Utc::now(),
+                queries_tried: vec!["test album".to_string()],
+                candidates_found: 0,
+                phase: AcquisitionPhase::Searching {
+                    query: "test album".to_string(),
+                },
+            },
+        )
+        .expect("Failed to set acquiring state");
+
+    let orchestrator = harness.create_orchestrator();
+    orchestrator.start().await;
+
+    let reached = harness
+        .wait_for_state(&ticket_id, "downloading", Duration::from_secs(5))
+        .await;
+
+    orchestrator.stop().await;
+
+    assert!(reached, "Interrupted acquisition should be restarted");
+    assert!(!harness.searcher.recorded_searches().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_restart_during_download_resumes_and_completes() {
+    let harness = TestHarness::new().await;
+    let ticket_id = harness.create_approved_ticket("Test album", 100, "dlhash");
+
+    let orchestrator = harness.create_orchestrator_with_running_pipeline().await;
+    orchestrator.start().await;
+    assert!(
+        harness
+            .wait_for_state(&ticket_id, "downloading", Duration::from_secs(5))
+            .await
+    );
+    orchestrator.stop().await;
+
+    // The download finishes while we are down
+    harness.torrent_client.set_progress("dlhash", 1.0).await;
+
+    let orchestrator = harness.create_orchestrator_with_running_pipeline().await;
+    orchestrator.start().await;
+
+    let reached = harness
+        .wait_for_state(&ticket_id, "completed", Duration::from_secs(5))
+        .await;
+
+    orchestrator.stop().await;
+
+    assert!(
+        reached,
+        "Recovered download should run through the pipeline"
+    );
+    assert_eq!(harness.placer.placement_count().await, 1);
+}
+
+#[tokio::test]
+async fn test_restart_during_conversion_cleans_temp_and_reruns_pipeline() {
+    let harness = TestHarness::new().await;
+    let ticket_id = harness.create_ticket("Test album");
+    let download = completed_download(&harness, "Test Album");
+    let partial = write_partial_temp_file(&harness, &ticket_id);
+
+    // Simulate a crash mid-conversion
+    harness
+        .ticket_store
+        .update_state(
+            &ticket_id,
+            TicketState::Converting {
+                current_idx: 0,
+                total: 1,
+                current_name: "Test Album".to_string(),
+                started_at: chrono::Utc::now(),
+                download: Some(download.clone()),
+            },
+        )
+        .expect("Failed to set converting state");
+
+    let orchestrator = harness.create_orchestrator_with_running_pipeline().await;
+    orchestrator.start().await;
+
+    let reached = harness
+        .wait_for_state(&ticket_id, "completed", Duration::from_secs(5))
+        .await;
+
+    orchestrator.stop().await;
+
+    assert!(reached, "Interrupted conversion should be re-run");
+    assert!(!partial.exists(), "Partial temp files should be removed");
+
+    let placements = harness.placer.recorded_placements().await;
+    assert_eq!(placements.len(), 1);
+    assert_eq!(
+        placements[0].job.files[0].source,
+        PathBuf::from(&download.path)
+    );
+}
+
+#[tokio::test]
+async fn test_restart_during_placement_reruns_placement() {
+    let harness = TestHarness::new().await;
+    let ticket_id = harness.create_ticket("Test album");
+    let download = completed_download(&harness, "Test Album");
+
+    // Simulate a crash mid-placement
+    harness
+        .ticket_store
+        .update_state(
+            &ticket_id,
+            TicketState::Placing {
+                files_placed: 0,
+                total_files: 1,
+                started_at: chrono::Utc::now(),
+                download: Some(download),
+            },
+        )
+        .expect("Failed to set placing state");
+
+    let orchestrator = harness.create_orchestrator_with_running_pipeline().await;
+    orchestrator.start().await;
+
+    let reached = harness
+        .wait_for_state(&ticket_id, "completed", Duration::from_secs(5))
+        .await;
+
+    orchestrator.stop().await;
+
+    assert!(reached, "Interrupted placement should be re-run");
+    assert_eq!(harness.placer.placement_count().await, 1);
+}
+
+#[tokio::test]
+async fn test_restart_without_persisted_download_fails_retryable() {
+    let harness = TestHarness::new().await;
+    let ticket_id = harness.create_ticket("Test album");
+
+    // State written before downloads were persisted
+    harness
+        .ticket_store
+        .update_state(
+            &ticket_id,
+            TicketState::Placing {
+                files_placed: 0,
+                total_files: 1,
+                started_at: chrono::Utc::now(),
+                download: None,
+            },
+        )
+        .expect("Failed to set placing state");
+
+    let orchestrator = harness.create_orchestrator_with_running_pipeline().await;
+    orchestrator.start().await;
+    orchestrator.stop().await;
+
+    let ticket = harness.ticket_store.get(&ticket_id).unwrap().unwrap();
+    assert!(matches!(
+        ticket.state,
+        TicketState::Failed {
+            retryable: true,
+            ..
+        }
+    ));
+    assert_eq!(harness.placer.placement_count().await, 0);
+}
+
 // =============================================================================
 // Discography Fallback Tests
 // =============================================================================
@@ -493,6 +1399,7 @@ async fn test_discography_fallback_finds_album_in_discography() {
         },
         dest_path: "/media/test".into(),
         output_constraints: None,
+        textbrain_overrides: None,
     };
 
     let ticket_id = harness
@@ -14,7 +14,7 @@ use tempfile::TempDir;
 use tokio::sync::mpsc;
 
 use torrentino_core::{
-    converter::{ConversionConstraints, ConverterError},
+    converter::{AudiobookConstraints, ConversionConstraints, ConverterError},
     placer::PlacerError,
     processor::{PipelineJob, PipelineProgress, SourceFile},
     testing::{MockConverter, MockPlacer},
@@ -83,6 +83,7 @@ impl TestHarness {
                 .to_string_lossy()
                 .to_string(),
             output_constraints: None,
+            textbrain_overrides: None,
         };
 
         self.ticket_store
@@ -115,6 +116,7 @@ impl TestHarness {
             TicketState::Approved { .. } => "approved",
             TicketState::Rejected { .. } => "rejected",
             TicketState::Downloading { .. } => "downloading",
+            TicketState::Preempted { .. } => "preempted",
             TicketState::Converting { .. } => "converting",
             TicketState::Placing { .. } => "placing",
             TicketState::Completed { .. } => "completed",
@@ -171,6 +173,7 @@ async fn test_pipeline_rejects_jobs_when_not_running() {
         constraints: None,
         dest_dir: harness.temp_dir.path().join("output"),
         metadata: None,
+        download: None,
     };
 
     let result = harness.processor.process(job, None).await;
@@ -197,11 +200,14 @@ async fn test_pipeline_processes_job_successfully() {
             path: source_path,
             item_id: "track01".to_string(),
             dest_filename: "track01.ogg".to_string(),
+            segment: None,
+            metadata: None,
         }],
         file_mappings: vec![],
         constraints: None, // No conversion, just copy
         dest_dir: harness.temp_dir.path().join("output"),
         metadata: None,
+        download: None,
     };
 
     harness
@@ -233,6 +239,73 @@ async fn test_pipeline_processes_job_successfully() {
     assert_eq!(state, Some("completed".to_string()));
 }
 
+#[tokio::test]
+async fn test_pipeline_merges_audiobook_chapters() {
+    let harness = TestHarness::new().await;
+    let ticket_id = harness.create_ticket("Test audiobook");
+    let chapters: Vec<PathBuf> = ["01.mp3", "02.mp3", "03.mp3"]
+        .iter()
+        .map(|name| harness.create_source_file(name))
+        .collect();
+
+    harness.processor.start().await;
+
+    let (progress_tx, mut progress_rx) = mpsc::channel(100);
+
+    let job = PipelineJob {
+        ticket_id: ticket_id.clone(),
+        source_files: chapters
+            .iter()
+            .enumerate()
+            .map(|(idx, path)| SourceFile {
+                path: path.clone(),
+                item_id: format!("chapter-{}", idx + 1),
+                dest_filename: "Dune.m4b".to_string(),
+                segment: None,
+                metadata: None,
+            })
+            .collect(),
+        file_mappings: vec![],
+        constraints: Some(ConversionConstraints::Audiobook(
+            AudiobookConstraints::default(),
+        )),
+        dest_dir: harness.temp_dir.path().join("output"),
+        metadata: None,
+        download: None,
+    };
+
+    harness
+        .processor
+        .process(job, Some(progress_tx))
+        .await
+        .unwrap();
+
+    while let Some(progress) = progress_rx.recv().await {
+        match progress {
+            PipelineProgress::Completed { .. } => break,
+            PipelineProgress::Failed { error, .. } => {
+                panic!("Pipeline failed unexpectedly: {}", error);
+            }
+            _ => {}
+        }
+    }
+
+    // All chapters go into a single merge, in order
+    let merges = harness.converter.recorded_merges().await;
+    assert_eq!(merges.len(), 1);
+    let merged: Vec<PathBuf> = merges[0].chapters.iter().map(|c| c.path.clone()).collect();
+    assert_eq!(merged, chapters);
+    assert_eq!(merges[0].chapters[0].title, "01");
+    assert!(harness.converter.recorded_conversions().await.is_empty());
+
+    // One M4B is placed
+    let placements = harness.placer.recorded_placements().await;
+    assert_eq!(placements[0].job.files.len(), 1);
+    assert!(placements[0].job.files[0]
+        .destination
+        .ends_with("output/Dune.m4b"));
+}
+
 #[tokio::test]
 async fn test_pipeline_updates_ticket_to_converting_state() {
     let harness = TestHarness::new().await;
@@ -255,6 +328,8 @@ async fn test_pipeline_updates_ticket_to_converting_state() {
             path: source_path,
             item_id: "track01".to_string(),
             dest_filename: "track01.ogg".to_string(),
+            segment: None,
+            metadata: None,
         }],
         file_mappings: vec![],
         constraints: Some(ConversionConstraints::Audio(
@@ -268,6 +343,7 @@ async fn test_pipeline_updates_ticket_to_converting_state() {
         )),
         dest_dir: harness.temp_dir.path().join("output"),
         metadata: None,
+        download: None,
     };
 
     harness
@@ -315,6 +391,8 @@ async fn test_pipeline_handles_conversion_failure() {
             path: source_path,
             item_id: "track01".to_string(),
             dest_filename: "track01.ogg".to_string(),
+            segment: None,
+            metadata: None,
         }],
         file_mappings: vec![],
         constraints: Some(ConversionConstraints::Audio(
@@ -328,6 +406,7 @@ async fn test_pipeline_handles_conversion_failure() {
         )),
         dest_dir: harness.temp_dir.path().join("output"),
         metadata: None,
+        download: None,
     };
 
     harness
@@ -377,11 +456,14 @@ async fn test_pipeline_handles_placement_failure() {
             path: source_path,
             item_id: "track01".to_string(),
             dest_filename: "track01.ogg".to_string(),
+            segment: None,
+            metadata: None,
         }],
         file_mappings: vec![],
         constraints: None, // No conversion - go straight to placement
         dest_dir: harness.temp_dir.path().join("output"),
         metadata: None,
+        download: None,
     };
 
     harness
@@ -442,6 +524,8 @@ async fn test_pipeline_respects_max_parallel_conversions() {
                 path: source_path,
                 item_id: format!("track{:02}", i),
                 dest_filename: format!("track{:02}.ogg", i),
+                segment: None,
+                metadata: None,
             }],
             file_mappings: vec![],
             constraints: Some(ConversionConstraints::Audio(
@@ -455,6 +539,7 @@ async fn test_pipeline_respects_max_parallel_conversions() {
             )),
             dest_dir: harness.temp_dir.path().join("output"),
             metadata: None,
+            download: None,
         };
 
         harness
@@ -509,6 +594,8 @@ async fn test_pipeline_prevents_duplicate_jobs() {
             path: source_path.clone(),
             item_id: "track01".to_string(),
             dest_filename: "track01.ogg".to_string(),
+            segment: None,
+            metadata: None,
         }],
         file_mappings: vec![],
         constraints: Some(ConversionConstraints::Audio(
@@ -522,6 +609,7 @@ async fn test_pipeline_prevents_duplicate_jobs() {
         )),
         dest_dir: harness.temp_dir.path().join("output"),
         metadata: None,
+        download: None,
     };
 
     // First job should succeed
@@ -559,6 +647,8 @@ async fn test_pipeline_status_shows_active_jobs() {
             path: source_path,
             item_id: "track01".to_string(),
             dest_filename: "track01.ogg".to_string(),
+            segment: None,
+            metadata: None,
         }],
         file_mappings: vec![],
         constraints: Some(ConversionConstraints::Audio(
@@ -572,6 +662,7 @@ async fn test_pipeline_status_shows_active_jobs() {
         )),
         dest_dir: harness.temp_dir.path().join("output"),
         metadata: None,
+        download: None,
     };
 
     let (progress_tx, _) = mpsc::channel(100);
@@ -49,9 +49,18 @@ export interface SelectedCandidateState {
   torrent_url?: string
   size_bytes: number
   score: number
+  seeders?: number
   file_mappings?: FileMapping[]
 }
 
+// Failover bookkeeping recorded by the failover strategy (Downloading state)
+export interface FailoverRecord {
+  strategy: string
+  reason: string
+  tried: number[]
+  racing?: { candidate_idx: number; info_hash: string }[]
+}
+
 // Completion stats
 export interface CompletionStats {
   total_download_bytes: number
@@ -59,6 +68,15 @@ export interface CompletionStats {
   conversion_duration_secs: number
   final_size_bytes: number
   files_placed: number
+  audio_quality?: AudioQualityReport
+}
+
+// Lossless quality findings for a music download
+export interface AudioQualityReport {
+  files_checked: number
+  bit_depth?: number
+  sample_rate_hz?: number
+  warnings?: string[]
 }
 
 // TicketState uses discriminated union with 'type' field
@@ -115,6 +133,18 @@ export type TicketState =
       candidate_idx: number
       failover_round: number
       candidates: SelectedCandidateState[]
+      failover?: FailoverRecord
+    }
+  | {
+      type: 'preempted'
+      info_hash: string
+      progress_pct: number
+      started_at: string
+      candidate_idx: number
+      failover_round: number
+      candidates: SelectedCandidateState[]
+      preempted_by: string
+      preempted_at: string
     }
   | {
       type: 'converting'
@@ -157,9 +187,20 @@ export interface Ticket {
   query_context: QueryContext
   dest_path: string
   output_constraints?: OutputConstraints
+  textbrain_overrides?: TextBrainOverrides
   updated_at: string
 }
 
+export type TextBrainMode = 'dumb_only' | 'dumb_first' | 'llm_first' | 'llm_only'
+
+// Per-ticket overrides of the global TextBrain settings
+export interface TextBrainOverrides {
+  mode?: TextBrainMode
+  auto_approve_threshold?: number
+  max_queries?: number
+  require_approval?: boolean
+}
+
 export interface TicketListResponse {
   tickets: Ticket[]
   total: number
@@ -205,11 +246,18 @@ export interface VideoConstraints {
   audio?: AudioConstraints
 }
 
+// Audiobook constraints - chapters merged into a single M4B
+export interface AudiobookConstraints {
+  bitrate_kbps?: number
+  channels?: number
+}
+
 // Output constraints - what format to convert to (or keep original)
 export type OutputConstraints =
   | { type: 'original' }
   | ({ type: 'audio' } & AudioConstraints)
   | ({ type: 'video' } & VideoConstraints)
+  | ({ type: 'audiobook' } & AudiobookConstraints)
 
 export interface CreateTicketRequest {
   priority?: number
@@ -222,6 +270,7 @@ export interface CreateTicketRequest {
   }
   dest_path: string
   output_constraints?: OutputConstraints
+  textbrain_overrides?: TextBrainOverrides
 }
 
 export interface CancelTicketRequest {
@@ -829,6 +878,26 @@ export interface ExpectedTrack {
   disc_number?: number
 }
 
+export interface ExpectedChapter {
+  number: number
+  title: string
+  duration_secs?: number
+}
+
+export interface ExpectedSeason {
+  season: number
+  episodes: number[]
+}
+
+export interface ExpectedAlbum {
+  title: string
+  year?: number
+  mbid?: string
+  tracks?: ExpectedTrack[]
+}
+
+export type BookFormat = 'epub' | 'azw3' | 'mobi' | 'pdf' | 'cbz' | 'cbr'
+
 export type ExpectedContent =
   | {
       type: 'album'
@@ -852,6 +921,44 @@ export type ExpectedContent =
       season: number
       episodes: number[]
     }
+  | {
+      type: 'tv_season'
+      series: string
+      season: number
+      episodes?: number[]
+    }
+  | {
+      type: 'tv_series'
+      series: string
+      seasons?: number[]
+      resolved_seasons?: ExpectedSeason[]
+    }
+  | {
+      type: 'discography'
+      artist: string
+      albums: ExpectedAlbum[]
+    }
+  | {
+      type: 'audiobook'
+      author?: string
+      title: string
+      narrator?: string
+      series?: string
+      chapters?: ExpectedChapter[]
+    }
+  | {
+      type: 'book'
+      author?: string
+      title: string
+      isbn?: string
+      formats?: BookFormat[]
+    }
+  | {
+      type: 'comic'
+      series: string
+      issue?: number
+      volume?: number
+    }
 
 export interface QueryContextWithExpected extends QueryContext {
   expected?: ExpectedContent
@@ -981,6 +1088,23 @@ export interface TextBrainConfigResponse {
   auto_approve_threshold: number
   llm_configured: boolean
   llm_provider?: string
+  llm_budget?: LlmBudgetStatus
+}
+
+export interface LlmBudgetPeriod {
+  input_tokens: number
+  output_tokens: number
+  cost: number
+  token_limit?: number
+  cost_limit?: number
+  tokens_remaining?: number
+  cost_remaining?: number
+}
+
+export interface LlmBudgetStatus {
+  daily: LlmBudgetPeriod
+  monthly: LlmBudgetPeriod
+  exhausted: boolean
 }
 
 // =============================================================================
@@ -1083,6 +1207,7 @@ export type CatalogReference =
       media_type: TmdbMediaType
       runtime_minutes?: number
       episode_count?: number
+      absolute_offset?: number
     }
 
 // Resolution for video constraints
@@ -1094,10 +1219,18 @@ export type VideoSource = 'cam' | 'hdtv' | 'web_dl' | 'blu_ray' | 'remux'
 // Video codec for constraints (different from conversion codec)
 export type VideoSearchCodec = 'x264' | 'x265' | 'av1'
 
+// HDR preference for video constraints
+export type HdrPreference = 'prefer' | 'prefer_dolby_vision' | 'exclude'
+
+// Movie edition for video constraints
+export type Edition = 'theatrical' | 'directors_cut' | 'extended' | 'unrated' | 'imax'
+
 // Audio search constraints
 export interface AudioSearchConstraints {
   preferred_formats?: AudioFormat[]
   min_bitrate_kbps?: number
+  min_bit_depth?: number
+  min_sample_rate_hz?: number
   avoid_compilations?: boolean
   avoid_live?: boolean
 }
@@ -1120,6 +1253,16 @@ export interface VideoSearchConstraints {
   audio_languages?: LanguagePreference[]
   subtitle_languages?: LanguagePreference[]
   exclude_hardcoded_subs?: boolean
+  hdr?: HdrPreference
+  required_edition?: Edition
+  prefer_proper?: boolean
+  anime?: AnimeConstraints
+}
+
+// Anime release preferences
+export interface AnimeConstraints {
+  preferred_groups?: string[]
+  prefer_dual_audio?: boolean
 }
 
 // Combined search constraints
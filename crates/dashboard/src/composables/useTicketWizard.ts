@@ -30,6 +30,8 @@ export function useTicketWizard() {
   const audioConstraints = ref<AudioSearchConstraints>({
     preferred_formats: [],
     min_bitrate_kbps: undefined,
+    min_bit_depth: undefined,
+    min_sample_rate_hz: undefined,
     avoid_compilations: false,
     avoid_live: false,
   })
@@ -42,6 +44,9 @@ export function useTicketWizard() {
     audio_languages: [],
     subtitle_languages: [],
     exclude_hardcoded_subs: false,
+    hdr: undefined,
+    required_edition: undefined,
+    prefer_proper: false,
   })
 
   // Ticket details
@@ -114,6 +119,8 @@ export function useTicketWizard() {
       const hasConstraints =
         (ac.preferred_formats?.length ?? 0) > 0 ||
         ac.min_bitrate_kbps !== undefined ||
+        ac.min_bit_depth !== undefined ||
+        ac.min_sample_rate_hz !== undefined ||
         ac.avoid_compilations ||
         ac.avoid_live
 
@@ -129,7 +136,10 @@ export function useTicketWizard() {
         (vc.preferred_codecs?.length ?? 0) > 0 ||
         (vc.audio_languages?.length ?? 0) > 0 ||
         (vc.subtitle_languages?.length ?? 0) > 0 ||
-        vc.exclude_hardcoded_subs
+        vc.exclude_hardcoded_subs ||
+        vc.hdr !== undefined ||
+        vc.required_edition !== undefined ||
+        vc.prefer_proper
 
       if (hasConstraints) {
         return { video: vc }
@@ -286,6 +296,8 @@ export function useTicketWizard() {
     audioConstraints.value = {
       preferred_formats: [],
       min_bitrate_kbps: undefined,
+      min_bit_depth: undefined,
+      min_sample_rate_hz: undefined,
       avoid_compilations: false,
       avoid_live: false,
     }
@@ -298,6 +310,9 @@ export function useTicketWizard() {
       audio_languages: [],
       subtitle_languages: [],
       exclude_hardcoded_subs: false,
+      hdr: undefined,
+      required_edition: undefined,
+      prefer_proper: false,
     }
 
     catalog.clearAll()
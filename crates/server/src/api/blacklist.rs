@@ -0,0 +1,144 @@
+//! Blacklist API handlers.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use torrentino_core::{
+    BlacklistEntry, BlacklistError, BlacklistFilter, BlacklistKind, CreateBlacklistEntry,
+};
+
+use crate::api::AuthUser;
+use crate::state::AppState;
+
+// ============================================================================
+// Request/Response types
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct BlacklistQueryParams {
+    /// Only entries scoped to this ticket.
+    #[serde(default)]
+    pub ticket_id: Option<String>,
+    /// Only global entries.
+    #[serde(default)]
+    pub global: bool,
+    /// Only entries of this kind.
+    #[serde(default)]
+    pub kind: Option<BlacklistKind>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AddBlacklistEntryRequest {
+    pub kind: BlacklistKind,
+    pub value: String,
+    /// Scope the entry to a ticket (omit for a global entry).
+    #[serde(default)]
+    pub ticket_id: Option<String>,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BlacklistListResponse {
+    pub entries: Vec<BlacklistEntry>,
+    pub total: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ErrorResponse {
+    pub error: String,
+}
+
+fn error_response(e: BlacklistError) -> (StatusCode, Json<ErrorResponse>) {
+    let status = match e {
+        BlacklistError::NotFound(_) => StatusCode::NOT_FOUND,
+        BlacklistError::Invalid(_) => StatusCode::BAD_REQUEST,
+        BlacklistError::Database(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    (
+        status,
+        Json(ErrorResponse {
+            error: e.to_string(),
+        }),
+    )
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// GET /api/v1/blacklist
+///
+/// List blacklist entries, optionally filtered by ticket, scope or kind.
+pub async fn list_blacklist(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<BlacklistQueryParams>,
+) -> Result<Json<BlacklistListResponse>, impl IntoResponse> {
+    let filter = BlacklistFilter {
+        ticket_id: params.ticket_id,
+        global_only: params.global,
+        kind: params.kind,
+    };
+
+    match state.blacklist_store().list(&filter) {
+        Ok(entries) => {
+            let total = entries.len();
+            Ok(Json(BlacklistListResponse { entries, total }))
+        }
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+/// POST /api/v1/blacklist
+///
+/// Add a blacklist entry. Adding an identical rule again returns the existing entry.
+pub async fn add_entry(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user_id): AuthUser,
+    Json(body): Json<AddBlacklistEntryRequest>,
+) -> Result<(StatusCode, Json<BlacklistEntry>), impl IntoResponse> {
+    if let Some(ref ticket_id) = body.ticket_id {
+        match state.ticket_store().get(ticket_id) {
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                return Err(error_response(BlacklistError::NotFound(format!(
+                    "ticket {}",
+                    ticket_id
+                ))));
+            }
+            Err(e) => return Err(error_response(BlacklistError::Database(e.to_string()))),
+        }
+    }
+
+    let request = CreateBlacklistEntry {
+        kind: body.kind,
+        value: body.value,
+        ticket_id: body.ticket_id,
+        reason: body.reason,
+        created_by: user_id,
+    };
+
+    match state.blacklist_store().add(request) {
+        Ok(entry) => Ok((StatusCode::CREATED, Json(entry))),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+/// DELETE /api/v1/blacklist/{id}
+///
+/// Remove a blacklist entry.
+pub async fn remove_entry(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<BlacklistEntry>, impl IntoResponse> {
+    match state.blacklist_store().remove(&id) {
+        Ok(entry) => Ok(Json(entry)),
+        Err(e) => Err(error_response(e)),
+    }
+}
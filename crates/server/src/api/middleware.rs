@@ -164,7 +164,8 @@ mod tests {
     use torrentino_core::textbrain::TextBrainConfig;
     use torrentino_core::{
         create_audit_system, ApiKeyAuthenticator, AuthConfig, AuthMethod, Config, DatabaseConfig,
-        SqliteAuditStore, SqliteCatalog, SqliteTicketStore,
+        SqliteAuditStore, SqliteBlacklistStore, SqliteCatalog, SqliteLlmUsageStore,
+        SqliteScoringProfileStore, SqliteTicketStore,
     };
     use tower::ServiceExt;
 
@@ -203,6 +204,14 @@ mod tests {
             as Arc<dyn torrentino_core::TicketStore>;
         let catalog = Arc::new(SqliteCatalog::new(&db_path).unwrap())
             as Arc<dyn torrentino_core::TorrentCatalog>;
+        let blacklist_store = Arc::new(SqliteBlacklistStore::new(&db_path).unwrap())
+            as Arc<dyn torrentino_core::BlacklistStore>;
+        let scoring_profile_store = Arc::new(SqliteScoringProfileStore::new(&db_path).unwrap())
+            as Arc<dyn torrentino_core::ScoringProfileStore>;
+        let llm_budget = Arc::new(torrentino_core::LlmBudget::from_config(
+            Arc::new(SqliteLlmUsageStore::new(&db_path).unwrap()),
+            None,
+        ));
 
         // Leak the temp_dir to keep the database around
         std::mem::forget(temp_dir);
@@ -213,6 +222,11 @@ mod tests {
             audit_handle,
             audit_store,
             ticket_store,
+            blacklist_store,
+            scoring_profile_store,
+            llm_budget,
+            Arc::new(torrentino_core::LlmProviderHealth::default()),
+            Arc::new(torrentino_core::PromptLibrary::builtin()),
             None,
             None,
             catalog,
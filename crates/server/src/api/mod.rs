@@ -1,4 +1,5 @@
 pub mod audit;
+pub mod blacklist;
 pub mod catalog;
 pub mod external_catalog;
 pub mod handlers;
@@ -6,10 +7,12 @@ pub mod middleware;
 pub mod orchestrator;
 pub mod pipeline;
 pub mod routes;
+pub mod scoring;
 pub mod searcher;
 pub mod textbrain;
 pub mod tickets;
 pub mod torrents;
+pub mod training;
 pub mod ws;
 
 pub use middleware::AuthUser;
@@ -27,6 +27,8 @@ pub struct OrchestratorStatusResponse {
     pub needs_approval_count: usize,
     /// Tickets currently downloading
     pub downloading_count: usize,
+    /// Tickets whose download is paused by priority preemption
+    pub preempted_count: usize,
 }
 
 /// Error response
@@ -58,6 +60,7 @@ pub async fn get_status(State(state): State<Arc<AppState>>) -> Json<Orchestrator
                 pending_count: status.pending_count,
                 needs_approval_count: status.needs_approval_count,
                 downloading_count: status.downloading_count,
+                preempted_count: status.preempted_count,
             })
         }
         None => Json(OrchestratorStatusResponse {
@@ -68,6 +71,7 @@ pub async fn get_status(State(state): State<Arc<AppState>>) -> Json<Orchestrator
             pending_count: 0,
             needs_approval_count: 0,
             downloading_count: 0,
+            preempted_count: 0,
         }),
     }
 }
@@ -389,6 +389,8 @@ pub async fn process_ticket(
             path: PathBuf::from(f.path),
             item_id: f.item_id,
             dest_filename: f.dest_filename,
+            segment: None,
+            metadata: None,
         })
         .collect();
 
@@ -406,6 +408,7 @@ pub async fn process_ticket(
         })),
         dest_dir: PathBuf::from(request.dest_dir),
         metadata: None, // TODO: Extract from ticket
+        download: None,
     };
 
     // Submit job to pipeline
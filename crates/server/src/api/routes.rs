@@ -9,9 +9,9 @@ use std::sync::Arc;
 use tower_http::services::{ServeDir, ServeFile};
 
 use super::{
-    audit, catalog, external_catalog, handlers,
+    audit, blacklist, catalog, external_catalog, handlers,
     middleware::{auth_middleware, metrics_middleware},
-    orchestrator, pipeline, searcher, textbrain, tickets, torrents, ws,
+    orchestrator, pipeline, scoring, searcher, textbrain, tickets, torrents, training, ws,
 };
 use crate::metrics::{collect_dynamic_metrics, encode_metrics};
 use crate::state::AppState;
@@ -77,8 +77,15 @@ pub fn create_router(state: Arc<AppState>) -> Router {
         .route("/catalog/stats", get(catalog::get_stats))
         .route("/catalog/{hash}", get(catalog::get_entry))
         .route("/catalog/{hash}", delete(catalog::remove_entry))
+        // Blacklist (candidates that must never be picked)
+        .route("/blacklist", get(blacklist::list_blacklist))
+        .route("/blacklist", post(blacklist::add_entry))
+        .route("/blacklist/{id}", delete(blacklist::remove_entry))
         // TextBrain (LLM experimentation)
         .route("/textbrain/config", get(textbrain::get_config))
+        .route("/textbrain/llm/health", get(textbrain::get_llm_health))
+        .route("/textbrain/prompts", get(textbrain::list_prompts))
+        .route("/textbrain/prompts/reload", post(textbrain::reload_prompts))
         .route("/textbrain/queries", post(textbrain::build_queries))
         .route("/textbrain/score", post(textbrain::score_candidates))
         .route("/textbrain/complete", post(textbrain::complete))
@@ -87,6 +94,22 @@ pub fn create_router(state: Arc<AppState>) -> Router {
             post(textbrain::process_ticket),
         )
         .route("/textbrain/acquire", post(textbrain::acquire))
+        // Training datasets (from audit training events)
+        .route("/training/export", get(training::export))
+        // Scoring profiles (weights calibrated from user approvals)
+        .route("/scoring/profiles", get(scoring::list_profiles))
+        .route("/scoring/profiles/active", get(scoring::get_active_profile))
+        .route("/scoring/profiles/calibrate", post(scoring::calibrate))
+        .route("/scoring/profiles/deactivate", post(scoring::deactivate))
+        .route(
+            "/scoring/profiles/{id}/preview",
+            get(scoring::preview_profile),
+        )
+        .route(
+            "/scoring/profiles/{id}/activate",
+            post(scoring::activate_profile),
+        )
+        .route("/scoring/replay", post(scoring::replay))
         // Pipeline (Phase 4 - conversion & placement)
         .route("/pipeline/status", get(pipeline::get_status))
         .route("/pipeline/converter", get(pipeline::get_converter_info))
@@ -0,0 +1,287 @@
+//! Scoring profile API handlers.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use torrentino_core::calibration::{self, collect_replay_samples, collect_samples};
+use torrentino_core::{
+    CalibrationError, CalibrationPreview, ReplayReport, ScoringProfile, ScoringWeights,
+    TextBrainConfig,
+};
+
+use crate::state::AppState;
+
+// ============================================================================
+// Request/Response types
+// ============================================================================
+
+#[derive(Debug, Serialize)]
+pub struct ScoringProfileListResponse {
+    pub profiles: Vec<ScoringProfile>,
+    pub total: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CalibrationResponse {
+    /// The newly saved (inactive) profile.
+    pub profile: ScoringProfile,
+    /// Impact compared to the weights currently in use.
+    pub preview: CalibrationPreview,
+}
+
+/// One side of a replay comparison. Omitted fields fall back to the
+/// configuration currently in use.
+#[derive(Debug, Default, Deserialize)]
+pub struct ReplayConfigRequest {
+    /// Scoring profile to replay with.
+    #[serde(default)]
+    pub profile_id: Option<String>,
+    /// Scorer weights (override the profile's).
+    #[serde(default)]
+    pub scoring: Option<ScoringWeights>,
+    /// Auto-approve threshold (overrides the profile's).
+    #[serde(default)]
+    pub auto_approve_threshold: Option<f32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReplayRequest {
+    /// Configuration to compare against (default: the one currently in use).
+    #[serde(default)]
+    pub baseline: ReplayConfigRequest,
+    /// Configuration under evaluation.
+    pub candidate: ReplayConfigRequest,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ErrorResponse {
+    pub error: String,
+}
+
+fn error_response(e: CalibrationError) -> (StatusCode, Json<ErrorResponse>) {
+    let status = match e {
+        CalibrationError::NotFound(_) => StatusCode::NOT_FOUND,
+        CalibrationError::NotEnoughSamples { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        CalibrationError::Database(_) | CalibrationError::History(_) => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+    (
+        status,
+        Json(ErrorResponse {
+            error: e.to_string(),
+        }),
+    )
+}
+
+/// Weights and threshold currently in use: the active profile's, or the configured ones.
+fn current_scoring(state: &AppState) -> Result<(ScoringWeights, f32), CalibrationError> {
+    Ok(match state.scoring_profile_store().active()? {
+        Some(profile) => (profile.weights, profile.auto_approve_threshold),
+        None => (
+            state.textbrain_config().scoring.clone(),
+            state.textbrain_config().auto_approve_threshold,
+        ),
+    })
+}
+
+/// Build the TextBrain config for one side of a replay.
+fn replay_config(
+    state: &AppState,
+    request: &ReplayConfigRequest,
+) -> Result<TextBrainConfig, (StatusCode, Json<ErrorResponse>)> {
+    let bad_request = |error: String| (StatusCode::BAD_REQUEST, Json(ErrorResponse { error }));
+
+    let profile = match request.profile_id {
+        Some(ref id) => Some(
+            state
+                .scoring_profile_store()
+                .get(id)
+                .map_err(error_response)?
+                .ok_or_else(|| {
+                    error_response(CalibrationError::NotFound(format!(
+                        "scoring profile {}",
+                        id
+                    )))
+                })?,
+        ),
+        None => state
+            .scoring_profile_store()
+            .active()
+            .map_err(error_response)?,
+    };
+
+    let mut config = match profile {
+        Some(profile) => profile.apply(state.textbrain_config()),
+        None => state.textbrain_config().clone(),
+    };
+    if let Some(ref scoring) = request.scoring {
+        scoring.validate().map_err(bad_request)?;
+        config.scoring = scoring.clone();
+    }
+    if let Some(threshold) = request.auto_approve_threshold {
+        if !(0.0..=1.0).contains(&threshold) {
+            return Err(bad_request(
+                "auto_approve_threshold must be between 0.0 and 1.0".to_string(),
+            ));
+        }
+        config.auto_approve_threshold = threshold;
+    }
+    Ok(config)
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// GET /api/v1/scoring/profiles
+///
+/// List scoring profiles, newest version first.
+pub async fn list_profiles(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<ScoringProfileListResponse>, impl IntoResponse> {
+    match state.scoring_profile_store().list() {
+        Ok(profiles) => {
+            let total = profiles.len();
+            Ok(Json(ScoringProfileListResponse { profiles, total }))
+        }
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+/// GET /api/v1/scoring/profiles/active
+///
+/// Get the active scoring profile.
+pub async fn get_active_profile(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<ScoringProfile>, impl IntoResponse> {
+    match state.scoring_profile_store().active() {
+        Ok(Some(profile)) => Ok(Json(profile)),
+        Ok(None) => Err(error_response(CalibrationError::NotFound(
+            "no active scoring profile".to_string(),
+        ))),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+/// POST /api/v1/scoring/profiles/calibrate
+///
+/// Fit scoring weights and the auto-approve threshold to past approvals,
+/// rejections and corrections, and save them as a new inactive profile.
+pub async fn calibrate(
+    State(state): State<Arc<AppState>>,
+) -> Result<(StatusCode, Json<CalibrationResponse>), impl IntoResponse> {
+    let fit_state = Arc::clone(&state);
+    let fitted = tokio::task::spawn_blocking(move || {
+        let samples = collect_samples(
+            fit_state.audit_store().as_ref(),
+            fit_state.ticket_store().as_ref(),
+        )?;
+        let (weights, threshold) = current_scoring(&fit_state)?;
+        let request = calibration::calibrate(&samples, &weights, threshold)?;
+        let preview = calibration::preview(
+            &samples,
+            &weights,
+            threshold,
+            &request.weights,
+            request.auto_approve_threshold,
+        );
+        Ok((request, preview))
+    })
+    .await
+    .map_err(|e| error_response(CalibrationError::History(e.to_string())))?;
+
+    let (request, preview) = fitted.map_err(error_response)?;
+    match state.scoring_profile_store().create(request) {
+        Ok(profile) => Ok((
+            StatusCode::CREATED,
+            Json(CalibrationResponse { profile, preview }),
+        )),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+/// GET /api/v1/scoring/profiles/{id}/preview
+///
+/// Compare a profile against the weights currently in use on the labelled history.
+pub async fn preview_profile(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<CalibrationPreview>, impl IntoResponse> {
+    let profile = match state.scoring_profile_store().get(&id) {
+        Ok(Some(profile)) => profile,
+        Ok(None) => {
+            return Err(error_response(CalibrationError::NotFound(format!(
+                "scoring profile {}",
+                id
+            ))))
+        }
+        Err(e) => return Err(error_response(e)),
+    };
+
+    let samples = collect_samples(state.audit_store().as_ref(), state.ticket_store().as_ref())
+        .map_err(error_response)?;
+    let (weights, threshold) = current_scoring(&state).map_err(error_response)?;
+
+    Ok(Json(calibration::preview(
+        &samples,
+        &weights,
+        threshold,
+        &profile.weights,
+        profile.auto_approve_threshold,
+    )))
+}
+
+/// POST /api/v1/scoring/profiles/{id}/activate
+///
+/// Make a profile the active one. New acquisitions use its weights and threshold.
+pub async fn activate_profile(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<ScoringProfile>, impl IntoResponse> {
+    match state.scoring_profile_store().activate(&id) {
+        Ok(profile) => Ok(Json(profile)),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+/// POST /api/v1/scoring/profiles/deactivate
+///
+/// Deactivate the active profile, reverting to the configured weights.
+pub async fn deactivate(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    match state.scoring_profile_store().deactivate_all() {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => error_response(e).into_response(),
+    }
+}
+
+/// POST /api/v1/scoring/replay
+///
+/// Re-score past approvals and rejections under a baseline and a candidate
+/// configuration, using cached candidate details, and compare both with the
+/// human decisions.
+pub async fn replay(
+    State(state): State<Arc<AppState>>,
+    Json(body): Json<ReplayRequest>,
+) -> Result<Json<ReplayReport>, impl IntoResponse> {
+    let baseline = replay_config(&state, &body.baseline)?;
+    let candidate = replay_config(&state, &body.candidate)?;
+
+    let samples = collect_replay_samples(
+        state.audit_store().as_ref(),
+        state.ticket_store().as_ref(),
+        state.catalog().as_ref(),
+    )
+    .map_err(error_response)?;
+
+    calibration::replay(&samples, &baseline, &candidate)
+        .await
+        .map(Json)
+        .map_err(error_response)
+}
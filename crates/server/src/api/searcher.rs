@@ -104,37 +104,7 @@ pub async fn search(
             Ok(cached) => {
                 // Convert CachedTorrent to TorrentCandidate with from_cache = true
                 for ct in cached {
-                    cached_results.push(TorrentCandidate {
-                        title: ct.title,
-                        info_hash: ct.info_hash,
-                        size_bytes: ct.size_bytes,
-                        seeders: ct.sources.iter().map(|s| s.seeders).sum(),
-                        leechers: ct.sources.iter().map(|s| s.leechers).sum(),
-                        category: ct.category,
-                        publish_date: None,
-                        files: ct.files.map(|files| {
-                            files
-                                .into_iter()
-                                .map(|f| torrentino_core::TorrentFile {
-                                    path: f.path,
-                                    size_bytes: f.size_bytes,
-                                })
-                                .collect()
-                        }),
-                        sources: ct
-                            .sources
-                            .into_iter()
-                            .map(|s| torrentino_core::TorrentSource {
-                                indexer: s.indexer,
-                                magnet_uri: s.magnet_uri,
-                                torrent_url: s.torrent_url,
-                                seeders: s.seeders,
-                                leechers: s.leechers,
-                                details_url: s.details_url,
-                            })
-                            .collect(),
-                        from_cache: true,
-                    });
+                    cached_results.push(ct.into());
                 }
             }
             Err(e) => {
@@ -213,7 +183,7 @@ pub async fn search(
     }
 
     // Sort by seeders descending
-    combined.sort_by(|a, b| b.seeders.cmp(&a.seeders));
+    combined.sort_by_key(|r| std::cmp::Reverse(r.seeders));
 
     // Apply limit
     if combined.len() > limit as usize {
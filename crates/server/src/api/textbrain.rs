@@ -9,9 +9,10 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use torrentino_core::{
-    AnthropicClient, AuditEvent, CandidateMatcher, CompletionRequest, DumbMatcher,
-    DumbQueryBuilder, ExpectedContent, ExpectedTrack, FileEnricher, LlmClient, LlmUsage,
-    QueryBuilder, QueryContext, SearchQuery, TextBrain, TextBrainConfig, TextBrainMode,
+    AnthropicClient, AuditEvent, CandidateMatcher, CircuitState, CompletionRequest, DumbMatcher,
+    DumbQueryBuilder, ExpectedContent, ExpectedTrack, FileEnricher, LlmBudgetStatus, LlmClient,
+    LlmProviderStatus, LlmUsage, PromptTemplateInfo, QueryBuilder, QueryContext, SearchQuery,
+    TextBrain, TextBrainConfig, TextBrainMode,
 };
 
 use crate::state::AppState;
@@ -376,7 +377,7 @@ pub async fn process_ticket(
                 })
                 .collect();
 
-            result.sort_by(|a, b| b.score.cmp(&a.score));
+            result.sort_by_key(|c| std::cmp::Reverse(c.score));
             result
         }
         Err(e) => {
@@ -565,7 +566,7 @@ pub async fn acquire(
     Json(body): Json<AcquireRequest>,
 ) -> Result<Json<AcquireResponse>, impl IntoResponse> {
     use std::time::Instant;
-    use torrentino_core::{CatalogSearchQuery, TorrentCandidate, TorrentFile, TorrentSource};
+    use torrentino_core::{CatalogSearchQuery, TorrentCandidate};
 
     let start = Instant::now();
 
@@ -617,37 +618,7 @@ pub async fn acquire(
             if let Ok(cached) = catalog.search(&catalog_query) {
                 for ct in cached {
                     if seen_hashes.insert(ct.info_hash.clone()) {
-                        all_candidates.push(TorrentCandidate {
-                            title: ct.title,
-                            info_hash: ct.info_hash,
-                            size_bytes: ct.size_bytes,
-                            seeders: ct.sources.iter().map(|s| s.seeders).sum(),
-                            leechers: ct.sources.iter().map(|s| s.leechers).sum(),
-                            category: ct.category,
-                            publish_date: None,
-                            files: ct.files.map(|files| {
-                                files
-                                    .into_iter()
-                                    .map(|f| TorrentFile {
-                                        path: f.path,
-                                        size_bytes: f.size_bytes,
-                                    })
-                                    .collect()
-                            }),
-                            sources: ct
-                                .sources
-                                .into_iter()
-                                .map(|s| TorrentSource {
-                                    indexer: s.indexer,
-                                    magnet_uri: s.magnet_uri,
-                                    torrent_url: s.torrent_url,
-                                    seeders: s.seeders,
-                                    leechers: s.leechers,
-                                    details_url: s.details_url,
-                                })
-                                .collect(),
-                            from_cache: true,
-                        });
+                        all_candidates.push(ct.into());
                     }
                 }
             }
@@ -797,22 +768,124 @@ pub struct ConfigResponse {
     pub auto_approve_threshold: f32,
     pub llm_configured: bool,
     pub llm_provider: Option<String>,
+    /// Current LLM usage against the configured budget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub llm_budget: Option<LlmBudgetStatus>,
 }
 
 /// GET /api/v1/textbrain/config
 ///
-/// Get TextBrain configuration status.
-pub async fn get_config() -> Json<ConfigResponse> {
-    // For now, return default dumb-only config
-    // In the future, this would read from actual config
+/// Get TextBrain configuration status, including the remaining LLM budget.
+pub async fn get_config(State(state): State<Arc<AppState>>) -> Json<ConfigResponse> {
+    let config = state.textbrain_config();
+    let llm_budget = match state.llm_budget().status() {
+        Ok(status) => Some(status),
+        Err(e) => {
+            tracing::warn!("Failed to read LLM budget: {}", e);
+            None
+        }
+    };
+
     Json(ConfigResponse {
-        mode: "dumb_only".to_string(),
-        auto_approve_threshold: 0.85,
-        llm_configured: false,
-        llm_provider: None,
+        mode: serialized_name(&config.mode).unwrap_or_default(),
+        auto_approve_threshold: config.auto_approve_threshold,
+        llm_configured: config.llm.is_some(),
+        llm_provider: config
+            .llm
+            .as_ref()
+            .and_then(|llm| serialized_name(&llm.provider)),
+        llm_budget,
     })
 }
 
+/// GET /api/v1/textbrain/llm/health response.
+#[derive(Debug, Serialize)]
+pub struct LlmHealthResponse {
+    pub llm_configured: bool,
+    /// Whether at least one provider is not skipped by its circuit breaker.
+    pub available: bool,
+    /// Configured providers, in the order they are tried.
+    pub providers: Vec<LlmProviderStatus>,
+}
+
+/// GET /api/v1/textbrain/llm/health
+///
+/// Report the circuit breaker state and request statistics of each
+/// configured LLM provider.
+pub async fn get_llm_health(State(state): State<Arc<AppState>>) -> Json<LlmHealthResponse> {
+    let config = state.textbrain_config();
+    let providers: Vec<LlmProviderStatus> = config
+        .llm
+        .as_ref()
+        .map(|llm| {
+            llm.providers()
+                .iter()
+                .map(|p| {
+                    state
+                        .llm_health()
+                        .status(&serialized_name(&p.provider).unwrap_or_default(), &p.model)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Json(LlmHealthResponse {
+        llm_configured: config.llm.is_some(),
+        available: providers.iter().any(|p| p.state != CircuitState::Open),
+        providers,
+    })
+}
+
+/// GET /api/v1/textbrain/prompts response.
+#[derive(Debug, Serialize)]
+pub struct PromptsResponse {
+    /// Template override directory, if configured.
+    pub dir: Option<String>,
+    pub templates: Vec<PromptTemplateInfo>,
+}
+
+fn prompts_response(state: &AppState) -> PromptsResponse {
+    PromptsResponse {
+        dir: state
+            .prompts()
+            .dir()
+            .map(|dir| dir.to_string_lossy().to_string()),
+        templates: state.prompts().list(),
+    }
+}
+
+/// GET /api/v1/textbrain/prompts
+///
+/// List the prompt templates in use with their versions.
+pub async fn list_prompts(State(state): State<Arc<AppState>>) -> Json<PromptsResponse> {
+    Json(prompts_response(&state))
+}
+
+/// POST /api/v1/textbrain/prompts/reload
+///
+/// Re-read the prompt template directory. On error the previously loaded
+/// templates stay in use.
+pub async fn reload_prompts(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<PromptsResponse>, impl IntoResponse> {
+    match state.prompts().reload() {
+        Ok(_) => Ok(Json(prompts_response(&state))),
+        Err(e) => Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )),
+    }
+}
+
+/// Name of a unit enum variant as it appears in the config file.
+fn serialized_name<T: Serialize>(value: &T) -> Option<String> {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(String::from))
+}
+
 // ============================================================================
 // Query building endpoint
 // ============================================================================
@@ -10,9 +10,11 @@ use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use torrentino_core::{
-    AuditEvent, CatalogReference, CreateTicketRequest, ExpectedContent, OutputConstraints,
-    QueryContext, SearchConstraints, SelectedCandidate, Ticket, TicketError, TicketFilter,
-    TicketState,
+    resolve_absolute_offset, resolve_discography, resolve_tv_episodes, AuditEvent, BlacklistKind,
+    CatalogReference, CreateBlacklistEntry, CreateTicketRequest, ExpectedContent, ExternalCatalog,
+    ExternalCatalogError, OutputConstraints, QueryContext, ResolvedEpisodes, SearchConstraints,
+    SelectedCandidate, TextBrainOverrides, Ticket, TicketError, TicketFilter, TicketState,
+    TmdbMediaType, TrainingCandidate,
 };
 
 use crate::api::AuthUser;
@@ -40,6 +42,9 @@ pub struct CreateTicketBody {
     pub dest_path: String,
     /// Output format constraints (None = keep original, no conversion)
     pub output_constraints: Option<OutputConstraints>,
+    /// Per-ticket TextBrain mode and threshold overrides
+    #[serde(default)]
+    pub textbrain_overrides: Option<TextBrainOverrides>,
 }
 
 /// Query context in request body
@@ -107,6 +112,8 @@ pub struct TicketResponse {
     pub dest_path: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output_constraints: Option<OutputConstraints>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub textbrain_overrides: Option<TextBrainOverrides>,
     pub updated_at: String,
 }
 
@@ -121,6 +128,7 @@ impl From<Ticket> for TicketResponse {
             query_context: ticket.query_context,
             dest_path: ticket.dest_path,
             output_constraints: ticket.output_constraints,
+            textbrain_overrides: ticket.textbrain_overrides,
             updated_at: ticket.updated_at.to_rfc3339(),
         }
     }
@@ -151,12 +159,72 @@ pub async fn create_ticket(
     AuthUser(user_id): AuthUser,
     Json(body): Json<CreateTicketBody>,
 ) -> Result<(StatusCode, Json<TicketResponse>), impl IntoResponse> {
+    // Reject overrides the configured TextBrain cannot honor
+    let textbrain_overrides = body.textbrain_overrides.filter(|o| !o.is_empty());
+    if let Some(ref overrides) = textbrain_overrides {
+        if let Err(e) = overrides.validate(state.textbrain_config()) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(TicketErrorResponse {
+                    error: format!("Invalid textbrain_overrides: {}", e),
+                }),
+            ));
+        }
+    }
+
+    // Books and comics are placed as downloaded
+    let is_book = matches!(
+        body.query_context.expected,
+        Some(ExpectedContent::Book { .. }) | Some(ExpectedContent::Comic { .. })
+    );
+    if is_book
+        && body
+            .output_constraints
+            .as_ref()
+            .is_some_and(|c| c.needs_conversion())
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(TicketErrorResponse {
+                error: "Books and comics cannot be converted; use original output".to_string(),
+            }),
+        ));
+    }
+
     // Build query context with optional catalog fields
     let mut query_context = QueryContext::new(
         body.query_context.tags.clone(),
         &body.query_context.description,
     );
-    if let Some(expected) = body.query_context.expected {
+    let mut resolved_episodes = None;
+    if let Some(mut expected) = body.query_context.expected {
+        // Whole seasons and series get their episode lists from TMDB; if that
+        // fails the ticket falls back to detecting episodes from filenames
+        if expected.needs_episode_resolution() {
+            if let Some(catalog) = state.external_catalog() {
+                let tmdb_id = match &body.query_context.catalog_reference {
+                    Some(CatalogReference::Tmdb {
+                        id,
+                        media_type: TmdbMediaType::Tv,
+                        ..
+                    }) => Some(*id),
+                    _ => None,
+                };
+                match resolve_tv_episodes(catalog.as_ref(), &mut expected, tmdb_id).await {
+                    Ok(resolved) => resolved_episodes = resolved,
+                    Err(e) => tracing::warn!("Failed to resolve episode list: {}", e),
+                }
+            }
+        }
+        // Discographies without an album list get the artist's studio albums
+        // from MusicBrainz; otherwise coverage can only be estimated
+        if expected.needs_album_resolution() {
+            if let Some(catalog) = state.external_catalog() {
+                if let Err(e) = resolve_discography(catalog.as_ref(), &mut expected).await {
+                    tracing::warn!("Failed to resolve discography: {}", e);
+                }
+            }
+        }
         query_context = query_context.with_expected(expected);
     }
     if let Some(catalog_ref) = body.query_context.catalog_reference {
@@ -165,6 +233,19 @@ pub async fn create_ticket(
     if let Some(constraints) = body.query_context.search_constraints {
         query_context = query_context.with_search_constraints(constraints);
     }
+    // Season and series packs are size-checked against the episode runtime
+    if let Some(resolved) = resolved_episodes {
+        record_episode_runtime(&mut query_context, resolved);
+    }
+
+    // Anime releases number episodes across seasons
+    if query_context.is_anime() {
+        if let Some(catalog) = state.external_catalog() {
+            if let Err(e) = resolve_anime_numbering(catalog.as_ref(), &mut query_context).await {
+                tracing::warn!("Failed to resolve absolute episode numbering: {}", e);
+            }
+        }
+    }
 
     let request = CreateTicketRequest {
         created_by: user_id,
@@ -172,6 +253,7 @@ pub async fn create_ticket(
         query_context,
         dest_path: body.dest_path.clone(),
         output_constraints: body.output_constraints,
+        textbrain_overrides,
     };
 
     match state.ticket_store().create(request) {
@@ -205,6 +287,67 @@ pub async fn create_ticket(
     }
 }
 
+/// Record the episode runtime found while resolving a TV ticket's episode
+/// list in its TMDB catalog reference, creating the reference when missing.
+/// A runtime given with the ticket is kept.
+fn record_episode_runtime(query_context: &mut QueryContext, resolved: ResolvedEpisodes) {
+    match &mut query_context.catalog_reference {
+        Some(CatalogReference::Tmdb {
+            media_type: TmdbMediaType::Tv,
+            runtime_minutes: runtime_minutes @ None,
+            ..
+        }) => *runtime_minutes = resolved.episode_runtime_minutes,
+        None => {
+            query_context.catalog_reference = Some(CatalogReference::Tmdb {
+                id: resolved.tmdb_id,
+                media_type: TmdbMediaType::Tv,
+                runtime_minutes: resolved.episode_runtime_minutes,
+                episode_count: None,
+                absolute_offset: None,
+            });
+        }
+        _ => {}
+    }
+}
+
+/// Record the absolute episode offset of an anime TV ticket in its TMDB
+/// catalog reference, creating the reference when missing.
+async fn resolve_anime_numbering(
+    catalog: &dyn ExternalCatalog,
+    query_context: &mut QueryContext,
+) -> Result<(), ExternalCatalogError> {
+    let (series, season) = match &query_context.expected {
+        Some(ExpectedContent::TvEpisode { series, season, .. })
+        | Some(ExpectedContent::TvSeason { series, season, .. }) => (series.clone(), *season),
+        _ => return Ok(()),
+    };
+
+    match &mut query_context.catalog_reference {
+        Some(CatalogReference::Tmdb {
+            id,
+            media_type: TmdbMediaType::Tv,
+            absolute_offset,
+            ..
+        }) => {
+            let (_, offset) = resolve_absolute_offset(catalog, &series, season, Some(*id)).await?;
+            *absolute_offset = Some(offset);
+        }
+        None => {
+            let (id, offset) = resolve_absolute_offset(catalog, &series, season, None).await?;
+            query_context.catalog_reference = Some(CatalogReference::Tmdb {
+                id,
+                media_type: TmdbMediaType::Tv,
+                runtime_minutes: None,
+                episode_count: None,
+                absolute_offset: Some(offset),
+            });
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
 /// Get a ticket by ID
 pub async fn get_ticket(
     State(state): State<Arc<AppState>>,
@@ -460,8 +603,12 @@ pub async fn approve_ticket(
     };
 
     // Check that ticket is in NeedsApproval state
-    let candidates = match &current_ticket.state {
-        TicketState::NeedsApproval { candidates, .. } => candidates,
+    let (candidates, recommended_idx) = match &current_ticket.state {
+        TicketState::NeedsApproval {
+            candidates,
+            recommended_idx,
+            ..
+        } => (candidates, *recommended_idx),
         _ => {
             return Err((
                 StatusCode::CONFLICT,
@@ -524,6 +671,7 @@ pub async fn approve_ticket(
         torrent_url: None, // Will be populated from catalog if available
         size_bytes: selected_summary.size_bytes,
         score: selected_summary.score,
+        seeders: selected_summary.seeders,
         file_mappings: vec![], // TODO: Get from file mapper
     };
 
@@ -548,6 +696,7 @@ pub async fn approve_ticket(
                 torrent_url: None,
                 size_bytes: c.size_bytes,
                 score: c.score,
+                seeders: c.seeders,
                 file_mappings: vec![],
             }
         })
@@ -555,6 +704,30 @@ pub async fn approve_ticket(
 
     let previous_state = current_ticket.state.state_type().to_string();
 
+    // Picking something other than the recommendation is a ranking correction
+    let correction = (candidate_idx != recommended_idx).then(|| AuditEvent::UserCorrection {
+        ticket_id: id.clone(),
+        recommended_idx,
+        selected_idx: candidate_idx,
+        context_description: current_ticket.query_context.description.clone(),
+        expected_content: current_ticket
+            .query_context
+            .expected
+            .as_ref()
+            .and_then(|e| serde_json::to_string(e).ok()),
+        candidates: candidates
+            .iter()
+            .map(|c| TrainingCandidate {
+                title: c.title.clone(),
+                hash: c.info_hash.clone(),
+                size_bytes: c.size_bytes,
+                seeders: c.seeders,
+                category: None,
+            })
+            .collect(),
+        user_id: approved_by.clone(),
+    });
+
     let new_state = TicketState::Approved {
         selected,
         candidates: all_candidates,
@@ -569,7 +742,11 @@ pub async fn approve_ticket(
                 .with_label_values(&[&previous_state, "approved"])
                 .inc();
 
-            // Emit audit event
+            // Emit audit events (the correction first, so calibration sees it
+            // before the approval it belongs to)
+            if let Some(correction) = correction {
+                state.audit().try_emit(correction);
+            }
             state.audit().try_emit(AuditEvent::TicketStateChanged {
                 ticket_id: ticket.id.clone(),
                 from_state: previous_state,
@@ -724,6 +901,26 @@ pub async fn reject_ticket(
 
     let previous_state = current_ticket.state.state_type().to_string();
 
+    // Rejected candidates must not be offered again for this ticket
+    if let TicketState::NeedsApproval { ref candidates, .. } = current_ticket.state {
+        let blacklist_reason = match reason {
+            Some(ref r) => format!("rejected: {}", r),
+            None => "rejected".to_string(),
+        };
+        for candidate in candidates {
+            let entry = CreateBlacklistEntry {
+                kind: BlacklistKind::InfoHash,
+                value: candidate.info_hash.clone(),
+                ticket_id: Some(id.clone()),
+                reason: Some(blacklist_reason.clone()),
+                created_by: rejected_by.clone(),
+            };
+            if let Err(e) = state.blacklist_store().add(entry) {
+                tracing::warn!("Failed to blacklist rejected candidate: {}", e);
+            }
+        }
+    }
+
     let new_state = TicketState::Rejected {
         rejected_by: rejected_by.clone(),
         reason: reason.clone(),
@@ -0,0 +1,103 @@
+//! Training dataset API handlers.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use torrentino_core::textbrain::{export_training_data, to_jsonl};
+use torrentino_core::{TrainingExportFormat, TrainingExportOptions, TrainingTask};
+
+use crate::state::AppState;
+
+// ============================================================================
+// Request/Response types
+// ============================================================================
+
+/// Which half of the split to return.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DatasetSplit {
+    #[default]
+    Train,
+    Validation,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrainingExportParams {
+    /// Task to export (query_generation, candidate_scoring, file_mapping).
+    pub task: TrainingTask,
+    /// Sample format (chat or instruction, default chat).
+    #[serde(default)]
+    pub format: TrainingExportFormat,
+    /// Split to return (train or validation, default train).
+    #[serde(default)]
+    pub split: DatasetSplit,
+    /// Fraction of samples held out for validation (default 0.1).
+    #[serde(default)]
+    pub validation_fraction: Option<f32>,
+    /// Include query samples whose ticket failed or has no outcome yet.
+    #[serde(default)]
+    pub include_unsuccessful: bool,
+    /// Minimum mapping quality for file mapping samples (default 0.8).
+    #[serde(default)]
+    pub min_mapping_quality: Option<f32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ErrorResponse {
+    pub error: String,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// GET /api/v1/training/export
+///
+/// Export a training dataset as JSON Lines (one sample per line).
+pub async fn export(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<TrainingExportParams>,
+) -> Result<impl IntoResponse, impl IntoResponse> {
+    let mut options = TrainingExportOptions::new(params.task);
+    options.format = params.format;
+    options.include_unsuccessful = params.include_unsuccessful;
+    if let Some(fraction) = params.validation_fraction {
+        if !(0.0..=1.0).contains(&fraction) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "validation_fraction must be between 0.0 and 1.0".to_string(),
+                }),
+            ));
+        }
+        options.validation_fraction = fraction;
+    }
+    if let Some(quality) = params.min_mapping_quality {
+        options.min_mapping_quality = quality;
+    }
+
+    let export = export_training_data(state.audit_store().as_ref(), &options).map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    let samples = match params.split {
+        DatasetSplit::Train => &export.train,
+        DatasetSplit::Validation => &export.validation,
+    };
+
+    Ok((
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        to_jsonl(samples),
+    ))
+}
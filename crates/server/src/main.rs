@@ -10,11 +10,13 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use torrentino_core::{
     create_audit_system, create_authenticator, load_config, validate_config, AuditEvent,
-    AuditStore, Authenticator, CombinedCatalogClient, ConverterConfig, EncoderCapabilities,
-    ExternalCatalog, FfmpegConverter, FsPlacer, JackettSearcher, LibrqbitClient, MusicBrainzClient,
-    PipelineProcessor, PlacerConfig, ProcessorConfig, QBittorrentClient, Searcher, SearcherBackend,
-    SqliteAuditStore, SqliteCatalog, SqliteTicketStore, TicketOrchestrator, TicketStore,
-    TmdbClient, TorrentCatalog, TorrentClient, TorrentClientBackend,
+    AuditStore, Authenticator, BlacklistStore, CombinedCatalogClient, ConverterConfig,
+    EncoderCapabilities, ExternalCatalog, FfmpegConverter, FsPlacer, JackettSearcher,
+    LibrqbitClient, LlmBudget, LlmCache, LlmProviderHealth, MusicBrainzClient, PipelineProcessor,
+    PlacerConfig, ProcessorConfig, PromptLibrary, QBittorrentClient, ScoringProfileStore, Searcher,
+    SearcherBackend, SqliteAuditStore, SqliteBlacklistStore, SqliteCatalog, SqliteLlmCache,
+    SqliteLlmUsageStore, SqliteScoringProfileStore, SqliteTicketStore, TicketOrchestrator,
+    TicketStore, TmdbClient, TorrentCatalog, TorrentClient, TorrentClientBackend,
 };
 
 use torrentino_server::api::{create_router, WsBroadcaster};
@@ -89,6 +91,54 @@ async fn run() -> Result<()> {
     );
     info!("Torrent catalog initialized");
 
+    // Create SQLite blacklist store (rejected/stalled candidates, bad release groups)
+    let blacklist_store: Arc<dyn BlacklistStore> = Arc::new(
+        SqliteBlacklistStore::new(&config.database.path)
+            .context("Failed to create blacklist store")?,
+    );
+    info!("Blacklist store initialized");
+
+    // Create SQLite scoring profile store (calibrated scoring weights)
+    let scoring_profile_store: Arc<dyn ScoringProfileStore> = Arc::new(
+        SqliteScoringProfileStore::new(&config.database.path)
+            .context("Failed to create scoring profile store")?,
+    );
+    info!("Scoring profile store initialized");
+
+    // Create SQLite LLM completion cache, dropping entries that have expired
+    let llm_cache: Arc<dyn LlmCache> =
+        Arc::new(SqliteLlmCache::new(&config.database.path).context("Failed to create LLM cache")?);
+    match llm_cache.purge_expired() {
+        Ok(purged) => info!("LLM cache initialized ({} expired entries purged)", purged),
+        Err(e) => error!("Failed to purge expired LLM cache entries: {}", e),
+    }
+
+    // Create LLM usage budget backed by the SQLite usage ledger
+    let llm_budget = Arc::new(LlmBudget::from_config(
+        Arc::new(
+            SqliteLlmUsageStore::new(&config.database.path)
+                .context("Failed to create LLM usage store")?,
+        ),
+        config.textbrain.llm.as_ref(),
+    ));
+    info!("LLM budget initialized");
+
+    // Circuit breakers of the LLM providers, shared by every acquisition
+    let llm_health = Arc::new(LlmProviderHealth::new(
+        config
+            .textbrain
+            .llm
+            .as_ref()
+            .map(|llm| llm.circuit_breaker.clone())
+            .unwrap_or_default(),
+    ));
+
+    // Load prompt template overrides, if configured
+    let prompts = Arc::new(match config.textbrain.prompts_dir {
+        Some(ref dir) => PromptLibrary::from_dir(dir).context("Failed to load prompt templates")?,
+        None => PromptLibrary::builtin(),
+    });
+
     // Create audit system
     let (audit_handle, audit_writer) =
         create_audit_system(Arc::clone(&audit_store), AUDIT_BUFFER_SIZE);
@@ -252,7 +302,13 @@ async fn run() -> Result<()> {
                     Some(audit_handle.clone()),
                     config.textbrain.clone(),
                 )
-                .with_update_callback(update_callback);
+                .with_update_callback(update_callback)
+                .with_blacklist_store(Arc::clone(&blacklist_store))
+                .with_scoring_profile_store(Arc::clone(&scoring_profile_store))
+                .with_llm_cache(Arc::clone(&llm_cache))
+                .with_llm_budget(Arc::clone(&llm_budget))
+                .with_llm_health(Arc::clone(&llm_health))
+                .with_prompt_library(Arc::clone(&prompts));
 
                 orch.start().await;
                 info!("Ticket orchestrator started");
@@ -318,6 +374,11 @@ async fn run() -> Result<()> {
         audit_handle.clone(),
         audit_store,
         ticket_store,
+        blacklist_store,
+        scoring_profile_store,
+        llm_budget,
+        llm_health,
+        prompts,
         searcher,
         torrent_client,
         catalog,
@@ -334,6 +334,11 @@ pub async fn collect_dynamic_metrics(state: &crate::state::AppState) {
         PLACEMENT_POOL_QUEUED.set(status.placement_pool.queued_jobs as i64);
     }
 
+    // Update remaining LLM budget metrics
+    if let Err(e) = state.llm_budget().status() {
+        tracing::warn!("Failed to read LLM budget: {}", e);
+    }
+
     // Update catalog metrics
     if let Ok(stats) = state.catalog().stats() {
         CATALOG_ENTRIES.set(stats.total_torrents as i64);
@@ -349,6 +354,7 @@ pub async fn collect_dynamic_metrics(state: &crate::state::AppState) {
         "auto_approved",
         "approved",
         "downloading",
+        "preempted",
         "converting",
         "placing",
         "completed",
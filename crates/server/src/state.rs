@@ -1,8 +1,9 @@
 use std::sync::Arc;
 use torrentino_core::{
-    AuditHandle, AuditStore, Authenticator, Config, EncoderCapabilities, ExternalCatalog,
-    FfmpegConverter, FsPlacer, PipelineProcessor, SanitizedConfig, Searcher, TicketOrchestrator,
-    TicketStore, TorrentCatalog, TorrentClient,
+    AuditHandle, AuditStore, Authenticator, BlacklistStore, Config, EncoderCapabilities,
+    ExternalCatalog, FfmpegConverter, FsPlacer, LlmBudget, LlmProviderHealth, PipelineProcessor,
+    PromptLibrary, SanitizedConfig, ScoringProfileStore, Searcher, TextBrainConfig,
+    TicketOrchestrator, TicketStore, TorrentCatalog, TorrentClient,
 };
 
 use crate::api::WsBroadcaster;
@@ -20,6 +21,11 @@ pub struct AppState {
     audit_handle: AuditHandle,
     audit_store: Arc<dyn AuditStore>,
     ticket_store: Arc<dyn TicketStore>,
+    blacklist_store: Arc<dyn BlacklistStore>,
+    scoring_profile_store: Arc<dyn ScoringProfileStore>,
+    llm_budget: Arc<LlmBudget>,
+    llm_health: Arc<LlmProviderHealth>,
+    prompts: Arc<PromptLibrary>,
     searcher: Option<Arc<dyn Searcher>>,
     torrent_client: Option<Arc<dyn TorrentClient>>,
     catalog: Arc<dyn TorrentCatalog>,
@@ -38,6 +44,11 @@ impl AppState {
         audit_handle: AuditHandle,
         audit_store: Arc<dyn AuditStore>,
         ticket_store: Arc<dyn TicketStore>,
+        blacklist_store: Arc<dyn BlacklistStore>,
+        scoring_profile_store: Arc<dyn ScoringProfileStore>,
+        llm_budget: Arc<LlmBudget>,
+        llm_health: Arc<LlmProviderHealth>,
+        prompts: Arc<PromptLibrary>,
         searcher: Option<Arc<dyn Searcher>>,
         torrent_client: Option<Arc<dyn TorrentClient>>,
         catalog: Arc<dyn TorrentCatalog>,
@@ -53,6 +64,11 @@ impl AppState {
             audit_handle,
             audit_store,
             ticket_store,
+            blacklist_store,
+            scoring_profile_store,
+            llm_budget,
+            llm_health,
+            prompts,
             searcher,
             torrent_client,
             catalog,
@@ -68,6 +84,11 @@ impl AppState {
         SanitizedConfig::from(&self.config)
     }
 
+    /// Get the configured TextBrain settings
+    pub fn textbrain_config(&self) -> &TextBrainConfig {
+        &self.config.textbrain
+    }
+
     #[allow(dead_code)]
     pub fn authenticator(&self) -> &dyn Authenticator {
         self.authenticator.as_ref()
@@ -88,6 +109,31 @@ impl AppState {
         &self.ticket_store
     }
 
+    /// Get the candidate blacklist store
+    pub fn blacklist_store(&self) -> &Arc<dyn BlacklistStore> {
+        &self.blacklist_store
+    }
+
+    /// Get the scoring profile store
+    pub fn scoring_profile_store(&self) -> &Arc<dyn ScoringProfileStore> {
+        &self.scoring_profile_store
+    }
+
+    /// Get the LLM usage budget
+    pub fn llm_budget(&self) -> &Arc<LlmBudget> {
+        &self.llm_budget
+    }
+
+    /// Get the circuit breaker state of the LLM providers
+    pub fn llm_health(&self) -> &Arc<LlmProviderHealth> {
+        &self.llm_health
+    }
+
+    /// Get the prompt templates of the LLM components
+    pub fn prompts(&self) -> &Arc<PromptLibrary> {
+        &self.prompts
+    }
+
     /// Get the searcher (if configured)
     pub fn searcher(&self) -> Option<&Arc<dyn Searcher>> {
         self.searcher.as_ref()
@@ -19,8 +19,10 @@ use torrentino_core::{
     create_audit_system,
     testing::{MockExternalCatalog, MockSearcher, MockTorrentClient},
     AuditStore, AuthMethod, Config, DatabaseConfig, EncoderCapabilities, FfmpegConverter, FsPlacer,
-    NoneAuthenticator, OrchestratorConfig, PipelineProcessor, PlacerConfig, ProcessorConfig,
-    ServerConfig, SqliteAuditStore, SqliteCatalog, SqliteTicketStore, TextBrainConfig,
+    LlmBudget, LlmProviderHealth, NoneAuthenticator, OrchestratorConfig, PipelineProcessor,
+    PlacerConfig, ProcessorConfig, PromptLibrary, ServerConfig, SqliteAuditStore,
+    SqliteBlacklistStore, SqliteCatalog, SqliteLlmUsageStore, SqliteScoringProfileStore,
+    SqliteTicketStore, TextBrainConfig,
 };
 
 /// Re-export fixtures for test convenience
@@ -117,6 +119,17 @@ impl TestFixture {
         let ticket_store =
             Arc::new(SqliteTicketStore::new(&db_path).expect("Failed to create ticket store"));
         let catalog = Arc::new(SqliteCatalog::new(&db_path).expect("Failed to create catalog"));
+        let blacklist_store = Arc::new(
+            SqliteBlacklistStore::new(&db_path).expect("Failed to create blacklist store"),
+        );
+        let scoring_profile_store = Arc::new(
+            SqliteScoringProfileStore::new(&db_path)
+                .expect("Failed to create scoring profile store"),
+        );
+        let llm_budget = Arc::new(LlmBudget::from_config(
+            Arc::new(SqliteLlmUsageStore::new(&db_path).expect("Failed to create LLM usage store")),
+            None,
+        ));
 
         // Create audit system
         let (audit_handle, audit_writer) = create_audit_system(Arc::clone(&audit_store), 100);
@@ -149,6 +162,11 @@ impl TestFixture {
             audit_handle,
             audit_store,
             ticket_store,
+            blacklist_store,
+            scoring_profile_store,
+            llm_budget,
+            Arc::new(LlmProviderHealth::default()),
+            Arc::new(PromptLibrary::builtin()),
             Some(Arc::clone(&searcher) as Arc<dyn torrentino_core::Searcher>),
             Some(Arc::clone(&torrent_client) as Arc<dyn torrentino_core::TorrentClient>),
             catalog,
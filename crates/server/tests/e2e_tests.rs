@@ -560,6 +560,48 @@ async fn test_textbrain_config() {
     assert_eq!(response.status, StatusCode::OK);
     assert!(response.body["mode"].is_string());
     assert!(response.body["auto_approve_threshold"].is_number());
+    assert_eq!(response.body["mode"], "dumb_only");
+    assert_eq!(response.body["llm_configured"], false);
+
+    // No usage and no limits configured
+    let budget = &response.body["llm_budget"];
+    assert_eq!(budget["exhausted"], false);
+    assert_eq!(budget["daily"]["input_tokens"], 0);
+    assert!(budget["daily"]["tokens_remaining"].is_null());
+}
+
+#[tokio::test]
+async fn test_textbrain_llm_health_without_llm() {
+    let fixture = TestFixture::new().await;
+
+    let response = fixture.get("/api/v1/textbrain/llm/health").await;
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body["llm_configured"], false);
+    assert_eq!(response.body["available"], false);
+    assert_eq!(response.body["providers"], json!([]));
+}
+
+#[tokio::test]
+async fn test_textbrain_prompts_builtin() {
+    let fixture = TestFixture::new().await;
+
+    let response = fixture.get("/api/v1/textbrain/prompts").await;
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert!(response.body["dir"].is_null());
+    let templates = response.body["templates"].as_array().unwrap();
+    assert_eq!(templates.len(), 3);
+    assert_eq!(templates[0]["task"], "file_mapping");
+    assert!(templates[0]["version"]
+        .as_str()
+        .unwrap()
+        .starts_with("builtin-"));
+
+    let response = fixture
+        .post("/api/v1/textbrain/prompts/reload", json!({}))
+        .await;
+    assert_eq!(response.status, StatusCode::OK);
 }
 
 #[tokio::test]
@@ -746,6 +788,150 @@ async fn test_catalog_clear() {
     assert_eq!(stats_response.body["total_torrents"], 0);
 }
 
+// =============================================================================
+// Blacklist Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_blacklist_add_list_remove() {
+    let fixture = TestFixture::new().await;
+
+    let add_response = fixture
+        .post(
+            "/api/v1/blacklist",
+            json!({ "kind": "release_group", "value": "BadGroup", "reason": "fake releases" }),
+        )
+        .await;
+    assert_eq!(add_response.status, StatusCode::CREATED);
+    assert_eq!(add_response.body["kind"], "release_group");
+    let entry_id = add_response.body["id"].as_str().unwrap().to_string();
+
+    let list_response = fixture.get("/api/v1/blacklist?global=true").await;
+    assert_eq!(list_response.status, StatusCode::OK);
+    assert_eq!(list_response.body["total"], 1);
+    assert_eq!(list_response.body["entries"][0]["value"], "BadGroup");
+
+    let remove_response = fixture
+        .delete(&format!("/api/v1/blacklist/{}", entry_id))
+        .await;
+    assert_eq!(remove_response.status, StatusCode::OK);
+
+    let list_response = fixture.get("/api/v1/blacklist").await;
+    assert_eq!(list_response.body["total"], 0);
+
+    let remove_again = fixture
+        .delete(&format!("/api/v1/blacklist/{}", entry_id))
+        .await;
+    assert_eq!(remove_again.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_blacklist_rejects_invalid_entries() {
+    let fixture = TestFixture::new().await;
+
+    let bad_regex = fixture
+        .post(
+            "/api/v1/blacklist",
+            json!({ "kind": "title_regex", "value": "(unclosed" }),
+        )
+        .await;
+    assert_eq!(bad_regex.status, StatusCode::BAD_REQUEST);
+
+    let unknown_ticket = fixture
+        .post(
+            "/api/v1/blacklist",
+            json!({ "kind": "info_hash", "value": "abc123", "ticket_id": "no-such-ticket" }),
+        )
+        .await;
+    assert_eq!(unknown_ticket.status, StatusCode::NOT_FOUND);
+}
+
+// =============================================================================
+// Training Export Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_training_export() {
+    let fixture = TestFixture::new().await;
+
+    let response = fixture
+        .get("/api/v1/training/export?task=query_generation&format=instruction&split=validation")
+        .await;
+    assert_eq!(response.status, StatusCode::OK);
+
+    let unknown_task = fixture.get("/api/v1/training/export?task=unknown").await;
+    assert_eq!(unknown_task.status, StatusCode::BAD_REQUEST);
+
+    let bad_fraction = fixture
+        .get("/api/v1/training/export?task=file_mapping&validation_fraction=2")
+        .await;
+    assert_eq!(bad_fraction.status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_scoring_profiles_without_history() {
+    let fixture = TestFixture::new().await;
+
+    let list = fixture.get("/api/v1/scoring/profiles").await;
+    assert_eq!(list.status, StatusCode::OK);
+    assert_eq!(list.body["total"], 0);
+
+    let active = fixture.get("/api/v1/scoring/profiles/active").await;
+    assert_eq!(active.status, StatusCode::NOT_FOUND);
+
+    // No approvals yet, so there is nothing to fit
+    let calibrate = fixture
+        .post("/api/v1/scoring/profiles/calibrate", json!({}))
+        .await;
+    assert_eq!(calibrate.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(calibrate.body["error"]
+        .as_str()
+        .unwrap()
+        .contains("Not enough labelled samples"));
+
+    let activate = fixture
+        .post("/api/v1/scoring/profiles/missing/activate", json!({}))
+        .await;
+    assert_eq!(activate.status, StatusCode::NOT_FOUND);
+
+    let deactivate = fixture
+        .post("/api/v1/scoring/profiles/deactivate", json!({}))
+        .await;
+    assert_eq!(deactivate.status, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn test_scoring_replay() {
+    let fixture = TestFixture::new().await;
+
+    let response = fixture
+        .post(
+            "/api/v1/scoring/replay",
+            json!({ "candidate": { "auto_approve_threshold": 0.5 } }),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body["sample_count"], 0);
+    assert_eq!(response.body["changed_decisions"], 0);
+    assert!(response.body["changes"].as_array().unwrap().is_empty());
+
+    let bad_threshold = fixture
+        .post(
+            "/api/v1/scoring/replay",
+            json!({ "candidate": { "auto_approve_threshold": 1.5 } }),
+        )
+        .await;
+    assert_eq!(bad_threshold.status, StatusCode::BAD_REQUEST);
+
+    let unknown_profile = fixture
+        .post(
+            "/api/v1/scoring/replay",
+            json!({ "candidate": { "profile_id": "missing" } }),
+        )
+        .await;
+    assert_eq!(unknown_profile.status, StatusCode::NOT_FOUND);
+}
+
 // =============================================================================
 // Searcher Status Tests
 // =============================================================================
@@ -1238,6 +1424,232 @@ async fn test_ticket_with_output_constraints() {
     assert!(response.body["output_constraints"].is_object());
 }
 
+#[tokio::test]
+async fn test_ticket_with_textbrain_overrides() {
+    let fixture = TestFixture::new().await;
+
+    let response = fixture
+        .post(
+            "/api/v1/tickets",
+            json!({
+                "query_context": {
+                    "tags": ["music"],
+                    "description": "bulk import"
+                },
+                "dest_path": "/test/overrides",
+                "textbrain_overrides": {
+                    "mode": "dumb_only",
+                    "auto_approve_threshold": 0.5,
+                    "require_approval": true
+                }
+            }),
+        )
+        .await;
+
+    assert_eq!(response.status, StatusCode::CREATED);
+    assert_eq!(response.body["textbrain_overrides"]["mode"], "dumb_only");
+    assert_eq!(
+        response.body["textbrain_overrides"]["require_approval"],
+        true
+    );
+
+    let id = response.body["id"].as_str().unwrap();
+    let response = fixture.get(&format!("/api/v1/tickets/{}", id)).await;
+    assert_eq!(
+        response.body["textbrain_overrides"]["auto_approve_threshold"],
+        0.5
+    );
+
+    // The test server has no LLM configured
+    let response = fixture
+        .post(
+            "/api/v1/tickets",
+            json!({
+                "query_context": {
+                    "tags": ["music"],
+                    "description": "rare release"
+                },
+                "dest_path": "/test/overrides",
+                "textbrain_overrides": { "mode": "llm_only" }
+            }),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_book_ticket_rejects_conversion() {
+    let fixture = TestFixture::new().await;
+
+    let expected = json!({
+        "type": "book",
+        "author": "Frank Herbert",
+        "title": "Dune",
+        "formats": ["epub", "azw3"]
+    });
+
+    let response = fixture
+        .post(
+            "/api/v1/tickets",
+            json!({
+                "query_context": {
+                    "tags": ["book"],
+                    "description": "Dune",
+                    "expected": expected
+                },
+                "dest_path": "/test/books",
+                "output_constraints": { "type": "audio", "format": "mp3" }
+            }),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+
+    let response = fixture
+        .post(
+            "/api/v1/tickets",
+            json!({
+                "query_context": {
+                    "tags": ["book"],
+                    "description": "Dune",
+                    "expected": expected
+                },
+                "dest_path": "/test/books"
+            }),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::CREATED);
+    assert_eq!(
+        response.body["query_context"]["expected"]["formats"][1],
+        "azw3"
+    );
+}
+
+#[tokio::test]
+async fn test_season_ticket_resolves_episodes() {
+    let fixture = TestFixture::new().await;
+
+    let series = fixtures::tmdb_series("Breaking Bad", 5);
+    fixture.external_catalog.add_series(series.clone()).await;
+    fixture
+        .external_catalog
+        .add_season(series.id, fixtures::tmdb_season(2, 13))
+        .await;
+
+    let response = fixture
+        .post(
+            "/api/v1/tickets",
+            json!({
+                "query_context": {
+                    "tags": ["tv"],
+                    "description": "Breaking Bad season 2",
+                    "expected": {
+                        "type": "tv_season",
+                        "series": "Breaking Bad",
+                        "season": 2
+                    },
+                    "catalog_reference": {
+                        "type": "tmdb",
+                        "id": series.id,
+                        "media_type": "tv"
+                    }
+                },
+                "dest_path": "/test/tv"
+            }),
+        )
+        .await;
+
+    assert_eq!(response.status, StatusCode::CREATED);
+    let episodes = response.body["query_context"]["expected"]["episodes"]
+        .as_array()
+        .unwrap();
+    assert_eq!(episodes.len(), 13);
+    // The episode runtime is kept for size checks of season packs
+    assert_eq!(
+        response.body["query_context"]["catalog_reference"]["runtime_minutes"],
+        45
+    );
+}
+
+#[tokio::test]
+async fn test_discography_ticket_resolves_albums() {
+    let fixture = TestFixture::new().await;
+
+    for (title, year) in [
+        ("OK Computer", 1997),
+        ("Pablo Honey", 1993),
+        ("Kid A", 2000),
+    ] {
+        fixture
+            .external_catalog
+            .add_release_group(fixtures::musicbrainz_release_group(
+                "Radiohead",
+                title,
+                year,
+            ))
+            .await;
+    }
+
+    let response = fixture
+        .post(
+            "/api/v1/tickets",
+            json!({
+                "query_context": {
+                    "tags": ["music"],
+                    "description": "Radiohead discography",
+                    "expected": {
+                        "type": "discography",
+                        "artist": "Radiohead"
+                    }
+                },
+                "dest_path": "/test/music"
+            }),
+        )
+        .await;
+
+    assert_eq!(response.status, StatusCode::CREATED);
+    let albums: Vec<&str> = response.body["query_context"]["expected"]["albums"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(albums, ["Pablo Honey", "OK Computer", "Kid A"]);
+}
+
+#[tokio::test]
+async fn test_anime_ticket_resolves_absolute_offset() {
+    let fixture = TestFixture::new().await;
+
+    fixture
+        .external_catalog
+        .add_series(fixtures::tmdb_series("Frieren", 2))
+        .await;
+
+    let response = fixture
+        .post(
+            "/api/v1/tickets",
+            json!({
+                "query_context": {
+                    "tags": ["anime"],
+                    "description": "Frieren season 2 episode 3",
+                    "expected": {
+                        "type": "tv_episode",
+                        "series": "Frieren",
+                        "season": 2,
+                        "episodes": [3]
+                    }
+                },
+                "dest_path": "/test/anime"
+            }),
+        )
+        .await;
+
+    assert_eq!(response.status, StatusCode::CREATED);
+    let reference = &response.body["query_context"]["catalog_reference"];
+    assert_eq!(reference["type"], "tmdb");
+    assert_eq!(reference["absolute_offset"], 10);
+}
+
 // =============================================================================
 // Pipeline with Fixture Tests
 // =============================================================================